@@ -0,0 +1,65 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  # Internals
+  constantine/named/[algebras, zoo_endomorphisms],
+  constantine/math/[arithmetic, extension_fields],
+  constantine/math/ec_shortweierstrass,
+  # Helpers
+  ./bench_elliptic_template
+
+# ############################################################
+#
+#        Benchmark of fixed-base scalar multiplication
+#        against variable-base scalar multiplication
+#
+# ############################################################
+
+
+const MulIters = 100
+const PrecomputeIters = 10
+const AvailableCurves = [
+  BN254_Snarks,
+  Secp256k1,
+  Pallas,
+  Vesta,
+  BLS12_381,
+]
+const AvailableCurvesG2 = [
+  BN254_Snarks,
+  BLS12_381,
+]
+
+proc main() =
+  separator()
+  staticFor i, 0, AvailableCurves.len:
+    const curve = AvailableCurves[i]
+    const bits = Fr[curve].bits()
+    fixedBasePrecomputeBench(EC_ShortW_Jac[Fp[curve], G1], PrecomputeIters)
+    separator()
+    scalarMulGenericBench(EC_ShortW_Jac[Fp[curve], G1], bits, window = 4, MulIters)
+    when curve.hasEndomorphismAcceleration():
+      scalarMulEndo(EC_ShortW_Jac[Fp[curve], G1], bits, MulIters)
+    scalarMulVartimeWNAFBench(EC_ShortW_Jac[Fp[curve], G1], bits, window = 5, MulIters)
+    scalarMulFixedBaseBench(EC_ShortW_Jac[Fp[curve], G1], bits, MulIters)
+    scalarMulFixedBaseBench(EC_ShortW_Prj[Fp[curve], G1], bits, MulIters)
+    separator()
+  staticFor i, 0, AvailableCurvesG2.len:
+    const curve = AvailableCurvesG2[i]
+    const bits = Fr[curve].bits()
+    fixedBasePrecomputeBench(EC_ShortW_Jac[Fp2[curve], G2], PrecomputeIters)
+    separator()
+    scalarMulGenericBench(EC_ShortW_Jac[Fp2[curve], G2], bits, window = 4, MulIters)
+    scalarMulEndo(EC_ShortW_Jac[Fp2[curve], G2], bits, MulIters)
+    scalarMulVartimeWNAFBench(EC_ShortW_Jac[Fp2[curve], G2], bits, window = 5, MulIters)
+    scalarMulFixedBaseBench(EC_ShortW_Jac[Fp2[curve], G2], bits, MulIters)
+    separator()
+
+main()
+notes()
@@ -24,7 +24,8 @@ import
     ec_shortweierstrass_jacobian,
     ec_shortweierstrass_jacobian_extended,
     ec_shortweierstrass_batch_ops,
-    ec_scalar_mul],
+    ec_scalar_mul,
+    ec_scalar_mul_fixed_base],
     constantine/named/zoo_subgroups,
   # Helpers
   helpers/prng_unsafe,
@@ -234,6 +235,35 @@ proc scalarMulVartimeEndoWNAFBench*(EC: typedesc, bits, window: static int, iter
     r = P
     r.scalarMulEndo_wNAF_vartime(exponent, window)
 
+proc scalarMulFixedBaseBench*(EC: typedesc, bits: static int, iters: int) {.noinline.} =
+  var r {.noInit.}: EC
+  var P = rng.random_unsafe(EC)
+  P.clearCofactor()
+  var Paff {.noInit.}: affine(EC)
+  Paff.affine(P)
+
+  var table: FixedBaseTable[affine(EC)]
+  table.precompute(Paff)
+
+  let exponent = rng.random_unsafe(BigInt[bits])
+
+  bench("EC ScalarMul " & $bits & "-bit " & $EC.G & " (constant-time, fixed-base table)", EC, iters):
+    r.scalarMul(table, exponent)
+
+  table.delete()
+
+proc fixedBasePrecomputeBench*(EC: typedesc, iters: int) {.noinline.} =
+  var P = rng.random_unsafe(EC)
+  P.clearCofactor()
+  var Paff {.noInit.}: affine(EC)
+  Paff.affine(P)
+
+  var table: FixedBaseTable[affine(EC)]
+
+  bench("EC Fixed-base table precompute " & $EC.G, EC, iters):
+    table.precompute(Paff)
+    table.delete()
+
 proc subgroupCheckBench*(EC: typedesc, iters: int) {.noinline.} =
   var P = rng.random_unsafe(EC)
   P.clearCofactor()
@@ -0,0 +1,126 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  constantine/commitments/kzg,
+  constantine/commitments_setups/[powers_of_tau, powers_of_tau_parallel],
+  constantine/math/arithmetic,
+  constantine/platforms/allocs,
+  constantine/threadpool,
+  ./c_curve_decls
+
+export kzg, powers_of_tau, powers_of_tau_parallel
+
+# Overview
+# ------------------------------------------------------------
+#
+# This files provides template for C bindings generation
+# of polynomial commitments over pairing-friendly curves.
+
+template genBindings_KZG*(Curve, EcG1Aff, EcG2Aff, ScalarField: untyped) =
+  when appType == "lib":
+    {.push noconv, dynlib, exportc,  raises: [].} # No exceptions allowed
+  else:
+    {.push noconv, exportc,  raises: [].} # No exceptions allowed
+
+  # --------------------------------------------------------------------------------------
+  func `ctt _ Curve _ kzg_commit_vartime`(
+          commitment: var EcG1Aff,
+          poly: ptr UncheckedArray[ScalarField],
+          powers_of_tau_g1: ptr UncheckedArray[EcG1Aff],
+          len: csize_t) =
+    powers_of_tau_g1.kzg_commit(commitment, poly, cast[int](len))
+
+  func `ctt _ Curve _ kzg_prove_monomial_vartime`(
+          proof: var EcG1Aff,
+          eval_at_challenge: var ScalarField,
+          poly: ptr UncheckedArray[ScalarField],
+          powers_of_tau_g1: ptr UncheckedArray[EcG1Aff],
+          len: csize_t,
+          opening_challenge: ScalarField) =
+    powers_of_tau_g1.kzg_prove_monomial(
+      eval_at_challenge, proof,
+      poly, cast[int](len),
+      opening_challenge)
+
+  func `ctt _ Curve _ kzg_verify_vartime`(
+          commitment: EcG1Aff,
+          opening_challenge, eval_at_challenge: ScalarField,
+          proof: EcG1Aff,
+          tau_g2: EcG2Aff): bool =
+    kzg_verify(
+      commitment,
+      opening_challenge.toBig(), eval_at_challenge.toBig(),
+      proof, tau_g2)
+
+  func `ctt _ Curve _ kzg_verify_batch_vartime`(
+          commitments: ptr UncheckedArray[EcG1Aff],
+          challenges: ptr UncheckedArray[ScalarField],
+          evals_at_challenges: ptr UncheckedArray[ScalarField],
+          proofs: ptr UncheckedArray[EcG1Aff],
+          linear_indep_random_numbers: ptr UncheckedArray[ScalarField],
+          len: csize_t,
+          tau_g2: EcG2Aff): bool =
+    let n = cast[int](len)
+    let evals = allocHeapArrayAligned(ScalarField.getBigInt(), n, alignment = 64)
+    evals.batchFromField(evals_at_challenges, n)
+    result = kzg_verify_batch(
+      commitments, challenges, evals, proofs,
+      linear_indep_random_numbers, n, tau_g2)
+    freeHeapAligned(evals)
+
+  func `ctt _ Curve _ kzg_setup_insecure_for_testing`(
+          powers_of_tau_g1: ptr UncheckedArray[EcG1Aff],
+          len: csize_t,
+          tau_g2: var EcG2Aff,
+          tau: ScalarField) =
+    powers_of_tau_g1.kzg_setup_insecure_for_testing(cast[int](len), tau_g2, tau)
+
+  func `ctt _ Curve _ srs_g1_from_montgomery_le_unchecked`(
+          dst: ptr UncheckedArray[EcG1Aff],
+          src: ptr UncheckedArray[byte],
+          len: csize_t): bool =
+    dst.batchFromMontgomeryLE_unchecked(src, cast[int](len))
+
+  func `ctt _ Curve _ srs_g2_from_montgomery_le_unchecked`(
+          dst: ptr UncheckedArray[EcG2Aff],
+          src: ptr UncheckedArray[byte],
+          len: csize_t): bool =
+    dst.batchFromMontgomeryLE_unchecked(src, cast[int](len))
+
+  func `ctt _ Curve _ srs_monomial_to_lagrange_vartime`(
+          lagrange_g1: ptr UncheckedArray[EcG1Aff],
+          powers_of_tau_g1: ptr UncheckedArray[EcG1Aff],
+          len: csize_t,
+          root_of_unity: ScalarField): bool =
+    lagrange_g1.monomialToLagrange_vartime(powers_of_tau_g1, cast[int](len), root_of_unity)
+
+  {.pop.}
+
+template genParallelBindings_KZG*(Curve, EcG1Aff, EcG2Aff: untyped) =
+  # Like genParallelBindings_EC_ShortW_NonAffine
+  # exportc breaks the threadpool generated closure
+  # hence instead of push/pop we create a pragma alias
+
+  when appType == "lib":
+    {.pragma: libExport, dynlib, exportc,  raises: [].} # No exceptions allowed
+  else:
+    {.pragma: libExport, exportc,  raises: [].} # No exceptions allowed
+
+  # --------------------------------------------------------------------------------------
+  proc `ctt _ Curve _ srs_g1_validate_parallel`(
+          tp: Threadpool,
+          points: ptr UncheckedArray[EcG1Aff],
+          len: csize_t): bool {.libExport.} =
+    tp.validate_parallel(points, cast[int](len))
+
+  proc `ctt _ Curve _ srs_g2_validate_parallel`(
+          tp: Threadpool,
+          points: ptr UncheckedArray[EcG2Aff],
+          len: csize_t): bool {.libExport.} =
+    tp.validate_parallel(points, cast[int](len))
@@ -191,6 +191,38 @@ template genBindingsFieldSqrt*(Field: untyped) =
   {.pop.}
 
 
+template genBindingsFieldNTT*(Field: untyped) =
+  when appType == "lib":
+    {.push noconv, dynlib, exportc,  raises: [].} # No exceptions allowed
+  else:
+    {.push noconv, exportc,  raises: [].} # No exceptions allowed
+
+  # In-place transforms over the multiplicative subgroup generated by omega.
+  # omega MUST be a primitive root of unity of order values_len.
+  # Return false if values_len is 0 or not a power of 2.
+
+  func `ctt _ Field _ ntt_vartime`(values: var openArray[Field], omega: Field): bool =
+    if values.len == 0:
+      return false
+    values.ntt_vartime(omega) == FFTS_Success
+
+  func `ctt _ Field _ intt_vartime`(values: var openArray[Field], omega: Field): bool =
+    if values.len == 0:
+      return false
+    values.intt_vartime(omega) == FFTS_Success
+
+  func `ctt _ Field _ coset_ntt_vartime`(values: var openArray[Field], omega, shift: Field): bool =
+    if values.len == 0:
+      return false
+    values.coset_ntt_vartime(omega, shift) == FFTS_Success
+
+  func `ctt _ Field _ coset_intt_vartime`(values: var openArray[Field], omega, shift: Field): bool =
+    if values.len == 0:
+      return false
+    values.coset_intt_vartime(omega, shift) == FFTS_Success
+
+  {.pop.}
+
 template genBindingsExtField*(Field: untyped) =
   when appType == "lib":
     {.push noconv, dynlib, exportc,  raises: [].} # No exceptions allowed
@@ -415,6 +447,20 @@ template genBindings_EC_ShortW_NonAffine*(EC, EcAff, ScalarBig, ScalarField: unt
 
     P.scalarMul_vartime(scalar)
 
+  func `ctt _ EC _ multi_scalar_mul_big_coefs`(
+          r: var EC,
+          coefs: ptr UncheckedArray[ScalarBig],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) =
+    r.multiScalarMul(coefs, points, cast[int](len))
+
+  func `ctt _ EC _ multi_scalar_mul_fr_coefs`(
+          r: var EC,
+          coefs: ptr UncheckedArray[ScalarField],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) =
+    r.multiScalarMul(coefs, points, cast[int](len))
+
   func `ctt _ EC _ multi_scalar_mul_big_coefs_vartime`(
           r: var EC,
           coefs: ptr UncheckedArray[ScalarBig],
@@ -429,6 +475,28 @@ template genBindings_EC_ShortW_NonAffine*(EC, EcAff, ScalarBig, ScalarField: unt
           len: csize_t)=
     r.multiScalarMul_vartime(coefs, points, cast[int](len))
 
+  func `ctt _ EC _ multi_scalar_mul_u64_coefs_vartime`(
+          r: var EC,
+          coefs: ptr UncheckedArray[uint64],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) =
+    r.multiScalarMul_vartime(coefs, points, cast[int](len))
+
+  func `ctt _ EC _ multi_scalar_mul_i64_coefs_vartime`(
+          r: var EC,
+          coefs: ptr UncheckedArray[int64],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) =
+    r.multiScalarMul_vartime(coefs, points, cast[int](len))
+
+  func `ctt _ EC _ multi_scalar_mul_fr_coefs_max_bits_vartime`(
+          r: var EC,
+          coefs: ptr UncheckedArray[ScalarField],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t,
+          coefs_max_bits: csize_t) =
+    r.multiScalarMul_vartime(coefs, points, cast[int](len), cast[int](coefs_max_bits))
+
   {.pop.}
 
 template genBindings_EC_TwEdw_Affine*(EC, Field: untyped) =
@@ -531,6 +599,20 @@ template genBindings_EC_TwEdw_Projective*(EC, EcAff, ScalarBig, ScalarField: unt
   func `ctt _ EC _ scalar_mul_fr_coef_vartime`(P: var EC, scalar: ScalarField) =
     P.scalarMul_vartime(scalar)
 
+  func `ctt _ EC _ multi_scalar_mul_big_coefs`(
+          r: var EC,
+          coefs: ptr UncheckedArray[ScalarBig],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) =
+    r.multiScalarMul(coefs, points, cast[int](len))
+
+  func `ctt _ EC _ multi_scalar_mul_fr_coefs`(
+          r: var EC,
+          coefs: ptr UncheckedArray[ScalarField],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) =
+    r.multiScalarMul(coefs, points, cast[int](len))
+
   func `ctt _ EC _ multi_scalar_mul_big_coefs_vartime`(
           r: var EC,
           coefs: ptr UncheckedArray[ScalarBig],
@@ -544,9 +626,68 @@ template genBindings_EC_TwEdw_Projective*(EC, EcAff, ScalarBig, ScalarField: unt
           points: ptr UncheckedArray[EcAff],
           len: csize_t)=
     r.multiScalarMul_vartime(coefs, points, cast[int](len))
+
+  func `ctt _ EC _ multi_scalar_mul_u64_coefs_vartime`(
+          r: var EC,
+          coefs: ptr UncheckedArray[uint64],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) =
+    r.multiScalarMul_vartime(coefs, points, cast[int](len))
+
+  func `ctt _ EC _ multi_scalar_mul_i64_coefs_vartime`(
+          r: var EC,
+          coefs: ptr UncheckedArray[int64],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) =
+    r.multiScalarMul_vartime(coefs, points, cast[int](len))
+
+  func `ctt _ EC _ multi_scalar_mul_fr_coefs_max_bits_vartime`(
+          r: var EC,
+          coefs: ptr UncheckedArray[ScalarField],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t,
+          coefs_max_bits: csize_t) =
+    r.multiScalarMul_vartime(coefs, points, cast[int](len), cast[int](coefs_max_bits))
     
   {.pop.}
 
+template genBindings_EC_FixedBaseTable*(Table, EcAff: untyped) =
+  when appType == "lib":
+    {.push noconv, dynlib, exportc,  raises: [].} # No exceptions allowed
+  else:
+    {.push noconv, exportc,  raises: [].} # No exceptions allowed
+
+  # --------------------------------------------------------------------------------------
+  func `ctt _ Table _ precompute`(table: var Table, base: EcAff) =
+    table.precompute(base)
+
+  func `ctt _ Table _ precompute_generator`(table: var Table) =
+    var g {.noInit.}: EcAff
+    g.setGenerator()
+    table.precompute(g)
+
+  func `ctt _ Table _ delete`(table: var Table) =
+    table.delete()
+
+  {.pop.}
+
+template genBindings_EC_FixedBaseScalarMul*(EC, Table, ScalarBig, ScalarField: untyped) =
+  when appType == "lib":
+    {.push noconv, dynlib, exportc,  raises: [].} # No exceptions allowed
+  else:
+    {.push noconv, exportc,  raises: [].} # No exceptions allowed
+
+  # --------------------------------------------------------------------------------------
+  func `ctt _ EC _ fixed_base_scalar_mul_big_coef`(
+          r: var EC, table: ptr Table, scalar: ScalarBig) =
+    r.scalarMul(table[], scalar)
+
+  func `ctt _ EC _ fixed_base_scalar_mul_fr_coef`(
+          r: var EC, table: ptr Table, scalar: ScalarField) =
+    r.scalarMul(table[], scalar)
+
+  {.pop.}
+
 template genBindings_EC_hash_to_curve*(EC: untyped, mapping, hash: untyped, k: static int) =
   when appType == "lib":
     {.push noconv, dynlib, exportc,  raises: [].} # No exceptions allowed
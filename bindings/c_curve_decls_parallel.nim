@@ -8,11 +8,56 @@
 
 import
   constantine/lowlevel_elliptic_curves_parallel,
+  constantine/math/polynomials/fft_parallel,
+  constantine/platforms/primitives,
   constantine/threadpool
 
 export lowlevel_elliptic_curves_parallel
 
-template genParallelBindings_EC_ShortW_NonAffine*(EC, EcAff, ScalarField: untyped) =
+template genParallelBindings_FieldNTT*(Field: untyped) =
+  ## In-place transforms over the multiplicative subgroup generated by omega.
+  ## omega MUST be a primitive root of unity of order values_len.
+  ## Return false if values_len is 0 or not a power of 2.
+
+  when appType == "lib":
+    {.pragma: libExport, dynlib, exportc,  raises: [].} # No exceptions allowed
+  else:
+    {.pragma: libExport, exportc,  raises: [].} # No exceptions allowed
+
+  # --------------------------------------------------------------------------------------
+  proc `ctt _ Field _ ntt_vartime_parallel`(
+          tp: Threadpool,
+          values: var openArray[Field],
+          omega: Field): bool {.libExport.} =
+    if values.len == 0:
+      return false
+    tp.ntt_vartime_parallel(values.asUnchecked(), values.len, omega) == FFTS_Success
+
+  proc `ctt _ Field _ intt_vartime_parallel`(
+          tp: Threadpool,
+          values: var openArray[Field],
+          omega: Field): bool {.libExport.} =
+    if values.len == 0:
+      return false
+    tp.intt_vartime_parallel(values.asUnchecked(), values.len, omega) == FFTS_Success
+
+  proc `ctt _ Field _ coset_ntt_vartime_parallel`(
+          tp: Threadpool,
+          values: var openArray[Field],
+          omega, shift: Field): bool {.libExport.} =
+    if values.len == 0:
+      return false
+    tp.coset_ntt_vartime_parallel(values.asUnchecked(), values.len, omega, shift) == FFTS_Success
+
+  proc `ctt _ Field _ coset_intt_vartime_parallel`(
+          tp: Threadpool,
+          values: var openArray[Field],
+          omega, shift: Field): bool {.libExport.} =
+    if values.len == 0:
+      return false
+    tp.coset_intt_vartime_parallel(values.asUnchecked(), values.len, omega, shift) == FFTS_Success
+
+template genParallelBindings_EC_ShortW_NonAffine*(EC, EcAff, Table, ScalarField: untyped) =
   # TODO: remove the need of explicit ScalarField
 
   # For some unknown reason {.push noconv.}
@@ -43,3 +88,153 @@ template genParallelBindings_EC_ShortW_NonAffine*(EC, EcAff, ScalarField: untype
           points: ptr UncheckedArray[EcAff],
           len: csize_t) {.libExport.} =
     tp.multiScalarMul_vartime_parallel(r.addr, coefs, points, cast[int](len))
+
+  proc `ctt _ EC _ multi_scalar_mul_u64_coefs_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          coefs: ptr UncheckedArray[uint64],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) {.libExport.} =
+    tp.multiScalarMul_vartime_parallel(r.addr, coefs, points, cast[int](len))
+
+  proc `ctt _ EC _ multi_scalar_mul_i64_coefs_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          coefs: ptr UncheckedArray[int64],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) {.libExport.} =
+    tp.multiScalarMul_vartime_parallel(r.addr, coefs, points, cast[int](len))
+
+  proc `ctt _ EC _ multi_scalar_mul_fr_coefs_max_bits_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          coefs: ptr UncheckedArray[ScalarField],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t,
+          coefs_max_bits: csize_t) {.libExport.} =
+    tp.multiScalarMul_vartime_parallel(r.addr, coefs, points, cast[int](len), cast[int](coefs_max_bits))
+
+  proc `ctt _ EC _ multi_scalar_mul_fr_coefs_table_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          coefs: ptr UncheckedArray[ScalarField],
+          table: ptr Table,
+          len: csize_t) {.libExport.} =
+    tp.multiScalarMul_vartime_parallel(r.addr, coefs, table, cast[int](len))
+
+  # Batch of MSMs with the same bases.
+  # `coefs` holds r_len pointers to rows of `len` coefficients, one per MSM.
+  proc `ctt _ EC _ batch_multi_scalar_mul_fr_coefs_vartime_parallel`(
+          tp: Threadpool,
+          r: var openArray[EC],
+          coefs: ptr UncheckedArray[ptr UncheckedArray[ScalarField]],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) {.libExport.} =
+    if r.len == 0:
+      return
+    tp.multiScalarMul_batch_vartime_parallel(r.asUnchecked(), coefs, r.len, points, cast[int](len))
+
+  proc `ctt _ EC _ batch_multi_scalar_mul_fr_coefs_table_vartime_parallel`(
+          tp: Threadpool,
+          r: var openArray[EC],
+          coefs: ptr UncheckedArray[ptr UncheckedArray[ScalarField]],
+          table: ptr Table,
+          len: csize_t) {.libExport.} =
+    if r.len == 0:
+      return
+    tp.multiScalarMul_batch_vartime_parallel(r.asUnchecked(), coefs, r.len, table, cast[int](len))
+
+  proc `ctt _ EC _ batch_affine_parallel`(
+          tp: Threadpool,
+          dst: ptr UncheckedArray[EcAff],
+          src: ptr UncheckedArray[EC],
+          n: csize_t) {.libExport.} =
+    tp.batchAffine_parallel(dst, src, cast[int](n))
+
+  proc `ctt _ EC _ sum_reduce_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) {.libExport.} =
+    tp.sum_reduce_vartime_parallel(r, points.toOpenArray(0, cast[int](len)-1))
+
+template genParallelBindings_EC_TwEdw_Projective*(EC, EcAff, ScalarField: untyped) =
+  when appType == "lib":
+    {.pragma: libExport, dynlib, exportc,  raises: [].} # No exceptions allowed
+  else:
+    {.pragma: libExport, exportc,  raises: [].} # No exceptions allowed
+
+  # --------------------------------------------------------------------------------------
+  proc `ctt _ EC _ multi_scalar_mul_big_coefs_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          coefs: ptr UncheckedArray[BigInt[ScalarField.bits()]],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) {.libExport.} =
+    tp.multiScalarMul_vartime_parallel(r.addr, coefs, points, cast[int](len))
+
+  proc `ctt _ EC _ multi_scalar_mul_fr_coefs_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          coefs: ptr UncheckedArray[ScalarField],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) {.libExport.} =
+    tp.multiScalarMul_vartime_parallel(r.addr, coefs, points, cast[int](len))
+
+  proc `ctt _ EC _ multi_scalar_mul_u64_coefs_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          coefs: ptr UncheckedArray[uint64],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) {.libExport.} =
+    tp.multiScalarMul_vartime_parallel(r.addr, coefs, points, cast[int](len))
+
+  proc `ctt _ EC _ multi_scalar_mul_i64_coefs_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          coefs: ptr UncheckedArray[int64],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) {.libExport.} =
+    tp.multiScalarMul_vartime_parallel(r.addr, coefs, points, cast[int](len))
+
+  proc `ctt _ EC _ multi_scalar_mul_fr_coefs_max_bits_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          coefs: ptr UncheckedArray[ScalarField],
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t,
+          coefs_max_bits: csize_t) {.libExport.} =
+    tp.multiScalarMul_vartime_parallel(r.addr, coefs, points, cast[int](len), cast[int](coefs_max_bits))
+
+  proc `ctt _ EC _ batch_affine_parallel`(
+          tp: Threadpool,
+          dst: ptr UncheckedArray[EcAff],
+          src: ptr UncheckedArray[EC],
+          n: csize_t) {.libExport.} =
+    tp.batchAffine_parallel(dst, src, cast[int](n))
+
+  proc `ctt _ EC _ sum_reduce_vartime_parallel`(
+          tp: Threadpool,
+          r: var EC,
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) {.libExport.} =
+    tp.sum_reduce_vartime_parallel(r, points.toOpenArray(0, cast[int](len)-1))
+
+template genParallelBindings_EC_ShortW_MsmTable*(Table, EcAff: untyped) =
+  ## Tables of fixed MSM bases, see `MsmBasesTable`
+
+  when appType == "lib":
+    {.pragma: libExport, dynlib, exportc,  raises: [].} # No exceptions allowed
+  else:
+    {.pragma: libExport, exportc,  raises: [].} # No exceptions allowed
+
+  # --------------------------------------------------------------------------------------
+  proc `ctt _ Table _ precompute_parallel`(
+          tp: Threadpool,
+          table: var Table,
+          points: ptr UncheckedArray[EcAff],
+          len: csize_t) {.libExport.} =
+    tp.precompute_parallel(table, points, cast[int](len))
+
+  proc `ctt _ Table _ delete`(table: var Table) {.libExport.} =
+    table.delete()
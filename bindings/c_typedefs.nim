@@ -99,6 +99,12 @@ proc genExtField*(name: string, degree: int, basename: string): string =
 proc genEllipticCurvePoint*(name, coords, basename: string): string =
   &"typedef struct {{ {basename} {coords}; }} {name};"
 
+proc genMsmTable*(name, pointname: string): string =
+  &"typedef struct {{ size_t len; {pointname}* points; }} {name};"
+
+proc genFixedBaseTable*(name, pointname: string): string =
+  &"typedef struct {{ {pointname}* points; }} {name};"
+
 # Nim internals
 # -------------------------------------------
 
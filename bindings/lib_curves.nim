@@ -15,8 +15,9 @@
 import
   ./macro_curves_bindings,
   ./c_curve_decls,
-  ./c_curve_decls_parallel
-export c_curve_decls, c_curve_decls_parallel
+  ./c_curve_decls_parallel,
+  ./c_commitments_decls
+export c_curve_decls, c_curve_decls_parallel, c_commitments_decls
 
 type
   big253 = BigInt[253]
@@ -39,9 +40,13 @@ type
   bls12_381_g1_aff = EC_ShortW_Aff[Fp[BLS12_381], G1]
   bls12_381_g1_jac = EC_ShortW_Jac[Fp[BLS12_381], G1]
   bls12_381_g1_prj = EC_ShortW_Prj[Fp[BLS12_381], G1]
+  bls12_381_g1_msm_table = MsmBasesTable[bls12_381_g1_aff]
+  bls12_381_g1_fixed_base_table = FixedBaseTable[bls12_381_g1_aff]
   bls12_381_g2_aff = EC_ShortW_Aff[Fp2[BLS12_381], G2]
   bls12_381_g2_jac = EC_ShortW_Jac[Fp2[BLS12_381], G2]
   bls12_381_g2_prj = EC_ShortW_Prj[Fp2[BLS12_381], G2]
+  bls12_381_g2_msm_table = MsmBasesTable[bls12_381_g2_aff]
+  bls12_381_g2_fixed_base_table = FixedBaseTable[bls12_381_g2_aff]
 
 collectBindings(cBindings_bls12_381):
   genBindingsField(big255, bls12_381_fr)
@@ -52,19 +57,31 @@ collectBindings(cBindings_bls12_381):
   genBindings_EC_ShortW_Affine(bls12_381_g1_aff, bls12_381_fp)
   genBindings_EC_ShortW_NonAffine(bls12_381_g1_jac, bls12_381_g1_aff, big255, bls12_381_fr)
   genBindings_EC_ShortW_NonAffine(bls12_381_g1_prj, bls12_381_g1_aff, big255, bls12_381_fr)
+  genBindings_EC_FixedBaseTable(bls12_381_g1_fixed_base_table, bls12_381_g1_aff)
+  genBindings_EC_FixedBaseScalarMul(bls12_381_g1_jac, bls12_381_g1_fixed_base_table, big255, bls12_381_fr)
+  genBindings_EC_FixedBaseScalarMul(bls12_381_g1_prj, bls12_381_g1_fixed_base_table, big255, bls12_381_fr)
   genBindings_EC_ShortW_Affine(bls12_381_g2_aff, bls12_381_fp2)
   genBindings_EC_ShortW_NonAffine(bls12_381_g2_jac, bls12_381_g2_aff, big255, bls12_381_fr)
   genBindings_EC_ShortW_NonAffine(bls12_381_g2_prj, bls12_381_g2_aff, big255, bls12_381_fr)
+  genBindings_EC_FixedBaseTable(bls12_381_g2_fixed_base_table, bls12_381_g2_aff)
+  genBindings_EC_FixedBaseScalarMul(bls12_381_g2_jac, bls12_381_g2_fixed_base_table, big255, bls12_381_fr)
+  genBindings_EC_FixedBaseScalarMul(bls12_381_g2_prj, bls12_381_g2_fixed_base_table, big255, bls12_381_fr)
   genBindings_EC_hash_to_curve(bls12_381_g1_aff, sswu, sha256, k = 128)
   genBindings_EC_hash_to_curve(bls12_381_g1_jac, sswu, sha256, k = 128)
   genBindings_EC_hash_to_curve(bls12_381_g1_prj, sswu, sha256, k = 128)
   genBindings_EC_hash_to_curve(bls12_381_g2_aff, sswu, sha256, k = 128)
   genBindings_EC_hash_to_curve(bls12_381_g2_jac, sswu, sha256, k = 128)
   genBindings_EC_hash_to_curve(bls12_381_g2_prj, sswu, sha256, k = 128)
+  genBindings_KZG(bls12_381, bls12_381_g1_aff, bls12_381_g2_aff, bls12_381_fr)
 
 collectBindings(cBindings_bls12_381_parallel):
-  genParallelBindings_EC_ShortW_NonAffine(bls12_381_g1_jac, bls12_381_g1_aff, bls12_381_fr)
-  genParallelBindings_EC_ShortW_NonAffine(bls12_381_g1_prj, bls12_381_g1_aff, bls12_381_fr)
+  genParallelBindings_EC_ShortW_MsmTable(bls12_381_g1_msm_table, bls12_381_g1_aff)
+  genParallelBindings_EC_ShortW_NonAffine(bls12_381_g1_jac, bls12_381_g1_aff, bls12_381_g1_msm_table, bls12_381_fr)
+  genParallelBindings_EC_ShortW_NonAffine(bls12_381_g1_prj, bls12_381_g1_aff, bls12_381_g1_msm_table, bls12_381_fr)
+  genParallelBindings_EC_ShortW_MsmTable(bls12_381_g2_msm_table, bls12_381_g2_aff)
+  genParallelBindings_EC_ShortW_NonAffine(bls12_381_g2_jac, bls12_381_g2_aff, bls12_381_g2_msm_table, bls12_381_fr)
+  genParallelBindings_EC_ShortW_NonAffine(bls12_381_g2_prj, bls12_381_g2_aff, bls12_381_g2_msm_table, bls12_381_fr)
+  genParallelBindings_KZG(bls12_381, bls12_381_g1_aff, bls12_381_g2_aff)
 # ----------------------------------------------------------
 
 type
@@ -74,12 +91,17 @@ type
   bn254_snarks_g1_aff = EC_ShortW_Aff[Fp[BN254_Snarks], G1]
   bn254_snarks_g1_jac = EC_ShortW_Jac[Fp[BN254_Snarks], G1]
   bn254_snarks_g1_prj = EC_ShortW_Prj[Fp[BN254_Snarks], G1]
+  bn254_snarks_g1_msm_table = MsmBasesTable[bn254_snarks_g1_aff]
+  bn254_snarks_g1_fixed_base_table = FixedBaseTable[bn254_snarks_g1_aff]
   bn254_snarks_g2_aff = EC_ShortW_Aff[Fp2[BN254_Snarks], G2]
   bn254_snarks_g2_jac = EC_ShortW_Jac[Fp2[BN254_Snarks], G2]
   bn254_snarks_g2_prj = EC_ShortW_Prj[Fp2[BN254_Snarks], G2]
+  bn254_snarks_g2_msm_table = MsmBasesTable[bn254_snarks_g2_aff]
+  bn254_snarks_g2_fixed_base_table = FixedBaseTable[bn254_snarks_g2_aff]
 
 collectBindings(cBindings_bn254_snarks):
   genBindingsField(big254, bn254_snarks_fr)
+  genBindingsFieldNTT(bn254_snarks_fr)
   genBindingsField(big254, bn254_snarks_fp)
   genBindingsFieldSqrt(bn254_snarks_fp)
   genBindingsExtField(bn254_snarks_fp2)
@@ -87,19 +109,32 @@ collectBindings(cBindings_bn254_snarks):
   genBindings_EC_ShortW_Affine(bn254_snarks_g1_aff, bn254_snarks_fp)
   genBindings_EC_ShortW_NonAffine(bn254_snarks_g1_jac, bn254_snarks_g1_aff, big254, bn254_snarks_fr)
   genBindings_EC_ShortW_NonAffine(bn254_snarks_g1_prj, bn254_snarks_g1_aff, big254, bn254_snarks_fr)
+  genBindings_EC_FixedBaseTable(bn254_snarks_g1_fixed_base_table, bn254_snarks_g1_aff)
+  genBindings_EC_FixedBaseScalarMul(bn254_snarks_g1_jac, bn254_snarks_g1_fixed_base_table, big254, bn254_snarks_fr)
+  genBindings_EC_FixedBaseScalarMul(bn254_snarks_g1_prj, bn254_snarks_g1_fixed_base_table, big254, bn254_snarks_fr)
   genBindings_EC_ShortW_Affine(bn254_snarks_g2_aff, bn254_snarks_fp2)
   genBindings_EC_ShortW_NonAffine(bn254_snarks_g2_jac, bn254_snarks_g2_aff, big254, bn254_snarks_fr)
   genBindings_EC_ShortW_NonAffine(bn254_snarks_g2_prj, bn254_snarks_g2_aff, big254, bn254_snarks_fr)
+  genBindings_EC_FixedBaseTable(bn254_snarks_g2_fixed_base_table, bn254_snarks_g2_aff)
+  genBindings_EC_FixedBaseScalarMul(bn254_snarks_g2_jac, bn254_snarks_g2_fixed_base_table, big254, bn254_snarks_fr)
+  genBindings_EC_FixedBaseScalarMul(bn254_snarks_g2_prj, bn254_snarks_g2_fixed_base_table, big254, bn254_snarks_fr)
   genBindings_EC_hash_to_curve(bn254_snarks_g1_aff, svdw, sha256, k = 128)
   genBindings_EC_hash_to_curve(bn254_snarks_g1_jac, svdw, sha256, k = 128)
   genBindings_EC_hash_to_curve(bn254_snarks_g1_prj, svdw, sha256, k = 128)
   genBindings_EC_hash_to_curve(bn254_snarks_g2_aff, svdw, sha256, k = 128)
   genBindings_EC_hash_to_curve(bn254_snarks_g2_jac, svdw, sha256, k = 128)
   genBindings_EC_hash_to_curve(bn254_snarks_g2_prj, svdw, sha256, k = 128)
+  genBindings_KZG(bn254_snarks, bn254_snarks_g1_aff, bn254_snarks_g2_aff, bn254_snarks_fr)
 
 collectBindings(cBindings_bn254_snarks_parallel):
-  genParallelBindings_EC_ShortW_NonAffine(bn254_snarks_g1_jac, bn254_snarks_g1_aff, bn254_snarks_fr)
-  genParallelBindings_EC_ShortW_NonAffine(bn254_snarks_g1_prj, bn254_snarks_g1_aff, bn254_snarks_fr)
+  genParallelBindings_FieldNTT(bn254_snarks_fr)
+  genParallelBindings_EC_ShortW_MsmTable(bn254_snarks_g1_msm_table, bn254_snarks_g1_aff)
+  genParallelBindings_EC_ShortW_NonAffine(bn254_snarks_g1_jac, bn254_snarks_g1_aff, bn254_snarks_g1_msm_table, bn254_snarks_fr)
+  genParallelBindings_EC_ShortW_NonAffine(bn254_snarks_g1_prj, bn254_snarks_g1_aff, bn254_snarks_g1_msm_table, bn254_snarks_fr)
+  genParallelBindings_EC_ShortW_MsmTable(bn254_snarks_g2_msm_table, bn254_snarks_g2_aff)
+  genParallelBindings_EC_ShortW_NonAffine(bn254_snarks_g2_jac, bn254_snarks_g2_aff, bn254_snarks_g2_msm_table, bn254_snarks_fr)
+  genParallelBindings_EC_ShortW_NonAffine(bn254_snarks_g2_prj, bn254_snarks_g2_aff, bn254_snarks_g2_msm_table, bn254_snarks_fr)
+  genParallelBindings_KZG(bn254_snarks, bn254_snarks_g1_aff, bn254_snarks_g2_aff)
 
 # ----------------------------------------------------------
 
@@ -109,18 +144,26 @@ type
   pallas_ec_aff = EC_ShortW_Aff[Fp[Pallas], G1]
   pallas_ec_jac = EC_ShortW_Jac[Fp[Pallas], G1]
   pallas_ec_prj = EC_ShortW_Prj[Fp[Pallas], G1]
+  pallas_ec_msm_table = MsmBasesTable[pallas_ec_aff]
+  pallas_ec_fixed_base_table = FixedBaseTable[pallas_ec_aff]
 
 collectBindings(cBindings_pallas):
   genBindingsField(big255, pallas_fr)
+  genBindingsFieldNTT(pallas_fr)
   genBindingsField(big255, pallas_fp)
   genBindingsFieldSqrt(pallas_fp)
   genBindings_EC_ShortW_Affine(pallas_ec_aff, pallas_fp)
   genBindings_EC_ShortW_NonAffine(pallas_ec_jac, pallas_ec_aff, big255, pallas_fr)
   genBindings_EC_ShortW_NonAffine(pallas_ec_prj, pallas_ec_aff, big255, pallas_fr)
+  genBindings_EC_FixedBaseTable(pallas_ec_fixed_base_table, pallas_ec_aff)
+  genBindings_EC_FixedBaseScalarMul(pallas_ec_jac, pallas_ec_fixed_base_table, big255, pallas_fr)
+  genBindings_EC_FixedBaseScalarMul(pallas_ec_prj, pallas_ec_fixed_base_table, big255, pallas_fr)
 
 collectBindings(cBindings_pallas_parallel):
-  genParallelBindings_EC_ShortW_NonAffine(pallas_ec_jac, pallas_ec_aff, pallas_fr)
-  genParallelBindings_EC_ShortW_NonAffine(pallas_ec_prj, pallas_ec_aff, pallas_fr)
+  genParallelBindings_FieldNTT(pallas_fr)
+  genParallelBindings_EC_ShortW_MsmTable(pallas_ec_msm_table, pallas_ec_aff)
+  genParallelBindings_EC_ShortW_NonAffine(pallas_ec_jac, pallas_ec_aff, pallas_ec_msm_table, pallas_fr)
+  genParallelBindings_EC_ShortW_NonAffine(pallas_ec_prj, pallas_ec_aff, pallas_ec_msm_table, pallas_fr)
 
 type
   vesta_fr = Fr[Vesta]
@@ -128,18 +171,26 @@ type
   vesta_ec_aff = EC_ShortW_Aff[Fp[Vesta], G1]
   vesta_ec_jac = EC_ShortW_Jac[Fp[Vesta], G1]
   vesta_ec_prj = EC_ShortW_Prj[Fp[Vesta], G1]
+  vesta_ec_msm_table = MsmBasesTable[vesta_ec_aff]
+  vesta_ec_fixed_base_table = FixedBaseTable[vesta_ec_aff]
 
 collectBindings(cBindings_vesta):
   genBindingsField(big255, vesta_fr)
+  genBindingsFieldNTT(vesta_fr)
   genBindingsField(big255, vesta_fp)
   genBindingsFieldSqrt(vesta_fp)
   genBindings_EC_ShortW_Affine(vesta_ec_aff, vesta_fp)
   genBindings_EC_ShortW_NonAffine(vesta_ec_jac, vesta_ec_aff, big255, vesta_fr)
   genBindings_EC_ShortW_NonAffine(vesta_ec_prj, vesta_ec_aff, big255, vesta_fr)
+  genBindings_EC_FixedBaseTable(vesta_ec_fixed_base_table, vesta_ec_aff)
+  genBindings_EC_FixedBaseScalarMul(vesta_ec_jac, vesta_ec_fixed_base_table, big255, vesta_fr)
+  genBindings_EC_FixedBaseScalarMul(vesta_ec_prj, vesta_ec_fixed_base_table, big255, vesta_fr)
 
 collectBindings(cBindings_vesta_parallel):
-  genParallelBindings_EC_ShortW_NonAffine(vesta_ec_jac, vesta_ec_aff, vesta_fr)
-  genParallelBindings_EC_ShortW_NonAffine(vesta_ec_prj, vesta_ec_aff, vesta_fr)
+  genParallelBindings_FieldNTT(vesta_fr)
+  genParallelBindings_EC_ShortW_MsmTable(vesta_ec_msm_table, vesta_ec_aff)
+  genParallelBindings_EC_ShortW_NonAffine(vesta_ec_jac, vesta_ec_aff, vesta_ec_msm_table, vesta_fr)
+  genParallelBindings_EC_ShortW_NonAffine(vesta_ec_prj, vesta_ec_aff, vesta_ec_msm_table, vesta_fr)
 
 # ----------------------------------------------------------
 
@@ -148,6 +199,7 @@ type
   banderwagon_fp = Fp[Banderwagon]
   banderwagon_ec_aff = EC_TwEdw_Aff[Fp[Banderwagon]]
   banderwagon_ec_prj = EC_TwEdw_Prj[Fp[Banderwagon]]
+  banderwagon_ec_fixed_base_table = FixedBaseTable[banderwagon_ec_aff]
 
 collectBindings(cBindings_banderwagon):
   genBindingsField(big253, banderwagon_fr)
@@ -155,3 +207,8 @@ collectBindings(cBindings_banderwagon):
   genBindingsFieldSqrt(banderwagon_fp)
   genBindings_EC_TwEdw_Affine(banderwagon_ec_aff, banderwagon_fp)
   genBindings_EC_TwEdw_Projective(banderwagon_ec_prj, banderwagon_ec_aff, big253, banderwagon_fr)
+  genBindings_EC_FixedBaseTable(banderwagon_ec_fixed_base_table, banderwagon_ec_aff)
+  genBindings_EC_FixedBaseScalarMul(banderwagon_ec_prj, banderwagon_ec_fixed_base_table, big253, banderwagon_fr)
+
+collectBindings(cBindings_banderwagon_parallel):
+  genParallelBindings_EC_TwEdw_Projective(banderwagon_ec_prj, banderwagon_ec_aff, banderwagon_fr)
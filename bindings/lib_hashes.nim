@@ -19,7 +19,10 @@ import constantine/zoo_exports
 # static:
 #   prefix_sha256 = prefix_ffi & "sha256_"
 
-import constantine/hashes
+import
+  constantine/hashes,
+  constantine/hashes/h_sha256_parallel,
+  constantine/threadpool
 
 func sha256_hash(digest: var array[32, byte], message: openArray[byte], clearMem: bool) {.libPrefix: "ctt_".} =
   ## Compute the SHA-256 hash of message
@@ -32,3 +35,85 @@ func sha256_hash(digest: var array[32, byte], message: openArray[byte], clearMem
   # - Can be tail-call optimized into a goto jump instead of call/return
   # - Can be LTO-optimized
   sha256.hash(digest, message, clearMem)
+
+func sha256_hash_64byte_pairs(
+       digests: ptr UncheckedArray[array[32, byte]],
+       pairs: ptr UncheckedArray[array[64, byte]],
+       len: int) {.libPrefix: "ctt_".} =
+  ## Compute the SHA-256 hash of `len` 64-byte inputs
+  ## and store the results in digests.
+  sha256.hash_64byte_pairs(digests.toOpenArray(0, len-1), pairs.toOpenArray(0, len-1))
+
+proc sha256_hash_64byte_pairs_parallel(
+       tp: Threadpool,
+       digests: ptr UncheckedArray[array[32, byte]],
+       pairs: ptr UncheckedArray[array[64, byte]],
+       len: int) {.libPrefix: "ctt_".} =
+  ## Compute the SHA-256 hash of `len` 64-byte inputs
+  ## and store the results in digests.
+  ##
+  ## Parallelism: This only returns when computation is fully done
+  tp.hash_64byte_pairs_parallel(sha256, digests, pairs, len)
+
+func sha256_backend(): Sha256Backend {.libPrefix: "ctt_".} =
+  ## Returns the SHA-256 implementation selected at runtime
+  ## depending on the CPU features.
+  sha256.backend()
+
+func ripemd160_hash(digest: var array[20, byte], message: openArray[byte], clearMem: bool) {.libPrefix: "ctt_".} =
+  ## Compute the RIPEMD-160 hash of message
+  ## and store the result in digest.
+  ## Optionally, clear the memory buffer used.
+  ripemd160.hash(digest, message, clearMem)
+
+# Keccak and SHA3 contexts are generic over the digest size and delimiter
+# so they cannot be exported directly via `libPrefix`
+# ---------------------------------------------------------------------------
+
+func keccak256_init(ctx: var keccak256) {.libPrefix: "ctt_".} =
+  ## Initialize or reinitialize a Keccak-256 context.
+  ctx.init()
+
+func keccak256_update(ctx: var keccak256, message: openArray[byte]) {.libPrefix: "ctt_".} =
+  ## Append a message to a Keccak-256 context
+  ## for incremental Keccak-256 computation
+  ctx.update(message)
+
+func keccak256_finish(ctx: var keccak256, digest: var array[32, byte]) {.libPrefix: "ctt_".} =
+  ## Finalize a Keccak-256 computation and output the
+  ## message digest to the `digest` buffer.
+  ctx.finish(digest)
+
+func keccak256_clear(ctx: var keccak256) {.libPrefix: "ctt_".} =
+  ## Clear the context internal buffers
+  ctx.clear()
+
+func keccak256_hash(digest: var array[32, byte], message: openArray[byte], clearMem: bool) {.libPrefix: "ctt_".} =
+  ## Compute the Keccak-256 hash of message
+  ## and store the result in digest.
+  ## Optionally, clear the memory buffer used.
+  keccak256.hash(digest, message, clearMem)
+
+func sha3_256_init(ctx: var sha3_256) {.libPrefix: "ctt_".} =
+  ## Initialize or reinitialize a SHA3-256 context.
+  ctx.init()
+
+func sha3_256_update(ctx: var sha3_256, message: openArray[byte]) {.libPrefix: "ctt_".} =
+  ## Append a message to a SHA3-256 context
+  ## for incremental SHA3-256 computation
+  ctx.update(message)
+
+func sha3_256_finish(ctx: var sha3_256, digest: var array[32, byte]) {.libPrefix: "ctt_".} =
+  ## Finalize a SHA3-256 computation and output the
+  ## message digest to the `digest` buffer.
+  ctx.finish(digest)
+
+func sha3_256_clear(ctx: var sha3_256) {.libPrefix: "ctt_".} =
+  ## Clear the context internal buffers
+  ctx.clear()
+
+func sha3_256_hash(digest: var array[32, byte], message: openArray[byte], clearMem: bool) {.libPrefix: "ctt_".} =
+  ## Compute the SHA3-256 hash of message
+  ## and store the result in digest.
+  ## Optionally, clear the memory buffer used.
+  sha3_256.hash(digest, message, clearMem)
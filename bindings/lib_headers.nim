@@ -28,6 +28,8 @@ proc writeHeader_classicCurve(filepath: string, curve: string, modBits, orderBit
   header &= '\n'
   header &= genEllipticCurvePoint(&"{curve}_ec_prj", "x, y, z", &"{curve}_fp")
   header &= '\n'
+  header &= genFixedBaseTable(&"{curve}_ec_fixed_base_table", &"{curve}_ec_aff")
+  header &= '\n'
   header &= curve_decls
   header &= '\n'
 
@@ -60,6 +62,10 @@ proc writeHeader_pairingFriendly(filepath: string, curve: string, modBits, order
   header &= '\n'
   header &= genEllipticCurvePoint(&"{curve}_g2_prj", "x, y, z", &"{curve}_{fpK}")
   header &= '\n'
+  header &= genFixedBaseTable(&"{curve}_g1_fixed_base_table", &"{curve}_g1_aff")
+  header &= '\n'
+  header &= genFixedBaseTable(&"{curve}_g2_fixed_base_table", &"{curve}_g2_aff")
+  header &= '\n'
   header &= curve_decls
   header &= '\n'
 
@@ -97,7 +103,17 @@ proc writeParallelHeader(dirPath: string, Name: static Algebra, curve_decls: str
   includes &= &"#include \"constantine/curves/{curve}.h\""
   includes &= '\n'
 
-  var header: string
+  let g1 = when Name.family() == NoFamily: "ec"
+           else: "g1"
+
+  var header = "\n"
+  when Name.getEquationForm() == ShortWeierstrass:
+    header &= genMsmTable(&"{curve}_{g1}_msm_table", &"{curve}_{g1}_aff")
+    header &= '\n'
+    when Name.isPairingFriendly():
+      header &= genMsmTable(&"{curve}_g2_msm_table", &"{curve}_g2_aff")
+      header &= '\n'
+    header &= '\n'
   header &= curve_decls
   header &= '\n'
 
@@ -148,7 +164,8 @@ proc writeCurveParallelHeaders(dir: string) =
     BLS12_381: cBindings_bls12_381_parallel,
     BN254_Snarks: cBindings_bn254_snarks_parallel,
     Pallas: cBindings_pallas_parallel,
-    Vesta: cBindings_vesta_parallel
+    Vesta: cBindings_vesta_parallel,
+    Banderwagon: cBindings_banderwagon_parallel
   }
 
   var bigSizes = initIntSet()
@@ -158,9 +175,6 @@ proc writeCurveParallelHeaders(dir: string) =
     bigSizes.incl(Fp[curveMappings[i][0]].bits())
     bigSizes.incl(Fr[curveMappings[i][0]].bits())
 
-  #bigInt header for banderwagon
-  bigSizes.incl(Fp[Banderwagon].bits())
-  bigSizes.incl(Fr[Banderwagon].bits())
   dir.writeBigIntHeader(bigSizes, cBindings_big)
 
 when isMainModule:
@@ -0,0 +1,143 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Parallel batch operations on large vectors of elliptic curve points
+
+use crate::Threadpool;
+use constantine_sys::*;
+
+use ::core::mem::MaybeUninit;
+
+/// Elliptic curve points in jacobian or projective coordinates
+/// with parallel batch operations.
+pub trait EcBatchOps: Sized {
+    /// The affine representation of the points
+    type Affine;
+
+    /// dst[i] <- affine(src[i])
+    ///
+    /// The points are converted by chunks, each chunk uses a single field inversion.
+    fn batch_affine_parallel(tp: &Threadpool, dst: &mut [Self::Affine], src: &[Self]);
+
+    /// ∑ᵢ points[i]
+    ///
+    /// This is variable-time and MUST only be used with public points.
+    fn sum_reduce_vartime_parallel(tp: &Threadpool, points: &[Self::Affine]) -> Self;
+}
+
+macro_rules! impl_ec_batch_ops {
+    ($EC:ty, $Aff:ty, $batch_affine:ident, $sum_reduce:ident) => {
+        impl EcBatchOps for $EC {
+            type Affine = $Aff;
+
+            fn batch_affine_parallel(tp: &Threadpool, dst: &mut [$Aff], src: &[$EC]) {
+                assert_eq!(dst.len(), src.len());
+                unsafe {
+                    $batch_affine(
+                        tp.get_private_context(),
+                        dst.as_mut_ptr(),
+                        src.as_ptr(),
+                        src.len(),
+                    );
+                }
+            }
+
+            fn sum_reduce_vartime_parallel(tp: &Threadpool, points: &[$Aff]) -> $EC {
+                let mut result = MaybeUninit::<$EC>::uninit();
+                unsafe {
+                    $sum_reduce(
+                        tp.get_private_context(),
+                        result.as_mut_ptr(),
+                        points.as_ptr(),
+                        points.len(),
+                    );
+                    result.assume_init()
+                }
+            }
+        }
+    };
+}
+
+impl_ec_batch_ops!(
+    bn254_snarks_g1_jac,
+    bn254_snarks_g1_aff,
+    ctt_bn254_snarks_g1_jac_batch_affine_parallel,
+    ctt_bn254_snarks_g1_jac_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    bn254_snarks_g1_prj,
+    bn254_snarks_g1_aff,
+    ctt_bn254_snarks_g1_prj_batch_affine_parallel,
+    ctt_bn254_snarks_g1_prj_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    bn254_snarks_g2_jac,
+    bn254_snarks_g2_aff,
+    ctt_bn254_snarks_g2_jac_batch_affine_parallel,
+    ctt_bn254_snarks_g2_jac_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    bn254_snarks_g2_prj,
+    bn254_snarks_g2_aff,
+    ctt_bn254_snarks_g2_prj_batch_affine_parallel,
+    ctt_bn254_snarks_g2_prj_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    bls12_381_g1_jac,
+    bls12_381_g1_aff,
+    ctt_bls12_381_g1_jac_batch_affine_parallel,
+    ctt_bls12_381_g1_jac_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    bls12_381_g1_prj,
+    bls12_381_g1_aff,
+    ctt_bls12_381_g1_prj_batch_affine_parallel,
+    ctt_bls12_381_g1_prj_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    bls12_381_g2_jac,
+    bls12_381_g2_aff,
+    ctt_bls12_381_g2_jac_batch_affine_parallel,
+    ctt_bls12_381_g2_jac_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    bls12_381_g2_prj,
+    bls12_381_g2_aff,
+    ctt_bls12_381_g2_prj_batch_affine_parallel,
+    ctt_bls12_381_g2_prj_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    pallas_ec_jac,
+    pallas_ec_aff,
+    ctt_pallas_ec_jac_batch_affine_parallel,
+    ctt_pallas_ec_jac_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    pallas_ec_prj,
+    pallas_ec_aff,
+    ctt_pallas_ec_prj_batch_affine_parallel,
+    ctt_pallas_ec_prj_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    vesta_ec_jac,
+    vesta_ec_aff,
+    ctt_vesta_ec_jac_batch_affine_parallel,
+    ctt_vesta_ec_jac_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    vesta_ec_prj,
+    vesta_ec_aff,
+    ctt_vesta_ec_prj_batch_affine_parallel,
+    ctt_vesta_ec_prj_sum_reduce_vartime_parallel
+);
+impl_ec_batch_ops!(
+    banderwagon_ec_prj,
+    banderwagon_ec_aff,
+    ctt_banderwagon_ec_prj_batch_affine_parallel,
+    ctt_banderwagon_ec_prj_sum_reduce_vartime_parallel
+);
@@ -0,0 +1,278 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Parallel multi-scalar multiplications on elliptic curve points
+
+use crate::Threadpool;
+use constantine_sys::*;
+
+/// Elliptic curve points in jacobian or projective coordinates
+/// with multi-scalar multiplication.
+pub trait EcMsm: Sized {
+    /// The affine representation of the points
+    type Affine;
+    /// The scalar field of the curve
+    type Scalar;
+
+    /// r <- ∑ᵢ [coefs[i]]points[i]
+    ///
+    /// This is variable-time and MUST only be used with public coefficients.
+    ///
+    /// # Panics
+    ///
+    /// If `coefs` and `points` do not have the same length.
+    fn multi_scalar_mul_vartime_parallel(
+        tp: &Threadpool,
+        coefs: &[Self::Scalar],
+        points: &[Self::Affine],
+    ) -> Self;
+}
+
+/// Elliptic curve points in jacobian or projective coordinates
+/// with batches of multi-scalar multiplications over the same bases.
+pub trait EcBatchMsm: Sized {
+    /// The affine representation of the points
+    type Affine;
+    /// The scalar field of the curve
+    type Scalar: Copy;
+
+    /// r[k] <- ∑ᵢ [coefs[k][i]]points[i]
+    ///
+    /// The MSMs use the first `coefs[k].len()` points.
+    /// All MSMs are scheduled together on the threadpool.
+    ///
+    /// This is variable-time and MUST only be used with public coefficients.
+    ///
+    /// # Panics
+    ///
+    /// If the `coefs[k]` do not all have the same length
+    /// or if they are longer than `points`.
+    fn batch_multi_scalar_mul_vartime_parallel(
+        tp: &Threadpool,
+        coefs: &[&[Self::Scalar]],
+        points: &[Self::Affine],
+    ) -> Vec<Self>;
+}
+
+macro_rules! impl_ec_msm {
+    ($EC:ty, $Aff:ty, $Fr:ty, $msm:ident) => {
+        impl EcMsm for $EC {
+            type Affine = $Aff;
+            type Scalar = $Fr;
+
+            fn multi_scalar_mul_vartime_parallel(
+                tp: &Threadpool,
+                coefs: &[$Fr],
+                points: &[$Aff],
+            ) -> $EC {
+                assert_eq!(coefs.len(), points.len());
+
+                let mut result = ::core::mem::MaybeUninit::<$EC>::uninit();
+                unsafe {
+                    $msm(
+                        tp.get_private_context(),
+                        result.as_mut_ptr(),
+                        coefs.as_ptr(),
+                        points.as_ptr(),
+                        coefs.len(),
+                    );
+                    result.assume_init()
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_ec_batch_msm {
+    ($EC:ty, $Aff:ty, $Fr:ty, $batch_msm:ident) => {
+        impl EcBatchMsm for $EC {
+            type Affine = $Aff;
+            type Scalar = $Fr;
+
+            fn batch_multi_scalar_mul_vartime_parallel(
+                tp: &Threadpool,
+                coefs: &[&[$Fr]],
+                points: &[$Aff],
+            ) -> Vec<$EC> {
+                let n = coefs.first().map_or(0, |c| c.len());
+                assert!(
+                    coefs.iter().all(|c| c.len() == n),
+                    "all MSMs of a batch must have the same number of coefficients"
+                );
+                assert!(n <= points.len());
+
+                let rows = coefs.iter().map(|c| c.as_ptr()).collect::<Vec<_>>();
+                let mut result = Vec::<$EC>::with_capacity(coefs.len());
+                unsafe {
+                    $batch_msm(
+                        tp.get_private_context(),
+                        result.as_mut_ptr(),
+                        coefs.len(),
+                        rows.as_ptr(),
+                        points.as_ptr(),
+                        n,
+                    );
+                    result.set_len(coefs.len());
+                }
+                result
+            }
+        }
+    };
+}
+
+impl_ec_msm!(
+    bn254_snarks_g1_jac,
+    bn254_snarks_g1_aff,
+    bn254_snarks_fr,
+    ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    bn254_snarks_g1_prj,
+    bn254_snarks_g1_aff,
+    bn254_snarks_fr,
+    ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    bn254_snarks_g2_jac,
+    bn254_snarks_g2_aff,
+    bn254_snarks_fr,
+    ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    bn254_snarks_g2_prj,
+    bn254_snarks_g2_aff,
+    bn254_snarks_fr,
+    ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    bls12_381_g1_jac,
+    bls12_381_g1_aff,
+    bls12_381_fr,
+    ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    bls12_381_g1_prj,
+    bls12_381_g1_aff,
+    bls12_381_fr,
+    ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    bls12_381_g2_jac,
+    bls12_381_g2_aff,
+    bls12_381_fr,
+    ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    bls12_381_g2_prj,
+    bls12_381_g2_aff,
+    bls12_381_fr,
+    ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    pallas_ec_jac,
+    pallas_ec_aff,
+    pallas_fr,
+    ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    pallas_ec_prj,
+    pallas_ec_aff,
+    pallas_fr,
+    ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    vesta_ec_jac,
+    vesta_ec_aff,
+    vesta_fr,
+    ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    vesta_ec_prj,
+    vesta_ec_aff,
+    vesta_fr,
+    ctt_vesta_ec_prj_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_msm!(
+    banderwagon_ec_prj,
+    banderwagon_ec_aff,
+    banderwagon_fr,
+    ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+
+impl_ec_batch_msm!(
+    bn254_snarks_g1_jac,
+    bn254_snarks_g1_aff,
+    bn254_snarks_fr,
+    ctt_bn254_snarks_g1_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    bn254_snarks_g1_prj,
+    bn254_snarks_g1_aff,
+    bn254_snarks_fr,
+    ctt_bn254_snarks_g1_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    bn254_snarks_g2_jac,
+    bn254_snarks_g2_aff,
+    bn254_snarks_fr,
+    ctt_bn254_snarks_g2_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    bn254_snarks_g2_prj,
+    bn254_snarks_g2_aff,
+    bn254_snarks_fr,
+    ctt_bn254_snarks_g2_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    bls12_381_g1_jac,
+    bls12_381_g1_aff,
+    bls12_381_fr,
+    ctt_bls12_381_g1_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    bls12_381_g1_prj,
+    bls12_381_g1_aff,
+    bls12_381_fr,
+    ctt_bls12_381_g1_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    bls12_381_g2_jac,
+    bls12_381_g2_aff,
+    bls12_381_fr,
+    ctt_bls12_381_g2_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    bls12_381_g2_prj,
+    bls12_381_g2_aff,
+    bls12_381_fr,
+    ctt_bls12_381_g2_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    pallas_ec_jac,
+    pallas_ec_aff,
+    pallas_fr,
+    ctt_pallas_ec_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    pallas_ec_prj,
+    pallas_ec_aff,
+    pallas_fr,
+    ctt_pallas_ec_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    vesta_ec_jac,
+    vesta_ec_aff,
+    vesta_fr,
+    ctt_vesta_ec_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
+impl_ec_batch_msm!(
+    vesta_ec_prj,
+    vesta_ec_aff,
+    vesta_fr,
+    ctt_vesta_ec_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel
+);
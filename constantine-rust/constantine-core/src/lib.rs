@@ -67,3 +67,9 @@ impl Drop for Threadpool {
         unsafe { ctt_threadpool_shutdown(self.ctx) }
     }
 }
+
+// Elliptic curves
+// ------------------------------------------------------------
+
+pub mod ec_batch_ops;
+pub mod ec_msm;
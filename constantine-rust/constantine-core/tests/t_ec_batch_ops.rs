@@ -0,0 +1,81 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_core::ec_batch_ops::EcBatchOps;
+use constantine_core::{hardware, Threadpool};
+use constantine_sys::*;
+
+use ::core::mem::MaybeUninit;
+
+const DST: &[u8] = b"CTT-BATCH-OPS-TEST";
+
+/// Generate points by hashing to BN254 𝔾1
+fn bn254_g1_points(n: usize) -> Vec<bn254_snarks_g1_jac> {
+    (0..n as u64)
+        .map(|i| {
+            let msg = i.to_le_bytes();
+            let mut p = MaybeUninit::<bn254_snarks_g1_jac>::uninit();
+            unsafe {
+                ctt_bn254_snarks_g1_jac_svdw_sha256(
+                    p.as_mut_ptr(),
+                    core::ptr::null(),
+                    0,
+                    msg.as_ptr(),
+                    msg.len(),
+                    DST.as_ptr(),
+                    DST.len(),
+                );
+                p.assume_init()
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn t_batch_affine_parallel_bn254_g1() {
+    let tp = Threadpool::new(hardware::get_num_threads_os());
+
+    for n in [0, 1, 100, 5000, 20000] {
+        let points = bn254_g1_points(n);
+        let mut expected = vec![unsafe { MaybeUninit::zeroed().assume_init() }; n];
+        let mut affs = expected.clone();
+        unsafe {
+            if n > 0 {
+                ctt_bn254_snarks_g1_jac_batch_affine(expected.as_mut_ptr(), points.as_ptr(), n);
+            }
+        }
+        bn254_snarks_g1_jac::batch_affine_parallel(&tp, &mut affs, &points);
+        for (a, e) in affs.iter().zip(&expected) {
+            assert!(unsafe { ctt_bn254_snarks_g1_aff_is_eq(a, e) } != 0);
+        }
+    }
+}
+
+#[test]
+fn t_sum_reduce_vartime_parallel_bn254_g1() {
+    let tp = Threadpool::new(hardware::get_num_threads_os());
+
+    for n in [0, 1, 100, 5000, 20000] {
+        let points = bn254_g1_points(n);
+        let mut affs = vec![unsafe { MaybeUninit::zeroed().assume_init() }; n];
+        bn254_snarks_g1_jac::batch_affine_parallel(&tp, &mut affs, &points);
+
+        let mut expected = MaybeUninit::<bn254_snarks_g1_jac>::uninit();
+        let expected = unsafe {
+            ctt_bn254_snarks_g1_jac_set_neutral(expected.as_mut_ptr());
+            let mut expected = expected.assume_init();
+            for p in &points {
+                ctt_bn254_snarks_g1_jac_add_in_place(&mut expected, p);
+            }
+            expected
+        };
+
+        let sum = bn254_snarks_g1_jac::sum_reduce_vartime_parallel(&tp, &affs);
+        assert!(unsafe { ctt_bn254_snarks_g1_jac_is_eq(&sum, &expected) } != 0);
+    }
+}
@@ -0,0 +1,165 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_core::ec_batch_ops::EcBatchOps;
+use constantine_core::ec_msm::{EcBatchMsm, EcMsm};
+use constantine_core::{hardware, Threadpool};
+use constantine_sys::*;
+
+use ::core::mem::MaybeUninit;
+
+const DST: &[u8] = b"CTT-MSM-TEST";
+
+/// Generate points by hashing to BN254 𝔾1
+fn bn254_g1_points(tp: &Threadpool, n: usize) -> Vec<bn254_snarks_g1_aff> {
+    let points: Vec<bn254_snarks_g1_jac> = (0..n as u64)
+        .map(|i| {
+            let msg = i.to_le_bytes();
+            let mut p = MaybeUninit::<bn254_snarks_g1_jac>::uninit();
+            unsafe {
+                ctt_bn254_snarks_g1_jac_svdw_sha256(
+                    p.as_mut_ptr(),
+                    core::ptr::null(),
+                    0,
+                    msg.as_ptr(),
+                    msg.len(),
+                    DST.as_ptr(),
+                    DST.len(),
+                );
+                p.assume_init()
+            }
+        })
+        .collect();
+    let mut affs = vec![unsafe { MaybeUninit::zeroed().assume_init() }; n];
+    bn254_snarks_g1_jac::batch_affine_parallel(tp, &mut affs, &points);
+    affs
+}
+
+fn bn254_fr(x: u64) -> bn254_snarks_fr {
+    let bytes = x.wrapping_mul(0x9E37_79B9_7F4A_7C15).to_be_bytes();
+    let mut fr = MaybeUninit::<bn254_snarks_fr>::uninit();
+    unsafe {
+        assert!(ctt_bn254_snarks_fr_unmarshalBE(
+            fr.as_mut_ptr(),
+            bytes.as_ptr(),
+            bytes.len()
+        ));
+        fr.assume_init()
+    }
+}
+
+/// Generate points [k]G on Banderwagon
+fn banderwagon_points(tp: &Threadpool, n: usize) -> Vec<banderwagon_ec_aff> {
+    let mut table = MaybeUninit::<banderwagon_ec_fixed_base_table>::uninit();
+    let points: Vec<banderwagon_ec_prj> = unsafe {
+        ctt_banderwagon_ec_fixed_base_table_precompute_generator(table.as_mut_ptr());
+        (0..n as u64)
+            .map(|i| {
+                let mut p = MaybeUninit::<banderwagon_ec_prj>::uninit();
+                ctt_banderwagon_ec_prj_fixed_base_scalar_mul_fr_coef(
+                    p.as_mut_ptr(),
+                    table.as_ptr(),
+                    &banderwagon_fr_from(i + 1),
+                );
+                p.assume_init()
+            })
+            .collect()
+    };
+    unsafe { ctt_banderwagon_ec_fixed_base_table_delete(table.as_mut_ptr()) };
+
+    let mut affs = vec![unsafe { MaybeUninit::zeroed().assume_init() }; n];
+    banderwagon_ec_prj::batch_affine_parallel(tp, &mut affs, &points);
+    affs
+}
+
+fn banderwagon_fr_from(x: u64) -> banderwagon_fr {
+    let bytes = x.wrapping_mul(0x9E37_79B9_7F4A_7C15).to_be_bytes();
+    let mut fr = MaybeUninit::<banderwagon_fr>::uninit();
+    unsafe {
+        assert!(ctt_banderwagon_fr_unmarshalBE(
+            fr.as_mut_ptr(),
+            bytes.as_ptr(),
+            bytes.len()
+        ));
+        fr.assume_init()
+    }
+}
+
+#[test]
+fn t_msm_vartime_parallel_banderwagon() {
+    let tp = Threadpool::new(hardware::get_num_threads_os());
+    let points = banderwagon_points(&tp, 256);
+
+    for n in [1, 2, 7, 64, 256] {
+        let coefs: Vec<banderwagon_fr> = (0..n)
+            .map(|i| banderwagon_fr_from(1000 + i as u64))
+            .collect();
+
+        let r = banderwagon_ec_prj::multi_scalar_mul_vartime_parallel(&tp, &coefs, &points[..n]);
+
+        let mut expected = MaybeUninit::<banderwagon_ec_prj>::uninit();
+        unsafe {
+            ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs_vartime(
+                expected.as_mut_ptr(),
+                coefs.as_ptr(),
+                points.as_ptr(),
+                n,
+            );
+            assert!(ctt_banderwagon_ec_prj_is_eq(&r, &expected.assume_init()) != 0);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_msm_length_mismatch() {
+    let tp = Threadpool::new(1);
+    let points = banderwagon_points(&tp, 2);
+    let coefs = [banderwagon_fr_from(1)];
+    banderwagon_ec_prj::multi_scalar_mul_vartime_parallel(&tp, &coefs, &points);
+}
+
+#[test]
+fn t_batch_msm_vartime_parallel_bn254_g1() {
+    let tp = Threadpool::new(hardware::get_num_threads_os());
+    let points = bn254_g1_points(&tp, 1000);
+
+    for (num_msms, n) in [(0, 1), (1, 1), (3, 100), (5, 1000)] {
+        let coefs: Vec<Vec<bn254_snarks_fr>> = (0..num_msms)
+            .map(|k| (0..n).map(|i| bn254_fr((k * n + i) as u64)).collect())
+            .collect();
+        let rows: Vec<&[bn254_snarks_fr]> = coefs.iter().map(|c| c.as_slice()).collect();
+
+        let batch =
+            bn254_snarks_g1_jac::batch_multi_scalar_mul_vartime_parallel(&tp, &rows, &points);
+        assert_eq!(batch.len(), num_msms);
+
+        for (r, row) in batch.iter().zip(&rows) {
+            let mut expected = MaybeUninit::<bn254_snarks_g1_jac>::uninit();
+            unsafe {
+                ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_vartime(
+                    expected.as_mut_ptr(),
+                    row.as_ptr(),
+                    points.as_ptr(),
+                    n,
+                );
+                assert!(ctt_bn254_snarks_g1_jac_is_eq(r, &expected.assume_init()) != 0);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "same number of coefficients")]
+fn t_batch_msm_ragged_rows() {
+    let tp = Threadpool::new(1);
+    let points = bn254_g1_points(&tp, 2);
+    let coefs = [vec![bn254_fr(1); 2], vec![bn254_fr(2); 1]];
+    let rows: Vec<&[bn254_snarks_fr]> = coefs.iter().map(|c| c.as_slice()).collect();
+    bn254_snarks_g1_jac::batch_multi_scalar_mul_vartime_parallel(&tp, &rows, &points);
+}
@@ -0,0 +1,195 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use crate::gas::Precompile;
+
+use constantine_sys::*;
+
+use std::fmt;
+
+/// Why a precompile rejected its input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureReason {
+    /// The input, or one of its field elements, does not have the expected length
+    InvalidLength,
+    /// The 16 upper bytes of a 64-byte EIP-2537 field element are not zero
+    NonZeroPadding,
+    /// A field element is greater or equal to the field modulus
+    CoordinateGreaterOrEqualModulus,
+    PointNotOnCurve,
+    PointNotInSubgroup,
+    /// The status has no finer reason, for example an invalid signature
+    Status(ctt_evm_status),
+}
+
+impl FailureReason {
+    /// Reason implied by a status, without the details recorded by input parsing
+    fn from_status(status: ctt_evm_status) -> Self {
+        match status {
+            ctt_evm_status::cttEVM_InvalidInputSize => FailureReason::InvalidLength,
+            ctt_evm_status::cttEVM_IntLargerThanModulus => {
+                FailureReason::CoordinateGreaterOrEqualModulus
+            }
+            ctt_evm_status::cttEVM_PointNotOnCurve => FailureReason::PointNotOnCurve,
+            ctt_evm_status::cttEVM_PointNotInSubgroup => FailureReason::PointNotInSubgroup,
+            _ => FailureReason::Status(status),
+        }
+    }
+
+    /// Reason recorded by input parsing, if consistent with the returned status
+    fn from_recorded(reason: ctt_evm_failure_reason, status: ctt_evm_status) -> Option<Self> {
+        use ctt_evm_failure_reason::*;
+        use ctt_evm_status::*;
+        match (reason, status) {
+            (cttEVMFail_InvalidLength, cttEVM_InvalidInputSize) => {
+                Some(FailureReason::InvalidLength)
+            }
+            (cttEVMFail_NonZeroPadding, cttEVM_IntLargerThanModulus) => {
+                Some(FailureReason::NonZeroPadding)
+            }
+            (cttEVMFail_CoordinateGreaterOrEqualModulus, cttEVM_IntLargerThanModulus) => {
+                Some(FailureReason::CoordinateGreaterOrEqualModulus)
+            }
+            (cttEVMFail_PointNotOnCurve, cttEVM_PointNotOnCurve) => {
+                Some(FailureReason::PointNotOnCurve)
+            }
+            (cttEVMFail_PointNotInSubgroup, cttEVM_PointNotInSubgroup) => {
+                Some(FailureReason::PointNotInSubgroup)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for FailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FailureReason::InvalidLength => write!(f, "invalid length"),
+            FailureReason::NonZeroPadding => write!(f, "non-zero padding"),
+            FailureReason::CoordinateGreaterOrEqualModulus => {
+                write!(f, "coordinate greater or equal to the field modulus")
+            }
+            FailureReason::PointNotOnCurve => write!(f, "point not on curve"),
+            FailureReason::PointNotInSubgroup => write!(f, "point not in subgroup"),
+            FailureReason::Status(status) => write!(f, "{}", status),
+        }
+    }
+}
+
+/// A precompile rejected its input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Failure {
+    pub status: ctt_evm_status,
+    pub reason: FailureReason,
+    /// Byte offset of the offending field element or point in the input
+    pub offset: Option<usize>,
+    /// Index of the offending point, or (point, scalar) or (G1, G2) pair,
+    /// for precompiles taking several of them.
+    pub index: Option<usize>,
+}
+
+type PrecompileDetailedFn = unsafe extern "C" fn(
+    *mut byte,
+    usize,
+    *const byte,
+    usize,
+    *mut ctt_evm_failure,
+) -> ctt_evm_status;
+
+// Define a byte compatible version of ctt_evm_failure so that we have
+// access to the fields
+#[repr(C)]
+pub(crate) struct FailureDetails {
+    reason: ctt_evm_failure_reason,
+    offset: usize,
+}
+
+impl FailureDetails {
+    pub(crate) fn new() -> Self {
+        Self {
+            reason: ctt_evm_failure_reason::cttEVMFail_None,
+            offset: 0,
+        }
+    }
+
+    /// Call the `_detailed` variant of a precompile with an output of `len` bytes,
+    /// keeping why and where its input was rejected.
+    pub(crate) fn call(
+        &mut self,
+        precompile: PrecompileDetailedFn,
+        len: usize,
+        inputs: &[u8],
+    ) -> Result<Vec<u8>, ctt_evm_status> {
+        let mut r = vec![0u8; len];
+        let status = unsafe {
+            precompile(
+                r.as_mut_ptr() as *mut byte,
+                r.len(),
+                inputs.as_ptr() as *const byte,
+                inputs.len(),
+                self as *mut Self as *mut ctt_evm_failure,
+            )
+        };
+        match status {
+            ctt_evm_status::cttEVM_Success => Ok(r),
+            _ => Err(status),
+        }
+    }
+
+    /// Details of the failure of `precompile` returning `status`
+    pub(crate) fn failure(&self, precompile: Precompile, status: ctt_evm_status) -> Failure {
+        match FailureReason::from_recorded(self.reason, status) {
+            Some(reason) => Failure {
+                status,
+                reason,
+                offset: Some(self.offset),
+                index: element_size(precompile).map(|size| self.offset / size),
+            },
+            None => Failure::from(status),
+        }
+    }
+}
+
+impl From<ctt_evm_status> for Failure {
+    fn from(status: ctt_evm_status) -> Self {
+        Self {
+            status,
+            reason: FailureReason::from_status(status),
+            offset: None,
+            index: None,
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.reason)?;
+        if let Some(index) = self.index {
+            write!(f, " in element {}", index)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " at input offset {}", offset)?;
+        }
+        Ok(())
+    }
+}
+
+/// Size of the points, or (point, scalar) or (G1, G2) pairs,
+/// of precompiles taking several of them.
+fn element_size(precompile: Precompile) -> Option<usize> {
+    match precompile {
+        Precompile::Bn254G1Add => Some(64),
+        Precompile::Bn254PairingCheck => Some(192),
+        Precompile::Bls12381G1Add => Some(128),
+        Precompile::Bls12381G1Msm => Some(160),
+        Precompile::Bls12381G2Add => Some(256),
+        Precompile::Bls12381G2Msm => Some(288),
+        Precompile::Bls12381PairingCheck => Some(384),
+        _ => None,
+    }
+}
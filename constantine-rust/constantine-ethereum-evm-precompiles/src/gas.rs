@@ -0,0 +1,273 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Gas cost of the EVM precompiles
+//!
+//! Gas is computed from the raw input, before any validation,
+//! an invalid input still consumes the gas returned here.
+
+/// Ethereum mainnet hardforks, in activation order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Hardfork {
+    Frontier,
+    Homestead,
+    TangerineWhistle,
+    SpuriousDragon,
+    Byzantium,
+    Constantinople,
+    Petersburg,
+    Istanbul,
+    MuirGlacier,
+    Berlin,
+    London,
+    ArrowGlacier,
+    GrayGlacier,
+    Paris,
+    Shanghai,
+    Cancun,
+    Prague,
+    Osaka,
+}
+
+/// Precompiles implemented by this crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Precompile {
+    EcRecover,
+    Sha256,
+    Ripemd160,
+    Identity,
+    ModExp,
+    Bn254G1Add,
+    Bn254G1Mul,
+    Bn254PairingCheck,
+    Blake2f,
+    KzgPointEvaluation,
+    Bls12381G1Add,
+    /// Scalar multiplication from EIP-2537 drafts, priced as a G1 MSM of size 1
+    Bls12381G1Mul,
+    Bls12381G1Msm,
+    Bls12381G2Add,
+    /// Scalar multiplication from EIP-2537 drafts, priced as a G2 MSM of size 1
+    Bls12381G2Mul,
+    Bls12381G2Msm,
+    Bls12381PairingCheck,
+    Bls12381MapFpToG1,
+    Bls12381MapFp2ToG2,
+    P256Verify,
+}
+
+/// Returns the gas required to execute `precompile` on `input` at `fork`.
+///
+/// Forks predating a precompile use the pricing it was introduced with.
+/// Costs that do not fit in a u64 saturate to `u64::MAX`.
+pub fn required_gas(precompile: Precompile, input: &[u8], fork: Hardfork) -> u64 {
+    match precompile {
+        Precompile::EcRecover => 3000,
+        Precompile::Sha256 => 60 + 12 * words(input.len(), 32),
+        Precompile::Ripemd160 => 600 + 120 * words(input.len(), 32),
+        Precompile::Identity => 15 + 3 * words(input.len(), 32),
+        Precompile::ModExp => modexp_gas(input, fork),
+
+        // EIP-196 and EIP-197, repriced in EIP-1108
+        Precompile::Bn254G1Add => {
+            if fork >= Hardfork::Istanbul {
+                150
+            } else {
+                500
+            }
+        }
+        Precompile::Bn254G1Mul => {
+            if fork >= Hardfork::Istanbul {
+                6000
+            } else {
+                40000
+            }
+        }
+        Precompile::Bn254PairingCheck => {
+            let pairs = (input.len() / 192) as u64;
+            if fork >= Hardfork::Istanbul {
+                45000 + 34000 * pairs
+            } else {
+                100000 + 80000 * pairs
+            }
+        }
+
+        // EIP-152, malformed inputs are rejected at no cost
+        Precompile::Blake2f => match input.len() {
+            213 => u32::from_be_bytes(input[..4].try_into().unwrap()) as u64,
+            _ => 0,
+        },
+
+        // EIP-4844
+        Precompile::KzgPointEvaluation => 50000,
+
+        // EIP-2537
+        Precompile::Bls12381G1Add => 375,
+        Precompile::Bls12381G1Mul => BLS12_381_G1_MUL_GAS,
+        Precompile::Bls12381G1Msm => msm_gas(
+            input.len() / BLS12_381_G1_MSM_PAIR_SIZE,
+            BLS12_381_G1_MUL_GAS,
+            &BLS12_381_G1_MSM_DISCOUNTS,
+        ),
+        Precompile::Bls12381G2Add => 600,
+        Precompile::Bls12381G2Mul => BLS12_381_G2_MUL_GAS,
+        Precompile::Bls12381G2Msm => msm_gas(
+            input.len() / BLS12_381_G2_MSM_PAIR_SIZE,
+            BLS12_381_G2_MUL_GAS,
+            &BLS12_381_G2_MSM_DISCOUNTS,
+        ),
+        Precompile::Bls12381PairingCheck => 37700 + 32600 * (input.len() / 384) as u64,
+        Precompile::Bls12381MapFpToG1 => 5500,
+        Precompile::Bls12381MapFp2ToG2 => 23800,
+
+        // RIP-7212, repriced in EIP-7951
+        Precompile::P256Verify => {
+            if fork >= Hardfork::Osaka {
+                6900
+            } else {
+                3450
+            }
+        }
+    }
+}
+
+/// Number of `word_size` words needed to store `len` bytes
+fn words(len: usize, word_size: usize) -> u64 {
+    len.div_ceil(word_size) as u64
+}
+
+// Modular exponentiation
+// ------------------------------------------------------------
+
+/// Read `len` bytes at `offset` as if `input` was infinitely right-padded with zeros.
+/// `len` must be at most 32, the result is big-endian and right-aligned.
+fn get_data(input: &[u8], offset: u64, len: usize) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let start = usize::try_from(offset)
+        .unwrap_or(usize::MAX)
+        .min(input.len());
+    let end = start.saturating_add(len).min(input.len());
+    out[32 - len..32 - len + (end - start)].copy_from_slice(&input[start..end]);
+    out
+}
+
+/// Big-endian 256-bit integer, saturated to u64::MAX
+fn to_u64_saturating(bytes: &[u8; 32]) -> u64 {
+    if bytes[..24].iter().any(|&b| b != 0) {
+        return u64::MAX;
+    }
+    u64::from_be_bytes(bytes[24..].try_into().unwrap())
+}
+
+/// Index of the most significant bit, 0 if the integer is 0
+fn msb(bytes: &[u8; 32]) -> u64 {
+    match bytes.iter().position(|&b| b != 0) {
+        Some(i) => (31 - i as u64) * 8 + (7 - bytes[i].leading_zeros() as u64),
+        None => 0,
+    }
+}
+
+/// Multiplication complexity of EIP-198
+fn mult_complexity_eip198(x: u128) -> u128 {
+    if x <= 64 {
+        x * x
+    } else if x <= 1024 {
+        x * x / 4 + 96 * x - 3072
+    } else {
+        x.saturating_mul(x) / 16 + x.saturating_mul(480) - 199680
+    }
+}
+
+/// Gas of MODEXP, following
+/// - EIP-198 from Byzantium
+/// - EIP-2565 from Berlin
+/// - EIP-7883 from Osaka
+fn modexp_gas(input: &[u8], fork: Hardfork) -> u64 {
+    let base_len = to_u64_saturating(&get_data(input, 0, 32));
+    let exp_len = to_u64_saturating(&get_data(input, 32, 32));
+    let mod_len = to_u64_saturating(&get_data(input, 64, 32));
+    let data = input.get(96..).unwrap_or(&[]);
+
+    // The head is the first 32 bytes of the exponent
+    let exp_head = if data.len() as u64 <= base_len {
+        [0u8; 32]
+    } else {
+        get_data(data, base_len, exp_len.min(32) as usize)
+    };
+
+    let exp_len_multiplier: u128 = if fork >= Hardfork::Osaka { 16 } else { 8 };
+    let adj_exp_len = (exp_len.saturating_sub(32) as u128)
+        .saturating_mul(exp_len_multiplier)
+        .saturating_add(msb(&exp_head) as u128)
+        .max(1);
+
+    let max_len = base_len.max(mod_len) as u128;
+    let gas = if fork >= Hardfork::Osaka {
+        let complexity = if max_len <= 32 {
+            16
+        } else {
+            2 * words_u128(max_len).pow(2)
+        };
+        complexity.saturating_mul(adj_exp_len).max(500)
+    } else if fork >= Hardfork::Berlin {
+        (words_u128(max_len).pow(2).saturating_mul(adj_exp_len) / 3).max(200)
+    } else {
+        mult_complexity_eip198(max_len).saturating_mul(adj_exp_len) / 20
+    };
+    u64::try_from(gas).unwrap_or(u64::MAX)
+}
+
+/// Number of 64-bit words to store `len` bytes, `len` is at most 2⁶⁴-1
+fn words_u128(len: u128) -> u128 {
+    len.div_ceil(8)
+}
+
+// BLS12-381 multi-scalar multiplication
+// ------------------------------------------------------------
+
+const BLS12_381_G1_MUL_GAS: u64 = 12000;
+const BLS12_381_G2_MUL_GAS: u64 = 22500;
+
+/// 128-byte G1 point and 32-byte scalar
+const BLS12_381_G1_MSM_PAIR_SIZE: usize = 160;
+/// 256-byte G2 point and 32-byte scalar
+const BLS12_381_G2_MSM_PAIR_SIZE: usize = 288;
+
+/// EIP-2537 G1 MSM discounts in ‰ for k = 1 to 128 pairs, the last entry applies to larger k
+#[rustfmt::skip]
+const BLS12_381_G1_MSM_DISCOUNTS: [u16; 128] = [
+    1000, 949, 848, 797, 764, 750, 738, 728, 719, 712, 705, 698, 692, 687, 682, 677,
+    673, 669, 665, 661, 658, 654, 651, 648, 645, 642, 640, 637, 635, 632, 630, 627,
+    625, 623, 621, 619, 617, 615, 613, 611, 609, 608, 606, 604, 603, 601, 599, 598,
+    596, 595, 593, 592, 591, 589, 588, 586, 585, 584, 582, 581, 580, 579, 577, 576,
+    575, 574, 573, 572, 570, 569, 568, 567, 566, 565, 564, 563, 562, 561, 560, 559,
+    558, 557, 556, 555, 554, 553, 552, 551, 550, 549, 548, 547, 547, 546, 545, 544,
+    543, 542, 541, 540, 540, 539, 538, 537, 536, 536, 535, 534, 533, 532, 532, 531,
+    530, 529, 528, 528, 527, 526, 525, 525, 524, 523, 522, 522, 521, 520, 520, 519,
+];
+
+/// EIP-2537 G2 MSM discounts in ‰ for k = 1 to 128 pairs, the last entry applies to larger k
+#[rustfmt::skip]
+const BLS12_381_G2_MSM_DISCOUNTS: [u16; 128] = [
+    1000, 1000, 923, 884, 855, 832, 812, 796, 782, 770, 759, 749, 740, 732, 724, 717,
+    711, 704, 699, 693, 688, 683, 679, 674, 670, 666, 663, 659, 655, 652, 649, 646,
+    643, 640, 637, 634, 632, 629, 627, 624, 622, 620, 618, 615, 613, 611, 609, 607,
+    606, 604, 602, 600, 598, 597, 595, 593, 592, 590, 589, 587, 586, 584, 583, 582,
+    580, 579, 578, 576, 575, 574, 573, 571, 570, 569, 568, 567, 566, 565, 563, 562,
+    561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 552, 551, 550, 549, 548, 547,
+    546, 545, 545, 544, 543, 542, 541, 541, 540, 539, 538, 537, 537, 536, 535, 535,
+    534, 533, 532, 532, 531, 530, 530, 529, 528, 528, 527, 526, 526, 525, 524, 524,
+];
+
+fn msm_gas(k: usize, mul_gas: u64, discounts: &[u16; 128]) -> u64 {
+    if k == 0 {
+        return 0;
+    }
+    let discount = discounts[k.min(discounts.len()) - 1] as u64;
+    (k as u64).saturating_mul(mul_gas).saturating_mul(discount) / 1000
+}
@@ -8,7 +8,13 @@
 
 use constantine_sys::*;
 
-use ::core::mem::MaybeUninit;
+mod failure;
+pub mod gas;
+mod registry;
+
+pub use failure::{Failure, FailureReason};
+pub use gas::{Hardfork, Precompile};
+pub use registry::{Address, PrecompileError, PrecompileOutput, Precompiles};
 
 // Reexport
 pub use constantine_ethereum_kzg::EthKzgContext;
@@ -17,289 +23,337 @@ pub use constantine_ethereum_kzg::EthKzgContext;
 // ------- EVM precompiles --------
 // --------------------------------
 
+// Each precompile has a `_into` variant writing its output
+// at the start of a caller-provided buffer and returning the written length.
+// The buffer may be larger than the output,
+// a buffer too small returns `cttEVM_InvalidOutputSize`.
+
+type PrecompileFn = unsafe extern "C" fn(*mut byte, usize, *const byte, usize) -> ctt_evm_status;
+
+/// Call a precompile with an output of `len` bytes
+#[inline(always)]
+fn call_into(
+    precompile: PrecompileFn,
+    r: &mut [u8],
+    len: usize,
+    inputs: &[u8],
+) -> Result<usize, ctt_evm_status> {
+    let r = r
+        .get_mut(..len)
+        .ok_or(ctt_evm_status::cttEVM_InvalidOutputSize)?;
+    let status = unsafe {
+        precompile(
+            r.as_mut_ptr() as *mut byte,
+            r.len() as usize,
+            inputs.as_ptr() as *const byte,
+            inputs.len() as usize,
+        )
+    };
+    match status {
+        ctt_evm_status::cttEVM_Success => Ok(len),
+        _ => Err(status),
+    }
+}
+
+/// Recover the Ethereum address of the signer of a message digest.
+///
+/// `input` is `digest || v || r || s`, 32 bytes each,
+/// shorter inputs are right-padded with zeros and extra bytes are ignored.
+///
+/// Returns the 20-byte address left-padded to 32 bytes.
+/// An invalid signature returns `cttEVM_MalformedSignature`,
+/// the EVM returns an empty output in that case.
+#[inline]
+pub fn evm_ecrecover(input: &[u8]) -> Result<[u8; 32], ctt_evm_status> {
+    let mut result = [0u8; 32];
+    evm_ecrecover_into(&mut result, input)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_ecrecover_into(r: &mut [u8], input: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_ecrecover, r, 32, input)
+}
+
+/// Verify a P-256 (secp256r1) ECDSA signature of a message digest.
+///
+/// `input` is `digest || r || s || x || y`, 32 bytes each,
+/// with `(x, y)` the public key.
+///
+/// Returns 1 as a 32-byte big-endian integer on a valid signature.
+/// Any error is an invalid signature,
+/// the EVM returns an empty output in that case.
+#[inline]
+pub fn evm_p256verify(input: &[u8]) -> Result<[u8; 32], ctt_evm_status> {
+    let mut result = [0u8; 32];
+    evm_p256verify_into(&mut result, input)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_p256verify_into(r: &mut [u8], input: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_p256verify, r, 32, input)
+}
+
 #[inline]
 pub fn evm_ripemd160(message: &[u8]) -> Result<[u8; 32], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_ripemd160(
-            result.as_mut_ptr() as *mut byte,
-            32,
-            message.as_ptr() as *const byte,
-            message.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 32];
+    evm_ripemd160_into(&mut result, message)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_ripemd160_into(r: &mut [u8], message: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_ripemd160, r, 32, message)
 }
 
 #[inline]
 pub fn evm_sha256(message: &[u8]) -> Result<[u8; 32], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_sha256(
-            result.as_mut_ptr() as *mut byte,
-            32,
-            message.as_ptr() as *const byte,
-            message.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 32];
+    evm_sha256_into(&mut result, message)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_sha256_into(r: &mut [u8], message: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_sha256, r, 32, message)
 }
 
 #[inline]
 pub fn evm_modexp(inputs: &[u8]) -> Result<Vec<u8>, ctt_evm_status> {
-    // Call Nim function to determine correct size to allocate for `result`
-    unsafe {
-        let mut size = 0u64;
-        let status = ctt_eth_evm_modexp_result_size(
-            &mut size,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        if status != ctt_evm_status::cttEVM_Success {
-            return Err(status);
-        }
+    let size = evm_modexp_result_size(inputs)?;
+    let mut result = vec![0u8; size];
+    call_into(ctt_eth_evm_modexp, &mut result, size, inputs)?;
+    Ok(result)
+}
 
-        let mut result = vec![0u8; size as usize];
-        let status = ctt_eth_evm_modexp(
-            result.as_mut_ptr() as *mut byte,
-            result.len() as usize,
+/// Size of the MODEXP output for `inputs`, i.e. the modulus length
+#[inline]
+pub fn evm_modexp_result_size(inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    let mut size = 0u64;
+    let status = unsafe {
+        ctt_eth_evm_modexp_result_size(
+            &mut size,
             inputs.as_ptr() as *const byte,
             inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result),
-            _ => Err(status),
+        )
+    };
+    match status {
+        ctt_evm_status::cttEVM_Success => {
+            usize::try_from(size).map_err(|_| ctt_evm_status::cttEVM_InvalidInputSize)
         }
+        _ => Err(status),
     }
 }
 
+/// `r` must hold at least `evm_modexp_result_size(inputs)` bytes
+#[inline]
+pub fn evm_modexp_into(r: &mut [u8], inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    let size = evm_modexp_result_size(inputs)?;
+    call_into(ctt_eth_evm_modexp, r, size, inputs)
+}
+
 #[inline]
 pub fn evm_bn254_g1add(inputs: &[u8]) -> Result<[u8; 64], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 64]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bn254_g1add(
-            result.as_mut_ptr() as *mut byte,
-            64,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 64];
+    evm_bn254_g1add_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bn254_g1add_into(r: &mut [u8], inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bn254_g1add, r, 64, inputs)
 }
 
 #[inline]
 pub fn evm_bn254_g1mul(inputs: &[u8]) -> Result<[u8; 64], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 64]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bn254_g1mul(
-            result.as_mut_ptr() as *mut byte,
-            64,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 64];
+    evm_bn254_g1mul_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bn254_g1mul_into(r: &mut [u8], inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bn254_g1mul, r, 64, inputs)
 }
 
 #[inline]
 pub fn evm_bn254_ec_pairing_check(inputs: &[u8]) -> Result<[u8; 32], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bn254_ecpairingcheck(
-            result.as_mut_ptr() as *mut byte,
-            32,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 32];
+    evm_bn254_ec_pairing_check_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bn254_ec_pairing_check_into(
+    r: &mut [u8],
+    inputs: &[u8],
+) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bn254_ecpairingcheck, r, 32, inputs)
+}
+
+#[inline]
+pub fn evm_blake2f(inputs: &[u8]) -> Result<[u8; 64], ctt_evm_status> {
+    let mut result = [0u8; 64];
+    evm_blake2f_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_blake2f_into(r: &mut [u8], inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_blake2f, r, 64, inputs)
 }
 
 #[inline]
 pub fn evm_bls12381_g1add(inputs: &[u8]) -> Result<[u8; 128], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 128]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bls12381_g1add(
-            result.as_mut_ptr() as *mut byte,
-            128,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 128];
+    evm_bls12381_g1add_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bls12381_g1add_into(r: &mut [u8], inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bls12381_g1add, r, 128, inputs)
 }
 
 #[inline]
 pub fn evm_bls12381_g1mul(inputs: &[u8]) -> Result<[u8; 128], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 128]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bls12381_g1mul(
-            result.as_mut_ptr() as *mut byte,
-            128,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 128];
+    evm_bls12381_g1mul_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bls12381_g1mul_into(r: &mut [u8], inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bls12381_g1mul, r, 128, inputs)
 }
 
 #[inline]
 pub fn evm_bls12381_g1msm(inputs: &[u8]) -> Result<[u8; 128], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 128]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bls12381_g1msm(
-            result.as_mut_ptr() as *mut byte,
-            128,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 128];
+    evm_bls12381_g1msm_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bls12381_g1msm_into(r: &mut [u8], inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bls12381_g1msm, r, 128, inputs)
 }
 
 #[inline]
 pub fn evm_bls12381_g2add(inputs: &[u8]) -> Result<[u8; 256], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 256]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bls12381_g2add(
-            result.as_mut_ptr() as *mut byte,
-            256,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 256];
+    evm_bls12381_g2add_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bls12381_g2add_into(r: &mut [u8], inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bls12381_g2add, r, 256, inputs)
 }
 
 #[inline]
 pub fn evm_bls12381_g2mul(inputs: &[u8]) -> Result<[u8; 256], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 256]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bls12381_g2mul(
-            result.as_mut_ptr() as *mut byte,
-            256,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 256];
+    evm_bls12381_g2mul_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bls12381_g2mul_into(r: &mut [u8], inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bls12381_g2mul, r, 256, inputs)
 }
 
 #[inline]
 pub fn evm_bls12381_g2msm(inputs: &[u8]) -> Result<[u8; 256], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 256]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bls12381_g2msm(
-            result.as_mut_ptr() as *mut byte,
-            256,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 256];
+    evm_bls12381_g2msm_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bls12381_g2msm_into(r: &mut [u8], inputs: &[u8]) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bls12381_g2msm, r, 256, inputs)
 }
 
 #[inline]
 pub fn evm_bls12381_pairing_check(inputs: &[u8]) -> Result<[u8; 32], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bls12381_pairingcheck(
-            result.as_mut_ptr() as *mut byte,
-            32,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 32];
+    evm_bls12381_pairing_check_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bls12381_pairing_check_into(
+    r: &mut [u8],
+    inputs: &[u8],
+) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bls12381_pairingcheck, r, 32, inputs)
 }
 
 #[inline]
 pub fn evm_bls12381_map_fp_to_g1(inputs: &[u8]) -> Result<[u8; 128], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 128]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bls12381_map_fp_to_g1(
-            result.as_mut_ptr() as *mut byte,
-            128,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 128];
+    evm_bls12381_map_fp_to_g1_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bls12381_map_fp_to_g1_into(
+    r: &mut [u8],
+    inputs: &[u8],
+) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bls12381_map_fp_to_g1, r, 128, inputs)
 }
 
 #[inline]
 pub fn evm_bls12381_map_fp2_to_g2(inputs: &[u8]) -> Result<[u8; 256], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 256]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_bls12381_map_fp2_to_g2(
-            result.as_mut_ptr() as *mut byte,
-            256,
-            inputs.as_ptr() as *const byte,
-            inputs.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
-    }
+    let mut result = [0u8; 256];
+    evm_bls12381_map_fp2_to_g2_into(&mut result, inputs)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_bls12381_map_fp2_to_g2_into(
+    r: &mut [u8],
+    inputs: &[u8],
+) -> Result<usize, ctt_evm_status> {
+    call_into(ctt_eth_evm_bls12381_map_fp2_to_g2, r, 256, inputs)
 }
 
 /// KZG Point Evaluation for EIP-4844.
 /// The Ethereum KZG Context must be set with Ethereum Mainnet trusted setup
 /// It does not have to be set with a threadpool as this precompile is single-threaded
 #[inline]
-pub fn evm_kzg_point_evaluation<'tp>(ctx: &EthKzgContext<'tp>, message: &[u8]) -> Result<[u8; 64], ctt_evm_status> {
-    let mut result: MaybeUninit<[u8; 64]> = MaybeUninit::uninit();
-    unsafe {
-        let status = ctt_eth_evm_kzg_point_evaluation(
+pub fn evm_kzg_point_evaluation<'tp>(
+    ctx: &EthKzgContext<'tp>,
+    message: &[u8],
+) -> Result<[u8; 64], ctt_evm_status> {
+    let mut result = [0u8; 64];
+    evm_kzg_point_evaluation_into(ctx, &mut result, message)?;
+    Ok(result)
+}
+
+#[inline]
+pub fn evm_kzg_point_evaluation_into<'tp>(
+    ctx: &EthKzgContext<'tp>,
+    r: &mut [u8],
+    message: &[u8],
+) -> Result<usize, ctt_evm_status> {
+    let r = r
+        .get_mut(..64)
+        .ok_or(ctt_evm_status::cttEVM_InvalidOutputSize)?;
+    let status = unsafe {
+        ctt_eth_evm_kzg_point_evaluation(
             ctx.ctx,
-            result.as_mut_ptr() as *mut byte,
-            64,
+            r.as_mut_ptr() as *mut byte,
+            r.len() as usize,
             message.as_ptr() as *const byte,
             message.len() as usize,
-        );
-        match status {
-            ctt_evm_status::cttEVM_Success => Ok(result.assume_init()),
-            _ => Err(status),
-        }
+        )
+    };
+    match status {
+        ctt_evm_status::cttEVM_Success => Ok(64),
+        _ => Err(status),
     }
-}
\ No newline at end of file
+}
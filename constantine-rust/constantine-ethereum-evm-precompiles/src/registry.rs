@@ -0,0 +1,291 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use crate::failure::FailureDetails;
+use crate::gas::{required_gas, Hardfork, Precompile};
+use crate::*;
+
+use constantine_sys::ctt_evm_status;
+
+use std::fmt;
+
+/// 20-byte EVM account address
+pub type Address = [u8; 20];
+
+/// Precompiles with an address, in address order
+const ALL_PRECOMPILES: [Precompile; 18] = [
+    Precompile::EcRecover,
+    Precompile::Sha256,
+    Precompile::Ripemd160,
+    Precompile::Identity,
+    Precompile::ModExp,
+    Precompile::Bn254G1Add,
+    Precompile::Bn254G1Mul,
+    Precompile::Bn254PairingCheck,
+    Precompile::Blake2f,
+    Precompile::KzgPointEvaluation,
+    Precompile::Bls12381G1Add,
+    Precompile::Bls12381G1Msm,
+    Precompile::Bls12381G2Add,
+    Precompile::Bls12381G2Msm,
+    Precompile::Bls12381PairingCheck,
+    Precompile::Bls12381MapFpToG1,
+    Precompile::Bls12381MapFp2ToG2,
+    Precompile::P256Verify,
+];
+
+impl Precompile {
+    /// Address of the precompile.
+    /// The scalar multiplications of EIP-2537 drafts were merged into MSMs and have none.
+    pub fn address(self) -> Option<Address> {
+        let id: u16 = match self {
+            Precompile::EcRecover => 0x01,
+            Precompile::Sha256 => 0x02,
+            Precompile::Ripemd160 => 0x03,
+            Precompile::Identity => 0x04,
+            Precompile::ModExp => 0x05,
+            Precompile::Bn254G1Add => 0x06,
+            Precompile::Bn254G1Mul => 0x07,
+            Precompile::Bn254PairingCheck => 0x08,
+            Precompile::Blake2f => 0x09,
+            Precompile::KzgPointEvaluation => 0x0a,
+            Precompile::Bls12381G1Add => 0x0b,
+            Precompile::Bls12381G1Msm => 0x0c,
+            Precompile::Bls12381G2Add => 0x0d,
+            Precompile::Bls12381G2Msm => 0x0e,
+            Precompile::Bls12381PairingCheck => 0x0f,
+            Precompile::Bls12381MapFpToG1 => 0x10,
+            Precompile::Bls12381MapFp2ToG2 => 0x11,
+            Precompile::P256Verify => 0x100,
+            Precompile::Bls12381G1Mul | Precompile::Bls12381G2Mul => return None,
+        };
+        let mut address = [0u8; 20];
+        address[18..].copy_from_slice(&id.to_be_bytes());
+        Some(address)
+    }
+
+    /// Hardfork that activated the precompile
+    pub fn activation(self) -> Hardfork {
+        match self {
+            Precompile::EcRecover
+            | Precompile::Sha256
+            | Precompile::Ripemd160
+            | Precompile::Identity => Hardfork::Frontier,
+            Precompile::ModExp
+            | Precompile::Bn254G1Add
+            | Precompile::Bn254G1Mul
+            | Precompile::Bn254PairingCheck => Hardfork::Byzantium,
+            Precompile::Blake2f => Hardfork::Istanbul,
+            Precompile::KzgPointEvaluation => Hardfork::Cancun,
+            Precompile::Bls12381G1Add
+            | Precompile::Bls12381G1Mul
+            | Precompile::Bls12381G1Msm
+            | Precompile::Bls12381G2Add
+            | Precompile::Bls12381G2Mul
+            | Precompile::Bls12381G2Msm
+            | Precompile::Bls12381PairingCheck
+            | Precompile::Bls12381MapFpToG1
+            | Precompile::Bls12381MapFp2ToG2 => Hardfork::Prague,
+            Precompile::P256Verify => Hardfork::Osaka,
+        }
+    }
+}
+
+/// Successful precompile execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrecompileOutput {
+    pub gas_used: u64,
+    pub bytes: Vec<u8>,
+}
+
+/// Failed precompile execution.
+///
+/// The EVM consumes all the gas given to a failed precompile call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrecompileError {
+    /// The address is not a precompile active at the configured hardfork
+    NotAPrecompile(Address),
+    /// The gas limit does not cover the cost of the precompile
+    OutOfGas { required: u64, limit: u64 },
+    /// The precompile rejected its input
+    Failure(Failure),
+    /// KZG point evaluation was called without a KZG context
+    KzgContextNotSet,
+}
+
+impl From<ctt_evm_status> for PrecompileError {
+    fn from(status: ctt_evm_status) -> Self {
+        PrecompileError::Failure(status.into())
+    }
+}
+
+impl fmt::Display for PrecompileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PrecompileError::NotAPrecompile(address) => {
+                write!(f, "0x{} is not a precompile", hex_address(address))
+            }
+            PrecompileError::OutOfGas { required, limit } => {
+                write!(f, "out of gas: {} required, {} available", required, limit)
+            }
+            PrecompileError::Failure(failure) => write!(f, "precompile failure: {}", failure),
+            PrecompileError::KzgContextNotSet => write!(f, "KZG context not set"),
+        }
+    }
+}
+
+impl std::error::Error for PrecompileError {}
+
+fn hex_address(address: &Address) -> String {
+    address.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// The set of precompiles active at a hardfork
+///
+/// ```ignore
+/// let precompiles = Precompiles::new(Hardfork::Prague).set_kzg_context(&ctx);
+/// let output = precompiles.call(&address, &input, gas_limit)?;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Precompiles<'a> {
+    fork: Hardfork,
+    kzg_ctx: Option<&'a EthKzgContext<'a>>,
+}
+
+impl<'a> Precompiles<'a> {
+    pub fn new(fork: Hardfork) -> Self {
+        Self {
+            fork,
+            kzg_ctx: None,
+        }
+    }
+
+    /// Set the context used by KZG point evaluation,
+    /// it must be loaded with the Ethereum mainnet trusted setup.
+    pub fn set_kzg_context(self, ctx: &'a EthKzgContext<'a>) -> Self {
+        Self {
+            kzg_ctx: Some(ctx),
+            ..self
+        }
+    }
+
+    pub fn fork(&self) -> Hardfork {
+        self.fork
+    }
+
+    /// Precompiles active at the configured hardfork, in address order
+    pub fn active(&self) -> impl Iterator<Item = Precompile> + '_ {
+        ALL_PRECOMPILES
+            .into_iter()
+            .filter(move |p| p.activation() <= self.fork)
+    }
+
+    /// Addresses of the precompiles active at the configured hardfork, in increasing order
+    pub fn addresses(&self) -> impl Iterator<Item = Address> + '_ {
+        self.active().filter_map(Precompile::address)
+    }
+
+    /// Returns the precompile at `address` if it is active at the configured hardfork
+    pub fn get(&self, address: &Address) -> Option<Precompile> {
+        self.active()
+            .find(|p| p.address().as_ref() == Some(address))
+    }
+
+    pub fn contains(&self, address: &Address) -> bool {
+        self.get(address).is_some()
+    }
+
+    /// Execute the precompile at `address`.
+    ///
+    /// Gas is checked before execution.
+    /// As in Geth, ECRECOVER and P256VERIFY return an empty output on an invalid signature.
+    pub fn call(
+        &self,
+        address: &Address,
+        input: &[u8],
+        gas_limit: u64,
+    ) -> Result<PrecompileOutput, PrecompileError> {
+        let precompile = self
+            .get(address)
+            .ok_or(PrecompileError::NotAPrecompile(*address))?;
+
+        let gas_used = required_gas(precompile, input, self.fork);
+        if gas_used > gas_limit {
+            return Err(PrecompileError::OutOfGas {
+                required: gas_used,
+                limit: gas_limit,
+            });
+        }
+
+        let kzg_ctx = match precompile {
+            Precompile::KzgPointEvaluation => {
+                Some(self.kzg_ctx.ok_or(PrecompileError::KzgContextNotSet)?)
+            }
+            _ => None,
+        };
+
+        let mut details = FailureDetails::new();
+        let result = match precompile {
+            Precompile::EcRecover => match evm_ecrecover(input) {
+                Ok(r) => Ok(r.to_vec()),
+                Err(ctt_evm_status::cttEVM_MalformedSignature) => Ok(Vec::new()),
+                Err(status) => Err(status),
+            },
+            Precompile::Sha256 => evm_sha256(input).map(|r| r.to_vec()),
+            Precompile::Ripemd160 => evm_ripemd160(input).map(|r| r.to_vec()),
+            Precompile::Identity => Ok(input.to_vec()),
+            Precompile::ModExp => evm_modexp(input),
+            Precompile::Bn254G1Add => details.call(ctt_eth_evm_bn254_g1add_detailed, 64, input),
+            Precompile::Bn254G1Mul => details.call(ctt_eth_evm_bn254_g1mul_detailed, 64, input),
+            Precompile::Bn254PairingCheck => {
+                details.call(ctt_eth_evm_bn254_ecpairingcheck_detailed, 32, input)
+            }
+            Precompile::Blake2f => evm_blake2f(input).map(|r| r.to_vec()),
+            Precompile::KzgPointEvaluation => {
+                evm_kzg_point_evaluation(kzg_ctx.unwrap(), input).map(|r| r.to_vec())
+            }
+            Precompile::Bls12381G1Add => {
+                details.call(ctt_eth_evm_bls12381_g1add_detailed, 128, input)
+            }
+            Precompile::Bls12381G1Mul => {
+                details.call(ctt_eth_evm_bls12381_g1mul_detailed, 128, input)
+            }
+            Precompile::Bls12381G1Msm => {
+                details.call(ctt_eth_evm_bls12381_g1msm_detailed, 128, input)
+            }
+            Precompile::Bls12381G2Add => {
+                details.call(ctt_eth_evm_bls12381_g2add_detailed, 256, input)
+            }
+            Precompile::Bls12381G2Mul => {
+                details.call(ctt_eth_evm_bls12381_g2mul_detailed, 256, input)
+            }
+            Precompile::Bls12381G2Msm => {
+                details.call(ctt_eth_evm_bls12381_g2msm_detailed, 256, input)
+            }
+            Precompile::Bls12381PairingCheck => {
+                details.call(ctt_eth_evm_bls12381_pairingcheck_detailed, 32, input)
+            }
+            Precompile::Bls12381MapFpToG1 => {
+                details.call(ctt_eth_evm_bls12381_map_fp_to_g1_detailed, 128, input)
+            }
+            Precompile::Bls12381MapFp2ToG2 => {
+                details.call(ctt_eth_evm_bls12381_map_fp2_to_g2_detailed, 256, input)
+            }
+            Precompile::P256Verify => match details.call(ctt_eth_evm_p256verify_detailed, 32, input) {
+                Ok(r) => Ok(r),
+                Err(ctt_evm_status::cttEVM_InvalidOutputSize) => {
+                    Err(ctt_evm_status::cttEVM_InvalidOutputSize)
+                }
+                Err(_) => Ok(Vec::new()),
+            },
+        };
+        let bytes = result
+            .map_err(|status| PrecompileError::Failure(details.failure(precompile, status)))?;
+        Ok(PrecompileOutput { gas_used, bytes })
+    }
+}
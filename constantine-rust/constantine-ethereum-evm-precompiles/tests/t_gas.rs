@@ -0,0 +1,261 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_ethereum_evm_precompiles::gas::{required_gas, Hardfork, Precompile};
+
+use std::fs;
+
+use hex;
+use serde::Deserialize;
+use serde_json;
+
+macro_rules! test_dir {
+    () => {
+        "../../tests/protocol_ethereum_evm_precompiles/"
+    };
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Debug)]
+struct PrecompileTest {
+    Input: String,
+    Name: String,
+    Gas: u64,
+}
+
+/// Check gas against Geth test vectors
+fn t_gas_vectors(test_file: &str, precompile: Precompile, fork: Hardfork) {
+    let unparsed = fs::read_to_string(test_file).unwrap();
+    let vectors: Vec<PrecompileTest> = serde_json::from_str(&unparsed).unwrap();
+
+    for vector in vectors {
+        println!("Running test case: {}", vector.Name);
+        let input = hex::decode(vector.Input.trim_start_matches("0x")).unwrap();
+        assert_eq!(
+            required_gas(precompile, &input, fork),
+            vector.Gas,
+            "{}",
+            vector.Name
+        );
+    }
+}
+
+#[test]
+fn t_gas_ecrecover() {
+    t_gas_vectors(
+        concat!(test_dir!(), "ecRecover.json"),
+        Precompile::EcRecover,
+        Hardfork::Cancun,
+    );
+}
+
+#[test]
+fn t_gas_modexp() {
+    t_gas_vectors(
+        concat!(test_dir!(), "modexp.json"),
+        Precompile::ModExp,
+        Hardfork::Byzantium,
+    );
+    t_gas_vectors(
+        concat!(test_dir!(), "modexp_eip2565.json"),
+        Precompile::ModExp,
+        Hardfork::Berlin,
+    );
+}
+
+#[test]
+fn t_gas_bn254() {
+    t_gas_vectors(
+        concat!(test_dir!(), "bn256Add.json"),
+        Precompile::Bn254G1Add,
+        Hardfork::Istanbul,
+    );
+    t_gas_vectors(
+        concat!(test_dir!(), "bn256ScalarMul.json"),
+        Precompile::Bn254G1Mul,
+        Hardfork::Istanbul,
+    );
+    t_gas_vectors(
+        concat!(test_dir!(), "bn256Pairing.json"),
+        Precompile::Bn254PairingCheck,
+        Hardfork::Istanbul,
+    );
+
+    assert_eq!(
+        required_gas(Precompile::Bn254G1Add, &[], Hardfork::Byzantium),
+        500
+    );
+    assert_eq!(
+        required_gas(Precompile::Bn254G1Mul, &[], Hardfork::Byzantium),
+        40000
+    );
+    assert_eq!(
+        required_gas(
+            Precompile::Bn254PairingCheck,
+            &[0; 384],
+            Hardfork::Byzantium
+        ),
+        260000
+    );
+}
+
+#[test]
+fn t_gas_point_evaluation() {
+    t_gas_vectors(
+        concat!(test_dir!(), "eip-4844/pointEvaluation.json"),
+        Precompile::KzgPointEvaluation,
+        Hardfork::Cancun,
+    );
+}
+
+#[test]
+fn t_gas_hashes() {
+    assert_eq!(required_gas(Precompile::Sha256, &[], Hardfork::Cancun), 60);
+    assert_eq!(
+        required_gas(Precompile::Sha256, &[0; 33], Hardfork::Cancun),
+        84
+    );
+    assert_eq!(
+        required_gas(Precompile::Ripemd160, &[0; 32], Hardfork::Cancun),
+        720
+    );
+}
+
+#[test]
+fn t_gas_bls12381() {
+    // The vectors in eip-2537/ predate the final pricing
+    let fork = Hardfork::Prague;
+    assert_eq!(
+        required_gas(Precompile::Bls12381G1Add, &[0; 256], fork),
+        375
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381G2Add, &[0; 512], fork),
+        600
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381MapFpToG1, &[0; 64], fork),
+        5500
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381MapFp2ToG2, &[0; 128], fork),
+        23800
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381PairingCheck, &[0; 768], fork),
+        102900
+    );
+
+    assert_eq!(required_gas(Precompile::Bls12381G1Msm, &[], fork), 0);
+    assert_eq!(
+        required_gas(Precompile::Bls12381G1Msm, &[0; 160], fork),
+        12000
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381G1Msm, &[0; 2 * 160], fork),
+        22776
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381G1Msm, &[0; 128 * 160], fork),
+        797184
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381G1Msm, &[0; 200 * 160], fork),
+        1245600
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381G1Mul, &[0; 160], fork),
+        required_gas(Precompile::Bls12381G1Msm, &[0; 160], fork)
+    );
+
+    assert_eq!(
+        required_gas(Precompile::Bls12381G2Msm, &[0; 288], fork),
+        22500
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381G2Msm, &[0; 2 * 288], fork),
+        45000
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381G2Msm, &[0; 3 * 288], fork),
+        62302
+    );
+    assert_eq!(
+        required_gas(Precompile::Bls12381G2Mul, &[0; 288], fork),
+        required_gas(Precompile::Bls12381G2Msm, &[0; 288], fork)
+    );
+}
+
+#[test]
+fn t_gas_p256verify() {
+    t_gas_vectors(
+        concat!(test_dir!(), "p256Verify.json"),
+        Precompile::P256Verify,
+        Hardfork::Osaka,
+    );
+    t_gas_vectors(
+        concat!(test_dir!(), "p256Verify_edge_cases.json"),
+        Precompile::P256Verify,
+        Hardfork::Osaka,
+    );
+    // RIP-7212 pricing
+    assert_eq!(
+        required_gas(Precompile::P256Verify, &[0; 160], Hardfork::Prague),
+        3450
+    );
+}
+
+#[test]
+fn t_gas_modexp_edge_cases() {
+    // Lengths that do not fit in 64-bit saturate
+    let mut huge = [0u8; 96];
+    huge[0] = 1; // base length 2²⁴⁸
+    huge[95] = 1; // modulus length 1
+    for fork in [Hardfork::Byzantium, Hardfork::Berlin, Hardfork::Osaka] {
+        assert_eq!(required_gas(Precompile::ModExp, &huge, fork), u64::MAX);
+    }
+
+    // Empty base and modulus are free before minimum prices, whatever the exponent length
+    let mut empty = [0u8; 96];
+    empty[32] = 0xff;
+    assert_eq!(
+        required_gas(Precompile::ModExp, &empty, Hardfork::Byzantium),
+        0
+    );
+    assert_eq!(
+        required_gas(Precompile::ModExp, &empty, Hardfork::Berlin),
+        200
+    );
+
+    // EIP-7883: 32-byte operands and 3-bit exponent: max(500, 16 * 2)
+    let mut small = vec![0u8; 96];
+    small[31] = 32;
+    small[63] = 1;
+    small[95] = 32;
+    small.extend_from_slice(&[0xff; 32]);
+    small.push(0x05);
+    assert_eq!(
+        required_gas(Precompile::ModExp, &small, Hardfork::Osaka),
+        500
+    );
+
+    // EIP-7883: 64-byte operands, 64-byte exponent: 2 * 8² * (16 * 32 + 255)
+    let mut large = vec![0u8; 96];
+    large[31] = 64;
+    large[63] = 64;
+    large[95] = 64;
+    large.extend_from_slice(&[0xff; 64 + 64 + 64]);
+    assert_eq!(
+        required_gas(Precompile::ModExp, &large, Hardfork::Osaka),
+        2 * 64 * (16 * 32 + 255)
+    );
+    assert_eq!(
+        required_gas(Precompile::ModExp, &large, Hardfork::Berlin),
+        64 * (8 * 32 + 255) / 3
+    );
+}
@@ -0,0 +1,263 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_ethereum_evm_precompiles::*;
+use constantine_sys::ctt_evm_status;
+
+use std::fs;
+use std::path::Path;
+
+use hex;
+use serde::Deserialize;
+use serde_json;
+
+macro_rules! test_dir {
+    () => {
+        "../../tests/protocol_ethereum_evm_precompiles/"
+    };
+}
+
+const SRS_PATH: &str =
+    "../../constantine/commitments_setups/trusted_setup_ethereum_kzg4844_reference.dat";
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Debug)]
+struct PrecompileTest {
+    Input: String,
+    #[serde(default)]
+    Expected: Option<String>,
+    Name: String,
+    #[serde(default)]
+    Gas: u64,
+}
+
+fn address(id: u16) -> Address {
+    let mut address = [0u8; 20];
+    address[18..].copy_from_slice(&id.to_be_bytes());
+    address
+}
+
+/// Run Geth test vectors through the registry, checking outputs and gas
+fn t_call_vectors(precompiles: &Precompiles, id: u16, test_file: &str) {
+    let unparsed = fs::read_to_string(test_file).unwrap();
+    let vectors: Vec<PrecompileTest> = serde_json::from_str(&unparsed).unwrap();
+
+    for vector in vectors {
+        println!("Running test case: {}", vector.Name);
+        let input = hex::decode(vector.Input.trim_start_matches("0x")).unwrap();
+        match precompiles.call(&address(id), &input, u64::MAX) {
+            Ok(output) => {
+                assert_eq!(
+                    hex::encode(output.bytes),
+                    vector.Expected.unwrap(),
+                    "{}",
+                    vector.Name
+                );
+                assert_eq!(output.gas_used, vector.Gas, "{}", vector.Name);
+            }
+            Err(err) => {
+                assert!(vector.Expected.is_none(), "{}: {}", vector.Name, err);
+            }
+        }
+    }
+}
+
+#[test]
+fn t_active_precompiles() {
+    let count = |fork| Precompiles::new(fork).addresses().count();
+    assert_eq!(count(Hardfork::Frontier), 4);
+    assert_eq!(count(Hardfork::Byzantium), 8);
+    assert_eq!(count(Hardfork::Istanbul), 9);
+    assert_eq!(count(Hardfork::Berlin), 9);
+    assert_eq!(count(Hardfork::Cancun), 10);
+    assert_eq!(count(Hardfork::Prague), 17);
+    assert_eq!(count(Hardfork::Osaka), 18);
+
+    let prague = Precompiles::new(Hardfork::Prague);
+    let addresses: Vec<Address> = prague.addresses().collect();
+    assert_eq!(addresses, (0x01..=0x11).map(address).collect::<Vec<_>>());
+    assert_eq!(prague.get(&address(0x0c)), Some(Precompile::Bls12381G1Msm));
+    assert!(!prague.contains(&address(0x12)));
+
+    let osaka = Precompiles::new(Hardfork::Osaka);
+    assert_eq!(osaka.get(&address(0x100)), Some(Precompile::P256Verify));
+    assert!(!prague.contains(&address(0x100)));
+
+    let berlin = Precompiles::new(Hardfork::Berlin);
+    assert!(!berlin.contains(&address(0x0a)));
+    assert_eq!(
+        berlin.call(&address(0x0a), &[], u64::MAX),
+        Err(PrecompileError::NotAPrecompile(address(0x0a)))
+    );
+}
+
+#[test]
+fn t_call() {
+    let precompiles = Precompiles::new(Hardfork::Cancun);
+
+    assert_eq!(
+        precompiles.call(&address(0x04), &[1, 2, 3], 18),
+        Ok(PrecompileOutput {
+            gas_used: 18,
+            bytes: vec![1, 2, 3]
+        })
+    );
+    assert_eq!(
+        precompiles.call(&address(0x02), &[], 59),
+        Err(PrecompileError::OutOfGas {
+            required: 60,
+            limit: 59
+        })
+    );
+    assert_eq!(
+        precompiles.call(&address(0x09), &[0; 212], u64::MAX),
+        Err(PrecompileError::Failure(
+            ctt_evm_status::cttEVM_InvalidInputSize.into()
+        ))
+    );
+    assert_eq!(
+        precompiles.call(&address(0x0a), &[0; 192], u64::MAX),
+        Err(PrecompileError::KzgContextNotSet)
+    );
+
+    // Invalid signatures return an empty output
+    assert_eq!(
+        precompiles.call(&address(0x01), &[0; 128], u64::MAX),
+        Ok(PrecompileOutput {
+            gas_used: 3000,
+            bytes: vec![]
+        })
+    );
+}
+
+#[test]
+fn t_call_failure_location() {
+    let precompiles = Precompiles::new(Hardfork::Prague);
+
+    // G1ADD: last padding byte of the x coordinate of the second point
+    let mut input = [0u8; 256];
+    input[128 + 15] = 1;
+    assert_eq!(
+        precompiles.call(&address(0x0b), &input, u64::MAX),
+        Err(PrecompileError::Failure(Failure {
+            status: ctt_evm_status::cttEVM_IntLargerThanModulus,
+            reason: FailureReason::NonZeroPadding,
+            offset: Some(128),
+            index: Some(1),
+        }))
+    );
+
+    // MAP_FP_TO_G1: only the last padding byte is non-zero
+    let mut input = [0u8; 64];
+    input[15] = 1;
+    assert_eq!(
+        precompiles.call(&address(0x10), &input, u64::MAX),
+        Err(PrecompileError::Failure(Failure {
+            status: ctt_evm_status::cttEVM_IntLargerThanModulus,
+            reason: FailureReason::NonZeroPadding,
+            offset: Some(0),
+            index: None,
+        }))
+    );
+
+    // MAP_FP2_TO_G2: only the last padding byte of the second coordinate is non-zero
+    let mut input = [0u8; 128];
+    input[64 + 15] = 1;
+    let failure = match precompiles.call(&address(0x11), &input, u64::MAX) {
+        Err(PrecompileError::Failure(failure)) => failure,
+        result => panic!("unexpected result {:?}", result),
+    };
+    assert_eq!(failure.reason, FailureReason::NonZeroPadding);
+    assert_eq!(failure.offset, Some(64));
+    assert_eq!(failure.to_string(), "non-zero padding at input offset 64");
+
+    // G1MSM: second point (0, 1) is not on the curve
+    let mut input = [0u8; 320];
+    input[160 + 127] = 1;
+    assert_eq!(
+        precompiles.call(&address(0x0c), &input, u64::MAX),
+        Err(PrecompileError::Failure(Failure {
+            status: ctt_evm_status::cttEVM_PointNotOnCurve,
+            reason: FailureReason::PointNotOnCurve,
+            offset: Some(160),
+            index: Some(1),
+        }))
+    );
+
+    // The MSM input length is not a multiple of a (point, scalar) pair
+    assert_eq!(
+        precompiles.call(&address(0x0c), &input[..319], u64::MAX),
+        Err(PrecompileError::Failure(Failure {
+            status: ctt_evm_status::cttEVM_InvalidInputSize,
+            reason: FailureReason::InvalidLength,
+            offset: None,
+            index: None,
+        }))
+    );
+}
+
+#[test]
+fn t_call_vectors_istanbul() {
+    let precompiles = Precompiles::new(Hardfork::Istanbul);
+    t_call_vectors(&precompiles, 0x01, concat!(test_dir!(), "ecRecover.json"));
+    t_call_vectors(&precompiles, 0x05, concat!(test_dir!(), "modexp.json"));
+    t_call_vectors(&precompiles, 0x06, concat!(test_dir!(), "bn256Add.json"));
+    t_call_vectors(
+        &precompiles,
+        0x07,
+        concat!(test_dir!(), "bn256ScalarMul.json"),
+    );
+    t_call_vectors(
+        &precompiles,
+        0x08,
+        concat!(test_dir!(), "bn256Pairing.json"),
+    );
+    t_call_vectors(&precompiles, 0x09, concat!(test_dir!(), "blake2F.json"));
+    t_call_vectors(
+        &precompiles,
+        0x09,
+        concat!(test_dir!(), "fail-blake2f.json"),
+    );
+}
+
+#[test]
+fn t_call_vectors_cancun() {
+    let ctx = EthKzgContext::load_trusted_setup(Path::new(SRS_PATH))
+        .expect("Trusted setup should be loaded without error.");
+    let precompiles = Precompiles::new(Hardfork::Cancun).set_kzg_context(&ctx);
+    t_call_vectors(
+        &precompiles,
+        0x05,
+        concat!(test_dir!(), "modexp_eip2565.json"),
+    );
+    t_call_vectors(
+        &precompiles,
+        0x0a,
+        concat!(test_dir!(), "eip-4844/pointEvaluation.json"),
+    );
+}
+
+#[test]
+fn t_call_vectors_osaka() {
+    let precompiles = Precompiles::new(Hardfork::Osaka);
+    t_call_vectors(&precompiles, 0x100, concat!(test_dir!(), "p256Verify.json"));
+    t_call_vectors(
+        &precompiles,
+        0x100,
+        concat!(test_dir!(), "p256Verify_edge_cases.json"),
+    );
+
+    // Invalid signatures return an empty output
+    assert_eq!(
+        precompiles.call(&address(0x100), &[0; 160], u64::MAX),
+        Ok(PrecompileOutput {
+            gas_used: 6900,
+            bytes: vec![]
+        })
+    );
+}
@@ -0,0 +1,175 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use crate::types::{Blob, KzgCommitment, KzgProof, BYTES_PER_FIELD_ELEMENT};
+
+use constantine_sys::*;
+
+use ::core::mem::MaybeUninit;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Errors of the Ethereum KZG API.
+///
+/// A failed verification is not an error, verification functions return `Ok(false)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KzgError {
+    // Trusted setup
+    /// The trusted setup file does not exist or cannot be opened
+    TrustedSetupMissingOrInaccessibleFile { path: PathBuf },
+    /// The trusted setup file is malformed or contains invalid points
+    TrustedSetupInvalidFile { path: PathBuf },
+    /// The context builder was not given a trusted setup
+    TrustedSetupNotLoaded,
+
+    // Input validation
+    /// A scalar is zero where this is not allowed
+    ScalarZero,
+    /// A field element of a blob or a challenge is not reduced modulo the curve order
+    ScalarLargerThanCurveOrder,
+    /// A commitment or proof does not follow the compressed point encoding
+    EccInvalidEncoding,
+    /// A commitment or proof has a coordinate greater or equal to the field modulus
+    EccCoordinateGreaterThanOrEqualModulus,
+    /// A commitment or proof is not on the curve
+    EccPointNotOnCurve,
+    /// A commitment or proof is not in the prime-order subgroup
+    EccPointNotInSubgroup,
+
+    // Batch
+    /// Batch inputs do not have the same number of elements
+    InputsLengthsMismatch { blobs: usize, commitments: usize, proofs: usize },
+    /// The blob, commitment or proof at `index` of a batch is invalid
+    InvalidBatchInput { index: usize, error: Box<KzgError> },
+
+    // Bindings
+    /// Constantine returned a status that is not an error of this call.
+    /// This is a bug of the bindings and should be reported.
+    Internal(ctt_eth_kzg_status),
+}
+
+impl fmt::Display for KzgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KzgError::TrustedSetupMissingOrInaccessibleFile { path } => {
+                write!(f, "trusted setup file {} is missing or inaccessible", path.display())
+            }
+            KzgError::TrustedSetupInvalidFile { path } => {
+                write!(f, "trusted setup file {} is invalid", path.display())
+            }
+            KzgError::TrustedSetupNotLoaded => write!(f, "trusted setup not loaded"),
+            KzgError::ScalarZero => write!(f, "scalar is zero"),
+            KzgError::ScalarLargerThanCurveOrder => write!(f, "scalar larger than curve order"),
+            KzgError::EccInvalidEncoding => write!(f, "invalid point encoding"),
+            KzgError::EccCoordinateGreaterThanOrEqualModulus => {
+                write!(f, "point coordinate greater than or equal to field modulus")
+            }
+            KzgError::EccPointNotOnCurve => write!(f, "point not on curve"),
+            KzgError::EccPointNotInSubgroup => write!(f, "point not in subgroup"),
+            KzgError::InputsLengthsMismatch { blobs, commitments, proofs } => write!(
+                f,
+                "inputs lengths mismatch: {} blobs, {} commitments, {} proofs",
+                blobs, commitments, proofs
+            ),
+            KzgError::InvalidBatchInput { index, error } => {
+                write!(f, "invalid batch input at index {}: {}", index, error)
+            }
+            KzgError::Internal(status) => write!(f, "unexpected Constantine status {}", status),
+        }
+    }
+}
+
+impl std::error::Error for KzgError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KzgError::InvalidBatchInput { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Convert an error status of the C API.
+/// Success and verification failure are not errors and must be handled by the caller,
+/// they are reported as `KzgError::Internal` like any status the caller did not expect.
+pub(crate) fn kzg_error(status: ctt_eth_kzg_status) -> KzgError {
+    match status {
+        ctt_eth_kzg_status::cttEthKzg_ScalarZero => KzgError::ScalarZero,
+        ctt_eth_kzg_status::cttEthKzg_ScalarLargerThanCurveOrder => KzgError::ScalarLargerThanCurveOrder,
+        ctt_eth_kzg_status::cttEthKzg_EccInvalidEncoding => KzgError::EccInvalidEncoding,
+        ctt_eth_kzg_status::cttEthKzg_EccCoordinateGreaterThanOrEqualModulus => {
+            KzgError::EccCoordinateGreaterThanOrEqualModulus
+        }
+        ctt_eth_kzg_status::cttEthKzg_EccPointNotOnCurve => KzgError::EccPointNotOnCurve,
+        ctt_eth_kzg_status::cttEthKzg_EccPointNotInSubgroup => KzgError::EccPointNotInSubgroup,
+        // Lengths are checked on the Rust side to report them
+        ctt_eth_kzg_status::cttEthKzg_Success
+        | ctt_eth_kzg_status::cttEthKzg_VerificationFailure
+        | ctt_eth_kzg_status::cttEthKzg_InputsLengthsMismatch => KzgError::Internal(status),
+    }
+}
+
+pub(crate) fn trusted_setup_error(status: ctt_eth_trusted_setup_status, path: PathBuf) -> KzgError {
+    match status {
+        ctt_eth_trusted_setup_status::cttEthTS_InvalidFile => KzgError::TrustedSetupInvalidFile { path },
+        _ => KzgError::TrustedSetupMissingOrInaccessibleFile { path },
+    }
+}
+
+// Locating invalid inputs in batches
+// ------------------------------------------------------------
+
+fn validate_blob(blob: &Blob) -> Result<(), KzgError> {
+    let mut scalar = MaybeUninit::<big255>::uninit();
+    for fe in blob.as_bytes().chunks_exact(BYTES_PER_FIELD_ELEMENT) {
+        let status = unsafe { ctt_bls12_381_deserialize_scalar(scalar.as_mut_ptr(), fe.as_ptr()) };
+        match status {
+            ctt_codec_scalar_status::cttCodecScalar_Success | ctt_codec_scalar_status::cttCodecScalar_Zero => {}
+            ctt_codec_scalar_status::cttCodecScalar_ScalarLargerThanCurveOrder => {
+                return Err(KzgError::ScalarLargerThanCurveOrder)
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_g1(bytes: &[u8; 48]) -> Result<(), KzgError> {
+    let mut point = MaybeUninit::<bls12_381_g1_aff>::uninit();
+    let status = unsafe { ctt_bls12_381_deserialize_g1_compressed(point.as_mut_ptr(), bytes.as_ptr()) };
+    match status {
+        // The point at infinity is a valid commitment or proof
+        ctt_codec_ecc_status::cttCodecEcc_Success | ctt_codec_ecc_status::cttCodecEcc_PointAtInfinity => Ok(()),
+        ctt_codec_ecc_status::cttCodecEcc_InvalidEncoding => Err(KzgError::EccInvalidEncoding),
+        ctt_codec_ecc_status::cttCodecEcc_CoordinateGreaterThanOrEqualModulus => {
+            Err(KzgError::EccCoordinateGreaterThanOrEqualModulus)
+        }
+        ctt_codec_ecc_status::cttCodecEcc_PointNotOnCurve => Err(KzgError::EccPointNotOnCurve),
+        ctt_codec_ecc_status::cttCodecEcc_PointNotInSubgroup => Err(KzgError::EccPointNotInSubgroup),
+    }
+}
+
+/// The batch C API reports the kind of error but not which input caused it.
+/// This is only called on the error path to locate the faulty input.
+pub(crate) fn batch_error(
+    status: ctt_eth_kzg_status,
+    blobs: &[Blob],
+    commitments: &[KzgCommitment],
+    proofs: &[KzgProof],
+) -> KzgError {
+    for (index, ((blob, commitment), proof)) in blobs.iter().zip(commitments).zip(proofs).enumerate() {
+        let validation = validate_g1(commitment.as_bytes())
+            .and_then(|_| validate_g1(proof.as_bytes()))
+            .and_then(|_| validate_blob(blob));
+        if let Err(error) = validation {
+            return KzgError::InvalidBatchInput {
+                index,
+                error: Box::new(error),
+            };
+        }
+    }
+    kzg_error(status)
+}
@@ -12,6 +12,21 @@ use constantine_sys::*;
 use ::core::mem::MaybeUninit;
 use std::{ffi::CString, path::Path};
 
+mod errors;
+mod types;
+pub use errors::KzgError;
+pub use types::*;
+
+use errors::{batch_error, kzg_error, trusted_setup_error};
+
+fn invalid_indices(invalid: &[bool]) -> Vec<usize> {
+    invalid
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &is_invalid)| is_invalid.then_some(i))
+        .collect()
+}
+
 // Trusted setup
 // ------------------------------------------------------------
 
@@ -34,7 +49,7 @@ impl<'tp> Drop for EthKzgContext<'tp> {
 }
 
 impl<'tp> EthKzgContextBuilder<'tp> {
-    pub fn load_trusted_setup(self, file_path: &Path) -> Result<Self, ctt_eth_trusted_setup_status> {
+    pub fn load_trusted_setup(self, file_path: &Path) -> Result<Self, KzgError> {
         // The joy of OS Paths / C Paths:
         // https://users.rust-lang.org/t/easy-way-to-pass-a-path-to-c/51829
         // https://doc.rust-lang.org/std/ffi/index.html#conversions
@@ -53,12 +68,15 @@ impl<'tp> EthKzgContextBuilder<'tp> {
             file_path
                 .as_os_str()
                 .to_str()
-                .ok_or(ctt_eth_trusted_setup_status::cttEthTS_MissingOrInaccessibleFile)?
+                .ok_or_else(|| KzgError::TrustedSetupMissingOrInaccessibleFile {
+                    path: file_path.to_path_buf(),
+                })?
                 .as_bytes()
         };
 
-        let c_path = CString::new(raw_path)
-            .map_err(|_| ctt_eth_trusted_setup_status::cttEthTS_MissingOrInaccessibleFile)?;
+        let c_path = CString::new(raw_path).map_err(|_| KzgError::TrustedSetupMissingOrInaccessibleFile {
+            path: file_path.to_path_buf(),
+        })?;
 
         let mut ctx: *mut ctt_eth_kzg_context = std::ptr::null_mut();
         let ctx_ptr: *mut *mut ctt_eth_kzg_context = &mut ctx;
@@ -71,7 +89,7 @@ impl<'tp> EthKzgContextBuilder<'tp> {
         };
         match status {
             ctt_eth_trusted_setup_status::cttEthTS_Success => Ok(Self { ctx: Some(ctx), threadpool: self.threadpool }),
-            _ => Err(status),
+            _ => Err(trusted_setup_error(status, file_path.to_path_buf())),
         }
     }
 
@@ -82,8 +100,8 @@ impl<'tp> EthKzgContextBuilder<'tp> {
         Self { ctx, threadpool: Some(tp)}
     }
 
-    pub fn build(self) -> Result<EthKzgContext<'tp>, ctt_eth_trusted_setup_status> {
-        let ctx = self.ctx.ok_or(ctt_eth_trusted_setup_status::cttEthTS_MissingOrInaccessibleFile)?;
+    pub fn build(self) -> Result<EthKzgContext<'tp>, KzgError> {
+        let ctx = self.ctx.ok_or(KzgError::TrustedSetupNotLoaded)?;
         Ok(EthKzgContext{
             ctx,
             threadpool: self.threadpool,
@@ -97,7 +115,7 @@ impl<'tp> EthKzgContext<'tp> {
         EthKzgContextBuilder{ctx: None, threadpool: None}
     }
 
-    pub fn load_trusted_setup(file_path: &Path) -> Result<Self, ctt_eth_trusted_setup_status> {
+    pub fn load_trusted_setup(file_path: &Path) -> Result<Self, KzgError> {
         Ok(Self::builder()
             .load_trusted_setup(file_path)?
             .build()
@@ -107,18 +125,18 @@ impl<'tp> EthKzgContext<'tp> {
     #[inline]
     pub fn blob_to_kzg_commitment(
         &self,
-        blob: &[u8; 4096 * 32],
-    ) -> Result<[u8; 48], ctt_eth_kzg_status> {
-        let mut result: MaybeUninit<[u8; 48]> = MaybeUninit::uninit();
+        blob: &Blob,
+    ) -> Result<KzgCommitment, KzgError> {
+        let mut result: MaybeUninit<KzgCommitment> = MaybeUninit::uninit();
         unsafe {
             let status = ctt_eth_kzg_blob_to_kzg_commitment(
                 self.ctx,
                 result.as_mut_ptr() as *mut ctt_eth_kzg_commitment,
-                blob.as_ptr() as *const ctt_eth_kzg_blob,
+                blob.as_ffi(),
             );
             match status {
                 ctt_eth_kzg_status::cttEthKzg_Success => Ok(result.assume_init()),
-                _ => Err(status),
+                _ => Err(kzg_error(status)),
             }
         }
     }
@@ -126,24 +144,24 @@ impl<'tp> EthKzgContext<'tp> {
     #[inline]
     pub fn compute_kzg_proof(
         &self,
-        blob: &[u8; 4096 * 32],
-        z_challenge: &[u8; 32],
-    ) -> Result<([u8; 48], [u8; 32]), ctt_eth_kzg_status> {
-        let mut proof = MaybeUninit::<[u8; 48]>::uninit();
-        let mut y_eval = MaybeUninit::<[u8; 32]>::uninit();
+        blob: &Blob,
+        z_challenge: &FieldElement,
+    ) -> Result<(KzgProof, FieldElement), KzgError> {
+        let mut proof = MaybeUninit::<KzgProof>::uninit();
+        let mut y_eval = MaybeUninit::<FieldElement>::uninit();
         unsafe {
             let status = ctt_eth_kzg_compute_kzg_proof(
                 self.ctx,
                 proof.as_mut_ptr() as *mut ctt_eth_kzg_proof,
                 y_eval.as_mut_ptr() as *mut ctt_eth_kzg_eval_at_challenge,
-                blob.as_ptr() as *const ctt_eth_kzg_blob,
-                z_challenge.as_ptr() as *const ctt_eth_kzg_opening_challenge,
+                blob.as_ffi(),
+                z_challenge.as_bytes().as_ptr() as *const ctt_eth_kzg_opening_challenge,
             );
             match status {
                 ctt_eth_kzg_status::cttEthKzg_Success => {
                     Ok((proof.assume_init(), y_eval.assume_init()))
                 }
-                _ => Err(status),
+                _ => Err(kzg_error(status)),
             }
         }
     }
@@ -151,44 +169,44 @@ impl<'tp> EthKzgContext<'tp> {
     #[inline]
     pub fn verify_kzg_proof(
         &self,
-        commitment: &[u8; 48],
-        z_challenge: &[u8; 32],
-        y_eval_at_challenge: &[u8; 32],
-        proof: &[u8; 48],
-    ) -> Result<bool, ctt_eth_kzg_status> {
+        commitment: &KzgCommitment,
+        z_challenge: &FieldElement,
+        y_eval_at_challenge: &FieldElement,
+        proof: &KzgProof,
+    ) -> Result<bool, KzgError> {
         let status = unsafe {
             ctt_eth_kzg_verify_kzg_proof(
                 self.ctx,
-                commitment.as_ptr() as *const ctt_eth_kzg_commitment,
-                z_challenge.as_ptr() as *const ctt_eth_kzg_opening_challenge,
-                y_eval_at_challenge.as_ptr() as *const ctt_eth_kzg_eval_at_challenge,
-                proof.as_ptr() as *const ctt_eth_kzg_proof,
+                commitment.as_ffi(),
+                z_challenge.as_bytes().as_ptr() as *const ctt_eth_kzg_opening_challenge,
+                y_eval_at_challenge.as_bytes().as_ptr() as *const ctt_eth_kzg_eval_at_challenge,
+                proof.as_ffi(),
             )
         };
         match status {
             ctt_eth_kzg_status::cttEthKzg_Success => Ok(true),
             ctt_eth_kzg_status::cttEthKzg_VerificationFailure => Ok(false),
-            _ => Err(status),
+            _ => Err(kzg_error(status)),
         }
     }
 
     #[inline]
     pub fn compute_blob_kzg_proof(
         &self,
-        blob: &[u8; 4096 * 32],
-        commitment: &[u8; 48],
-    ) -> Result<[u8; 48], ctt_eth_kzg_status> {
-        let mut proof = MaybeUninit::<[u8; 48]>::uninit();
+        blob: &Blob,
+        commitment: &KzgCommitment,
+    ) -> Result<KzgProof, KzgError> {
+        let mut proof = MaybeUninit::<KzgProof>::uninit();
         unsafe {
             let status = ctt_eth_kzg_compute_blob_kzg_proof(
                 self.ctx,
                 proof.as_mut_ptr() as *mut ctt_eth_kzg_proof,
-                blob.as_ptr() as *const ctt_eth_kzg_blob,
-                commitment.as_ptr() as *const ctt_eth_kzg_commitment,
+                blob.as_ffi(),
+                commitment.as_ffi(),
             );
             match status {
                 ctt_eth_kzg_status::cttEthKzg_Success => Ok(proof.assume_init()),
-                _ => Err(status),
+                _ => Err(kzg_error(status)),
             }
         }
     }
@@ -196,41 +214,44 @@ impl<'tp> EthKzgContext<'tp> {
     #[inline]
     pub fn verify_blob_kzg_proof(
         &self,
-        blob: &[u8; 4096 * 32],
-        commitment: &[u8; 48],
-        proof: &[u8; 48],
-    ) -> Result<bool, ctt_eth_kzg_status> {
+        blob: &Blob,
+        commitment: &KzgCommitment,
+        proof: &KzgProof,
+    ) -> Result<bool, KzgError> {
         let status = unsafe {
             ctt_eth_kzg_verify_blob_kzg_proof(
                 self.ctx,
-                blob.as_ptr() as *const ctt_eth_kzg_blob,
-                commitment.as_ptr() as *const ctt_eth_kzg_commitment,
-                proof.as_ptr() as *const ctt_eth_kzg_proof,
+                blob.as_ffi(),
+                commitment.as_ffi(),
+                proof.as_ffi(),
             )
         };
         match status {
             ctt_eth_kzg_status::cttEthKzg_Success => Ok(true),
             ctt_eth_kzg_status::cttEthKzg_VerificationFailure => Ok(false),
-            _ => Err(status),
+            _ => Err(kzg_error(status)),
         }
     }
 
     #[inline]
     pub fn verify_blob_kzg_proof_batch(
         &self,
-        blobs: &[[u8; 4096 * 32]],
-        commitments: &[[u8; 48]],
-        proofs: &[[u8; 48]],
+        blobs: &[Blob],
+        commitments: &[KzgCommitment],
+        proofs: &[KzgProof],
         secure_random_bytes: &[u8; 32],
-    ) -> Result<bool, ctt_eth_kzg_status> {
+    ) -> Result<bool, KzgError> {
         if blobs.len() != commitments.len() || blobs.len() != proofs.len() {
-            return Err(ctt_eth_kzg_status::cttEthKzg_InputsLengthsMismatch);
+            return Err(KzgError::InputsLengthsMismatch {
+                blobs: blobs.len(),
+                commitments: commitments.len(),
+                proofs: proofs.len(),
+            });
         }
-
         let status = unsafe {
             ctt_eth_kzg_verify_blob_kzg_proof_batch(
                 self.ctx,
-                blobs.as_ptr() as *const ctt_eth_kzg_blob,
+                blobs.as_ptr().cast(),
                 commitments.as_ptr() as *const ctt_eth_kzg_commitment,
                 proofs.as_ptr() as *const ctt_eth_kzg_proof,
                 blobs.len(),
@@ -240,7 +261,49 @@ impl<'tp> EthKzgContext<'tp> {
         match status {
             ctt_eth_kzg_status::cttEthKzg_Success => Ok(true),
             ctt_eth_kzg_status::cttEthKzg_VerificationFailure => Ok(false),
-            _ => Err(status),
+            _ => Err(batch_error(status, blobs, commitments, proofs)),
+        }
+    }
+
+    /// Verify a batch of (blob, commitment, proof) sets
+    /// and return the indices of the invalid sets, in increasing order.
+    ///
+    /// The sets are first verified as a batch, on failure each set is verified individually.
+    /// A set with a malformed blob, commitment or proof is reported as invalid.
+    /// An empty vector means that all sets are valid,
+    /// an unexpected status from the C API is returned as an error.
+    #[inline]
+    pub fn verify_blob_kzg_proof_batch_find_invalid(
+        &self,
+        blobs: &[Blob],
+        commitments: &[KzgCommitment],
+        proofs: &[KzgProof],
+        secure_random_bytes: &[u8; 32],
+    ) -> Result<Vec<usize>, KzgError> {
+        if blobs.len() != commitments.len() || blobs.len() != proofs.len() {
+            return Err(KzgError::InputsLengthsMismatch {
+                blobs: blobs.len(),
+                commitments: commitments.len(),
+                proofs: proofs.len(),
+            });
+        }
+        let mut invalid = vec![false; blobs.len()];
+
+        let status = unsafe {
+            ctt_eth_kzg_verify_blob_kzg_proof_batch_find_invalid(
+                self.ctx,
+                invalid.as_mut_ptr(),
+                blobs.as_ptr().cast(),
+                commitments.as_ptr() as *const ctt_eth_kzg_commitment,
+                proofs.as_ptr() as *const ctt_eth_kzg_proof,
+                blobs.len(),
+                secure_random_bytes.as_ptr(),
+            )
+        };
+        match status {
+            ctt_eth_kzg_status::cttEthKzg_Success => Ok(Vec::new()),
+            ctt_eth_kzg_status::cttEthKzg_VerificationFailure => Ok(invalid_indices(&invalid)),
+            _ => Err(kzg_error(status)),
         }
     }
 
@@ -250,19 +313,19 @@ impl<'tp> EthKzgContext<'tp> {
     #[inline]
     pub fn blob_to_kzg_commitment_parallel(
         &self,
-        blob: &[u8; 4096 * 32],
-    ) -> Result<[u8; 48], ctt_eth_kzg_status> {
-        let mut result: MaybeUninit<[u8; 48]> = MaybeUninit::uninit();
+        blob: &Blob,
+    ) -> Result<KzgCommitment, KzgError> {
+        let mut result: MaybeUninit<KzgCommitment> = MaybeUninit::uninit();
         unsafe {
             let status = ctt_eth_kzg_blob_to_kzg_commitment_parallel(
                 self.threadpool.expect("Threadpool has been set").get_private_context(),
                 self.ctx,
                 result.as_mut_ptr() as *mut ctt_eth_kzg_commitment,
-                blob.as_ptr() as *const ctt_eth_kzg_blob,
+                blob.as_ffi(),
             );
             match status {
                 ctt_eth_kzg_status::cttEthKzg_Success => Ok(result.assume_init()),
-                _ => Err(status),
+                _ => Err(kzg_error(status)),
             }
         }
     }
@@ -270,25 +333,25 @@ impl<'tp> EthKzgContext<'tp> {
     #[inline]
     pub fn compute_kzg_proof_parallel(
         &self,
-        blob: &[u8; 4096 * 32],
-        z_challenge: &[u8; 32],
-    ) -> Result<([u8; 48], [u8; 32]), ctt_eth_kzg_status> {
-        let mut proof = MaybeUninit::<[u8; 48]>::uninit();
-        let mut y_eval = MaybeUninit::<[u8; 32]>::uninit();
+        blob: &Blob,
+        z_challenge: &FieldElement,
+    ) -> Result<(KzgProof, FieldElement), KzgError> {
+        let mut proof = MaybeUninit::<KzgProof>::uninit();
+        let mut y_eval = MaybeUninit::<FieldElement>::uninit();
         unsafe {
             let status = ctt_eth_kzg_compute_kzg_proof_parallel(
                 self.threadpool.expect("Threadpool has been set").get_private_context(),
                 self.ctx,
                 proof.as_mut_ptr() as *mut ctt_eth_kzg_proof,
                 y_eval.as_mut_ptr() as *mut ctt_eth_kzg_eval_at_challenge,
-                blob.as_ptr() as *const ctt_eth_kzg_blob,
-                z_challenge.as_ptr() as *const ctt_eth_kzg_opening_challenge,
+                blob.as_ffi(),
+                z_challenge.as_bytes().as_ptr() as *const ctt_eth_kzg_opening_challenge,
             );
             match status {
                 ctt_eth_kzg_status::cttEthKzg_Success => {
                     Ok((proof.assume_init(), y_eval.assume_init()))
                 }
-                _ => Err(status),
+                _ => Err(kzg_error(status)),
             }
         }
     }
@@ -296,21 +359,21 @@ impl<'tp> EthKzgContext<'tp> {
     #[inline]
     pub fn compute_blob_kzg_proof_parallel(
         &self,
-        blob: &[u8; 4096 * 32],
-        commitment: &[u8; 48],
-    ) -> Result<[u8; 48], ctt_eth_kzg_status> {
-        let mut proof = MaybeUninit::<[u8; 48]>::uninit();
+        blob: &Blob,
+        commitment: &KzgCommitment,
+    ) -> Result<KzgProof, KzgError> {
+        let mut proof = MaybeUninit::<KzgProof>::uninit();
         unsafe {
             let status = ctt_eth_kzg_compute_blob_kzg_proof_parallel(
                 self.threadpool.expect("Threadpool has been set").get_private_context(),
                 self.ctx,
                 proof.as_mut_ptr() as *mut ctt_eth_kzg_proof,
-                blob.as_ptr() as *const ctt_eth_kzg_blob,
-                commitment.as_ptr() as *const ctt_eth_kzg_commitment,
+                blob.as_ffi(),
+                commitment.as_ffi(),
             );
             match status {
                 ctt_eth_kzg_status::cttEthKzg_Success => Ok(proof.assume_init()),
-                _ => Err(status),
+                _ => Err(kzg_error(status)),
             }
         }
     }
@@ -318,43 +381,46 @@ impl<'tp> EthKzgContext<'tp> {
     #[inline]
     pub fn verify_blob_kzg_proof_parallel(
         &self,
-        blob: &[u8; 4096 * 32],
-        commitment: &[u8; 48],
-        proof: &[u8; 48],
-    ) -> Result<bool, ctt_eth_kzg_status> {
+        blob: &Blob,
+        commitment: &KzgCommitment,
+        proof: &KzgProof,
+    ) -> Result<bool, KzgError> {
         let status = unsafe {
             ctt_eth_kzg_verify_blob_kzg_proof_parallel(
                 self.threadpool.expect("Threadpool has been set").get_private_context(),
                 self.ctx,
-                blob.as_ptr() as *const ctt_eth_kzg_blob,
-                commitment.as_ptr() as *const ctt_eth_kzg_commitment,
-                proof.as_ptr() as *const ctt_eth_kzg_proof,
+                blob.as_ffi(),
+                commitment.as_ffi(),
+                proof.as_ffi(),
             )
         };
         match status {
             ctt_eth_kzg_status::cttEthKzg_Success => Ok(true),
             ctt_eth_kzg_status::cttEthKzg_VerificationFailure => Ok(false),
-            _ => Err(status),
+            _ => Err(kzg_error(status)),
         }
     }
 
     #[inline]
     pub fn verify_blob_kzg_proof_batch_parallel(
         &self,
-        blobs: &[[u8; 4096 * 32]],
-        commitments: &[[u8; 48]],
-        proofs: &[[u8; 48]],
+        blobs: &[Blob],
+        commitments: &[KzgCommitment],
+        proofs: &[KzgProof],
         secure_random_bytes: &[u8; 32],
-    ) -> Result<bool, ctt_eth_kzg_status> {
+    ) -> Result<bool, KzgError> {
         if blobs.len() != commitments.len() || blobs.len() != proofs.len() {
-            return Err(ctt_eth_kzg_status::cttEthKzg_InputsLengthsMismatch);
+            return Err(KzgError::InputsLengthsMismatch {
+                blobs: blobs.len(),
+                commitments: commitments.len(),
+                proofs: proofs.len(),
+            });
         }
-
         let status = unsafe {
             ctt_eth_kzg_verify_blob_kzg_proof_batch_parallel(
                 self.threadpool.expect("Threadpool has been set").get_private_context(),
                 self.ctx,
-                blobs.as_ptr() as *const ctt_eth_kzg_blob,
+                blobs.as_ptr().cast(),
                 commitments.as_ptr() as *const ctt_eth_kzg_commitment,
                 proofs.as_ptr() as *const ctt_eth_kzg_proof,
                 blobs.len(),
@@ -364,7 +430,51 @@ impl<'tp> EthKzgContext<'tp> {
         match status {
             ctt_eth_kzg_status::cttEthKzg_Success => Ok(true),
             ctt_eth_kzg_status::cttEthKzg_VerificationFailure => Ok(false),
-            _ => Err(status),
+            _ => Err(batch_error(status, blobs, commitments, proofs)),
+        }
+    }
+
+    /// Verify a batch of (blob, commitment, proof) sets
+    /// and return the indices of the invalid sets, in increasing order.
+    ///
+    /// The sets are first verified as a batch, on failure each set is verified individually
+    /// in parallel on the threadpool.
+    /// A set with a malformed blob, commitment or proof is reported as invalid.
+    /// An empty vector means that all sets are valid,
+    /// an unexpected status from the C API is returned as an error.
+    #[inline]
+    pub fn verify_blob_kzg_proof_batch_find_invalid_parallel(
+        &self,
+        blobs: &[Blob],
+        commitments: &[KzgCommitment],
+        proofs: &[KzgProof],
+        secure_random_bytes: &[u8; 32],
+    ) -> Result<Vec<usize>, KzgError> {
+        if blobs.len() != commitments.len() || blobs.len() != proofs.len() {
+            return Err(KzgError::InputsLengthsMismatch {
+                blobs: blobs.len(),
+                commitments: commitments.len(),
+                proofs: proofs.len(),
+            });
+        }
+        let mut invalid = vec![false; blobs.len()];
+
+        let status = unsafe {
+            ctt_eth_kzg_verify_blob_kzg_proof_batch_find_invalid_parallel(
+                self.threadpool.expect("Threadpool has been set").get_private_context(),
+                self.ctx,
+                invalid.as_mut_ptr(),
+                blobs.as_ptr().cast(),
+                commitments.as_ptr() as *const ctt_eth_kzg_commitment,
+                proofs.as_ptr() as *const ctt_eth_kzg_proof,
+                blobs.len(),
+                secure_random_bytes.as_ptr(),
+            )
+        };
+        match status {
+            ctt_eth_kzg_status::cttEthKzg_Success => Ok(Vec::new()),
+            ctt_eth_kzg_status::cttEthKzg_VerificationFailure => Ok(invalid_indices(&invalid)),
+            _ => Err(kzg_error(status)),
         }
     }
 }
@@ -0,0 +1,414 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Strongly typed EIP-4844 KZG inputs and outputs.
+//!
+//! Commitments and proofs are both 48-byte compressed G1 points
+//! and challenges, evaluations and hashes are all 32 bytes.
+//! Distinct types prevent passing one for the other by accident.
+
+use constantine_sys::*;
+
+use std::fmt;
+
+// Constants
+// ------------------------------------------------------------
+
+pub const FIELD_ELEMENTS_PER_BLOB: usize = 4096;
+pub const BYTES_PER_FIELD_ELEMENT: usize = 32;
+pub const BYTES_PER_BLOB: usize = FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT;
+pub const BYTES_PER_COMMITMENT: usize = 48;
+pub const BYTES_PER_PROOF: usize = 48;
+
+/// Version byte prepended to the truncated SHA256 of a commitment
+/// https://eips.ethereum.org/EIPS/eip-4844#parameters
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+// Errors
+// ------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BytesError {
+    /// The input does not have the size of the target type
+    InvalidLength { expected: usize, actual: usize },
+    /// The input is not a valid hex string
+    InvalidHex,
+}
+
+impl fmt::Display for BytesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BytesError::InvalidLength { expected, actual } => {
+                write!(f, "invalid length: expected {} bytes, got {}", expected, actual)
+            }
+            BytesError::InvalidHex => write!(f, "invalid hex string"),
+        }
+    }
+}
+
+impl std::error::Error for BytesError {}
+
+fn decode_hex_to_slice(hex_str: &str, dst: &mut [u8]) -> Result<(), BytesError> {
+    let digits = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+    if digits.len() != 2 * dst.len() {
+        return Err(BytesError::InvalidLength {
+            expected: dst.len(),
+            actual: digits.len() / 2,
+        });
+    }
+    hex::decode_to_slice(digits, dst).map_err(|_| BytesError::InvalidHex)
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+// Fixed-size byte newtypes
+// ------------------------------------------------------------
+
+macro_rules! fixed_bytes_newtype {
+    ($(#[$attr:meta])* $name:ident, $size:expr) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name([u8; $size]);
+
+        impl $name {
+            pub const SIZE: usize = $size;
+
+            #[inline(always)]
+            pub const fn new(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+
+            #[inline(always)]
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, BytesError> {
+                Self::try_from(bytes)
+            }
+
+            #[inline(always)]
+            pub fn as_bytes(&self) -> &[u8; $size] {
+                &self.0
+            }
+
+            #[inline(always)]
+            pub fn to_bytes(self) -> [u8; $size] {
+                self.0
+            }
+
+            /// Parse from a hex string, with or without `0x` prefix
+            pub fn from_hex(hex_str: &str) -> Result<Self, BytesError> {
+                let mut bytes = [0u8; $size];
+                decode_hex_to_slice(hex_str, &mut bytes)?;
+                Ok(Self(bytes))
+            }
+
+            /// Serialize to a `0x`-prefixed lowercase hex string
+            pub fn to_hex(&self) -> String {
+                encode_hex(&self.0)
+            }
+        }
+
+        impl Default for $name {
+            #[inline(always)]
+            fn default() -> Self {
+                Self([0u8; $size])
+            }
+        }
+
+        impl From<[u8; $size]> for $name {
+            #[inline(always)]
+            fn from(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl From<$name> for [u8; $size] {
+            #[inline(always)]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = BytesError;
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                let array: [u8; $size] = bytes.try_into().map_err(|_| BytesError::InvalidLength {
+                    expected: $size,
+                    actual: bytes.len(),
+                })?;
+                Ok(Self(array))
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            #[inline(always)]
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl PartialEq<[u8; $size]> for $name {
+            #[inline(always)]
+            fn eq(&self, other: &[u8; $size]) -> bool {
+                &self.0 == other
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self.to_hex())
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.to_hex())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.to_hex())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+                Self::from_hex(&s).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+fixed_bytes_newtype!(
+    /// A commitment to a blob: a compressed BLS12-381 G1 point
+    KzgCommitment,
+    BYTES_PER_COMMITMENT
+);
+
+fixed_bytes_newtype!(
+    /// A KZG opening proof: a compressed BLS12-381 G1 point
+    KzgProof,
+    BYTES_PER_PROOF
+);
+
+fixed_bytes_newtype!(
+    /// A big-endian BLS12-381 scalar field element,
+    /// i.e. an opening challenge `z` or an evaluation `y = p(z)`
+    FieldElement,
+    BYTES_PER_FIELD_ELEMENT
+);
+
+fixed_bytes_newtype!(
+    /// Opaque 32 bytes, for example a versioned hash
+    Bytes32,
+    32
+);
+
+impl KzgCommitment {
+    #[inline(always)]
+    pub(crate) fn as_ffi(&self) -> *const ctt_eth_kzg_commitment {
+        self.0.as_ptr() as *const ctt_eth_kzg_commitment
+    }
+}
+
+impl KzgProof {
+    #[inline(always)]
+    pub(crate) fn as_ffi(&self) -> *const ctt_eth_kzg_proof {
+        self.0.as_ptr() as *const ctt_eth_kzg_proof
+    }
+}
+
+// Blob
+// ------------------------------------------------------------
+
+/// An EIP-4844 blob of 4096 field elements.
+///
+/// At 128KiB, blobs are only created on the heap, as `Box<Blob>` or `Vec<Blob>`,
+/// to avoid stack overflows and accidental copies.
+/// A slice of blobs is contiguous and is passed to the C API without copies.
+/// Clones are made on the heap with `Blob::to_boxed`.
+#[derive(PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Blob([u8; BYTES_PER_BLOB]);
+
+impl Blob {
+    pub const SIZE: usize = BYTES_PER_BLOB;
+
+    /// Allocate a zero blob
+    pub fn new() -> Box<Self> {
+        let boxed: Box<[u8]> = vec![0u8; BYTES_PER_BLOB].into_boxed_slice();
+        Self::from_boxed(boxed.try_into().expect("Allocated with the exact blob size"))
+    }
+
+    /// Allocate `n` contiguous zero blobs
+    pub fn new_vec(n: usize) -> Vec<Self> {
+        let mut blobs = Vec::with_capacity(n);
+        unsafe {
+            // Blob is a transparent wrapper over bytes, all zero is a valid blob
+            ::core::ptr::write_bytes(blobs.as_mut_ptr(), 0, n);
+            blobs.set_len(n);
+        }
+        blobs
+    }
+
+    #[inline(always)]
+    pub fn from_boxed(bytes: Box<[u8; BYTES_PER_BLOB]>) -> Box<Self> {
+        // Blob is a transparent wrapper over [u8; BYTES_PER_BLOB]
+        unsafe { Box::from_raw(Box::into_raw(bytes) as *mut Self) }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Box<Self>, BytesError> {
+        Box::<Self>::try_from(bytes)
+    }
+
+    /// View contiguous serialized blobs as a slice of blobs, without copies.
+    pub fn slice_from_bytes(bytes: &[u8]) -> Result<&[Self], BytesError> {
+        let (n, rem) = (bytes.len() / BYTES_PER_BLOB, bytes.len() % BYTES_PER_BLOB);
+        if rem != 0 {
+            return Err(BytesError::InvalidLength {
+                expected: (n + 1) * BYTES_PER_BLOB,
+                actual: bytes.len(),
+            });
+        }
+        // Blob is a transparent wrapper over bytes, with alignment 1
+        Ok(unsafe { ::core::slice::from_raw_parts(bytes.as_ptr() as *const Self, n) })
+    }
+
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8; BYTES_PER_BLOB] {
+        &self.0
+    }
+
+    #[inline(always)]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8; BYTES_PER_BLOB] {
+        &mut self.0
+    }
+
+    /// Copy the blob to a new heap allocation
+    pub fn to_boxed(&self) -> Box<Self> {
+        let boxed: Box<[u8]> = self.as_bytes().as_slice().into();
+        Self::from_boxed(boxed.try_into().expect("Copied from a blob"))
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self: Box<Self>) -> Box<[u8; BYTES_PER_BLOB]> {
+        unsafe { Box::from_raw(Box::into_raw(self) as *mut [u8; BYTES_PER_BLOB]) }
+    }
+
+    /// Parse from a hex string, with or without `0x` prefix
+    pub fn from_hex(hex_str: &str) -> Result<Box<Self>, BytesError> {
+        let mut blob = Self::new();
+        decode_hex_to_slice(hex_str, blob.0.as_mut_slice())?;
+        Ok(blob)
+    }
+
+    /// Serialize to a `0x`-prefixed lowercase hex string
+    pub fn to_hex(&self) -> String {
+        encode_hex(self.0.as_slice())
+    }
+
+    #[inline(always)]
+    pub(crate) fn as_ffi(&self) -> *const ctt_eth_kzg_blob {
+        self.0.as_ptr() as *const ctt_eth_kzg_blob
+    }
+}
+
+impl Default for Box<Blob> {
+    fn default() -> Self {
+        Blob::new()
+    }
+}
+
+impl Clone for Box<Blob> {
+    fn clone(&self) -> Self {
+        self.to_boxed()
+    }
+}
+
+impl From<Box<[u8; BYTES_PER_BLOB]>> for Box<Blob> {
+    #[inline(always)]
+    fn from(bytes: Box<[u8; BYTES_PER_BLOB]>) -> Self {
+        Blob::from_boxed(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Box<Blob> {
+    type Error = BytesError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != BYTES_PER_BLOB {
+            return Err(BytesError::InvalidLength {
+                expected: BYTES_PER_BLOB,
+                actual: bytes.len(),
+            });
+        }
+        let boxed: Box<[u8]> = bytes.into();
+        Ok(Blob::from_boxed(boxed.try_into().expect("Length was checked")))
+    }
+}
+
+impl AsRef<[u8]> for Blob {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl fmt::Debug for Blob {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Printing 128KiB of hex is never useful in logs
+        write!(
+            f,
+            "Blob(0x{}..{})",
+            hex::encode(&self.0[..4]),
+            hex::encode(&self.0[BYTES_PER_BLOB - 4..])
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Blob {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Box<Blob> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Blob::from_hex(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+// Versioned hash
+// ------------------------------------------------------------
+
+/// Compute the versioned hash of a commitment
+/// `VERSIONED_HASH_VERSION_KZG + sha256(commitment)[1:]`
+///
+/// Spec: https://eips.ethereum.org/EIPS/eip-4844#helpers
+#[must_use]
+pub fn kzg_to_versioned_hash(commitment: &KzgCommitment) -> Bytes32 {
+    let mut digest = [0u8; 32];
+    unsafe {
+        ctt_sha256_hash(
+            digest.as_mut_ptr() as *mut byte,
+            commitment.0.as_ptr() as *const byte,
+            commitment.0.len(),
+            false,
+        )
+    }
+    digest[0] = VERSIONED_HASH_VERSION_KZG;
+    Bytes32(digest)
+}
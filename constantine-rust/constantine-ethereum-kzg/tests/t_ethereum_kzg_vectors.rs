@@ -7,7 +7,11 @@
 //! at your option. This file may not be copied, modified, or distributed except according to those terms.
 
 use constantine_core::{csprngs, hardware, Threadpool};
-use constantine_ethereum_kzg::EthKzgContext;
+use constantine_sys::ctt_eth_kzg_status;
+use constantine_ethereum_kzg::{
+    kzg_to_versioned_hash, Blob, Bytes32, BytesError, EthKzgContext, FieldElement, KzgCommitment,
+    KzgError, KzgProof, BYTES_PER_BLOB,
+};
 
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -25,6 +29,110 @@ fn t_smoke_load_trusted_setup() {
     .expect("Trusted setup should be loaded without error.");
 }
 
+// Errors
+// -----------------------------------------------------------
+
+#[test]
+fn t_trusted_setup_errors() {
+    let path = Path::new("../../constantine/commitments_setups/does_not_exist.dat");
+    assert_eq!(
+        EthKzgContext::load_trusted_setup(path).err(),
+        Some(KzgError::TrustedSetupMissingOrInaccessibleFile { path: path.to_path_buf() })
+    );
+    assert_eq!(EthKzgContext::builder().build().err(), Some(KzgError::TrustedSetupNotLoaded));
+}
+
+#[test]
+fn t_batch_errors() {
+    let ctx = EthKzgContext::load_trusted_setup(Path::new(
+        "../../constantine/commitments_setups/trusted_setup_ethereum_kzg4844_reference.dat",
+    ))
+    .expect("Trusted setup should be loaded without error.");
+
+    // The zero polynomial commits to the point at infinity with a proof at infinity
+    let mut infinity = [0u8; 48];
+    infinity[0] = 0xc0;
+    let blobs = Blob::new_vec(3);
+    let mut commitments = vec![KzgCommitment::from(infinity); 3];
+    let mut proofs = vec![KzgProof::from(infinity); 3];
+    let random = [42u8; 32];
+
+    assert_eq!(ctx.verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &random), Ok(true));
+
+    assert_eq!(
+        ctx.verify_blob_kzg_proof_batch(&blobs, &commitments[1..], &proofs, &random),
+        Err(KzgError::InputsLengthsMismatch { blobs: 3, commitments: 2, proofs: 3 })
+    );
+
+    // Missing compression flag
+    commitments[1] = KzgCommitment::from([0u8; 48]);
+    let err = ctx
+        .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &random)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        KzgError::InvalidBatchInput { index: 1, error: Box::new(KzgError::EccInvalidEncoding) }
+    );
+    assert_eq!(err.to_string(), "invalid batch input at index 1: invalid point encoding");
+
+    commitments[1] = KzgCommitment::from(infinity);
+    proofs[0] = KzgProof::from([0u8; 48]);
+    let mut blobs = blobs;
+    blobs[2].as_bytes_mut()[..32].copy_from_slice(&[0xff; 32]);
+    assert_eq!(
+        ctx.verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &random),
+        Err(KzgError::InvalidBatchInput { index: 0, error: Box::new(KzgError::EccInvalidEncoding) })
+    );
+    proofs[0] = KzgProof::from(infinity);
+    assert_eq!(
+        ctx.verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &random),
+        Err(KzgError::InvalidBatchInput { index: 2, error: Box::new(KzgError::ScalarLargerThanCurveOrder) })
+    );
+}
+
+#[test]
+fn t_internal_error() {
+    // Statuses that are not errors of a call are reported instead of panicking
+    let err = KzgError::Internal(ctt_eth_kzg_status::cttEthKzg_VerificationFailure);
+    assert_eq!(err.to_string(), "unexpected Constantine status cttEthKzg_VerificationFailure");
+}
+
+// Typed wrappers
+// -----------------------------------------------------------
+
+#[test]
+fn t_typed_bytes_conversions() {
+    let hex_commitment = "0x8f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7";
+    let commitment = KzgCommitment::from_hex(hex_commitment).unwrap();
+    assert_eq!(commitment.to_hex(), hex_commitment);
+    assert_eq!(KzgCommitment::from_hex(&hex_commitment[2..]).unwrap(), commitment);
+    assert_eq!(KzgCommitment::try_from(commitment.as_ref()).unwrap(), commitment);
+
+    assert_eq!(
+        KzgProof::try_from(&[0u8; 47][..]),
+        Err(BytesError::InvalidLength { expected: 48, actual: 47 })
+    );
+    assert_eq!(FieldElement::from_hex("0xzz"), Err(BytesError::InvalidLength { expected: 32, actual: 1 }));
+    assert_eq!(Bytes32::from_hex(&"zz".repeat(32)), Err(BytesError::InvalidHex));
+
+    let blob = Blob::from_bytes(&vec![0x42u8; BYTES_PER_BLOB]).unwrap();
+    assert_eq!(Blob::from_hex(&blob.to_hex()).unwrap(), blob);
+    assert_eq!(blob.clone(), blob);
+    assert!(Blob::from_bytes(&[0u8; 32]).is_err());
+    assert_eq!(
+        Blob::slice_from_bytes(&[0u8; BYTES_PER_BLOB + 1]).err(),
+        Some(BytesError::InvalidLength { expected: 2 * BYTES_PER_BLOB, actual: BYTES_PER_BLOB + 1 })
+    );
+}
+
+#[test]
+fn t_kzg_to_versioned_hash() {
+    // From EIP-4844 point evaluation precompile test vectors
+    let commitment = KzgCommitment::from_hex("0x8f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7").unwrap();
+    let versioned_hash = Bytes32::from_hex("0x01e798154708fe7789429634053cbf9f99b619f9f084048927333fce637f549b").unwrap();
+    assert_eq!(kzg_to_versioned_hash(&commitment), versioned_hash);
+}
+
 // Official Ethereum test vectors
 // -----------------------------------------------------------
 
@@ -111,7 +219,7 @@ fn t_blob_to_kzg_commitment() {
             continue;
         };
 
-        match ctx.blob_to_kzg_commitment(&*blob) {
+        match ctx.blob_to_kzg_commitment(&Blob::from_boxed(blob)) {
             Ok(commitment) => {
                 assert_eq!(commitment, *test.output.opt_bytes.0.unwrap());
                 println!("{}=> SUCCESS", tv);
@@ -170,7 +278,7 @@ fn t_compute_kzg_proof() {
             continue;
         };
 
-        match ctx.compute_kzg_proof(&*blob, &*opening_challenge) {
+        match ctx.compute_kzg_proof(&Blob::from_boxed(blob), &FieldElement::from(*opening_challenge)) {
             Ok((proof, eval)) => {
                 let (true_proof, true_eval) = test.output.unwrap();
                 assert_eq!(proof, *true_proof.opt_bytes.0.unwrap());
@@ -237,7 +345,12 @@ fn t_verify_kzg_proof() {
             continue;
         };
 
-        match ctx.verify_kzg_proof(&*commitment, &*z, &*y, &*proof) {
+        match ctx.verify_kzg_proof(
+            &KzgCommitment::from(**commitment),
+            &FieldElement::from(**z),
+            &FieldElement::from(**y),
+            &KzgProof::from(**proof),
+        ) {
             Ok(valid) => {
                 assert_eq!(valid, test.output.unwrap());
                 if valid {
@@ -301,7 +414,7 @@ fn t_compute_blob_kzg_proof() {
             continue;
         };
 
-        match ctx.compute_blob_kzg_proof(&*blob, &*commitment) {
+        match ctx.compute_blob_kzg_proof(&Blob::from_boxed(blob), &KzgCommitment::from(*commitment)) {
             Ok(proof) => {
                 assert_eq!(proof, *test.output.opt_bytes.0.unwrap());
                 println!("{}=> SUCCESS", tv);
@@ -364,7 +477,7 @@ fn t_verify_blob_kzg_proof() {
             continue;
         };
 
-        match ctx.verify_blob_kzg_proof(&*blob, &*commitment, &*proof) {
+        match ctx.verify_blob_kzg_proof(&Blob::from_boxed(blob), &KzgCommitment::from(*commitment), &KzgProof::from(*proof)) {
             Ok(valid) => {
                 assert_eq!(valid, test.output.unwrap());
                 if valid {
@@ -425,29 +538,30 @@ fn t_verify_blob_kzg_proof_batch() {
             &test_name
         ));
 
-        let blobs: Vec<_> = test
+        let blob_bytes: Vec<u8> = test
             .input
             .blobs
             .into_iter()
             .filter_map(|v| v.opt_bytes.0) // deserialization failure will lead to length mismatch
-            .map(|v| *v)
+            .flat_map(|b| b.to_vec())
             .collect();
+        let blobs = Blob::slice_from_bytes(&blob_bytes).unwrap();
         let commitments: Vec<_> = test
             .input
             .commitments
             .into_iter()
             .filter_map(|v| v.opt_bytes.0) // deserialization failure will lead to length mismatch
-            .map(|v| *v)
+            .map(|v| KzgCommitment::from(*v))
             .collect();
         let proofs: Vec<_> = test
             .input
             .proofs
             .into_iter()
             .filter_map(|v| v.opt_bytes.0) // deserialization failure will lead to length mismatch
-            .map(|v| *v)
+            .map(|v| KzgProof::from(*v))
             .collect();
 
-        match ctx.verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &secure_random_bytes) {
+        match ctx.verify_blob_kzg_proof_batch(blobs, &commitments, &proofs, &secure_random_bytes) {
             Ok(valid) => {
                 assert_eq!(valid, test.output.unwrap());
                 if valid {
@@ -515,7 +629,7 @@ fn t_blob_to_kzg_commitment_parallel() {
             continue;
         };
 
-        match ctx.blob_to_kzg_commitment_parallel(&*blob) {
+        match ctx.blob_to_kzg_commitment_parallel(&Blob::from_boxed(blob)) {
             Ok(commitment) => {
                 assert_eq!(commitment, *test.output.opt_bytes.0.unwrap());
                 println!("{}=> SUCCESS", tv);
@@ -579,7 +693,7 @@ fn t_compute_kzg_proof_parallel() {
             continue;
         };
 
-        match ctx.compute_kzg_proof_parallel(&*blob, &*opening_challenge) {
+        match ctx.compute_kzg_proof_parallel(&Blob::from_boxed(blob), &FieldElement::from(*opening_challenge)) {
             Ok((proof, eval)) => {
                 let (true_proof, true_eval) = test.output.unwrap();
                 assert_eq!(proof, *true_proof.opt_bytes.0.unwrap());
@@ -646,7 +760,7 @@ fn t_compute_blob_kzg_proof_parallel() {
             continue;
         };
 
-        match ctx.compute_blob_kzg_proof_parallel(&*blob, &*commitment) {
+        match ctx.compute_blob_kzg_proof_parallel(&Blob::from_boxed(blob), &KzgCommitment::from(*commitment)) {
             Ok(proof) => {
                 assert_eq!(proof, *test.output.opt_bytes.0.unwrap());
                 println!("{}=> SUCCESS", tv);
@@ -714,7 +828,7 @@ fn t_verify_blob_kzg_proof_parallel() {
             continue;
         };
 
-        match ctx.verify_blob_kzg_proof_parallel(&*blob, &*commitment, &*proof) {
+        match ctx.verify_blob_kzg_proof_parallel(&Blob::from_boxed(blob), &KzgCommitment::from(*commitment), &KzgProof::from(*proof)) {
             Ok(valid) => {
                 assert_eq!(valid, test.output.unwrap());
                 if valid {
@@ -780,30 +894,31 @@ fn t_verify_blob_kzg_proof_batch_parallel() {
             &test_name
         ));
 
-        let blobs: Vec<_> = test
+        let blob_bytes: Vec<u8> = test
             .input
             .blobs
             .into_iter()
             .filter_map(|v| v.opt_bytes.0) // deserialization failure will lead to length mismatch
-            .map(|v| *v)
+            .flat_map(|b| b.to_vec())
             .collect();
+        let blobs = Blob::slice_from_bytes(&blob_bytes).unwrap();
         let commitments: Vec<_> = test
             .input
             .commitments
             .into_iter()
             .filter_map(|v| v.opt_bytes.0) // deserialization failure will lead to length mismatch
-            .map(|v| *v)
+            .map(|v| KzgCommitment::from(*v))
             .collect();
         let proofs: Vec<_> = test
             .input
             .proofs
             .into_iter()
             .filter_map(|v| v.opt_bytes.0) // deserialization failure will lead to length mismatch
-            .map(|v| *v)
+            .map(|v| KzgProof::from(*v))
             .collect();
 
         match ctx.verify_blob_kzg_proof_batch_parallel(
-            &blobs,
+            blobs,
             &commitments,
             &proofs,
             &secure_random_bytes,
@@ -823,3 +938,70 @@ fn t_verify_blob_kzg_proof_batch_parallel() {
         }
     }
 }
+
+// Locating invalid sets
+// -----------------------------------------------------------
+
+fn find_invalid_test_sets(ctx: &EthKzgContext) -> (Vec<Blob>, Vec<KzgCommitment>, Vec<KzgProof>) {
+    let mut blobs = Blob::new_vec(5);
+    for (i, blob) in (0..5u8).zip(blobs.iter_mut()) {
+        for (j, fe) in blob.as_bytes_mut().chunks_exact_mut(32).enumerate() {
+            // Keep field elements below the curve order
+            fe[31] = i.wrapping_mul(31).wrapping_add(j as u8);
+            fe[30] = i;
+        }
+    }
+    let commitments: Vec<KzgCommitment> =
+        blobs.iter().map(|b| ctx.blob_to_kzg_commitment(b).unwrap()).collect();
+    let proofs: Vec<KzgProof> = blobs
+        .iter()
+        .zip(&commitments)
+        .map(|(b, c)| ctx.compute_blob_kzg_proof(b, c).unwrap())
+        .collect();
+    (blobs, commitments, proofs)
+}
+
+#[test]
+fn t_verify_blob_kzg_proof_batch_find_invalid() {
+    let tp = Threadpool::new(hardware::get_num_threads_os());
+    let ctx = EthKzgContext::builder()
+        .load_trusted_setup(Path::new(SRS_PATH))
+        .expect("Trusted setup loaded successfully")
+        .set_threadpool(&tp)
+        .build()
+        .expect("EthKzgContext initialized successfully");
+
+    let mut secure_random_bytes = [0u8; 32];
+    csprngs::sysrand(secure_random_bytes.as_mut_slice());
+
+    let (blobs, mut commitments, mut proofs) = find_invalid_test_sets(&ctx);
+
+    for find_invalid in [
+        EthKzgContext::verify_blob_kzg_proof_batch_find_invalid,
+        EthKzgContext::verify_blob_kzg_proof_batch_find_invalid_parallel,
+    ] {
+        assert_eq!(find_invalid(&ctx, &[], &[], &[], &secure_random_bytes), Ok(vec![]));
+        assert_eq!(
+            find_invalid(&ctx, &blobs, &commitments, &proofs, &secure_random_bytes),
+            Ok(vec![])
+        );
+        assert_eq!(
+            find_invalid(&ctx, &blobs, &commitments, &proofs[1..], &secure_random_bytes),
+            Err(KzgError::InputsLengthsMismatch { blobs: 5, commitments: 5, proofs: 4 })
+        );
+    }
+
+    // Swapped proofs and a malformed commitment
+    proofs.swap(1, 2);
+    commitments[4] = KzgCommitment::from([0u8; 48]);
+
+    for find_invalid in [
+        EthKzgContext::verify_blob_kzg_proof_batch_find_invalid,
+        EthKzgContext::verify_blob_kzg_proof_batch_find_invalid_parallel,
+    ] {
+        assert_eq!(
+            find_invalid(&ctx, &blobs, &commitments, &proofs, &secure_random_bytes),
+            Ok(vec![1, 2, 4])
+        );
+    }
+}
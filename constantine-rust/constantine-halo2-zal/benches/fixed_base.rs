@@ -0,0 +1,53 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+
+//! To run this benchmark:
+//!
+//!     cargo bench -- fixed_base
+
+#[macro_use]
+extern crate criterion;
+
+use constantine_core::hardware;
+use constantine_halo2_zal::{CttEngine, FixedBaseTable};
+
+use halo2_middleware::halo2curves::bn256::{Fr as Scalar, G1Affine as Point};
+use halo2_middleware::halo2curves::ff::Field;
+use halo2_middleware::halo2curves::group::prime::PrimeCurveAffine;
+
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+use criterion::Criterion;
+
+const SEED: [u8; 16] = [
+    0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc, 0xe5,
+];
+
+fn fixed_base(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fixed_base");
+    let mut rng = XorShiftRng::from_seed(SEED);
+    let scalar = Scalar::random(&mut rng);
+    let g = Point::generator();
+
+    group.bench_function("halo2curves", |b| b.iter(|| g * scalar));
+
+    let engine = CttEngine::new(hardware::get_num_threads_os());
+    group.bench_function("constantine_precompute", |b| {
+        b.iter(|| FixedBaseTable::<Point>::generator(&engine))
+    });
+
+    // The table of a fixed base is precomputed once, out of timer measurement.
+    let table = FixedBaseTable::<Point>::generator(&engine);
+    group.bench_function("constantine_fixed_base", |b| b.iter(|| table.mul(&scalar)));
+    drop(table);
+    drop(engine); // Explicitly drop engine out of timer measurement
+    group.finish();
+}
+
+criterion_group!(benches, fixed_base);
+criterion_main!(benches);
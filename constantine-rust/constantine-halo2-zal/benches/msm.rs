@@ -109,6 +109,20 @@ fn msm(c: &mut Criterion) {
                 })
             })
             .sample_size(SAMPLE_SIZE);
+
+        // The bases of a proving session are fixed,
+        // their precomputation is done once, out of timer measurement.
+        let base_descriptor = engine.get_base_descriptor(&bases[..1 << k]);
+        group
+            .bench_function(BenchmarkId::new("constantine_cached_base", k), |b| {
+                assert!(k < 64);
+                let n: usize = 1 << k;
+                b.iter(|| {
+                    engine.msm_with_cached_base(&coeffs[..n], &base_descriptor);
+                })
+            })
+            .sample_size(SAMPLE_SIZE);
+        drop(base_descriptor);
         drop(engine); // Explicitly drop engine out of timer measurement
     }
     group.finish();
@@ -0,0 +1,209 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Batches of independent multi-scalar multiplications over the same bases
+//! using Constantine as a backend
+
+use crate::{CttEngine, CttMsmBaseDesc, CttMsmTable};
+use constantine_sys::*;
+
+use halo2_middleware::halo2curves::{bls12381, bn256, pasta};
+
+/// Many MSMs against the same bases, for example commitments
+/// to all the polynomials of a proof.
+///
+/// All MSMs are scheduled together on Constantine's threadpool
+/// so that threads do not idle during the reduction tail of each MSM.
+/// Large batches precompute a table of the bases shared by all MSMs.
+pub trait BatchMsmAccel<C: CttMsmTable> {
+    /// r[k] <- ∑ᵢ [coeffs[k][i]]bases[i]
+    ///
+    /// All `coeffs[k]` MUST have the same length, at most `bases.len()`.
+    /// The MSMs use the first `coeffs[k].len()` bases.
+    ///
+    /// # Panics
+    ///
+    /// If the `coeffs[k]` do not all have the same length
+    /// or if they are longer than `bases`.
+    fn batch_msm(&self, coeffs: &[&[C::Scalar]], bases: &[C]) -> Vec<C::Curve>;
+
+    /// r[k] <- ∑ᵢ [coeffs[k][i]]bases[i] with a table of the bases precomputed
+    /// by `MsmAccel::get_base_descriptor`.
+    ///
+    /// All `coeffs[k]` MUST have the same length, at most the number of cached bases.
+    ///
+    /// # Panics
+    ///
+    /// If the `coeffs[k]` do not all have the same length
+    /// or if they are longer than the cached bases.
+    fn batch_msm_with_cached_base(
+        &self,
+        coeffs: &[&[C::Scalar]],
+        base: &CttMsmBaseDesc<'_, C>,
+    ) -> Vec<C::Curve>;
+}
+
+/// Length of each MSM of a batch.
+/// Panics on ragged coefficients or if there are more coefficients than bases.
+fn batch_len<F>(coeffs: &[&[F]], max_len: usize) -> usize {
+    let n = coeffs.first().map_or(0, |c| c.len());
+    assert!(
+        coeffs.iter().all(|c| c.len() == n),
+        "all MSMs of a batch must have the same number of coefficients"
+    );
+    assert!(n <= max_len);
+    n
+}
+
+/// Implement `BatchMsmAccel` for a halo2curves affine point type
+macro_rules! impl_batch_msm_accel {
+    (
+        $Aff:ty, $Curve:ty, $Fr:ty,
+        $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty,
+        layout: $layout:ident,
+        conversions: $conv:ident,
+        batch_msm: $batch_msm:ident,
+        batch_msm_table: $batch_msm_table:ident $(,)?
+    ) => {
+        impl BatchMsmAccel<$Aff> for CttEngine {
+            fn batch_msm(&self, coeffs: &[&[$Fr]], bases: &[$Aff]) -> Vec<$Curve> {
+                let n = batch_len(coeffs, bases.len());
+                if coeffs.is_empty() {
+                    return Vec::new();
+                }
+                if self.1.$layout {
+                    let rows = coeffs
+                        .iter()
+                        .map(|c| c.as_ptr() as *const $ctt_fr)
+                        .collect::<Vec<_>>();
+                    let mut result = Vec::<$Curve>::with_capacity(coeffs.len());
+                    unsafe {
+                        $batch_msm(
+                            self.0.get_private_context(),
+                            result.as_mut_ptr() as *mut $ctt_curve,
+                            coeffs.len(),
+                            rows.as_ptr(),
+                            bases.as_ptr() as *const $ctt_aff,
+                            n,
+                        );
+                        result.set_len(coeffs.len());
+                    }
+                    result
+                } else {
+                    let converted = coeffs
+                        .iter()
+                        .map(|c| c.iter().map(crate::$conv::fr_to_ctt).collect::<Vec<_>>())
+                        .collect::<Vec<_>>();
+                    let rows = converted.iter().map(|c| c.as_ptr()).collect::<Vec<_>>();
+                    let bases = bases[..n]
+                        .iter()
+                        .map(crate::$conv::aff_to_ctt)
+                        .collect::<Vec<_>>();
+                    let mut result = Vec::<$ctt_curve>::with_capacity(coeffs.len());
+                    unsafe {
+                        $batch_msm(
+                            self.0.get_private_context(),
+                            result.as_mut_ptr(),
+                            coeffs.len(),
+                            rows.as_ptr(),
+                            bases.as_ptr(),
+                            n,
+                        );
+                        result.set_len(coeffs.len());
+                    }
+                    result.iter().map(crate::$conv::curve_from_ctt).collect()
+                }
+            }
+
+            fn batch_msm_with_cached_base(
+                &self,
+                coeffs: &[&[$Fr]],
+                base: &CttMsmBaseDesc<'_, $Aff>,
+            ) -> Vec<$Curve> {
+                let n = batch_len(coeffs, base.raw.len());
+                if coeffs.is_empty() {
+                    return Vec::new();
+                }
+                if self.1.$layout {
+                    let rows = coeffs
+                        .iter()
+                        .map(|c| c.as_ptr() as *const $ctt_fr)
+                        .collect::<Vec<_>>();
+                    let mut result = Vec::<$Curve>::with_capacity(coeffs.len());
+                    unsafe {
+                        $batch_msm_table(
+                            self.0.get_private_context(),
+                            result.as_mut_ptr() as *mut $ctt_curve,
+                            coeffs.len(),
+                            rows.as_ptr(),
+                            &base.table,
+                            n,
+                        );
+                        result.set_len(coeffs.len());
+                    }
+                    result
+                } else {
+                    let converted = coeffs
+                        .iter()
+                        .map(|c| c.iter().map(crate::$conv::fr_to_ctt).collect::<Vec<_>>())
+                        .collect::<Vec<_>>();
+                    let rows = converted.iter().map(|c| c.as_ptr()).collect::<Vec<_>>();
+                    let mut result = Vec::<$ctt_curve>::with_capacity(coeffs.len());
+                    unsafe {
+                        $batch_msm_table(
+                            self.0.get_private_context(),
+                            result.as_mut_ptr(),
+                            coeffs.len(),
+                            rows.as_ptr(),
+                            &base.table,
+                            n,
+                        );
+                        result.set_len(coeffs.len());
+                    }
+                    result.iter().map(crate::$conv::curve_from_ctt).collect()
+                }
+            }
+        }
+    };
+}
+
+impl_batch_msm_accel!(
+    bn256::G1Affine, bn256::G1, bn256::Fr,
+    bn254_snarks_g1_aff, bn254_snarks_g1_prj, bn254_snarks_fr,
+    layout: bn254_g1,
+    conversions: bn254_snarks_g1,
+    batch_msm: ctt_bn254_snarks_g1_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel,
+    batch_msm_table: ctt_bn254_snarks_g1_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel,
+);
+
+impl_batch_msm_accel!(
+    bls12381::G1Affine, bls12381::G1, bls12381::Fr,
+    bls12_381_g1_aff, bls12_381_g1_prj, bls12_381_fr,
+    layout: bls12_381_g1,
+    conversions: bls12_381_g1,
+    batch_msm: ctt_bls12_381_g1_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel,
+    batch_msm_table: ctt_bls12_381_g1_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel,
+);
+
+impl_batch_msm_accel!(
+    pasta::EpAffine, pasta::Ep, pasta::Fq,
+    pallas_ec_aff, pallas_ec_jac, pallas_fr,
+    layout: pallas,
+    conversions: pallas_ec,
+    batch_msm: ctt_pallas_ec_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel,
+    batch_msm_table: ctt_pallas_ec_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel,
+);
+
+impl_batch_msm_accel!(
+    pasta::EqAffine, pasta::Eq, pasta::Fp,
+    vesta_ec_aff, vesta_ec_jac, vesta_fr,
+    layout: vesta,
+    conversions: vesta_ec,
+    batch_msm: ctt_vesta_ec_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel,
+    batch_msm_table: ctt_vesta_ec_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel,
+);
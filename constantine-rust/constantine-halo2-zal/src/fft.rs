@@ -0,0 +1,180 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Number Theoretic Transforms (FFT over the scalar field) using Constantine as a backend
+
+use crate::CttEngine;
+use constantine_sys::*;
+
+use halo2_middleware::halo2curves::ff::{Field, PrimeField};
+use halo2_middleware::halo2curves::fft::best_fft;
+use halo2_middleware::halo2curves::{bn256, pasta};
+
+/// In-place FFTs over a scalar field, as used by halo2's `best_fft`.
+///
+/// `values.len()` MUST be `2^log_n` and `omega` MUST be
+/// a primitive root of unity of order `2^log_n`.
+/// Input and output are in natural order.
+pub trait FftAccel<F: PrimeField> {
+    /// values[i] <- ∑ⱼ values[j]·ωⁱʲ
+    ///
+    /// This is a drop-in replacement for `best_fft(values, omega, log_n)`.
+    fn fft(&self, values: &mut [F], omega: F, log_n: u32);
+
+    /// values[i] <- 1/n ∑ⱼ values[j]·ω⁻ⁱʲ
+    ///
+    /// `omega` is the root of unity of the forward transform.
+    fn ifft(&self, values: &mut [F], omega: F, log_n: u32);
+
+    /// Evaluate the polynomial with coefficients `values` over the coset shift·⟨ω⟩
+    ///   values[i] <- ∑ⱼ values[j]·(shift·ωⁱ)ʲ
+    fn coset_fft(&self, values: &mut [F], omega: F, shift: F, log_n: u32);
+
+    /// Interpolate the coefficients of a polynomial
+    /// from its evaluations over the coset shift·⟨ω⟩
+    fn coset_ifft(&self, values: &mut [F], omega: F, shift: F, log_n: u32);
+}
+
+/// halo2curves FFTs, used when halo2curves and Constantine
+/// have a different in-memory representation.
+mod fallback {
+    use super::*;
+
+    /// values[i] <- values[i]·gⁱ
+    fn scale_by_powers<F: Field>(values: &mut [F], g: F) {
+        let mut cur = F::ONE;
+        for v in values.iter_mut() {
+            *v *= cur;
+            cur *= g;
+        }
+    }
+
+    pub(super) fn ifft<F: PrimeField>(values: &mut [F], omega: F, log_n: u32) {
+        best_fft(values, omega.invert().unwrap(), log_n);
+        let inv_len = F::from(values.len() as u64).invert().unwrap();
+        values.iter_mut().for_each(|v| *v *= inv_len);
+    }
+
+    pub(super) fn coset_fft<F: PrimeField>(values: &mut [F], omega: F, shift: F, log_n: u32) {
+        scale_by_powers(values, shift);
+        best_fft(values, omega, log_n);
+    }
+
+    pub(super) fn coset_ifft<F: PrimeField>(values: &mut [F], omega: F, shift: F, log_n: u32) {
+        ifft(values, omega, log_n);
+        scale_by_powers(values, shift.invert().unwrap());
+    }
+}
+
+/// Implement `FftAccel` for a halo2curves scalar field
+macro_rules! impl_fft_accel {
+    (
+        $F:ty, $ctt_fr:ty,
+        layout: $layout:ident,
+        ntt: $ntt:ident,
+        intt: $intt:ident,
+        coset_ntt: $coset_ntt:ident,
+        coset_intt: $coset_intt:ident $(,)?
+    ) => {
+        impl FftAccel<$F> for CttEngine {
+            fn fft(&self, values: &mut [$F], omega: $F, log_n: u32) {
+                assert_eq!(values.len(), 1 << log_n);
+                if !self.1.$layout {
+                    return best_fft(values, omega, log_n);
+                }
+                let ok = unsafe {
+                    $ntt(
+                        self.0.get_private_context(),
+                        values.as_mut_ptr() as *mut $ctt_fr,
+                        values.len(),
+                        &omega as *const $F as *const $ctt_fr,
+                    )
+                };
+                assert!(ok);
+            }
+
+            fn ifft(&self, values: &mut [$F], omega: $F, log_n: u32) {
+                assert_eq!(values.len(), 1 << log_n);
+                if !self.1.$layout {
+                    return fallback::ifft(values, omega, log_n);
+                }
+                let ok = unsafe {
+                    $intt(
+                        self.0.get_private_context(),
+                        values.as_mut_ptr() as *mut $ctt_fr,
+                        values.len(),
+                        &omega as *const $F as *const $ctt_fr,
+                    )
+                };
+                assert!(ok);
+            }
+
+            fn coset_fft(&self, values: &mut [$F], omega: $F, shift: $F, log_n: u32) {
+                assert_eq!(values.len(), 1 << log_n);
+                if !self.1.$layout {
+                    return fallback::coset_fft(values, omega, shift, log_n);
+                }
+                let ok = unsafe {
+                    $coset_ntt(
+                        self.0.get_private_context(),
+                        values.as_mut_ptr() as *mut $ctt_fr,
+                        values.len(),
+                        &omega as *const $F as *const $ctt_fr,
+                        &shift as *const $F as *const $ctt_fr,
+                    )
+                };
+                assert!(ok);
+            }
+
+            fn coset_ifft(&self, values: &mut [$F], omega: $F, shift: $F, log_n: u32) {
+                assert_eq!(values.len(), 1 << log_n);
+                if !self.1.$layout {
+                    return fallback::coset_ifft(values, omega, shift, log_n);
+                }
+                let ok = unsafe {
+                    $coset_intt(
+                        self.0.get_private_context(),
+                        values.as_mut_ptr() as *mut $ctt_fr,
+                        values.len(),
+                        &omega as *const $F as *const $ctt_fr,
+                        &shift as *const $F as *const $ctt_fr,
+                    )
+                };
+                assert!(ok);
+            }
+        }
+    };
+}
+
+impl_fft_accel!(
+    bn256::Fr, bn254_snarks_fr,
+    layout: bn254_g1,
+    ntt: ctt_bn254_snarks_fr_ntt_vartime_parallel,
+    intt: ctt_bn254_snarks_fr_intt_vartime_parallel,
+    coset_ntt: ctt_bn254_snarks_fr_coset_ntt_vartime_parallel,
+    coset_intt: ctt_bn254_snarks_fr_coset_intt_vartime_parallel,
+);
+
+// Pallas scalar field is pasta::Fq and Vesta scalar field is pasta::Fp
+impl_fft_accel!(
+    pasta::Fq, pallas_fr,
+    layout: pallas,
+    ntt: ctt_pallas_fr_ntt_vartime_parallel,
+    intt: ctt_pallas_fr_intt_vartime_parallel,
+    coset_ntt: ctt_pallas_fr_coset_ntt_vartime_parallel,
+    coset_intt: ctt_pallas_fr_coset_intt_vartime_parallel,
+);
+
+impl_fft_accel!(
+    pasta::Fp, vesta_fr,
+    layout: vesta,
+    ntt: ctt_vesta_fr_ntt_vartime_parallel,
+    intt: ctt_vesta_fr_intt_vartime_parallel,
+    coset_ntt: ctt_vesta_fr_coset_ntt_vartime_parallel,
+    coset_intt: ctt_vesta_fr_coset_intt_vartime_parallel,
+);
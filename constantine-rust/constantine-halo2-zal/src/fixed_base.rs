@@ -0,0 +1,251 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Fixed-base scalar multiplications using Constantine as a backend
+
+use crate::CttEngine;
+use constantine_sys::*;
+
+use ::core::mem::MaybeUninit;
+use std::fmt;
+use std::mem;
+
+use halo2_middleware::halo2curves::group::prime::PrimeCurveAffine;
+use halo2_middleware::halo2curves::CurveAffine;
+use halo2_middleware::halo2curves::{bls12381, bn256, pasta};
+
+/// Curves with a Constantine table of precomputed multiples of a fixed base.
+///
+/// Curves without conversions (G2) only have a table
+/// if halo2curves and Constantine share the same in-memory representation.
+pub trait CttFixedBase: CurveAffine {
+    #[doc(hidden)]
+    type Table: fmt::Debug;
+    #[doc(hidden)]
+    fn same_layout(engine: &CttEngine) -> bool;
+    #[doc(hidden)]
+    fn precompute_fixed_base(base: &Self, same_layout: bool) -> Option<Self::Table>;
+    #[doc(hidden)]
+    fn fixed_base_mul(table: &Self::Table, scalar: &Self::Scalar, same_layout: bool)
+        -> Self::Curve;
+    #[doc(hidden)]
+    fn delete_fixed_base(table: &mut Self::Table);
+}
+
+/// A fixed base with its table of precomputed multiples.
+/// The table is freed on drop.
+///
+/// Scalar multiplications by the table are constant-time
+/// and suitable for secret scalars, like key generation, signing
+/// or commitment blinding factors.
+#[derive(Debug)]
+pub struct FixedBaseTable<G: CttFixedBase> {
+    base: G,
+    same_layout: bool,
+    table: Option<G::Table>,
+}
+
+impl<G: CttFixedBase> FixedBaseTable<G> {
+    /// Precompute the table of `base`.
+    pub fn new(engine: &CttEngine, base: G) -> Self {
+        let same_layout = G::same_layout(engine);
+        Self {
+            base,
+            same_layout,
+            table: G::precompute_fixed_base(&base, same_layout),
+        }
+    }
+
+    /// Precompute the table of the curve generator.
+    ///
+    /// The table is built from halo2curves' generator,
+    /// so that it always matches `base()` and the fallback without a table.
+    pub fn generator(engine: &CttEngine) -> Self {
+        Self::new(engine, G::generator())
+    }
+
+    /// The fixed base of the table
+    pub fn base(&self) -> &G {
+        &self.base
+    }
+
+    /// [scalar]base
+    pub fn mul(&self, scalar: &G::Scalar) -> G::Curve {
+        match &self.table {
+            Some(table) => G::fixed_base_mul(table, scalar, self.same_layout),
+            None => self.base * *scalar,
+        }
+    }
+}
+
+impl<G: CttFixedBase> Drop for FixedBaseTable<G> {
+    fn drop(&mut self) {
+        if let Some(table) = &mut self.table {
+            G::delete_fixed_base(table);
+        }
+    }
+}
+
+/// Implement `CttFixedBase` for a halo2curves affine point type
+macro_rules! impl_fixed_base {
+    // Points and scalars are converted if representations differ
+    (
+        $Aff:ty, $Curve:ty, $Fr:ty,
+        $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty, $ctt_table:ty,
+        layout: $layout:ident,
+        conversions: $conv:ident,
+        precompute: $precompute:ident,
+        mul: $mul:ident,
+        delete: $delete:ident $(,)?
+    ) => {
+        impl CttFixedBase for $Aff {
+            type Table = $ctt_table;
+
+            fn same_layout(engine: &CttEngine) -> bool {
+                engine.1.$layout
+            }
+
+            fn precompute_fixed_base(base: &Self, same_layout: bool) -> Option<Self::Table> {
+                let mut table = MaybeUninit::<$ctt_table>::uninit();
+                unsafe {
+                    if same_layout {
+                        $precompute(table.as_mut_ptr(), base as *const $Aff as *const $ctt_aff);
+                    } else {
+                        $precompute(table.as_mut_ptr(), &crate::$conv::aff_to_ctt(base));
+                    }
+                    Some(table.assume_init())
+                }
+            }
+
+            fn fixed_base_mul(table: &Self::Table, scalar: &$Fr, same_layout: bool) -> $Curve {
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    if same_layout {
+                        $mul(
+                            result.as_mut_ptr(),
+                            table,
+                            scalar as *const $Fr as *const $ctt_fr,
+                        );
+                        mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                    } else {
+                        $mul(result.as_mut_ptr(), table, &crate::$conv::fr_to_ctt(scalar));
+                        crate::$conv::curve_from_ctt(&result.assume_init())
+                    }
+                }
+            }
+
+            fn delete_fixed_base(table: &mut Self::Table) {
+                unsafe { $delete(table) }
+            }
+        }
+    };
+
+    // Without conversions.
+    // If representations differ, there is no table and halo2curves scalar multiplications are used.
+    (
+        $Aff:ty, $Curve:ty, $Fr:ty,
+        $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty, $ctt_table:ty,
+        layout: $layout:ident,
+        precompute: $precompute:ident,
+        mul: $mul:ident,
+        delete: $delete:ident $(,)?
+    ) => {
+        impl CttFixedBase for $Aff {
+            type Table = $ctt_table;
+
+            fn same_layout(engine: &CttEngine) -> bool {
+                engine.1.$layout
+            }
+
+            fn precompute_fixed_base(base: &Self, same_layout: bool) -> Option<Self::Table> {
+                if !same_layout {
+                    return None;
+                }
+                let mut table = MaybeUninit::<$ctt_table>::uninit();
+                unsafe {
+                    $precompute(table.as_mut_ptr(), base as *const $Aff as *const $ctt_aff);
+                    Some(table.assume_init())
+                }
+            }
+
+            fn fixed_base_mul(table: &Self::Table, scalar: &$Fr, _same_layout: bool) -> $Curve {
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    $mul(
+                        result.as_mut_ptr(),
+                        table,
+                        scalar as *const $Fr as *const $ctt_fr,
+                    );
+                    mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                }
+            }
+
+            fn delete_fixed_base(table: &mut Self::Table) {
+                unsafe { $delete(table) }
+            }
+        }
+    };
+}
+
+impl_fixed_base!(
+    bn256::G1Affine, bn256::G1, bn256::Fr,
+    bn254_snarks_g1_aff, bn254_snarks_g1_prj, bn254_snarks_fr, bn254_snarks_g1_fixed_base_table,
+    layout: bn254_g1,
+    conversions: bn254_snarks_g1,
+    precompute: ctt_bn254_snarks_g1_fixed_base_table_precompute,
+    mul: ctt_bn254_snarks_g1_prj_fixed_base_scalar_mul_fr_coef,
+    delete: ctt_bn254_snarks_g1_fixed_base_table_delete,
+);
+
+impl_fixed_base!(
+    bn256::G2Affine, bn256::G2, bn256::Fr,
+    bn254_snarks_g2_aff, bn254_snarks_g2_prj, bn254_snarks_fr, bn254_snarks_g2_fixed_base_table,
+    layout: bn254_g2,
+    precompute: ctt_bn254_snarks_g2_fixed_base_table_precompute,
+    mul: ctt_bn254_snarks_g2_prj_fixed_base_scalar_mul_fr_coef,
+    delete: ctt_bn254_snarks_g2_fixed_base_table_delete,
+);
+
+impl_fixed_base!(
+    bls12381::G1Affine, bls12381::G1, bls12381::Fr,
+    bls12_381_g1_aff, bls12_381_g1_prj, bls12_381_fr, bls12_381_g1_fixed_base_table,
+    layout: bls12_381_g1,
+    conversions: bls12_381_g1,
+    precompute: ctt_bls12_381_g1_fixed_base_table_precompute,
+    mul: ctt_bls12_381_g1_prj_fixed_base_scalar_mul_fr_coef,
+    delete: ctt_bls12_381_g1_fixed_base_table_delete,
+);
+
+impl_fixed_base!(
+    bls12381::G2Affine, bls12381::G2, bls12381::Fr,
+    bls12_381_g2_aff, bls12_381_g2_prj, bls12_381_fr, bls12_381_g2_fixed_base_table,
+    layout: bls12_381_g2,
+    precompute: ctt_bls12_381_g2_fixed_base_table_precompute,
+    mul: ctt_bls12_381_g2_prj_fixed_base_scalar_mul_fr_coef,
+    delete: ctt_bls12_381_g2_fixed_base_table_delete,
+);
+
+impl_fixed_base!(
+    pasta::EpAffine, pasta::Ep, pasta::Fq,
+    pallas_ec_aff, pallas_ec_jac, pallas_fr, pallas_ec_fixed_base_table,
+    layout: pallas,
+    conversions: pallas_ec,
+    precompute: ctt_pallas_ec_fixed_base_table_precompute,
+    mul: ctt_pallas_ec_jac_fixed_base_scalar_mul_fr_coef,
+    delete: ctt_pallas_ec_fixed_base_table_delete,
+);
+
+impl_fixed_base!(
+    pasta::EqAffine, pasta::Eq, pasta::Fp,
+    vesta_ec_aff, vesta_ec_jac, vesta_fr, vesta_ec_fixed_base_table,
+    layout: vesta,
+    conversions: vesta_ec,
+    precompute: ctt_vesta_ec_fixed_base_table_precompute,
+    mul: ctt_vesta_ec_jac_fixed_base_scalar_mul_fr_coef,
+    delete: ctt_vesta_ec_fixed_base_table_delete,
+);
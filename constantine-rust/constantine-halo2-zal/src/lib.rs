@@ -13,20 +13,76 @@ use constantine_core::Threadpool;
 use constantine_sys::*;
 
 use ::core::mem::MaybeUninit;
+use std::fmt;
 use std::mem;
+use std::ptr;
 
-use halo2_middleware::halo2curves::bn256;
+use halo2_middleware::halo2curves::ff::PrimeField;
+use halo2_middleware::halo2curves::group::prime::PrimeCurveAffine;
+use halo2_middleware::halo2curves::group::{Curve, Group};
+use halo2_middleware::halo2curves::msm::msm_best;
+use halo2_middleware::halo2curves::{bls12381, bn256, pasta};
 use halo2_middleware::zal::traits::MsmAccel;
-use halo2_middleware::halo2curves::CurveAffine;
+use halo2_middleware::halo2curves::{Coordinates, CurveAffine};
+
+mod batch_msm;
+mod fft;
+mod fixed_base;
+mod msm_ct;
+mod msm_small;
+pub use batch_msm::BatchMsmAccel;
+pub use fft::FftAccel;
+pub use fixed_base::{CttFixedBase, FixedBaseTable};
+pub use msm_ct::MsmCtAccel;
+pub use msm_small::MsmSmallAccel;
 
 #[derive(Debug)]
-pub struct CttEngine(Threadpool);
+pub struct CttEngine(Threadpool, sealed::Layouts);
 
 impl CttEngine {
-    #[inline(always)]
+    /// Create an engine backed by a threadpool with `num_threads` threads.
+    ///
+    /// A self-test checks for each curve that halo2curves and Constantine
+    /// share the same in-memory representation of fields and points,
+    /// in which case they are passed as-is, otherwise they are converted.
+    ///
+    /// Panics if Constantine and halo2curves results differ in both cases.
     pub fn new(num_threads: usize) -> Self {
-        Self(Threadpool::new(num_threads))
+        let tp = Threadpool::new(num_threads);
+        let layouts = sealed::Layouts {
+            bn254_g1: self_test::<bn256::G1Affine>(&tp),
+            bn254_g2: self_test::<bn256::G2Affine>(&tp),
+            bls12_381_g1: self_test::<bls12381::G1Affine>(&tp),
+            bls12_381_g2: self_test::<bls12381::G2Affine>(&tp),
+            pallas: self_test::<pasta::EpAffine>(&tp),
+            vesta: self_test::<pasta::EqAffine>(&tp),
+        };
+        Self(tp, layouts)
+    }
+}
+
+/// Returns true if halo2curves values of `C` can be passed as-is to Constantine
+/// and false if they need to be converted.
+fn self_test<C: CttMsmTable>(tp: &Threadpool) -> bool {
+    let g = C::generator();
+    let coeffs = [
+        C::Scalar::from(0x0123_4567_89ab_cdef),
+        -C::Scalar::from(3),
+        C::Scalar::from(5),
+    ];
+    let h = (g * coeffs[0]).to_affine();
+    let bases = [g, h, C::identity()];
+    let expected = g * coeffs[0] + h * coeffs[1];
+
+    if C::msm_same_layout(tp, &coeffs, &bases) == expected {
+        return true;
     }
+    assert!(
+        C::msm_converted(tp, &coeffs, &bases) == expected,
+        "Constantine and halo2curves MSMs differ for {}",
+        std::any::type_name::<C>()
+    );
+    false
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -34,63 +90,529 @@ pub struct CttMsmCoeffsDesc<'c, C: CurveAffine> {
     raw: &'c [C::Scalar],
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct CttMsmBaseDesc<'b, C: CurveAffine> {
+mod sealed {
+    use super::*;
+
+    /// Whether halo2curves values can be passed as-is to Constantine (true)
+    /// or need to be converted (false), see `CttEngine::new`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Layouts {
+        pub bn254_g1: bool,
+        pub bn254_g2: bool,
+        pub bls12_381_g1: bool,
+        pub bls12_381_g2: bool,
+        pub pallas: bool,
+        pub vesta: bool,
+    }
+
+    pub trait Sealed: CurveAffine {
+        /// MSM passing halo2curves values as-is
+        fn msm_same_layout(tp: &Threadpool, coeffs: &[Self::Scalar], bases: &[Self])
+            -> Self::Curve;
+        /// MSM converting halo2curves values
+        fn msm_converted(tp: &Threadpool, coeffs: &[Self::Scalar], bases: &[Self]) -> Self::Curve;
+    }
+}
+
+/// Curves with a Constantine table of precomputed fixed MSM bases.
+///
+/// For each base `P`, the table stores the multiples `[2⁶⁴ʲ]P` so that
+/// an MSM over n bases becomes an MSM over 4n bases with 64-bit coefficients.
+/// The table uses 4x the memory of the bases.
+///
+/// Curves without conversions (G2) only have a table
+/// if halo2curves and Constantine share the same in-memory representation.
+pub trait CttMsmTable: CurveAffine + sealed::Sealed {
+    #[doc(hidden)]
+    type Table: fmt::Debug;
+    #[doc(hidden)]
+    fn precompute(tp: &Threadpool, bases: &[Self], same_layout: bool) -> Self::Table;
+    #[doc(hidden)]
+    fn delete(table: &mut Self::Table);
+}
+
+/// Fixed MSM bases with their precomputed table.
+/// The table is freed on drop.
+#[derive(Debug)]
+pub struct CttMsmBaseDesc<'b, C: CttMsmTable> {
     raw: &'b [C],
+    table: C::Table,
 }
 
-impl MsmAccel<bn256::G1Affine> for CttEngine {
-    fn msm(&self, coeffs: &[bn256::Fr], bases: &[bn256::G1Affine]) -> bn256::G1 {
-        assert_eq!(coeffs.len(), bases.len());
-        let mut result = MaybeUninit::<bn254_snarks_g1_prj>::uninit();
-        unsafe {
-            ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
-                self.0.get_private_context(),
-                result.as_mut_ptr(),
-                coeffs.as_ptr() as *const bn254_snarks_fr,
-                bases.as_ptr() as *const bn254_snarks_g1_aff,
-                bases.len(),
-            );
-            mem::transmute::<MaybeUninit<bn254_snarks_g1_prj>, bn256::G1>(result)
-        }
+impl<C: CttMsmTable> Drop for CttMsmBaseDesc<'_, C> {
+    fn drop(&mut self) {
+        C::delete(&mut self.table);
     }
+}
+
+/// Check that a halo2curves type can be read and written as a Constantine type.
+/// Both libraries use the same Montgomery representation and limb layout
+/// for fields and the same coordinates for points.
+macro_rules! assert_layout_compatible {
+    ($halo2:ty, $ctt:ty) => {
+        const _: () = {
+            assert!(mem::size_of::<$halo2>() == mem::size_of::<$ctt>());
+            assert!(mem::align_of::<$halo2>() >= mem::align_of::<$ctt>());
+        };
+    };
+}
+
+/// Convert between halo2curves and Constantine for curves over a prime field
+/// through the canonical encoding of field elements.
+macro_rules! impl_conversions {
+    (
+        $m:ident, $Aff:ty, $Curve:ty, $Fr:ty,
+        $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty, $ctt_fp:ty,
+        fr_unmarshal: $fr_unmarshal:ident,
+        fp_unmarshal: $fp_unmarshal:ident,
+        fp_marshal: $fp_marshal:ident,
+        set_neutral: $set_neutral:ident,
+        is_neutral: $is_neutral:ident,
+        affine: $affine:ident $(,)?
+    ) => {
+        mod $m {
+            use super::*;
+
+            // Define a byte compatible version of the Constantine affine point
+            // so that we have access to the coordinates
+            #[repr(C)]
+            struct CttAffine {
+                x: $ctt_fp,
+                y: $ctt_fp,
+            }
+
+            type Base = <$Aff as CurveAffine>::Base;
+
+            // halo2curves canonical representations are little-endian.
+
+            pub(super) fn fr_to_ctt(a: &$Fr) -> $ctt_fr {
+                let mut bytes = a.to_repr();
+                bytes.as_mut().reverse();
+                let mut r = MaybeUninit::<$ctt_fr>::uninit();
+                unsafe {
+                    let ok = $fr_unmarshal(
+                        r.as_mut_ptr(),
+                        bytes.as_ref().as_ptr(),
+                        bytes.as_ref().len(),
+                    );
+                    assert!(ok);
+                    r.assume_init()
+                }
+            }
+
+            unsafe fn fp_to_ctt(r: *mut $ctt_fp, a: &Base) {
+                let mut bytes = a.to_repr();
+                bytes.as_mut().reverse();
+                let ok = $fp_unmarshal(r, bytes.as_ref().as_ptr(), bytes.as_ref().len());
+                assert!(ok);
+            }
+
+            fn fp_from_ctt(a: &$ctt_fp) -> Base {
+                let mut bytes = <Base as PrimeField>::Repr::default();
+                unsafe {
+                    let ok = $fp_marshal(bytes.as_mut().as_mut_ptr(), bytes.as_ref().len(), a);
+                    assert!(ok);
+                }
+                bytes.as_mut().reverse();
+                Base::from_repr(bytes).unwrap()
+            }
+
+            pub(super) fn aff_to_ctt(p: &$Aff) -> $ctt_aff {
+                let coords: Option<Coordinates<$Aff>> = p.coordinates().into();
+                let mut r = MaybeUninit::<$ctt_aff>::uninit();
+                unsafe {
+                    match coords {
+                        None => $set_neutral(r.as_mut_ptr()),
+                        Some(coords) => {
+                            let aff = r.as_mut_ptr() as *mut CttAffine;
+                            fp_to_ctt(ptr::addr_of_mut!((*aff).x), coords.x());
+                            fp_to_ctt(ptr::addr_of_mut!((*aff).y), coords.y());
+                        }
+                    }
+                    r.assume_init()
+                }
+            }
+
+            pub(super) fn curve_from_ctt(p: &$ctt_curve) -> $Curve {
+                let mut aff = MaybeUninit::<$ctt_aff>::uninit();
+                let aff = unsafe {
+                    $affine(aff.as_mut_ptr(), p);
+                    aff.assume_init()
+                };
+                if unsafe { $is_neutral(&aff) } != 0 {
+                    return <$Curve as Group>::identity();
+                }
+                let aff = unsafe { &*(&aff as *const $ctt_aff as *const CttAffine) };
+                <$Aff>::from_xy(fp_from_ctt(&aff.x), fp_from_ctt(&aff.y))
+                    .unwrap()
+                    .to_curve()
+            }
+        }
+    };
+}
+
+/// Implement `MsmAccel` with the caching API for a halo2curves affine point type
+macro_rules! impl_msm_accel {
+    // Parallel MSM with a table of precomputed fixed bases
+    (
+        $Aff:ty, $Curve:ty, $Fr:ty,
+        $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty, $ctt_table:ty,
+        layout: $layout:ident,
+        conversions: $conv:ident,
+        msm: $msm:ident,
+        msm_table: $msm_table:ident,
+        precompute: $precompute:ident,
+        delete: $delete:ident $(,)?
+    ) => {
+        impl_msm_accel!(@layout $Aff, $Curve, $Fr, $ctt_aff, $ctt_curve, $ctt_fr);
+
+        impl sealed::Sealed for $Aff {
+            fn msm_same_layout(tp: &Threadpool, coeffs: &[$Fr], bases: &[$Aff]) -> $Curve {
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    $msm(
+                        tp.get_private_context(),
+                        result.as_mut_ptr(),
+                        coeffs.as_ptr() as *const $ctt_fr,
+                        bases.as_ptr() as *const $ctt_aff,
+                        bases.len(),
+                    );
+                    mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                }
+            }
+
+            fn msm_converted(tp: &Threadpool, coeffs: &[$Fr], bases: &[$Aff]) -> $Curve {
+                let coeffs = coeffs.iter().map($conv::fr_to_ctt).collect::<Vec<_>>();
+                let bases = bases.iter().map($conv::aff_to_ctt).collect::<Vec<_>>();
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    $msm(
+                        tp.get_private_context(),
+                        result.as_mut_ptr(),
+                        coeffs.as_ptr(),
+                        bases.as_ptr(),
+                        bases.len(),
+                    );
+                    $conv::curve_from_ctt(&result.assume_init())
+                }
+            }
+        }
+
+        impl CttMsmTable for $Aff {
+            type Table = $ctt_table;
+
+            fn precompute(tp: &Threadpool, bases: &[Self], same_layout: bool) -> Self::Table {
+                let converted: Vec<$ctt_aff>;
+                let points = if same_layout {
+                    bases.as_ptr() as *const $ctt_aff
+                } else {
+                    converted = bases.iter().map($conv::aff_to_ctt).collect();
+                    converted.as_ptr()
+                };
+                let mut table = MaybeUninit::<$ctt_table>::uninit();
+                unsafe {
+                    $precompute(
+                        tp.get_private_context(),
+                        table.as_mut_ptr(),
+                        points,
+                        bases.len(),
+                    );
+                    table.assume_init()
+                }
+            }
+
+            fn delete(table: &mut Self::Table) {
+                unsafe { $delete(table) }
+            }
+        }
+
+        impl MsmAccel<$Aff> for CttEngine {
+            fn msm(&self, coeffs: &[$Fr], bases: &[$Aff]) -> $Curve {
+                assert_eq!(coeffs.len(), bases.len());
+                if self.1.$layout {
+                    <$Aff as sealed::Sealed>::msm_same_layout(&self.0, coeffs, bases)
+                } else {
+                    <$Aff as sealed::Sealed>::msm_converted(&self.0, coeffs, bases)
+                }
+            }
+
+            impl_msm_accel!(@caching $Aff, $Curve, $Fr, $layout);
+
+            fn msm_with_cached_base(
+                &self,
+                coeffs: &[$Fr],
+                base: &Self::BaseDescriptor<'_>,
+            ) -> $Curve {
+                assert!(coeffs.len() <= base.raw.len());
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    if self.1.$layout {
+                        $msm_table(
+                            self.0.get_private_context(),
+                            result.as_mut_ptr(),
+                            coeffs.as_ptr() as *const $ctt_fr,
+                            &base.table,
+                            coeffs.len(),
+                        );
+                        mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                    } else {
+                        let coeffs = coeffs.iter().map($conv::fr_to_ctt).collect::<Vec<_>>();
+                        $msm_table(
+                            self.0.get_private_context(),
+                            result.as_mut_ptr(),
+                            coeffs.as_ptr(),
+                            &base.table,
+                            coeffs.len(),
+                        );
+                        $conv::curve_from_ctt(&result.assume_init())
+                    }
+                }
+            }
+        }
+    };
+
+    // Parallel MSM with a table of precomputed fixed bases, without conversions.
+    // If representations differ, halo2curves MSM is used.
+    (
+        $Aff:ty, $Curve:ty, $Fr:ty,
+        $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty, $ctt_table:ty,
+        layout: $layout:ident,
+        msm: $msm:ident,
+        msm_table: $msm_table:ident,
+        precompute: $precompute:ident,
+        delete: $delete:ident $(,)?
+    ) => {
+        impl_msm_accel!(@layout $Aff, $Curve, $Fr, $ctt_aff, $ctt_curve, $ctt_fr);
+
+        impl sealed::Sealed for $Aff {
+            fn msm_same_layout(tp: &Threadpool, coeffs: &[$Fr], bases: &[$Aff]) -> $Curve {
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    $msm(
+                        tp.get_private_context(),
+                        result.as_mut_ptr(),
+                        coeffs.as_ptr() as *const $ctt_fr,
+                        bases.as_ptr() as *const $ctt_aff,
+                        bases.len(),
+                    );
+                    mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                }
+            }
+
+            fn msm_converted(_tp: &Threadpool, coeffs: &[$Fr], bases: &[$Aff]) -> $Curve {
+                msm_best(coeffs, bases)
+            }
+        }
+
+        impl CttMsmTable for $Aff {
+            type Table = Option<$ctt_table>;
+
+            fn precompute(tp: &Threadpool, bases: &[Self], same_layout: bool) -> Self::Table {
+                if !same_layout {
+                    return None;
+                }
+                let mut table = MaybeUninit::<$ctt_table>::uninit();
+                unsafe {
+                    $precompute(
+                        tp.get_private_context(),
+                        table.as_mut_ptr(),
+                        bases.as_ptr() as *const $ctt_aff,
+                        bases.len(),
+                    );
+                    Some(table.assume_init())
+                }
+            }
+
+            fn delete(table: &mut Self::Table) {
+                if let Some(table) = table {
+                    unsafe { $delete(table) }
+                }
+            }
+        }
+
+        impl MsmAccel<$Aff> for CttEngine {
+            fn msm(&self, coeffs: &[$Fr], bases: &[$Aff]) -> $Curve {
+                assert_eq!(coeffs.len(), bases.len());
+                if self.1.$layout {
+                    <$Aff as sealed::Sealed>::msm_same_layout(&self.0, coeffs, bases)
+                } else {
+                    <$Aff as sealed::Sealed>::msm_converted(&self.0, coeffs, bases)
+                }
+            }
+
+            impl_msm_accel!(@caching $Aff, $Curve, $Fr, $layout);
+
+            fn msm_with_cached_base(
+                &self,
+                coeffs: &[$Fr],
+                base: &Self::BaseDescriptor<'_>,
+            ) -> $Curve {
+                assert!(coeffs.len() <= base.raw.len());
+                let Some(table) = &base.table else {
+                    return msm_best(coeffs, &base.raw[..coeffs.len()]);
+                };
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    $msm_table(
+                        self.0.get_private_context(),
+                        result.as_mut_ptr(),
+                        coeffs.as_ptr() as *const $ctt_fr,
+                        table,
+                        coeffs.len(),
+                    );
+                    mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                }
+            }
+        }
+    };
+
+    (@layout $Aff:ty, $Curve:ty, $Fr:ty, $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty) => {
+        assert_layout_compatible!($Aff, $ctt_aff);
+        assert_layout_compatible!($Curve, $ctt_curve);
+        assert_layout_compatible!($Fr, $ctt_fr);
+    };
 
     // Caching API
     // -------------------------------------------------
+    (@caching $Aff:ty, $Curve:ty, $Fr:ty, $layout:ident) => {
+        type CoeffsDescriptor<'c> = CttMsmCoeffsDesc<'c, $Aff>;
+        type BaseDescriptor<'b> = CttMsmBaseDesc<'b, $Aff>;
 
-    type CoeffsDescriptor<'c> = CttMsmCoeffsDesc<'c, bn256::G1Affine>;
-    type BaseDescriptor<'b> = CttMsmBaseDesc<'b, bn256::G1Affine>;
+        fn get_coeffs_descriptor<'c>(&self, coeffs: &'c [$Fr]) -> Self::CoeffsDescriptor<'c> {
+            // Do expensive device/library specific preprocessing here
+            Self::CoeffsDescriptor { raw: coeffs }
+        }
+        fn get_base_descriptor<'b>(&self, base: &'b [$Aff]) -> Self::BaseDescriptor<'b> {
+            Self::BaseDescriptor {
+                raw: base,
+                table: <$Aff>::precompute(&self.0, base, self.1.$layout),
+            }
+        }
 
-    fn get_coeffs_descriptor<'c>(&self, coeffs: &'c [bn256::Fr]) -> Self::CoeffsDescriptor<'c> {
-        // Do expensive device/library specific preprocessing here
-        Self::CoeffsDescriptor { raw: coeffs }
-    }
-    fn get_base_descriptor<'b>(&self, base: &'b [bn256::G1Affine]) -> Self::BaseDescriptor<'b> {
-        // Do expensive device/library specific preprocessing here
-        Self::BaseDescriptor { raw: base }
-    }
+        fn msm_with_cached_scalars(
+            &self,
+            coeffs: &Self::CoeffsDescriptor<'_>,
+            base: &[$Aff],
+        ) -> $Curve {
+            <Self as MsmAccel<$Aff>>::msm(self, coeffs.raw, base)
+        }
 
-    fn msm_with_cached_scalars(
-        &self,
-        coeffs: &Self::CoeffsDescriptor<'_>,
-        base: &[bn256::G1Affine],
-    ) -> bn256::G1 {
-        self.msm(coeffs.raw, base)
-    }
+        fn msm_with_cached_inputs(
+            &self,
+            coeffs: &Self::CoeffsDescriptor<'_>,
+            base: &Self::BaseDescriptor<'_>,
+        ) -> $Curve {
+            <Self as MsmAccel<$Aff>>::msm_with_cached_base(self, coeffs.raw, base)
+        }
+    };
+}
 
-    fn msm_with_cached_base(
-        &self,
-        coeffs: &[bn256::Fr],
-        base: &Self::BaseDescriptor<'_>,
-    ) -> bn256::G1 {
-        self.msm(coeffs, base.raw)
-    }
+impl_conversions!(
+    bn254_snarks_g1, bn256::G1Affine, bn256::G1, bn256::Fr,
+    bn254_snarks_g1_aff, bn254_snarks_g1_prj, bn254_snarks_fr, bn254_snarks_fp,
+    fr_unmarshal: ctt_bn254_snarks_fr_unmarshalBE,
+    fp_unmarshal: ctt_bn254_snarks_fp_unmarshalBE,
+    fp_marshal: ctt_bn254_snarks_fp_marshalBE,
+    set_neutral: ctt_bn254_snarks_g1_aff_set_neutral,
+    is_neutral: ctt_bn254_snarks_g1_aff_is_neutral,
+    affine: ctt_bn254_snarks_g1_prj_affine,
+);
 
-    fn msm_with_cached_inputs(
-        &self,
-        coeffs: &Self::CoeffsDescriptor<'_>,
-        base: &Self::BaseDescriptor<'_>,
-    ) -> bn256::G1 {
-        self.msm(coeffs.raw, base.raw)
-    }
-}
+impl_msm_accel!(
+    bn256::G1Affine, bn256::G1, bn256::Fr,
+    bn254_snarks_g1_aff, bn254_snarks_g1_prj, bn254_snarks_fr, bn254_snarks_g1_msm_table,
+    layout: bn254_g1,
+    conversions: bn254_snarks_g1,
+    msm: ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel,
+    msm_table: ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel,
+    precompute: ctt_bn254_snarks_g1_msm_table_precompute_parallel,
+    delete: ctt_bn254_snarks_g1_msm_table_delete,
+);
+
+// G2 coordinates are in Fp2 which has no canonical encoding in halo2curves.
+impl_msm_accel!(
+    bn256::G2Affine, bn256::G2, bn256::Fr,
+    bn254_snarks_g2_aff, bn254_snarks_g2_prj, bn254_snarks_fr, bn254_snarks_g2_msm_table,
+    layout: bn254_g2,
+    msm: ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_vartime_parallel,
+    msm_table: ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel,
+    precompute: ctt_bn254_snarks_g2_msm_table_precompute_parallel,
+    delete: ctt_bn254_snarks_g2_msm_table_delete,
+);
+
+impl_conversions!(
+    bls12_381_g1, bls12381::G1Affine, bls12381::G1, bls12381::Fr,
+    bls12_381_g1_aff, bls12_381_g1_prj, bls12_381_fr, bls12_381_fp,
+    fr_unmarshal: ctt_bls12_381_fr_unmarshalBE,
+    fp_unmarshal: ctt_bls12_381_fp_unmarshalBE,
+    fp_marshal: ctt_bls12_381_fp_marshalBE,
+    set_neutral: ctt_bls12_381_g1_aff_set_neutral,
+    is_neutral: ctt_bls12_381_g1_aff_is_neutral,
+    affine: ctt_bls12_381_g1_prj_affine,
+);
+
+impl_msm_accel!(
+    bls12381::G1Affine, bls12381::G1, bls12381::Fr,
+    bls12_381_g1_aff, bls12_381_g1_prj, bls12_381_fr, bls12_381_g1_msm_table,
+    layout: bls12_381_g1,
+    conversions: bls12_381_g1,
+    msm: ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel,
+    msm_table: ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel,
+    precompute: ctt_bls12_381_g1_msm_table_precompute_parallel,
+    delete: ctt_bls12_381_g1_msm_table_delete,
+);
+
+impl_msm_accel!(
+    bls12381::G2Affine, bls12381::G2, bls12381::Fr,
+    bls12_381_g2_aff, bls12_381_g2_prj, bls12_381_fr, bls12_381_g2_msm_table,
+    layout: bls12_381_g2,
+    msm: ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_vartime_parallel,
+    msm_table: ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel,
+    precompute: ctt_bls12_381_g2_msm_table_precompute_parallel,
+    delete: ctt_bls12_381_g2_msm_table_delete,
+);
+
+// The Pasta curves use Jacobian coordinates
+impl_conversions!(
+    pallas_ec, pasta::EpAffine, pasta::Ep, pasta::Fq,
+    pallas_ec_aff, pallas_ec_jac, pallas_fr, pallas_fp,
+    fr_unmarshal: ctt_pallas_fr_unmarshalBE,
+    fp_unmarshal: ctt_pallas_fp_unmarshalBE,
+    fp_marshal: ctt_pallas_fp_marshalBE,
+    set_neutral: ctt_pallas_ec_aff_set_neutral,
+    is_neutral: ctt_pallas_ec_aff_is_neutral,
+    affine: ctt_pallas_ec_jac_affine,
+);
+
+impl_msm_accel!(
+    pasta::EpAffine, pasta::Ep, pasta::Fq,
+    pallas_ec_aff, pallas_ec_jac, pallas_fr, pallas_ec_msm_table,
+    layout: pallas,
+    conversions: pallas_ec,
+    msm: ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_vartime_parallel,
+    msm_table: ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel,
+    precompute: ctt_pallas_ec_msm_table_precompute_parallel,
+    delete: ctt_pallas_ec_msm_table_delete,
+);
+
+impl_conversions!(
+    vesta_ec, pasta::EqAffine, pasta::Eq, pasta::Fp,
+    vesta_ec_aff, vesta_ec_jac, vesta_fr, vesta_fp,
+    fr_unmarshal: ctt_vesta_fr_unmarshalBE,
+    fp_unmarshal: ctt_vesta_fp_unmarshalBE,
+    fp_marshal: ctt_vesta_fp_marshalBE,
+    set_neutral: ctt_vesta_ec_aff_set_neutral,
+    is_neutral: ctt_vesta_ec_aff_is_neutral,
+    affine: ctt_vesta_ec_jac_affine,
+);
+
+impl_msm_accel!(
+    pasta::EqAffine, pasta::Eq, pasta::Fp,
+    vesta_ec_aff, vesta_ec_jac, vesta_fr, vesta_ec_msm_table,
+    layout: vesta,
+    conversions: vesta_ec,
+    msm: ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_vartime_parallel,
+    msm_table: ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel,
+    precompute: ctt_vesta_ec_msm_table_precompute_parallel,
+    delete: ctt_vesta_ec_msm_table_delete,
+);
@@ -0,0 +1,184 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Constant-time multi-scalar multiplications using Constantine as a backend
+
+use crate::CttEngine;
+use constantine_sys::*;
+
+use ::core::mem::MaybeUninit;
+use std::mem;
+use std::ptr;
+
+use halo2_middleware::halo2curves::group::Group;
+use halo2_middleware::halo2curves::CurveAffine;
+use halo2_middleware::halo2curves::{bls12381, bn256, pasta};
+use halo2_middleware::zal::traits::MsmAccel;
+
+/// MSMs with an explicit choice between public and secret coefficients.
+///
+/// `MsmAccel::msm` and `msm_vartime` leak the coefficients through timing and memory accesses,
+/// they MUST only be used with public coefficients.
+/// `msm_ct` MUST be used when the coefficients are secret,
+/// for example blinding factors of Pedersen commitments or threshold signing shares.
+pub trait MsmCtAccel<C: CurveAffine>: MsmAccel<C> {
+    /// ∑ᵢ [coeffs[i]]bases[i] for public coefficients
+    ///
+    /// This is `MsmAccel::msm`, the time taken depends on the coefficients.
+    fn msm_vartime(&self, coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+        self.msm(coeffs, bases)
+    }
+
+    /// ∑ᵢ [coeffs[i]]bases[i] for secret coefficients
+    ///
+    /// The time taken and the memory accesses only depend on the number of bases.
+    /// This is significantly slower than `msm_vartime`.
+    fn msm_ct(&self, coeffs: &[C::Scalar], bases: &[C]) -> C::Curve;
+}
+
+/// Zero the converted secret coefficients before their memory is released.
+/// Volatile writes are not optimized away even though the memory is not read afterwards.
+///
+/// # Safety
+///
+/// The all-zero bit pattern must be a valid `T`.
+unsafe fn clear_secret<T>(values: &mut [T]) {
+    for v in values.iter_mut() {
+        ptr::write_volatile(v, mem::zeroed());
+    }
+}
+
+/// Sum of constant-time scalar multiplications by halo2curves, used when halo2curves and Constantine
+/// have a different in-memory representation and no conversion is available.
+fn fallback_msm_ct<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+    coeffs
+        .iter()
+        .zip(bases)
+        .fold(C::Curve::identity(), |acc, (&c, &b)| acc + b * c)
+}
+
+/// Implement `MsmCtAccel` for a halo2curves affine point type
+macro_rules! impl_msm_ct_accel {
+    // Points and coefficients are converted if representations differ
+    (
+        $Aff:ty, $Curve:ty, $Fr:ty,
+        $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty,
+        layout: $layout:ident,
+        conversions: $conv:ident,
+        msm_ct: $msm_ct:ident $(,)?
+    ) => {
+        impl MsmCtAccel<$Aff> for CttEngine {
+            fn msm_ct(&self, coeffs: &[$Fr], bases: &[$Aff]) -> $Curve {
+                assert_eq!(coeffs.len(), bases.len());
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    if self.1.$layout {
+                        $msm_ct(
+                            result.as_mut_ptr(),
+                            coeffs.as_ptr() as *const $ctt_fr,
+                            bases.as_ptr() as *const $ctt_aff,
+                            bases.len(),
+                        );
+                        mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                    } else {
+                        let mut coeffs = coeffs
+                            .iter()
+                            .map(crate::$conv::fr_to_ctt)
+                            .collect::<Vec<_>>();
+                        let bases = bases
+                            .iter()
+                            .map(crate::$conv::aff_to_ctt)
+                            .collect::<Vec<_>>();
+                        $msm_ct(
+                            result.as_mut_ptr(),
+                            coeffs.as_ptr(),
+                            bases.as_ptr(),
+                            bases.len(),
+                        );
+                        clear_secret(&mut coeffs);
+                        crate::$conv::curve_from_ctt(&result.assume_init())
+                    }
+                }
+            }
+        }
+    };
+
+    // Without conversions.
+    // If representations differ, halo2curves scalar multiplications are used.
+    (
+        $Aff:ty, $Curve:ty, $Fr:ty,
+        $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty,
+        layout: $layout:ident,
+        msm_ct: $msm_ct:ident $(,)?
+    ) => {
+        impl MsmCtAccel<$Aff> for CttEngine {
+            fn msm_ct(&self, coeffs: &[$Fr], bases: &[$Aff]) -> $Curve {
+                assert_eq!(coeffs.len(), bases.len());
+                if !self.1.$layout {
+                    return fallback_msm_ct(coeffs, bases);
+                }
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    $msm_ct(
+                        result.as_mut_ptr(),
+                        coeffs.as_ptr() as *const $ctt_fr,
+                        bases.as_ptr() as *const $ctt_aff,
+                        bases.len(),
+                    );
+                    mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                }
+            }
+        }
+    };
+}
+
+impl_msm_ct_accel!(
+    bn256::G1Affine, bn256::G1, bn256::Fr,
+    bn254_snarks_g1_aff, bn254_snarks_g1_prj, bn254_snarks_fr,
+    layout: bn254_g1,
+    conversions: bn254_snarks_g1,
+    msm_ct: ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs,
+);
+
+impl_msm_ct_accel!(
+    bn256::G2Affine, bn256::G2, bn256::Fr,
+    bn254_snarks_g2_aff, bn254_snarks_g2_prj, bn254_snarks_fr,
+    layout: bn254_g2,
+    msm_ct: ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs,
+);
+
+impl_msm_ct_accel!(
+    bls12381::G1Affine, bls12381::G1, bls12381::Fr,
+    bls12_381_g1_aff, bls12_381_g1_prj, bls12_381_fr,
+    layout: bls12_381_g1,
+    conversions: bls12_381_g1,
+    msm_ct: ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs,
+);
+
+impl_msm_ct_accel!(
+    bls12381::G2Affine, bls12381::G2, bls12381::Fr,
+    bls12_381_g2_aff, bls12_381_g2_prj, bls12_381_fr,
+    layout: bls12_381_g2,
+    msm_ct: ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs,
+);
+
+impl_msm_ct_accel!(
+    pasta::EpAffine, pasta::Ep, pasta::Fq,
+    pallas_ec_aff, pallas_ec_jac, pallas_fr,
+    layout: pallas,
+    conversions: pallas_ec,
+    msm_ct: ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs,
+);
+
+impl_msm_ct_accel!(
+    pasta::EqAffine, pasta::Eq, pasta::Fp,
+    vesta_ec_aff, vesta_ec_jac, vesta_fr,
+    layout: vesta,
+    conversions: vesta_ec,
+    msm_ct: ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs,
+);
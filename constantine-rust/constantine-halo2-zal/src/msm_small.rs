@@ -0,0 +1,277 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Multi-scalar multiplications with small or signed coefficients using Constantine as a backend
+
+use crate::CttEngine;
+use constantine_sys::*;
+
+use ::core::mem::MaybeUninit;
+use std::mem;
+
+use halo2_middleware::halo2curves::msm::msm_best;
+use halo2_middleware::halo2curves::CurveAffine;
+use halo2_middleware::halo2curves::{bls12381, bn256, pasta};
+
+/// MSMs with coefficients smaller than the scalar field.
+///
+/// The cost of an MSM is proportional to the bit-width of the coefficients.
+/// Witness bits, lookup indices or signed differences
+/// do not need to pay for a full scalar field multiplication.
+pub trait MsmSmallAccel<C: CurveAffine> {
+    /// ∑ᵢ [coeffs[i]]bases[i] with 64-bit unsigned coefficients
+    fn msm_u64(&self, coeffs: &[u64], bases: &[C]) -> C::Curve;
+
+    /// ∑ᵢ [coeffs[i]]bases[i] with 64-bit signed coefficients
+    fn msm_i64(&self, coeffs: &[i64], bases: &[C]) -> C::Curve;
+
+    /// ∑ᵢ [coeffs[i]]bases[i] with all coefficients less than 2^max_bits
+    ///
+    /// Coefficients greater or equal to 2^max_bits lead to an incorrect result.
+    fn msm_max_bits(&self, coeffs: &[C::Scalar], bases: &[C], max_bits: usize) -> C::Curve;
+}
+
+/// halo2curves MSMs, used when halo2curves and Constantine
+/// have a different in-memory representation and no conversion is available.
+mod fallback {
+    use super::*;
+
+    pub(super) fn msm_u64<C: CurveAffine>(coeffs: &[u64], bases: &[C]) -> C::Curve {
+        let coeffs = coeffs
+            .iter()
+            .map(|&c| C::Scalar::from(c))
+            .collect::<Vec<_>>();
+        msm_best(&coeffs, bases)
+    }
+
+    pub(super) fn msm_i64<C: CurveAffine>(coeffs: &[i64], bases: &[C]) -> C::Curve {
+        let coeffs = coeffs
+            .iter()
+            .map(|&c| {
+                let abs = C::Scalar::from(c.unsigned_abs());
+                if c < 0 {
+                    -abs
+                } else {
+                    abs
+                }
+            })
+            .collect::<Vec<_>>();
+        msm_best(&coeffs, bases)
+    }
+}
+
+/// Implement `MsmSmallAccel` for a halo2curves affine point type
+macro_rules! impl_msm_small_accel {
+    // Parallel MSMs, points are converted if representations differ
+    (
+        $Aff:ty, $Curve:ty, $Fr:ty,
+        $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty,
+        layout: $layout:ident,
+        conversions: $conv:ident,
+        msm_u64: $msm_u64:ident,
+        msm_i64: $msm_i64:ident,
+        msm_max_bits: $msm_max_bits:ident $(,)?
+    ) => {
+        impl MsmSmallAccel<$Aff> for CttEngine {
+            fn msm_u64(&self, coeffs: &[u64], bases: &[$Aff]) -> $Curve {
+                impl_msm_small_accel!(@parallel self, $layout, $conv, $ctt_aff, $ctt_curve, $Curve,
+                    $msm_u64, coeffs, bases)
+            }
+
+            fn msm_i64(&self, coeffs: &[i64], bases: &[$Aff]) -> $Curve {
+                impl_msm_small_accel!(@parallel self, $layout, $conv, $ctt_aff, $ctt_curve, $Curve,
+                    $msm_i64, coeffs, bases)
+            }
+
+            fn msm_max_bits(&self, coeffs: &[$Fr], bases: &[$Aff], max_bits: usize) -> $Curve {
+                assert_eq!(coeffs.len(), bases.len());
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    if self.1.$layout {
+                        $msm_max_bits(
+                            self.0.get_private_context(),
+                            result.as_mut_ptr(),
+                            coeffs.as_ptr() as *const $ctt_fr,
+                            bases.as_ptr() as *const $ctt_aff,
+                            bases.len(),
+                            max_bits,
+                        );
+                        mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                    } else {
+                        let coeffs = coeffs.iter().map(crate::$conv::fr_to_ctt).collect::<Vec<_>>();
+                        let bases = bases.iter().map(crate::$conv::aff_to_ctt).collect::<Vec<_>>();
+                        $msm_max_bits(
+                            self.0.get_private_context(),
+                            result.as_mut_ptr(),
+                            coeffs.as_ptr(),
+                            bases.as_ptr(),
+                            bases.len(),
+                            max_bits,
+                        );
+                        crate::$conv::curve_from_ctt(&result.assume_init())
+                    }
+                }
+            }
+        }
+    };
+
+    // Parallel MSMs without conversions.
+    // If representations differ, halo2curves MSM is used.
+    (
+        $Aff:ty, $Curve:ty, $Fr:ty,
+        $ctt_aff:ty, $ctt_curve:ty, $ctt_fr:ty,
+        layout: $layout:ident,
+        msm_u64: $msm_u64:ident,
+        msm_i64: $msm_i64:ident,
+        msm_max_bits: $msm_max_bits:ident $(,)?
+    ) => {
+        impl MsmSmallAccel<$Aff> for CttEngine {
+            fn msm_u64(&self, coeffs: &[u64], bases: &[$Aff]) -> $Curve {
+                assert_eq!(coeffs.len(), bases.len());
+                if !self.1.$layout {
+                    return fallback::msm_u64(coeffs, bases);
+                }
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    $msm_u64(
+                        self.0.get_private_context(),
+                        result.as_mut_ptr(),
+                        coeffs.as_ptr(),
+                        bases.as_ptr() as *const $ctt_aff,
+                        bases.len(),
+                    );
+                    mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                }
+            }
+
+            fn msm_i64(&self, coeffs: &[i64], bases: &[$Aff]) -> $Curve {
+                assert_eq!(coeffs.len(), bases.len());
+                if !self.1.$layout {
+                    return fallback::msm_i64(coeffs, bases);
+                }
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    $msm_i64(
+                        self.0.get_private_context(),
+                        result.as_mut_ptr(),
+                        coeffs.as_ptr(),
+                        bases.as_ptr() as *const $ctt_aff,
+                        bases.len(),
+                    );
+                    mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                }
+            }
+
+            fn msm_max_bits(&self, coeffs: &[$Fr], bases: &[$Aff], max_bits: usize) -> $Curve {
+                assert_eq!(coeffs.len(), bases.len());
+                if !self.1.$layout {
+                    return msm_best(coeffs, bases);
+                }
+                let mut result = MaybeUninit::<$ctt_curve>::uninit();
+                unsafe {
+                    $msm_max_bits(
+                        self.0.get_private_context(),
+                        result.as_mut_ptr(),
+                        coeffs.as_ptr() as *const $ctt_fr,
+                        bases.as_ptr() as *const $ctt_aff,
+                        bases.len(),
+                        max_bits,
+                    );
+                    mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+                }
+            }
+        }
+    };
+
+    // Machine-word coefficients need no conversion, only points do.
+    (@parallel $self:ident, $layout:ident, $conv:ident, $ctt_aff:ty, $ctt_curve:ty, $Curve:ty,
+        $msm:ident, $coeffs:ident, $bases:ident) => {{
+        assert_eq!($coeffs.len(), $bases.len());
+        let mut result = MaybeUninit::<$ctt_curve>::uninit();
+        unsafe {
+            if $self.1.$layout {
+                $msm(
+                    $self.0.get_private_context(),
+                    result.as_mut_ptr(),
+                    $coeffs.as_ptr(),
+                    $bases.as_ptr() as *const $ctt_aff,
+                    $bases.len(),
+                );
+                mem::transmute::<$ctt_curve, $Curve>(result.assume_init())
+            } else {
+                let bases = $bases.iter().map(crate::$conv::aff_to_ctt).collect::<Vec<_>>();
+                $msm(
+                    $self.0.get_private_context(),
+                    result.as_mut_ptr(),
+                    $coeffs.as_ptr(),
+                    bases.as_ptr(),
+                    bases.len(),
+                );
+                crate::$conv::curve_from_ctt(&result.assume_init())
+            }
+        }
+    }};
+}
+
+impl_msm_small_accel!(
+    bn256::G1Affine, bn256::G1, bn256::Fr,
+    bn254_snarks_g1_aff, bn254_snarks_g1_prj, bn254_snarks_fr,
+    layout: bn254_g1,
+    conversions: bn254_snarks_g1,
+    msm_u64: ctt_bn254_snarks_g1_prj_multi_scalar_mul_u64_coefs_vartime_parallel,
+    msm_i64: ctt_bn254_snarks_g1_prj_multi_scalar_mul_i64_coefs_vartime_parallel,
+    msm_max_bits: ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel,
+);
+
+impl_msm_small_accel!(
+    bn256::G2Affine, bn256::G2, bn256::Fr,
+    bn254_snarks_g2_aff, bn254_snarks_g2_prj, bn254_snarks_fr,
+    layout: bn254_g2,
+    msm_u64: ctt_bn254_snarks_g2_prj_multi_scalar_mul_u64_coefs_vartime_parallel,
+    msm_i64: ctt_bn254_snarks_g2_prj_multi_scalar_mul_i64_coefs_vartime_parallel,
+    msm_max_bits: ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel,
+);
+
+impl_msm_small_accel!(
+    bls12381::G1Affine, bls12381::G1, bls12381::Fr,
+    bls12_381_g1_aff, bls12_381_g1_prj, bls12_381_fr,
+    layout: bls12_381_g1,
+    conversions: bls12_381_g1,
+    msm_u64: ctt_bls12_381_g1_prj_multi_scalar_mul_u64_coefs_vartime_parallel,
+    msm_i64: ctt_bls12_381_g1_prj_multi_scalar_mul_i64_coefs_vartime_parallel,
+    msm_max_bits: ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel,
+);
+
+impl_msm_small_accel!(
+    bls12381::G2Affine, bls12381::G2, bls12381::Fr,
+    bls12_381_g2_aff, bls12_381_g2_prj, bls12_381_fr,
+    layout: bls12_381_g2,
+    msm_u64: ctt_bls12_381_g2_prj_multi_scalar_mul_u64_coefs_vartime_parallel,
+    msm_i64: ctt_bls12_381_g2_prj_multi_scalar_mul_i64_coefs_vartime_parallel,
+    msm_max_bits: ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel,
+);
+
+impl_msm_small_accel!(
+    pasta::EpAffine, pasta::Ep, pasta::Fq,
+    pallas_ec_aff, pallas_ec_jac, pallas_fr,
+    layout: pallas,
+    conversions: pallas_ec,
+    msm_u64: ctt_pallas_ec_jac_multi_scalar_mul_u64_coefs_vartime_parallel,
+    msm_i64: ctt_pallas_ec_jac_multi_scalar_mul_i64_coefs_vartime_parallel,
+    msm_max_bits: ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel,
+);
+
+impl_msm_small_accel!(
+    pasta::EqAffine, pasta::Eq, pasta::Fp,
+    vesta_ec_aff, vesta_ec_jac, vesta_fr,
+    layout: vesta,
+    conversions: vesta_ec,
+    msm_u64: ctt_vesta_ec_jac_multi_scalar_mul_u64_coefs_vartime_parallel,
+    msm_i64: ctt_vesta_ec_jac_multi_scalar_mul_i64_coefs_vartime_parallel,
+    msm_max_bits: ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel,
+);
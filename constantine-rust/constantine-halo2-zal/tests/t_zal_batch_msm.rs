@@ -0,0 +1,104 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_core::hardware;
+use constantine_halo2_zal::{BatchMsmAccel, CttEngine, CttMsmBaseDesc, CttMsmTable};
+
+use ark_std::{end_timer, start_timer};
+use rand_core::OsRng;
+
+use halo2_middleware::halo2curves::ff::Field;
+use halo2_middleware::halo2curves::group::prime::PrimeCurveAffine;
+use halo2_middleware::halo2curves::group::{Curve, Group};
+use halo2_middleware::halo2curves::msm::msm_best;
+use halo2_middleware::halo2curves::{bls12381, bn256, pasta};
+use halo2_middleware::zal::traits::MsmAccel;
+
+fn run_batch_msm_zal<C: CttMsmTable>(k: usize)
+where
+    CttEngine: for<'b> MsmAccel<C, BaseDescriptor<'b> = CttMsmBaseDesc<'b, C>> + BatchMsmAccel<C>,
+{
+    let points = (0..1 << k)
+        .map(|_| C::Curve::random(OsRng))
+        .collect::<Vec<_>>();
+    let mut affine_points = vec![C::identity(); 1 << k];
+    C::Curve::batch_normalize(&points[..], &mut affine_points[..]);
+    let points = &affine_points[..];
+
+    let engine = CttEngine::new(hardware::get_num_threads_os());
+    let base_descriptor = engine.get_base_descriptor(points);
+
+    // Below and above the threshold for sharing a table of the bases
+    for num_msms in [0, 1, 3, 10] {
+        let polys = (0..num_msms)
+            .map(|_| {
+                (0..1 << k)
+                    .map(|_| C::Scalar::random(OsRng))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let coeffs = polys.iter().map(|p| &p[..]).collect::<Vec<_>>();
+
+        let t0 = start_timer!(|| format!("{} freestanding msm k={}", num_msms, k));
+        let expected = coeffs
+            .iter()
+            .map(|c| msm_best(c, points))
+            .collect::<Vec<_>>();
+        end_timer!(t0);
+
+        let t1 = start_timer!(|| format!("CttEngine batch of {} msm k={}", num_msms, k));
+        let batch = engine.batch_msm(&coeffs, points);
+        end_timer!(t1);
+        assert_eq!(expected, batch);
+
+        let batch = engine.batch_msm_with_cached_base(&coeffs, &base_descriptor);
+        assert_eq!(expected, batch);
+
+        // Fewer coefficients than bases use a prefix of the bases
+        let half = coeffs.iter().map(|c| &c[..c.len() / 2]).collect::<Vec<_>>();
+        let expected = half
+            .iter()
+            .map(|c| msm_best(c, &points[..c.len()]))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, engine.batch_msm(&half, points));
+        assert_eq!(
+            expected,
+            engine.batch_msm_with_cached_base(&half, &base_descriptor)
+        );
+    }
+}
+
+#[test]
+fn t_batch_msm_zal_bn254() {
+    run_batch_msm_zal::<bn256::G1Affine>(10);
+}
+
+#[test]
+fn t_batch_msm_zal_bls12_381() {
+    run_batch_msm_zal::<bls12381::G1Affine>(10);
+}
+
+#[test]
+fn t_batch_msm_zal_pallas() {
+    run_batch_msm_zal::<pasta::EpAffine>(10);
+}
+
+#[test]
+fn t_batch_msm_zal_vesta() {
+    run_batch_msm_zal::<pasta::EqAffine>(10);
+}
+
+#[test]
+#[should_panic(expected = "same number of coefficients")]
+fn t_batch_msm_zal_ragged_rows() {
+    let engine = CttEngine::new(1);
+    let bases = vec![bn256::G1Affine::generator(); 2];
+    let coeffs = [vec![bn256::Fr::ONE; 2], vec![bn256::Fr::ONE; 1]];
+    let rows = coeffs.iter().map(|c| c.as_slice()).collect::<Vec<_>>();
+    engine.batch_msm(&rows, &bases);
+}
@@ -0,0 +1,94 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_core::hardware;
+use constantine_halo2_zal::{CttEngine, FftAccel};
+
+use ark_std::{end_timer, start_timer};
+use rand_core::OsRng;
+
+use halo2_middleware::halo2curves::ff::PrimeField;
+use halo2_middleware::halo2curves::fft::best_fft;
+use halo2_middleware::halo2curves::{bn256, pasta};
+
+/// Primitive root of unity of order 2^log_n
+fn root_of_unity<F: PrimeField>(log_n: u32) -> F {
+    assert!(log_n <= F::S);
+    let mut omega = F::ROOT_OF_UNITY;
+    for _ in log_n..F::S {
+        omega = omega.square();
+    }
+    omega
+}
+
+fn run_fft_zal<F: PrimeField>(max_k: u32)
+where
+    CttEngine: FftAccel<F>,
+{
+    let engine = CttEngine::new(hardware::get_num_threads_os());
+    let shift = F::MULTIPLICATIVE_GENERATOR;
+
+    for k in 0..=max_k {
+        let omega = root_of_unity::<F>(k);
+        let n_inv = F::from(1u64 << k).invert().unwrap();
+
+        let values = (0..1 << k).map(|_| F::random(OsRng)).collect::<Vec<_>>();
+
+        // FFT
+        let mut expected = values.clone();
+        let t0 = start_timer!(|| format!("halo2curves fft k={}", k));
+        best_fft(&mut expected, omega, k);
+        end_timer!(t0);
+
+        let mut ctt = values.clone();
+        let t1 = start_timer!(|| format!("CttEngine fft k={}", k));
+        engine.fft(&mut ctt, omega, k);
+        end_timer!(t1);
+        assert_eq!(expected, ctt);
+
+        // Inverse FFT
+        let mut expected_inv = expected.clone();
+        best_fft(&mut expected_inv, omega.invert().unwrap(), k);
+        expected_inv.iter_mut().for_each(|v| *v *= n_inv);
+        assert_eq!(expected_inv, values);
+
+        engine.ifft(&mut ctt, omega, k);
+        assert_eq!(values, ctt);
+
+        // Coset FFT
+        let mut expected_coset = values.clone();
+        let mut cur = F::ONE;
+        for v in expected_coset.iter_mut() {
+            *v *= cur;
+            cur *= shift;
+        }
+        best_fft(&mut expected_coset, omega, k);
+
+        engine.coset_fft(&mut ctt, omega, shift, k);
+        assert_eq!(expected_coset, ctt);
+
+        // Inverse coset FFT
+        engine.coset_ifft(&mut ctt, omega, shift, k);
+        assert_eq!(values, ctt);
+    }
+}
+
+#[test]
+fn t_fft_zal_bn254() {
+    run_fft_zal::<bn256::Fr>(16);
+}
+
+#[test]
+fn t_fft_zal_pallas() {
+    run_fft_zal::<pasta::Fq>(16);
+}
+
+#[test]
+fn t_fft_zal_vesta() {
+    run_fft_zal::<pasta::Fp>(16);
+}
@@ -0,0 +1,70 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_core::hardware;
+use constantine_halo2_zal::{CttEngine, CttFixedBase, FixedBaseTable};
+
+use rand_core::OsRng;
+
+use halo2_middleware::halo2curves::ff::Field;
+use halo2_middleware::halo2curves::group::prime::PrimeCurveAffine;
+use halo2_middleware::halo2curves::group::{Curve, Group};
+use halo2_middleware::halo2curves::{bls12381, bn256, pasta};
+
+fn run_fixed_base_zal<C: CttFixedBase>(num_scalars: usize) {
+    let engine = CttEngine::new(hardware::get_num_threads_os());
+
+    let base = C::Curve::random(OsRng).to_affine();
+    let table = FixedBaseTable::new(&engine, base);
+    let gen_table = FixedBaseTable::<C>::generator(&engine);
+    assert_eq!(gen_table.base().to_curve(), C::generator().to_curve());
+
+    let mut scalars = (0..num_scalars)
+        .map(|_| C::Scalar::random(OsRng))
+        .collect::<Vec<_>>();
+    scalars.extend([C::Scalar::ZERO, C::Scalar::ONE, -C::Scalar::ONE]);
+
+    for s in &scalars {
+        assert_eq!(base * *s, table.mul(s));
+        assert_eq!(C::generator() * *s, gen_table.mul(s));
+    }
+
+    // Identity base
+    let table = FixedBaseTable::new(&engine, C::identity());
+    assert_eq!(C::Curve::identity(), table.mul(&scalars[0]));
+}
+
+#[test]
+fn t_fixed_base_zal_bn254_g1() {
+    run_fixed_base_zal::<bn256::G1Affine>(32);
+}
+
+#[test]
+fn t_fixed_base_zal_bn254_g2() {
+    run_fixed_base_zal::<bn256::G2Affine>(16);
+}
+
+#[test]
+fn t_fixed_base_zal_bls12_381_g1() {
+    run_fixed_base_zal::<bls12381::G1Affine>(32);
+}
+
+#[test]
+fn t_fixed_base_zal_bls12_381_g2() {
+    run_fixed_base_zal::<bls12381::G2Affine>(16);
+}
+
+#[test]
+fn t_fixed_base_zal_pallas() {
+    run_fixed_base_zal::<pasta::EpAffine>(32);
+}
+
+#[test]
+fn t_fixed_base_zal_vesta() {
+    run_fixed_base_zal::<pasta::EqAffine>(32);
+}
@@ -12,7 +12,7 @@ use constantine_halo2_zal::CttEngine;
 use ark_std::{end_timer, start_timer};
 use rand_core::OsRng;
 
-use halo2_middleware::halo2curves::bn256;
+use halo2_middleware::halo2curves::{bls12381, bn256, pasta, CurveAffine};
 use halo2_middleware::halo2curves::ff::Field;
 use halo2_middleware::halo2curves::group::prime::PrimeCurveAffine;
 use halo2_middleware::halo2curves::group::{Curve, Group};
@@ -25,16 +25,19 @@ fn t_threadpool() {
     drop(tp);
 }
 
-fn run_msm_zal(min_k: usize, max_k: usize) {
+fn run_msm_zal<C: CurveAffine>(min_k: usize, max_k: usize)
+where
+    CttEngine: MsmAccel<C>,
+{
     let points = (0..1 << max_k)
-        .map(|_| bn256::G1::random(OsRng))
+        .map(|_| C::Curve::random(OsRng))
         .collect::<Vec<_>>();
-    let mut affine_points = vec![bn256::G1Affine::identity(); 1 << max_k];
-    bn256::G1::batch_normalize(&points[..], &mut affine_points[..]);
+    let mut affine_points = vec![C::identity(); 1 << max_k];
+    C::Curve::batch_normalize(&points[..], &mut affine_points[..]);
     let points = affine_points;
 
     let scalars = (0..1 << max_k)
-        .map(|_| bn256::Fr::random(OsRng))
+        .map(|_| C::Scalar::random(OsRng))
         .collect::<Vec<_>>();
 
     for k in min_k..=max_k {
@@ -54,16 +57,53 @@ fn run_msm_zal(min_k: usize, max_k: usize) {
 
         // Caching API
         // -----------
-        let t2 = start_timer!(|| format!("CttEngine msm cached base k={}", k));
+        let t2 = start_timer!(|| format!("CttEngine precompute base k={}", k));
         let base_descriptor = engine.get_base_descriptor(points);
-        let e2 = engine.msm_with_cached_base(scalars, &base_descriptor);
         end_timer!(t2);
 
-        assert_eq!(e0, e2)
+        let t3 = start_timer!(|| format!("CttEngine msm cached base k={}", k));
+        let e3 = engine.msm_with_cached_base(scalars, &base_descriptor);
+        end_timer!(t3);
+
+        assert_eq!(e0, e3);
+
+        let coeffs_descriptor = engine.get_coeffs_descriptor(scalars);
+        let e4 = engine.msm_with_cached_inputs(&coeffs_descriptor, &base_descriptor);
+        assert_eq!(e0, e4);
+
+        // Fewer coefficients than cached bases use a prefix of the bases
+        let half = scalars.len() / 2;
+        let e5 = engine.msm_with_cached_base(&scalars[..half], &base_descriptor);
+        assert_eq!(msm_best(&scalars[..half], &points[..half]), e5);
     }
 }
 
 #[test]
 fn t_msm_zal() {
-    run_msm_zal(3, 14);
+    run_msm_zal::<bn256::G1Affine>(3, 14);
+}
+
+#[test]
+fn t_msm_zal_bn254_g2() {
+    run_msm_zal::<bn256::G2Affine>(3, 8);
+}
+
+#[test]
+fn t_msm_zal_bls12_381() {
+    run_msm_zal::<bls12381::G1Affine>(3, 12);
+}
+
+#[test]
+fn t_msm_zal_bls12_381_g2() {
+    run_msm_zal::<bls12381::G2Affine>(3, 8);
+}
+
+#[test]
+fn t_msm_zal_pallas() {
+    run_msm_zal::<pasta::EpAffine>(3, 12);
+}
+
+#[test]
+fn t_msm_zal_vesta() {
+    run_msm_zal::<pasta::EqAffine>(3, 12);
 }
@@ -0,0 +1,85 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_core::hardware;
+use constantine_halo2_zal::{CttEngine, MsmCtAccel};
+
+use rand_core::OsRng;
+
+use halo2_middleware::halo2curves::ff::Field;
+use halo2_middleware::halo2curves::group::prime::PrimeCurveAffine;
+use halo2_middleware::halo2curves::group::{Curve, Group};
+use halo2_middleware::halo2curves::msm::msm_best;
+use halo2_middleware::halo2curves::{bls12381, bn256, pasta, CurveAffine};
+
+fn run_msm_ct_zal<C: CurveAffine>(min_k: usize, max_k: usize)
+where
+    CttEngine: MsmCtAccel<C>,
+{
+    let points = (0..1 << max_k)
+        .map(|_| C::Curve::random(OsRng))
+        .collect::<Vec<_>>();
+    let mut affine_points = vec![C::identity(); 1 << max_k];
+    C::Curve::batch_normalize(&points[..], &mut affine_points[..]);
+    let points = affine_points;
+
+    let scalars = (0..1 << max_k)
+        .map(|_| C::Scalar::random(OsRng))
+        .collect::<Vec<_>>();
+
+    let engine = CttEngine::new(hardware::get_num_threads_os());
+
+    for k in min_k..=max_k {
+        let points = &points[..1 << k];
+        let scalars = &scalars[..1 << k];
+
+        let expected = msm_best(scalars, points);
+        assert_eq!(expected, engine.msm_ct(scalars, points));
+        assert_eq!(expected, engine.msm_vartime(scalars, points));
+    }
+
+    // Zero and identity
+    let mut scalars = scalars[..3].to_vec();
+    scalars[1] = C::Scalar::ZERO;
+    let points = [points[0], points[1], C::identity()];
+    assert_eq!(
+        msm_best(&scalars, &points),
+        engine.msm_ct(&scalars, &points)
+    );
+    assert_eq!(C::Curve::identity(), engine.msm_ct(&[], &[]));
+}
+
+#[test]
+fn t_msm_ct_zal_bn254_g1() {
+    run_msm_ct_zal::<bn256::G1Affine>(3, 8);
+}
+
+#[test]
+fn t_msm_ct_zal_bn254_g2() {
+    run_msm_ct_zal::<bn256::G2Affine>(3, 6);
+}
+
+#[test]
+fn t_msm_ct_zal_bls12_381_g1() {
+    run_msm_ct_zal::<bls12381::G1Affine>(3, 8);
+}
+
+#[test]
+fn t_msm_ct_zal_bls12_381_g2() {
+    run_msm_ct_zal::<bls12381::G2Affine>(3, 6);
+}
+
+#[test]
+fn t_msm_ct_zal_pallas() {
+    run_msm_ct_zal::<pasta::EpAffine>(3, 8);
+}
+
+#[test]
+fn t_msm_ct_zal_vesta() {
+    run_msm_ct_zal::<pasta::EqAffine>(3, 8);
+}
@@ -0,0 +1,116 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_core::hardware;
+use constantine_halo2_zal::{CttEngine, MsmSmallAccel};
+
+use rand_core::{OsRng, RngCore};
+
+use halo2_middleware::halo2curves::ff::PrimeField;
+use halo2_middleware::halo2curves::group::prime::PrimeCurveAffine;
+use halo2_middleware::halo2curves::group::{Curve, Group};
+use halo2_middleware::halo2curves::msm::msm_best;
+use halo2_middleware::halo2curves::{bls12381, bn256, pasta, CurveAffine};
+
+/// Random scalar less than 2^max_bits
+fn random_bounded<F: PrimeField>(max_bits: usize) -> F {
+    let mut r = F::ZERO;
+    let mut bit = F::ONE;
+    for _ in 0..max_bits {
+        if OsRng.next_u32() & 1 == 1 {
+            r += bit;
+        }
+        bit = bit.double();
+    }
+    r
+}
+
+fn run_msm_small_zal<C: CurveAffine>(min_k: usize, max_k: usize)
+where
+    CttEngine: MsmSmallAccel<C>,
+{
+    let points = (0..1 << max_k)
+        .map(|_| C::Curve::random(OsRng))
+        .collect::<Vec<_>>();
+    let mut affine_points = vec![C::identity(); 1 << max_k];
+    C::Curve::batch_normalize(&points[..], &mut affine_points[..]);
+    let points = affine_points;
+
+    let engine = CttEngine::new(hardware::get_num_threads_os());
+
+    for k in min_k..=max_k {
+        let points = &points[..1 << k];
+
+        // Unsigned 64-bit
+        let coeffs = (0..1 << k).map(|_| OsRng.next_u64()).collect::<Vec<_>>();
+        let scalars = coeffs
+            .iter()
+            .map(|&c| C::Scalar::from(c))
+            .collect::<Vec<_>>();
+        assert_eq!(msm_best(&scalars, points), engine.msm_u64(&coeffs, points));
+
+        // Signed 64-bit
+        let mut coeffs = (0..1 << k)
+            .map(|_| OsRng.next_u64() as i64)
+            .collect::<Vec<_>>();
+        coeffs[0] = i64::MIN;
+        let scalars = coeffs
+            .iter()
+            .map(|&c| {
+                let abs = C::Scalar::from(c.unsigned_abs());
+                if c < 0 {
+                    -abs
+                } else {
+                    abs
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(msm_best(&scalars, points), engine.msm_i64(&coeffs, points));
+
+        // Bounded bit-width
+        for max_bits in [1, 8, 20, 64, 100, 128, 200, C::Scalar::NUM_BITS as usize] {
+            let scalars = (0..1 << k)
+                .map(|_| random_bounded::<C::Scalar>(max_bits))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                msm_best(&scalars, points),
+                engine.msm_max_bits(&scalars, points, max_bits)
+            );
+        }
+    }
+}
+
+#[test]
+fn t_msm_small_zal_bn254_g1() {
+    run_msm_small_zal::<bn256::G1Affine>(3, 10);
+}
+
+#[test]
+fn t_msm_small_zal_bn254_g2() {
+    run_msm_small_zal::<bn256::G2Affine>(3, 6);
+}
+
+#[test]
+fn t_msm_small_zal_bls12_381() {
+    run_msm_small_zal::<bls12381::G1Affine>(3, 10);
+}
+
+#[test]
+fn t_msm_small_zal_bls12_381_g2() {
+    run_msm_small_zal::<bls12381::G2Affine>(3, 6);
+}
+
+#[test]
+fn t_msm_small_zal_pallas() {
+    run_msm_small_zal::<pasta::EpAffine>(3, 10);
+}
+
+#[test]
+fn t_msm_small_zal_vesta() {
+    run_msm_small_zal::<pasta::EqAffine>(3, 10);
+}
@@ -0,0 +1,247 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Constantine hash functions
+//!
+//! Streaming hashers implement the traits of the `digest` crate:
+//!
+//! ```ignore
+//! use constantine_hashes::{Digest, Keccak256};
+//!
+//! let mut hasher = Keccak256::new();
+//! hasher.update(b"hello ");
+//! hasher.update(b"world");
+//! let digest: [u8; 32] = hasher.finalize().into();
+//! ```
+
+use constantine_sys::*;
+
+pub mod ssz_merkle;
+
+use ::core::mem::MaybeUninit;
+
+pub use digest::{self, Digest};
+
+use digest::{
+    consts::{U20, U32},
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+macro_rules! streaming_hasher {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $ctx:ty, $size:ty, $len:literal,
+        $init:ident, $update:ident, $finish:ident, $clear:ident
+    ) => {
+        $(#[$meta])*
+        ///
+        /// Cloning a hasher saves its midstate,
+        /// the internal buffers are cleared on drop.
+        #[derive(Clone)]
+        pub struct $name {
+            ctx: $ctx,
+        }
+
+        impl $name {
+            /// Clear the internal buffers.
+            ///
+            /// The hasher must be reset before reuse.
+            pub fn clear(&mut self) {
+                unsafe { $clear(&mut self.ctx) }
+            }
+
+            fn finish(&mut self, digest: &mut [u8; $len]) {
+                unsafe { $finish(&mut self.ctx, digest.as_mut_ptr() as *mut byte) }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                let mut ctx: MaybeUninit<$ctx> = MaybeUninit::uninit();
+                unsafe {
+                    $init(ctx.as_mut_ptr());
+                    Self {
+                        ctx: ctx.assume_init(),
+                    }
+                }
+            }
+        }
+
+        impl HashMarker for $name {}
+
+        impl OutputSizeUser for $name {
+            type OutputSize = $size;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, data: &[u8]) {
+                unsafe { $update(&mut self.ctx, data.as_ptr() as *const byte, data.len()) }
+            }
+        }
+
+        impl FixedOutput for $name {
+            fn finalize_into(mut self, out: &mut Output<Self>) {
+                self.finish(out.as_mut());
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                unsafe { $init(&mut self.ctx) }
+            }
+        }
+
+        impl FixedOutputReset for $name {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                self.finish(out.as_mut());
+                Reset::reset(self);
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                self.clear();
+            }
+        }
+    };
+}
+
+streaming_hasher!(
+    /// Keccak-256, the hash function of Ethereum,
+    /// it differs from SHA3-256 by its padding delimiter.
+    Keccak256,
+    ctt_keccak256_context,
+    U32,
+    32,
+    ctt_keccak256_init,
+    ctt_keccak256_update,
+    ctt_keccak256_finish,
+    ctt_keccak256_clear
+);
+
+streaming_hasher!(
+    /// SHA3-256 as standardized in FIPS 202
+    Sha3_256,
+    ctt_sha3_256_context,
+    U32,
+    32,
+    ctt_sha3_256_init,
+    ctt_sha3_256_update,
+    ctt_sha3_256_finish,
+    ctt_sha3_256_clear
+);
+
+streaming_hasher!(
+    /// SHA-256 as standardized in FIPS 180-4
+    Sha256,
+    ctt_sha256_context,
+    U32,
+    32,
+    ctt_sha256_init,
+    ctt_sha256_update,
+    ctt_sha256_finish,
+    ctt_sha256_clear
+);
+
+/// SHA-256 implementation selected at runtime
+pub type Sha256Backend = ctt_sha256_backend;
+
+impl Sha256 {
+    /// Compute the SHA-256 hash of each 64-byte input,
+    /// i.e. the hash of Merkle tree inner nodes.
+    #[must_use]
+    pub fn hash_64byte_pairs(pairs: &[[u8; 64]]) -> Vec<[u8; 32]> {
+        let mut digests = vec![[0u8; 32]; pairs.len()];
+        Self::hash_64byte_pairs_into(&mut digests, pairs);
+        digests
+    }
+
+    /// Compute the SHA-256 hash of each 64-byte input
+    /// and store them in `digests`.
+    ///
+    /// Panics if `digests` and `pairs` have different lengths.
+    pub fn hash_64byte_pairs_into(digests: &mut [[u8; 32]], pairs: &[[u8; 64]]) {
+        assert_eq!(digests.len(), pairs.len());
+        unsafe { ctt_sha256_hash_64byte_pairs(digests.as_mut_ptr(), pairs.as_ptr(), pairs.len()) }
+    }
+
+    /// The SHA-256 implementation selected at runtime
+    /// depending on the CPU features.
+    #[must_use]
+    pub fn backend() -> Sha256Backend {
+        unsafe { ctt_sha256_backend() }
+    }
+}
+
+streaming_hasher!(
+    /// RIPEMD-160
+    Ripemd160,
+    ctt_ripemd160_context,
+    U20,
+    20,
+    ctt_ripemd160_init,
+    ctt_ripemd160_update,
+    ctt_ripemd160_finish,
+    ctt_ripemd160_clear
+);
+
+#[must_use]
+pub fn keccak256_hash(message: &[u8], clear_memory: bool) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    unsafe {
+        ctt_keccak256_hash(
+            result.as_mut_ptr() as *mut byte,
+            message.as_ptr() as *const byte,
+            message.len(),
+            clear_memory,
+        )
+    }
+    result
+}
+
+#[must_use]
+pub fn sha3_256_hash(message: &[u8], clear_memory: bool) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    unsafe {
+        ctt_sha3_256_hash(
+            result.as_mut_ptr() as *mut byte,
+            message.as_ptr() as *const byte,
+            message.len(),
+            clear_memory,
+        )
+    }
+    result
+}
+
+#[must_use]
+pub fn sha256_hash(message: &[u8], clear_memory: bool) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    unsafe {
+        ctt_sha256_hash(
+            result.as_mut_ptr() as *mut byte,
+            message.as_ptr() as *const byte,
+            message.len(),
+            clear_memory,
+        )
+    }
+    result
+}
+
+#[must_use]
+pub fn ripemd160_hash(message: &[u8], clear_memory: bool) -> [u8; 20] {
+    let mut result = [0u8; 20];
+    unsafe {
+        ctt_ripemd160_hash(
+            result.as_mut_ptr() as *mut byte,
+            message.as_ptr() as *const byte,
+            message.len(),
+            clear_memory,
+        )
+    }
+    result
+}
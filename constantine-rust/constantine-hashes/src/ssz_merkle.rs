@@ -0,0 +1,169 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! SSZ merkleization
+//!
+//! Spec https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md#merkleization
+//!
+//! ```ignore
+//! // List[uint64, N] of balances packed in 32-byte chunks
+//! let root = merkleize(&chunks, Some(limit))?;
+//! let hash_tree_root = mix_in_length(&root, balances.len());
+//! ```
+
+use crate::{sha256_hash, Sha256};
+use constantine_core::Threadpool;
+use constantine_sys::*;
+
+use std::fmt;
+use std::sync::OnceLock;
+
+pub const BYTES_PER_CHUNK: usize = 32;
+
+pub type Chunk = [u8; BYTES_PER_CHUNK];
+
+/// Depth of a tree with `usize::MAX` leaves
+pub const MAX_DEPTH: usize = usize::BITS as usize;
+
+/// Levels with fewer pairs are hashed on the calling thread
+/// as the scheduling overhead would dominate.
+const MIN_PAIRS_PARALLEL: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MerkleizeError {
+    /// There are more chunks than the limit of the list
+    TooManyChunks { count: usize, limit: usize },
+}
+
+impl fmt::Display for MerkleizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MerkleizeError::TooManyChunks { count, limit } => {
+                write!(f, "{} chunks exceed the limit of {}", count, limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MerkleizeError {}
+
+/// Root of a tree of `2^depth` zero chunks, for `depth` in `0..=MAX_DEPTH`
+pub fn zero_hash(depth: usize) -> &'static Chunk {
+    static ZERO_HASHES: OnceLock<[Chunk; MAX_DEPTH + 1]> = OnceLock::new();
+    let zero_hashes = ZERO_HASHES.get_or_init(|| {
+        let mut zero_hashes = [[0u8; BYTES_PER_CHUNK]; MAX_DEPTH + 1];
+        for depth in 1..=MAX_DEPTH {
+            zero_hashes[depth] = hash_pair(&zero_hashes[depth - 1], &zero_hashes[depth - 1]);
+        }
+        zero_hashes
+    });
+    &zero_hashes[depth]
+}
+
+/// `hash(root || length)` with `length` as a 32-byte little-endian integer,
+/// the root of SSZ lists and bitlists.
+#[must_use]
+pub fn mix_in_length(root: &Chunk, length: usize) -> Chunk {
+    let mut length_chunk = [0u8; BYTES_PER_CHUNK];
+    length_chunk[..8].copy_from_slice(&(length as u64).to_le_bytes());
+    hash_pair(root, &length_chunk)
+}
+
+/// Merkleize `chunks`, padded with zero chunks
+/// to the next power of 2 of `limit` or of the number of chunks if there is no limit.
+pub fn merkleize(chunks: &[Chunk], limit: Option<usize>) -> Result<Chunk, MerkleizeError> {
+    merkleize_impl(chunks, limit, Sha256::hash_64byte_pairs_into)
+}
+
+/// Merkleize `chunks` like `merkleize`,
+/// hashing the large levels of the tree on the threadpool.
+pub fn merkleize_parallel(
+    tp: &Threadpool,
+    chunks: &[Chunk],
+    limit: Option<usize>,
+) -> Result<Chunk, MerkleizeError> {
+    merkleize_impl(chunks, limit, |digests, pairs| {
+        if pairs.len() < MIN_PAIRS_PARALLEL {
+            Sha256::hash_64byte_pairs_into(digests, pairs);
+        } else {
+            unsafe {
+                ctt_sha256_hash_64byte_pairs_parallel(
+                    tp.get_private_context(),
+                    digests.as_mut_ptr(),
+                    pairs.as_ptr(),
+                    pairs.len(),
+                )
+            }
+        }
+    })
+}
+
+fn hash_pair(left: &Chunk, right: &Chunk) -> Chunk {
+    let mut pair = [0u8; 2 * BYTES_PER_CHUNK];
+    pair[..BYTES_PER_CHUNK].copy_from_slice(left);
+    pair[BYTES_PER_CHUNK..].copy_from_slice(right);
+    sha256_hash(&pair, false)
+}
+
+/// Number of levels of a tree with `limit` leaves
+fn tree_depth(limit: usize) -> usize {
+    match limit.max(1).checked_next_power_of_two() {
+        Some(width) => width.trailing_zeros() as usize,
+        None => MAX_DEPTH,
+    }
+}
+
+/// View the chunks of a level as consecutive (left, right) pairs,
+/// the last chunk of an odd level is excluded.
+fn as_pairs(chunks: &[Chunk]) -> &[[u8; 2 * BYTES_PER_CHUNK]] {
+    // Chunks are byte arrays, a pair of consecutive chunks has the layout of a 64-byte array
+    unsafe { core::slice::from_raw_parts(chunks.as_ptr().cast(), chunks.len() / 2) }
+}
+
+fn merkleize_impl(
+    chunks: &[Chunk],
+    limit: Option<usize>,
+    hash_pairs: impl Fn(&mut [Chunk], &[[u8; 2 * BYTES_PER_CHUNK]]),
+) -> Result<Chunk, MerkleizeError> {
+    let count = chunks.len();
+    let limit = limit.unwrap_or(count);
+    if count > limit {
+        return Err(MerkleizeError::TooManyChunks { count, limit });
+    }
+
+    let depth = tree_depth(limit);
+    if count == 0 {
+        return Ok(*zero_hash(depth));
+    }
+
+    // Levels are hashed back and forth between 2 buffers,
+    // the first level is read directly from `chunks`.
+    let mut current: Vec<Chunk> = Vec::with_capacity(count.div_ceil(2));
+    let mut next: Vec<Chunk> = Vec::with_capacity(count.div_ceil(4));
+
+    for level in 0..depth {
+        let nodes = if level == 0 { chunks } else { &current[..] };
+        let len = nodes.len();
+
+        next.resize(len.div_ceil(2), [0u8; BYTES_PER_CHUNK]);
+        hash_pairs(&mut next[..len / 2], as_pairs(nodes));
+        if len % 2 == 1 {
+            // The right sibling of the last node is a subtree of zero chunks
+            next[len / 2] = hash_pair(&nodes[len - 1], zero_hash(level));
+        }
+
+        std::mem::swap(&mut current, &mut next);
+        next.clear();
+    }
+
+    if depth == 0 {
+        Ok(chunks[0])
+    } else {
+        Ok(current[0])
+    }
+}
@@ -0,0 +1,203 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_hashes::*;
+
+use hex;
+
+const ABC_LONG: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+/// Hash `message` in one shot, through `Digest` and in chunks of every size up to 150 bytes
+/// (the Keccak sponge rate is 136 bytes, the SHA-256 and RIPEMD-160 block size is 64 bytes).
+fn check<D: Digest + Clone>(message: &[u8], one_shot: &[u8], expected: &str) {
+    assert_eq!(hex::encode(one_shot), expected);
+    assert_eq!(hex::encode(D::digest(message)), expected);
+
+    for chunk_size in 1..150 {
+        let mut hasher = D::new();
+        for chunk in message.chunks(chunk_size) {
+            hasher.update(chunk);
+        }
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            expected,
+            "chunk size {}",
+            chunk_size
+        );
+    }
+}
+
+#[test]
+fn t_keccak256() {
+    for (message, expected) in [
+        (
+            &b""[..],
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        ),
+        (
+            b"abc",
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+        ),
+        (
+            b"abcdef0123456789",
+            "9d0db1e0c6820c62f470dbd81e9db48fdf7d76f62027568e6496566fad3661e0",
+        ),
+        (
+            ABC_LONG,
+            "45d3b367a6904e6e8d502ee04999a7c27647f91fa845d456525fd352ae3d7371",
+        ),
+    ] {
+        check::<Keccak256>(message, &keccak256_hash(message, false), expected);
+    }
+}
+
+#[test]
+fn t_sha3_256() {
+    for (message, expected) in [
+        (
+            &b""[..],
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+        ),
+        (
+            b"abc",
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+        ),
+        (
+            b"abcdef0123456789",
+            "11711275e983511cbd160d19d122e1756a44462cef4cc5a83dcc92b46e93a9c4",
+        ),
+        (
+            ABC_LONG,
+            "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376",
+        ),
+    ] {
+        check::<Sha3_256>(message, &sha3_256_hash(message, false), expected);
+    }
+}
+
+#[test]
+fn t_sha256() {
+    for (message, expected) in [
+        (
+            &b""[..],
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            b"abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            ABC_LONG,
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+    ] {
+        check::<Sha256>(message, &sha256_hash(message, true), expected);
+    }
+}
+
+#[test]
+fn t_ripemd160() {
+    for (message, expected) in [
+        (&b""[..], "9c1185a5c5e9fc54612808977ee8f548b2258d31"),
+        (b"abc", "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"),
+        (ABC_LONG, "12a053384a9c0c88e405a06c27dcf49ada62eb2b"),
+    ] {
+        check::<Ripemd160>(message, &ripemd160_hash(message, false), expected);
+    }
+}
+
+#[test]
+fn t_million_a() {
+    let message = vec![b'a'; 1_000_000];
+    assert_eq!(
+        hex::encode(Keccak256::digest(&message)),
+        "fadae6b49f129bbb812be8407b7b2894f34aecf6dbd1f9b0f0c7e9853098fc96"
+    );
+    assert_eq!(
+        hex::encode(Sha3_256::digest(&message)),
+        "5c8875ae474a3634ba4fd55ec85bffd661f32aca75c6d699d0cdcb6c115891c1"
+    );
+    assert_eq!(
+        hex::encode(Sha256::digest(&message)),
+        "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+    );
+    assert_eq!(
+        hex::encode(Ripemd160::digest(&message)),
+        "52783243c1697bdbe16d37f97f68f08325dc1528"
+    );
+}
+
+#[test]
+fn t_clone_and_reset() {
+    let mut hasher = Keccak256::new();
+    hasher.update(b"ab");
+    let midstate = hasher.clone();
+    hasher.update(b"c");
+    assert_eq!(
+        hex::encode(hasher.finalize_reset()),
+        "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+    );
+    assert_eq!(
+        hex::encode(hasher.finalize()),
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+
+    let mut hasher = midstate;
+    hasher.update(b"c");
+    assert_eq!(
+        hex::encode(hasher.finalize()),
+        "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+    );
+}
+
+#[test]
+fn t_sha256_64byte_pairs() {
+    let pairs: Vec<[u8; 64]> = (0..100u8)
+        .map(|i| core::array::from_fn(|j| i.wrapping_mul(31).wrapping_add(j as u8)))
+        .collect();
+
+    let digests = Sha256::hash_64byte_pairs(&pairs);
+    assert_eq!(digests.len(), pairs.len());
+    for (digest, pair) in digests.iter().zip(&pairs) {
+        assert_eq!(digest, &sha256_hash(pair, false));
+    }
+
+    assert!(Sha256::hash_64byte_pairs(&[]).is_empty());
+
+    // Merkle root of 2 zero chunks
+    assert_eq!(
+        hex::encode(Sha256::hash_64byte_pairs(&[[0u8; 64]])[0]),
+        "f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b"
+    );
+}
+
+/// The generic backend is always valid, the library may be compiled without assembly.
+#[test]
+fn t_sha256_backend() {
+    let backend = Sha256::backend();
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    match backend {
+        Sha256Backend::cttSha256_x86_SHA => assert!(is_x86_feature_detected!("sha")),
+        Sha256Backend::cttSha256_x86_SSSE3 => {
+            assert!(is_x86_feature_detected!("ssse3"));
+            assert!(!is_x86_feature_detected!("sha"));
+        }
+        Sha256Backend::cttSha256_Generic => {}
+        Sha256Backend::cttSha256_ARM64_SHA2 => panic!("ARM64 SHA-256 backend on x86"),
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    assert!(
+        backend == Sha256Backend::cttSha256_Generic
+            || (cfg!(target_os = "macos") && backend == Sha256Backend::cttSha256_ARM64_SHA2)
+    );
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+    assert_eq!(backend, Sha256Backend::cttSha256_Generic);
+}
@@ -0,0 +1,140 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_core::Threadpool;
+use constantine_hashes::sha256_hash;
+use constantine_hashes::ssz_merkle::*;
+
+/// Chunk `i` is `sha256(i as little-endian u64)`
+fn chunks(count: usize) -> Vec<Chunk> {
+    (0..count as u64)
+        .map(|i| sha256_hash(&i.to_le_bytes(), false))
+        .collect()
+}
+
+#[test]
+fn t_zero_hashes() {
+    assert_eq!(zero_hash(0), &[0u8; 32]);
+    assert_eq!(
+        hex::encode(zero_hash(1)),
+        "f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b"
+    );
+    assert_eq!(
+        hex::encode(zero_hash(2)),
+        "db56114e00fdd4c1f85c892bf35ac9a89289aaecb1ebd0a96cde606a748b5d71"
+    );
+    for depth in 0..=10 {
+        assert_eq!(
+            &merkleize(&[], Some(1 << depth)).unwrap(),
+            zero_hash(depth),
+            "depth {}",
+            depth
+        );
+    }
+}
+
+#[test]
+fn t_merkleize() {
+    for (count, limit, expected) in [
+        (
+            0,
+            None,
+            "0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            0,
+            Some(0),
+            "0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            0,
+            Some(4),
+            "db56114e00fdd4c1f85c892bf35ac9a89289aaecb1ebd0a96cde606a748b5d71",
+        ),
+        (
+            1,
+            None,
+            "af5570f5a1810b7af78caf4bc70a660f0df51e42baf91d4de5b2328de0e83dfc",
+        ),
+        (
+            3,
+            None,
+            "7b5282f9239e7fba17a6afcb71ac7772844470ac5f2b285a78464339c4f5f090",
+        ),
+        (
+            5,
+            None,
+            "61bfaf25831ef08adec0aef5f7481742bd5caefda995a91ad8466e45278ebcb6",
+        ),
+        (
+            5,
+            Some(8),
+            "61bfaf25831ef08adec0aef5f7481742bd5caefda995a91ad8466e45278ebcb6",
+        ),
+        (
+            6,
+            Some(1024),
+            "e0c160e532fcf1ccaf0eb40003edf20528b1c0c7340101033b6a0032b6ca7775",
+        ),
+    ] {
+        let root = merkleize(&chunks(count), limit).unwrap();
+        assert_eq!(
+            hex::encode(root),
+            expected,
+            "{} chunks, limit {:?}",
+            count,
+            limit
+        );
+    }
+
+    assert_eq!(
+        merkleize(&chunks(5), Some(4)),
+        Err(MerkleizeError::TooManyChunks { count: 5, limit: 4 })
+    );
+}
+
+#[test]
+fn t_mix_in_length() {
+    // Empty List[uint64, 1024], 4 balances per chunk
+    let root = merkleize(&[], Some(1024 / 4)).unwrap();
+    assert_eq!(
+        hex::encode(mix_in_length(&root, 0)),
+        "76859427a26d01891b23e04cfc6342b72e4f52caca9d7535d16cd7f36b5d52bb"
+    );
+
+    let root = merkleize(&chunks(3), None).unwrap();
+    assert_eq!(
+        hex::encode(mix_in_length(&root, 3)),
+        "d6a8d7df20c4124b025c1d7681c495258bf7882cfa412b9c6a6cdab6962fccf1"
+    );
+}
+
+#[test]
+fn t_merkleize_parallel() {
+    let tp = Threadpool::new(4);
+    let chunks = chunks(100003);
+    let expected = "86a613e5211b550e5f6501784da69011c767a81b49929e6e8c914d21c90af480";
+
+    assert_eq!(
+        hex::encode(merkleize(&chunks, Some(1 << 20)).unwrap()),
+        expected
+    );
+    assert_eq!(
+        hex::encode(merkleize_parallel(&tp, &chunks, Some(1 << 20)).unwrap()),
+        expected
+    );
+
+    for count in [0, 1, 2, 3, 4095, 8192, 8193] {
+        assert_eq!(
+            merkleize_parallel(&tp, &chunks[..count], None),
+            merkleize(&chunks[..count], None),
+            "{} chunks",
+            count
+        );
+    }
+}
@@ -0,0 +1,582 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! KZG polynomial commitments (Kate, Zaverucha, Goldberg)
+//! over pairing-friendly curves, with a user-supplied powers-of-tau setup.
+//!
+//! Unlike `constantine-ethereum-kzg` which is fixed to EIP-4844 blobs
+//! of 4096 field elements, polynomials may have any number of coefficients
+//! up to the size of the setup.
+
+use constantine_sys::*;
+
+use ::core::mem::MaybeUninit;
+use std::fmt;
+use std::marker::PhantomData;
+
+pub mod srs;
+
+// Curves
+// ------------------------------------------------------------
+
+/// A pairing-friendly curve on which KZG commitments can be computed.
+///
+/// The associated types are Constantine's internal representations.
+pub trait KzgCurve {
+    /// Scalar field element
+    type Fr: Copy;
+    /// Affine point on 𝔾1
+    type G1Aff: Copy;
+    /// Affine point on 𝔾2
+    type G2Aff: Copy;
+
+    #[doc(hidden)]
+    unsafe fn kzg_commit(
+        commitment: *mut Self::G1Aff,
+        poly: *const Self::Fr,
+        powers_of_tau_g1: *const Self::G1Aff,
+        len: usize,
+    );
+
+    #[doc(hidden)]
+    unsafe fn kzg_prove_monomial(
+        proof: *mut Self::G1Aff,
+        eval_at_challenge: *mut Self::Fr,
+        poly: *const Self::Fr,
+        powers_of_tau_g1: *const Self::G1Aff,
+        len: usize,
+        opening_challenge: *const Self::Fr,
+    );
+
+    #[doc(hidden)]
+    unsafe fn kzg_verify(
+        commitment: *const Self::G1Aff,
+        opening_challenge: *const Self::Fr,
+        eval_at_challenge: *const Self::Fr,
+        proof: *const Self::G1Aff,
+        tau_g2: *const Self::G2Aff,
+    ) -> bool;
+
+    #[doc(hidden)]
+    unsafe fn kzg_verify_batch(
+        commitments: *const Self::G1Aff,
+        challenges: *const Self::Fr,
+        evals_at_challenges: *const Self::Fr,
+        proofs: *const Self::G1Aff,
+        linear_indep_random_numbers: *const Self::Fr,
+        len: usize,
+        tau_g2: *const Self::G2Aff,
+    ) -> bool;
+
+    #[doc(hidden)]
+    unsafe fn kzg_setup_insecure_for_testing(
+        powers_of_tau_g1: *mut Self::G1Aff,
+        len: usize,
+        tau_g2: *mut Self::G2Aff,
+        tau: *const Self::Fr,
+    );
+
+    #[doc(hidden)]
+    unsafe fn srs_g1_from_montgomery_le_unchecked(
+        dst: *mut Self::G1Aff,
+        src: *const u8,
+        len: usize,
+    ) -> bool;
+
+    #[doc(hidden)]
+    unsafe fn srs_g2_from_montgomery_le_unchecked(
+        dst: *mut Self::G2Aff,
+        src: *const u8,
+        len: usize,
+    ) -> bool;
+
+    #[doc(hidden)]
+    unsafe fn srs_monomial_to_lagrange(
+        lagrange_g1: *mut Self::G1Aff,
+        powers_of_tau_g1: *const Self::G1Aff,
+        len: usize,
+        root_of_unity: *const Self::Fr,
+    ) -> bool;
+
+    #[doc(hidden)]
+    unsafe fn srs_g1_validate_parallel(
+        tp: *const ctt_threadpool,
+        points: *const Self::G1Aff,
+        len: usize,
+    ) -> bool;
+
+    #[doc(hidden)]
+    unsafe fn srs_g2_validate_parallel(
+        tp: *const ctt_threadpool,
+        points: *const Self::G2Aff,
+        len: usize,
+    ) -> bool;
+
+    #[doc(hidden)]
+    unsafe fn fr_square(r: *mut Self::Fr, a: *const Self::Fr);
+
+    /// Size in bytes of a base field element
+    const FP_BYTES: usize;
+    /// Base field modulus, little-endian
+    const FP_MODULUS_LE: &'static [u8];
+    /// 2-adicity of the scalar field, i.e. largest s such that 2ˢ divides r-1
+    const FR_TWO_ADICITY: u32;
+    /// Primitive 2ˢ-th root of unity 7^((r-1)/2ˢ), big-endian
+    const FR_TWO_ADIC_ROOT_OF_UNITY: [u8; 32];
+
+    /// Returns the primitive n-th root of unity ω = 7^((r-1)/n) for n a power of 2.
+    ///
+    /// This is the domain used by Ethereum EIP-4844 and halo2.
+    fn root_of_unity(n: usize) -> Option<Self::Fr> {
+        if !n.is_power_of_two() || n.trailing_zeros() > Self::FR_TWO_ADICITY {
+            return None;
+        }
+        let mut w = Self::fr_from_be_bytes(&Self::FR_TWO_ADIC_ROOT_OF_UNITY)?;
+        for _ in n.trailing_zeros()..Self::FR_TWO_ADICITY {
+            let mut t = MaybeUninit::<Self::Fr>::uninit();
+            unsafe {
+                Self::fr_square(t.as_mut_ptr(), &w);
+                w = t.assume_init();
+            }
+        }
+        Some(w)
+    }
+
+    /// Deserialize a scalar from its canonical big-endian representation.
+    /// Returns `None` if the input is too large.
+    fn fr_from_be_bytes(bytes: &[u8]) -> Option<Self::Fr>;
+
+    /// Serialize a scalar to its canonical 32-byte big-endian representation.
+    fn fr_to_be_bytes(fr: &Self::Fr) -> [u8; 32];
+
+    fn g1_is_eq(a: &Self::G1Aff, b: &Self::G1Aff) -> bool;
+}
+
+macro_rules! impl_kzg_curve {
+    ($curve:ident, $fr:ty, $g1_aff:ty, $g2_aff:ty,
+     $commit:ident, $prove:ident, $verify:ident, $verify_batch:ident, $setup:ident,
+     $g1_from_mont:ident, $g2_from_mont:ident, $to_lagrange:ident,
+     $g1_validate:ident, $g2_validate:ident,
+     $fr_unmarshal:ident, $fr_marshal:ident, $fr_square:ident, $g1_is_eq:ident,
+     fp_bytes: $fp_bytes:expr, fp_modulus_le: $fp_modulus_le:expr,
+     two_adicity: $two_adicity:expr, two_adic_root_of_unity: $root_of_unity:expr) => {
+        impl KzgCurve for $curve {
+            type Fr = $fr;
+            type G1Aff = $g1_aff;
+            type G2Aff = $g2_aff;
+
+            const FP_BYTES: usize = $fp_bytes;
+            const FP_MODULUS_LE: &'static [u8] = &$fp_modulus_le;
+            const FR_TWO_ADICITY: u32 = $two_adicity;
+            const FR_TWO_ADIC_ROOT_OF_UNITY: [u8; 32] = $root_of_unity;
+
+            #[inline(always)]
+            unsafe fn kzg_commit(
+                commitment: *mut Self::G1Aff,
+                poly: *const Self::Fr,
+                powers_of_tau_g1: *const Self::G1Aff,
+                len: usize,
+            ) {
+                $commit(commitment, poly, powers_of_tau_g1, len)
+            }
+
+            #[inline(always)]
+            unsafe fn kzg_prove_monomial(
+                proof: *mut Self::G1Aff,
+                eval_at_challenge: *mut Self::Fr,
+                poly: *const Self::Fr,
+                powers_of_tau_g1: *const Self::G1Aff,
+                len: usize,
+                opening_challenge: *const Self::Fr,
+            ) {
+                $prove(
+                    proof,
+                    eval_at_challenge,
+                    poly,
+                    powers_of_tau_g1,
+                    len,
+                    opening_challenge,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn kzg_verify(
+                commitment: *const Self::G1Aff,
+                opening_challenge: *const Self::Fr,
+                eval_at_challenge: *const Self::Fr,
+                proof: *const Self::G1Aff,
+                tau_g2: *const Self::G2Aff,
+            ) -> bool {
+                $verify(
+                    commitment,
+                    opening_challenge,
+                    eval_at_challenge,
+                    proof,
+                    tau_g2,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn kzg_verify_batch(
+                commitments: *const Self::G1Aff,
+                challenges: *const Self::Fr,
+                evals_at_challenges: *const Self::Fr,
+                proofs: *const Self::G1Aff,
+                linear_indep_random_numbers: *const Self::Fr,
+                len: usize,
+                tau_g2: *const Self::G2Aff,
+            ) -> bool {
+                $verify_batch(
+                    commitments,
+                    challenges,
+                    evals_at_challenges,
+                    proofs,
+                    linear_indep_random_numbers,
+                    len,
+                    tau_g2,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn kzg_setup_insecure_for_testing(
+                powers_of_tau_g1: *mut Self::G1Aff,
+                len: usize,
+                tau_g2: *mut Self::G2Aff,
+                tau: *const Self::Fr,
+            ) {
+                $setup(powers_of_tau_g1, len, tau_g2, tau)
+            }
+
+            #[inline(always)]
+            unsafe fn srs_g1_from_montgomery_le_unchecked(
+                dst: *mut Self::G1Aff,
+                src: *const u8,
+                len: usize,
+            ) -> bool {
+                $g1_from_mont(dst, src, len)
+            }
+
+            #[inline(always)]
+            unsafe fn srs_g2_from_montgomery_le_unchecked(
+                dst: *mut Self::G2Aff,
+                src: *const u8,
+                len: usize,
+            ) -> bool {
+                $g2_from_mont(dst, src, len)
+            }
+
+            #[inline(always)]
+            unsafe fn srs_monomial_to_lagrange(
+                lagrange_g1: *mut Self::G1Aff,
+                powers_of_tau_g1: *const Self::G1Aff,
+                len: usize,
+                root_of_unity: *const Self::Fr,
+            ) -> bool {
+                $to_lagrange(lagrange_g1, powers_of_tau_g1, len, root_of_unity)
+            }
+
+            #[inline(always)]
+            unsafe fn srs_g1_validate_parallel(
+                tp: *const ctt_threadpool,
+                points: *const Self::G1Aff,
+                len: usize,
+            ) -> bool {
+                $g1_validate(tp, points, len)
+            }
+
+            #[inline(always)]
+            unsafe fn srs_g2_validate_parallel(
+                tp: *const ctt_threadpool,
+                points: *const Self::G2Aff,
+                len: usize,
+            ) -> bool {
+                $g2_validate(tp, points, len)
+            }
+
+            #[inline(always)]
+            unsafe fn fr_square(r: *mut Self::Fr, a: *const Self::Fr) {
+                $fr_square(r, a)
+            }
+
+            fn fr_from_be_bytes(bytes: &[u8]) -> Option<Self::Fr> {
+                let mut result = MaybeUninit::<Self::Fr>::uninit();
+                unsafe {
+                    match $fr_unmarshal(result.as_mut_ptr(), bytes.as_ptr(), bytes.len()) {
+                        true => Some(result.assume_init()),
+                        false => None,
+                    }
+                }
+            }
+
+            fn fr_to_be_bytes(fr: &Self::Fr) -> [u8; 32] {
+                let mut result = [0u8; 32];
+                let ok = unsafe { $fr_marshal(result.as_mut_ptr(), result.len(), fr) };
+                debug_assert!(ok, "32 bytes are enough for the scalar field");
+                result
+            }
+
+            fn g1_is_eq(a: &Self::G1Aff, b: &Self::G1Aff) -> bool {
+                unsafe { $g1_is_eq(a, b) != 0 }
+            }
+        }
+    };
+}
+
+/// BLS12-381, used in Ethereum consensus and EIP-4844
+#[derive(Debug, Clone, Copy)]
+pub enum Bls12_381 {}
+
+/// BN254_Snarks, also called alt_bn128 or bn256, used in Ethereum EVM precompiles and many zk-SNARKs
+#[derive(Debug, Clone, Copy)]
+pub enum Bn254Snarks {}
+
+impl_kzg_curve!(
+    Bls12_381,
+    bls12_381_fr,
+    bls12_381_g1_aff,
+    bls12_381_g2_aff,
+    ctt_bls12_381_kzg_commit_vartime,
+    ctt_bls12_381_kzg_prove_monomial_vartime,
+    ctt_bls12_381_kzg_verify_vartime,
+    ctt_bls12_381_kzg_verify_batch_vartime,
+    ctt_bls12_381_kzg_setup_insecure_for_testing,
+    ctt_bls12_381_srs_g1_from_montgomery_le_unchecked,
+    ctt_bls12_381_srs_g2_from_montgomery_le_unchecked,
+    ctt_bls12_381_srs_monomial_to_lagrange_vartime,
+    ctt_bls12_381_srs_g1_validate_parallel,
+    ctt_bls12_381_srs_g2_validate_parallel,
+    ctt_bls12_381_fr_unmarshalBE,
+    ctt_bls12_381_fr_marshalBE,
+    ctt_bls12_381_fr_square,
+    ctt_bls12_381_g1_aff_is_eq,
+    fp_bytes: 48,
+    fp_modulus_le: [
+        0xab, 0xaa, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xb9, 0xff, 0xff, 0x53, 0xb1,
+        0xfe, 0xff, 0xab, 0x1e, 0x24, 0xf6, 0xb0, 0xf6, 0xa0, 0xd2, 0x30, 0x67,
+        0xbf, 0x12, 0x85, 0xf3, 0x84, 0x4b, 0x77, 0x64, 0xd7, 0xac, 0x4b, 0x43,
+        0xb6, 0xa7, 0x1b, 0x4b, 0x9a, 0xe6, 0x7f, 0x39, 0xea, 0x11, 0x01, 0x1a,
+    ],
+    two_adicity: 32,
+    two_adic_root_of_unity: [
+        0x16, 0xa2, 0xa1, 0x9e, 0xdf, 0xe8, 0x1f, 0x20, 0xd0, 0x9b, 0x68, 0x19,
+        0x22, 0xc8, 0x13, 0xb4, 0xb6, 0x36, 0x83, 0x50, 0x8c, 0x22, 0x80, 0xb9,
+        0x38, 0x29, 0x97, 0x1f, 0x43, 0x9f, 0x0d, 0x2b,
+    ]
+);
+
+impl_kzg_curve!(
+    Bn254Snarks,
+    bn254_snarks_fr,
+    bn254_snarks_g1_aff,
+    bn254_snarks_g2_aff,
+    ctt_bn254_snarks_kzg_commit_vartime,
+    ctt_bn254_snarks_kzg_prove_monomial_vartime,
+    ctt_bn254_snarks_kzg_verify_vartime,
+    ctt_bn254_snarks_kzg_verify_batch_vartime,
+    ctt_bn254_snarks_kzg_setup_insecure_for_testing,
+    ctt_bn254_snarks_srs_g1_from_montgomery_le_unchecked,
+    ctt_bn254_snarks_srs_g2_from_montgomery_le_unchecked,
+    ctt_bn254_snarks_srs_monomial_to_lagrange_vartime,
+    ctt_bn254_snarks_srs_g1_validate_parallel,
+    ctt_bn254_snarks_srs_g2_validate_parallel,
+    ctt_bn254_snarks_fr_unmarshalBE,
+    ctt_bn254_snarks_fr_marshalBE,
+    ctt_bn254_snarks_fr_square,
+    ctt_bn254_snarks_g1_aff_is_eq,
+    fp_bytes: 32,
+    fp_modulus_le: [
+        0x47, 0xfd, 0x7c, 0xd8, 0x16, 0x8c, 0x20, 0x3c, 0x8d, 0xca, 0x71, 0x68,
+        0x91, 0x6a, 0x81, 0x97, 0x5d, 0x58, 0x81, 0x81, 0xb6, 0x45, 0x50, 0xb8,
+        0x29, 0xa0, 0x31, 0xe1, 0x72, 0x4e, 0x64, 0x30,
+    ],
+    two_adicity: 28,
+    two_adic_root_of_unity: [
+        0x03, 0xdd, 0xb9, 0xf5, 0x16, 0x6d, 0x18, 0xb7, 0x98, 0x86, 0x5e, 0xa9,
+        0x3d, 0xd3, 0x1f, 0x74, 0x32, 0x15, 0xcf, 0x6d, 0xd3, 0x93, 0x29, 0xc8,
+        0xd3, 0x4f, 0x1e, 0xd9, 0x60, 0xc3, 0x7c, 0x9c,
+    ]
+);
+
+// Errors
+// ------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KzgError {
+    /// The polynomial has more coefficients than the setup has powers of τ
+    PolynomialTooLarge { len: usize, max_len: usize },
+    /// Batch verification inputs do not have the same number of elements
+    InputsLengthsMismatch,
+}
+
+impl fmt::Display for KzgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KzgError::PolynomialTooLarge { len, max_len } => write!(
+                f,
+                "polynomial has {} coefficients but the setup supports at most {}",
+                len, max_len
+            ),
+            KzgError::InputsLengthsMismatch => write!(f, "inputs lengths mismatch"),
+        }
+    }
+}
+
+impl std::error::Error for KzgError {}
+
+// KZG
+// ------------------------------------------------------------
+
+/// KZG polynomial commitment scheme over the curve `C`.
+///
+/// The setup holds [τⁱ]₁ for i in [0, n) and [τ]₂.
+/// Commitments accept polynomials in the same basis as the setup,
+/// openings at arbitrary points require the monomial basis.
+pub struct Kzg<C: KzgCurve> {
+    powers_of_tau_g1: Vec<C::G1Aff>,
+    tau_g2: C::G2Aff,
+    _curve: PhantomData<C>,
+}
+
+impl<C: KzgCurve> Kzg<C> {
+    /// Create a KZG context from a trusted setup.
+    /// The points are assumed to be on curve and in the prime-order subgroup.
+    pub fn new(powers_of_tau_g1: Vec<C::G1Aff>, tau_g2: C::G2Aff) -> Self {
+        Self {
+            powers_of_tau_g1,
+            tau_g2,
+            _curve: PhantomData,
+        }
+    }
+
+    /// Generate a monomial-basis setup of `max_len` powers from a known τ.
+    ///
+    /// ⚠️ Anyone who knows τ can forge proofs.
+    ///    This is only suitable for tests and benchmarks.
+    pub fn setup_insecure_for_testing(max_len: usize, tau: &C::Fr) -> Self {
+        let mut powers_of_tau_g1 = Vec::<C::G1Aff>::with_capacity(max_len);
+        let mut tau_g2 = MaybeUninit::<C::G2Aff>::uninit();
+        unsafe {
+            C::kzg_setup_insecure_for_testing(
+                powers_of_tau_g1.as_mut_ptr(),
+                max_len,
+                tau_g2.as_mut_ptr(),
+                tau,
+            );
+            powers_of_tau_g1.set_len(max_len);
+            Self::new(powers_of_tau_g1, tau_g2.assume_init())
+        }
+    }
+
+    /// The maximum number of coefficients of a committed polynomial
+    #[inline(always)]
+    pub fn max_len(&self) -> usize {
+        self.powers_of_tau_g1.len()
+    }
+
+    #[inline(always)]
+    pub fn powers_of_tau_g1(&self) -> &[C::G1Aff] {
+        &self.powers_of_tau_g1
+    }
+
+    #[inline(always)]
+    pub fn tau_g2(&self) -> &C::G2Aff {
+        &self.tau_g2
+    }
+
+    #[inline(always)]
+    fn check_len(&self, len: usize) -> Result<(), KzgError> {
+        if len > self.max_len() {
+            return Err(KzgError::PolynomialTooLarge {
+                len,
+                max_len: self.max_len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Commit to a polynomial
+    ///   [commitment]₁ = [p(τ)]₁
+    #[inline]
+    pub fn commit(&self, poly: &[C::Fr]) -> Result<C::G1Aff, KzgError> {
+        self.check_len(poly.len())?;
+        let mut commitment = MaybeUninit::<C::G1Aff>::uninit();
+        unsafe {
+            C::kzg_commit(
+                commitment.as_mut_ptr(),
+                poly.as_ptr(),
+                self.powers_of_tau_g1.as_ptr(),
+                poly.len(),
+            );
+            Ok(commitment.assume_init())
+        }
+    }
+
+    /// Open a polynomial in monomial basis at an arbitrary point z.
+    ///
+    /// Returns the proof [(p(τ) - p(z)) / (τ-z)]₁ and the evaluation y = p(z).
+    #[inline]
+    pub fn open(&self, poly: &[C::Fr], z: &C::Fr) -> Result<(C::G1Aff, C::Fr), KzgError> {
+        self.check_len(poly.len())?;
+        let mut proof = MaybeUninit::<C::G1Aff>::uninit();
+        let mut eval = MaybeUninit::<C::Fr>::uninit();
+        unsafe {
+            C::kzg_prove_monomial(
+                proof.as_mut_ptr(),
+                eval.as_mut_ptr(),
+                poly.as_ptr(),
+                self.powers_of_tau_g1.as_ptr(),
+                poly.len(),
+                z,
+            );
+            Ok((proof.assume_init(), eval.assume_init()))
+        }
+    }
+
+    /// Verify that the polynomial committed in `commitment` evaluates to `y` at `z`.
+    #[inline]
+    pub fn verify(&self, commitment: &C::G1Aff, z: &C::Fr, y: &C::Fr, proof: &C::G1Aff) -> bool {
+        unsafe { C::kzg_verify(commitment, z, y, proof, &self.tau_g2) }
+    }
+
+    /// Verify multiple openings at once.
+    ///
+    /// `linear_indep_random_numbers` MUST NOT be under the control of the prover,
+    /// for example sampled from a CSPRNG or derived by Fiat-Shamir from all other inputs.
+    #[inline]
+    pub fn verify_batch(
+        &self,
+        commitments: &[C::G1Aff],
+        zs: &[C::Fr],
+        ys: &[C::Fr],
+        proofs: &[C::G1Aff],
+        linear_indep_random_numbers: &[C::Fr],
+    ) -> Result<bool, KzgError> {
+        let n = commitments.len();
+        if zs.len() != n
+            || ys.len() != n
+            || proofs.len() != n
+            || linear_indep_random_numbers.len() != n
+        {
+            return Err(KzgError::InputsLengthsMismatch);
+        }
+        if n == 0 {
+            return Ok(true);
+        }
+        Ok(unsafe {
+            C::kzg_verify_batch(
+                commitments.as_ptr(),
+                zs.as_ptr(),
+                ys.as_ptr(),
+                proofs.as_ptr(),
+                linear_indep_random_numbers.as_ptr(),
+                n,
+                &self.tau_g2,
+            )
+        })
+    }
+}
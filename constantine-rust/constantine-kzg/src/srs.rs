@@ -0,0 +1,484 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Structured Reference Strings (SRS) import from trusted setup ceremonies:
+//!
+//! - the Ethereum KZG ceremony, either the sequencer `transcript.json`
+//!   or the `trusted_setup.json` derived from it. BLS12-381 only.
+//! - Perpetual Powers of Tau `.ptau` files from snarkjs.
+//! - halo2 `ParamsKZG` files in `SerdeFormat::RawBytes` (the format of `ParamsKZG::write`).
+//!
+//! Deserialization only checks that points are on the curve.
+//! Subgroup checks are expensive and are done separately by [`Srs::validate`]
+//! on a threadpool.
+
+use crate::{Bls12_381, Kzg, KzgCurve};
+
+use constantine_core::Threadpool;
+use constantine_sys::*;
+
+use ::core::mem::MaybeUninit;
+use serde::Deserialize;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Errors
+// ------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SrsError {
+    /// The file could not be read
+    MissingOrInaccessibleFile { path: PathBuf, kind: io::ErrorKind },
+    /// The JSON is malformed or does not follow the expected schema
+    InvalidJson(String),
+    /// The binary file is truncated or malformed
+    InvalidFormat(&'static str),
+    /// The setup is for a curve with a different base field
+    CurveMismatch,
+    /// A 𝔾1 point is not properly encoded or not on the curve, `index` is in file order
+    InvalidG1Point { index: usize },
+    /// A 𝔾2 point is not properly encoded or not on the curve, `index` is in file order
+    InvalidG2Point { index: usize },
+    /// A point is not in the prime-order subgroup
+    PointNotInSubgroup,
+    /// The requested transcript or basis is not present in the setup
+    Missing(&'static str),
+    /// The requested domain size is not a supported power of 2
+    InvalidDomainSize { len: usize },
+}
+
+impl fmt::Display for SrsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SrsError::MissingOrInaccessibleFile { path, kind } => {
+                write!(
+                    f,
+                    "cannot read trusted setup file {}: {}",
+                    path.display(),
+                    kind
+                )
+            }
+            SrsError::InvalidJson(msg) => write!(f, "invalid trusted setup JSON: {}", msg),
+            SrsError::InvalidFormat(msg) => write!(f, "invalid trusted setup file: {}", msg),
+            SrsError::CurveMismatch => write!(f, "trusted setup is for a different curve"),
+            SrsError::InvalidG1Point { index } => write!(f, "invalid G1 point at index {}", index),
+            SrsError::InvalidG2Point { index } => write!(f, "invalid G2 point at index {}", index),
+            SrsError::PointNotInSubgroup => write!(f, "point not in the prime-order subgroup"),
+            SrsError::Missing(what) => write!(f, "trusted setup has no {}", what),
+            SrsError::InvalidDomainSize { len } => {
+                write!(f, "domain size {} is not a supported power of 2", len)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SrsError {}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, SrsError> {
+    std::fs::read(path).map_err(|e| SrsError::MissingOrInaccessibleFile {
+        path: path.to_path_buf(),
+        kind: e.kind(),
+    })
+}
+
+// SRS
+// ------------------------------------------------------------
+
+/// Powers of τ from a trusted setup
+///
+/// - `g1_monomial`: [τⁱ]₁
+/// - `g1_lagrange`: [Lᵢ(τ)]₁, empty if the setup does not provide it.
+///   In the order of the setup it was loaded from:
+///   bit-reversal permuted for Ethereum setups, natural order otherwise.
+///   See [`Srs::compute_lagrange`].
+/// - `g2_monomial`: [τⁱ]₂
+pub struct Srs<C: KzgCurve> {
+    pub g1_monomial: Vec<C::G1Aff>,
+    pub g1_lagrange: Vec<C::G1Aff>,
+    pub g2_monomial: Vec<C::G2Aff>,
+}
+
+impl<C: KzgCurve> Clone for Srs<C> {
+    fn clone(&self) -> Self {
+        Self {
+            g1_monomial: self.g1_monomial.clone(),
+            g1_lagrange: self.g1_lagrange.clone(),
+            g2_monomial: self.g2_monomial.clone(),
+        }
+    }
+}
+
+impl<C: KzgCurve> Srs<C> {
+    /// Check that all points are in the prime-order subgroup.
+    ///
+    /// The checks are distributed on the threadpool.
+    pub fn validate(&self, tp: &Threadpool) -> Result<(), SrsError> {
+        let ctx = tp.get_private_context();
+        let valid = unsafe {
+            C::srs_g1_validate_parallel(ctx, self.g1_monomial.as_ptr(), self.g1_monomial.len())
+                && C::srs_g1_validate_parallel(
+                    ctx,
+                    self.g1_lagrange.as_ptr(),
+                    self.g1_lagrange.len(),
+                )
+                && C::srs_g2_validate_parallel(
+                    ctx,
+                    self.g2_monomial.as_ptr(),
+                    self.g2_monomial.len(),
+                )
+        };
+        match valid {
+            true => Ok(()),
+            false => Err(SrsError::PointNotInSubgroup),
+        }
+    }
+
+    /// Compute the Lagrange basis [Lᵢ(τ)]₁ of the first `len` powers of τ
+    /// over the domain {ωⁱ} with ω = [`KzgCurve::root_of_unity`]`(len)`.
+    ///
+    /// The result is in natural order and replaces `g1_lagrange`.
+    /// Ethereum EIP-4844 uses the bit-reversal permutation of this order.
+    pub fn compute_lagrange(&mut self, len: usize) -> Result<(), SrsError> {
+        if len > self.g1_monomial.len() {
+            return Err(SrsError::Missing("G1 monomial powers for this domain size"));
+        }
+        let omega = C::root_of_unity(len).ok_or(SrsError::InvalidDomainSize { len })?;
+
+        let mut lagrange = Vec::<C::G1Aff>::with_capacity(len);
+        unsafe {
+            if !C::srs_monomial_to_lagrange(
+                lagrange.as_mut_ptr(),
+                self.g1_monomial.as_ptr(),
+                len,
+                &omega,
+            ) {
+                return Err(SrsError::InvalidDomainSize { len });
+            }
+            lagrange.set_len(len);
+        }
+        self.g1_lagrange = lagrange;
+        Ok(())
+    }
+
+    /// Create a KZG context from the monomial powers of τ.
+    pub fn into_kzg(self) -> Result<Kzg<C>, SrsError> {
+        if self.g1_monomial.is_empty() {
+            return Err(SrsError::Missing("G1 monomial powers"));
+        }
+        let tau_g2 = *self.g2_monomial.get(1).ok_or(SrsError::Missing("[τ]₂"))?;
+        Ok(Kzg::new(self.g1_monomial, tau_g2))
+    }
+
+    // Montgomery little-endian formats
+    // ------------------------------------------------------------
+
+    fn g1_from_montgomery_le(src: &[u8], offset: usize) -> Result<Vec<C::G1Aff>, SrsError> {
+        let size = 2 * C::FP_BYTES;
+        let len = src.len() / size;
+        let mut points = Vec::<C::G1Aff>::with_capacity(len);
+        unsafe {
+            // Decode per point to report the faulty index
+            for i in 0..len {
+                let chunk = &src[i * size..(i + 1) * size];
+                if !C::srs_g1_from_montgomery_le_unchecked(
+                    points.as_mut_ptr().add(i),
+                    chunk.as_ptr(),
+                    1,
+                ) {
+                    return Err(SrsError::InvalidG1Point { index: offset + i });
+                }
+            }
+            points.set_len(len);
+        }
+        Ok(points)
+    }
+
+    fn g2_from_montgomery_le(src: &[u8], offset: usize) -> Result<Vec<C::G2Aff>, SrsError> {
+        let size = 4 * C::FP_BYTES;
+        let len = src.len() / size;
+        let mut points = Vec::<C::G2Aff>::with_capacity(len);
+        unsafe {
+            for i in 0..len {
+                let chunk = &src[i * size..(i + 1) * size];
+                if !C::srs_g2_from_montgomery_le_unchecked(
+                    points.as_mut_ptr().add(i),
+                    chunk.as_ptr(),
+                    1,
+                ) {
+                    return Err(SrsError::InvalidG2Point { index: offset + i });
+                }
+            }
+            points.set_len(len);
+        }
+        Ok(points)
+    }
+
+    /// Load a Perpetual Powers of Tau / snarkjs `.ptau` file.
+    ///
+    /// It contains 2ᵖ⁺¹-1 powers of τ in 𝔾1 and 2ᵖ in 𝔾2.
+    /// Lagrange bases are not loaded, see [`Srs::compute_lagrange`].
+    pub fn from_ptau(bytes: &[u8]) -> Result<Self, SrsError> {
+        let mut r = Reader::new(bytes);
+        if r.read_bytes(4)? != b"ptau" {
+            return Err(SrsError::InvalidFormat("missing ptau magic"));
+        }
+        let _version = r.read_u32_le()?;
+        let num_sections = r.read_u32_le()?;
+
+        // Sections may be in any order
+        let mut sections: [Option<&[u8]>; 4] = [None; 4];
+        for _ in 0..num_sections {
+            let kind = r.read_u32_le()? as usize;
+            let size = usize::try_from(r.read_u64_le()?)
+                .map_err(|_| SrsError::InvalidFormat("section too large"))?;
+            let data = r.read_bytes(size)?;
+            if kind < sections.len() {
+                if sections[kind].is_some() {
+                    return Err(SrsError::InvalidFormat("duplicate section"));
+                }
+                sections[kind] = Some(data);
+            }
+        }
+
+        // Header: n8, q, power, ceremonyPower
+        let mut header =
+            Reader::new(sections[1].ok_or(SrsError::InvalidFormat("missing header section"))?);
+        let n8 = header.read_u32_le()? as usize;
+        if n8 != C::FP_BYTES || header.read_bytes(n8)? != C::FP_MODULUS_LE {
+            return Err(SrsError::CurveMismatch);
+        }
+        let power = header.read_u32_le()?;
+        if power >= usize::BITS - 2 {
+            return Err(SrsError::InvalidFormat("power too large"));
+        }
+        let num_g1 = (1usize << (power + 1)) - 1;
+        let num_g2 = 1usize << power;
+
+        let tau_g1 = sections[2].ok_or(SrsError::InvalidFormat("missing tauG1 section"))?;
+        let tau_g2 = sections[3].ok_or(SrsError::InvalidFormat("missing tauG2 section"))?;
+        if Some(tau_g1.len()) != num_g1.checked_mul(2 * C::FP_BYTES)
+            || Some(tau_g2.len()) != num_g2.checked_mul(4 * C::FP_BYTES)
+        {
+            return Err(SrsError::InvalidFormat("unexpected section size"));
+        }
+
+        Ok(Self {
+            g1_monomial: Self::g1_from_montgomery_le(tau_g1, 0)?,
+            g1_lagrange: Vec::new(),
+            g2_monomial: Self::g2_from_montgomery_le(tau_g2, 0)?,
+        })
+    }
+
+    /// Load a Perpetual Powers of Tau / snarkjs `.ptau` file from disk.
+    pub fn from_ptau_file<P: AsRef<Path>>(path: P) -> Result<Self, SrsError> {
+        Self::from_ptau(&read_file(path.as_ref())?)
+    }
+
+    /// Load halo2 `ParamsKZG` serialized with `SerdeFormat::RawBytes`.
+    ///
+    /// The G2 powers are [1]₂ and [τ]₂.
+    pub fn from_halo2_params(bytes: &[u8]) -> Result<Self, SrsError> {
+        let mut r = Reader::new(bytes);
+        let k = r.read_u32_le()?;
+        if k >= usize::BITS - 1 {
+            return Err(SrsError::InvalidFormat("k too large"));
+        }
+        let n = 1usize << k;
+        let g1_size = 2 * C::FP_BYTES;
+        let g2_size = 4 * C::FP_BYTES;
+
+        let g1_bytes = n
+            .checked_mul(g1_size)
+            .ok_or(SrsError::InvalidFormat("k too large"))?;
+
+        let g = r.read_bytes(g1_bytes)?;
+        let g_lagrange = r.read_bytes(g1_bytes)?;
+        let g2 = r.read_bytes(2 * g2_size)?;
+        if !r.is_empty() {
+            return Err(SrsError::InvalidFormat("trailing bytes"));
+        }
+
+        Ok(Self {
+            g1_monomial: Self::g1_from_montgomery_le(g, 0)?,
+            g1_lagrange: Self::g1_from_montgomery_le(g_lagrange, n)?,
+            g2_monomial: Self::g2_from_montgomery_le(g2, 0)?,
+        })
+    }
+
+    /// Load halo2 `ParamsKZG` serialized with `SerdeFormat::RawBytes` from disk.
+    pub fn from_halo2_params_file<P: AsRef<Path>>(path: P) -> Result<Self, SrsError> {
+        Self::from_halo2_params(&read_file(path.as_ref())?)
+    }
+}
+
+// Ethereum KZG ceremony
+// ------------------------------------------------------------
+
+#[derive(Deserialize)]
+struct EthereumSetupJson {
+    #[serde(default)]
+    g1_monomial: Vec<String>,
+    #[serde(default)]
+    g1_lagrange: Vec<String>,
+    g2_monomial: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct EthereumTranscriptsJson {
+    transcripts: Vec<EthereumTranscript>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EthereumTranscript {
+    num_g1_powers: usize,
+    powers_of_tau: EthereumPowersOfTau,
+}
+
+#[derive(Deserialize)]
+struct EthereumPowersOfTau {
+    #[serde(rename = "G1Powers")]
+    g1_powers: Vec<String>,
+    #[serde(rename = "G2Powers")]
+    g2_powers: Vec<String>,
+}
+
+fn decode_hex<const N: usize>(s: &str) -> Option<[u8; N]> {
+    let mut bytes = [0u8; N];
+    hex::decode_to_slice(s.strip_prefix("0x").unwrap_or(s), &mut bytes).ok()?;
+    Some(bytes)
+}
+
+fn g1_from_compressed(points: &[String]) -> Result<Vec<bls12_381_g1_aff>, SrsError> {
+    let mut result = Vec::with_capacity(points.len());
+    for (i, hex) in points.iter().enumerate() {
+        let bytes = decode_hex::<48>(hex).ok_or(SrsError::InvalidG1Point { index: i })?;
+        let mut p = MaybeUninit::<bls12_381_g1_aff>::uninit();
+        unsafe {
+            match ctt_bls12_381_deserialize_g1_compressed_unchecked(p.as_mut_ptr(), bytes.as_ptr())
+            {
+                ctt_codec_ecc_status::cttCodecEcc_Success => result.push(p.assume_init()),
+                _ => return Err(SrsError::InvalidG1Point { index: i }),
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn g2_from_compressed(points: &[String]) -> Result<Vec<bls12_381_g2_aff>, SrsError> {
+    let mut result = Vec::with_capacity(points.len());
+    for (i, hex) in points.iter().enumerate() {
+        let bytes = decode_hex::<96>(hex).ok_or(SrsError::InvalidG2Point { index: i })?;
+        let mut p = MaybeUninit::<bls12_381_g2_aff>::uninit();
+        unsafe {
+            match ctt_bls12_381_deserialize_g2_compressed_unchecked(p.as_mut_ptr(), bytes.as_ptr())
+            {
+                ctt_codec_ecc_status::cttCodecEcc_Success => result.push(p.assume_init()),
+                _ => return Err(SrsError::InvalidG2Point { index: i }),
+            }
+        }
+    }
+    Ok(result)
+}
+
+impl Srs<Bls12_381> {
+    /// Load an Ethereum KZG `trusted_setup.json`
+    /// with keys `g1_lagrange`, `g2_monomial` and optionally `g1_monomial`.
+    ///
+    /// Points are compressed (Zcash format) and hex-encoded.
+    /// The Lagrange basis is kept in the Ethereum order,
+    /// i.e. the bit-reversal permutation of the natural order of [`Srs::compute_lagrange`].
+    pub fn from_ethereum_setup_json(json: &str) -> Result<Self, SrsError> {
+        let setup: EthereumSetupJson =
+            serde_json::from_str(json).map_err(|e| SrsError::InvalidJson(e.to_string()))?;
+        Ok(Self {
+            g1_monomial: g1_from_compressed(&setup.g1_monomial)?,
+            g1_lagrange: g1_from_compressed(&setup.g1_lagrange)?,
+            g2_monomial: g2_from_compressed(&setup.g2_monomial)?,
+        })
+    }
+
+    /// Load an Ethereum KZG `trusted_setup.json` from disk.
+    pub fn from_ethereum_setup_json_file<P: AsRef<Path>>(path: P) -> Result<Self, SrsError> {
+        let bytes = read_file(path.as_ref())?;
+        let json = std::str::from_utf8(&bytes).map_err(|e| SrsError::InvalidJson(e.to_string()))?;
+        Self::from_ethereum_setup_json(json)
+    }
+
+    /// Load the monomial powers of τ of the Ethereum KZG ceremony `transcript.json`
+    /// for the sub-ceremony with `num_g1_powers` 𝔾1 powers (4096, 8192, 16384 or 32768).
+    pub fn from_ethereum_transcript_json(
+        json: &str,
+        num_g1_powers: usize,
+    ) -> Result<Self, SrsError> {
+        let transcripts: EthereumTranscriptsJson =
+            serde_json::from_str(json).map_err(|e| SrsError::InvalidJson(e.to_string()))?;
+        let transcript = transcripts
+            .transcripts
+            .iter()
+            .find(|t| t.num_g1_powers == num_g1_powers)
+            .ok_or(SrsError::Missing(
+                "transcript with the requested number of G1 powers",
+            ))?;
+        if transcript.powers_of_tau.g1_powers.len() != num_g1_powers {
+            return Err(SrsError::InvalidJson(
+                "G1Powers length does not match numG1Powers".to_string(),
+            ));
+        }
+        Ok(Self {
+            g1_monomial: g1_from_compressed(&transcript.powers_of_tau.g1_powers)?,
+            g1_lagrange: Vec::new(),
+            g2_monomial: g2_from_compressed(&transcript.powers_of_tau.g2_powers)?,
+        })
+    }
+
+    /// Load the Ethereum KZG ceremony `transcript.json` from disk.
+    pub fn from_ethereum_transcript_json_file<P: AsRef<Path>>(
+        path: P,
+        num_g1_powers: usize,
+    ) -> Result<Self, SrsError> {
+        let bytes = read_file(path.as_ref())?;
+        let json = std::str::from_utf8(&bytes).map_err(|e| SrsError::InvalidJson(e.to_string()))?;
+        Self::from_ethereum_transcript_json(json, num_g1_powers)
+    }
+}
+
+// Binary reader
+// ------------------------------------------------------------
+
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], SrsError> {
+        if len > self.buf.len() {
+            return Err(SrsError::InvalidFormat("unexpected end of file"));
+        }
+        let (head, tail) = self.buf.split_at(len);
+        self.buf = tail;
+        Ok(head)
+    }
+
+    fn read_u32_le(&mut self) -> Result<u32, SrsError> {
+        Ok(u32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    fn read_u64_le(&mut self) -> Result<u64, SrsError> {
+        Ok(u64::from_le_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    }
+}
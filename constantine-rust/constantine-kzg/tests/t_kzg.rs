@@ -0,0 +1,109 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_kzg::*;
+
+const SETUP_LEN: usize = 64;
+
+fn fr<C: KzgCurve>(x: u64) -> C::Fr {
+    C::fr_from_be_bytes(&x.to_be_bytes()).unwrap()
+}
+
+fn poly<C: KzgCurve>(len: usize) -> Vec<C::Fr> {
+    (0..len as u64)
+        .map(|i| fr::<C>(i * i + 7 * i + 3))
+        .collect()
+}
+
+fn commit_open_verify<C: KzgCurve>() {
+    let kzg = Kzg::<C>::setup_insecure_for_testing(SETUP_LEN, &fr::<C>(0x1234_5678_9abc_def0));
+    assert_eq!(kzg.max_len(), SETUP_LEN);
+
+    for len in [1, 2, 17, SETUP_LEN] {
+        let p = poly::<C>(len);
+        let z = fr::<C>(42 + len as u64);
+
+        let commitment = kzg.commit(&p).unwrap();
+        let (proof, y) = kzg.open(&p, &z).unwrap();
+
+        if len == 1 {
+            assert_eq!(C::fr_to_be_bytes(&y), C::fr_to_be_bytes(&p[0]));
+        }
+
+        assert!(kzg.verify(&commitment, &z, &y, &proof));
+
+        let wrong_y = fr::<C>(1337);
+        assert!(!kzg.verify(&commitment, &z, &wrong_y, &proof));
+        let wrong_z = fr::<C>(1337);
+        assert!(!kzg.verify(&commitment, &wrong_z, &y, &proof));
+    }
+}
+
+fn commit_too_large<C: KzgCurve>() {
+    let kzg = Kzg::<C>::setup_insecure_for_testing(SETUP_LEN, &fr::<C>(0xdead_beef));
+    let p = poly::<C>(SETUP_LEN + 1);
+    assert_eq!(
+        kzg.commit(&p).err(),
+        Some(KzgError::PolynomialTooLarge {
+            len: SETUP_LEN + 1,
+            max_len: SETUP_LEN
+        })
+    );
+    assert!(kzg.open(&p, &fr::<C>(1)).is_err());
+}
+
+fn verify_batch<C: KzgCurve>() {
+    let kzg = Kzg::<C>::setup_insecure_for_testing(SETUP_LEN, &fr::<C>(0xcafe_babe));
+
+    let n = 5;
+    let mut commitments = Vec::new();
+    let mut zs = Vec::new();
+    let mut ys = Vec::new();
+    let mut proofs = Vec::new();
+    let mut randoms = Vec::new();
+    for i in 0..n {
+        let p = poly::<C>(SETUP_LEN - i);
+        let z = fr::<C>(1000 + i as u64);
+        let (proof, y) = kzg.open(&p, &z).unwrap();
+        commitments.push(kzg.commit(&p).unwrap());
+        zs.push(z);
+        ys.push(y);
+        proofs.push(proof);
+        randoms.push(fr::<C>(0x5eed_0000 + i as u64));
+    }
+
+    assert_eq!(
+        kzg.verify_batch(&commitments, &zs, &ys, &proofs, &randoms),
+        Ok(true)
+    );
+
+    ys.swap(0, 1);
+    assert_eq!(
+        kzg.verify_batch(&commitments, &zs, &ys, &proofs, &randoms),
+        Ok(false)
+    );
+
+    assert_eq!(
+        kzg.verify_batch(&commitments, &zs[1..], &ys, &proofs, &randoms),
+        Err(KzgError::InputsLengthsMismatch)
+    );
+}
+
+#[test]
+fn t_kzg_bls12_381() {
+    commit_open_verify::<Bls12_381>();
+    commit_too_large::<Bls12_381>();
+    verify_batch::<Bls12_381>();
+}
+
+#[test]
+fn t_kzg_bn254_snarks() {
+    commit_open_verify::<Bn254Snarks>();
+    commit_too_large::<Bn254Snarks>();
+    verify_batch::<Bn254Snarks>();
+}
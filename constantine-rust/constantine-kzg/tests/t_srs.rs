@@ -0,0 +1,341 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+use constantine_core::Threadpool;
+use constantine_kzg::srs::*;
+use constantine_kzg::*;
+use constantine_sys::*;
+
+use std::fs;
+use std::mem::MaybeUninit;
+
+const ETH_TRUSTED_SETUP: &str =
+    "../../constantine/commitments_setups/trusted_setup_ethereum_kzg4844_reference.dat";
+
+// BN254 generators in Montgomery form, little-endian, as serialized by snarkjs and halo2
+const BN254_P_LE: &str = "47fd7cd8168c203c8dca7168916a81975d588181b64550b829a031e1724e6430";
+const BN254_G1_MONT_LE: &str = "9d0d8fc58d435dd33d0bc7f528eb780a2c4679786fa36e662fdf079ac1770a0e\
+                                3a1b1e8b1b87baa67b168eeb51d6f114588cf2f0de46ddcc5ebe0f3483ef141c";
+const BN254_G2_MONT_LE: &str = "2620bc02d1b5838e72017b493519ebdcdf1a81974726b8fb3b5096af41385719\
+                                40614ca87d73b4afc4d802585add4360862fa052fc50e9096b7bea3a83f0fe14\
+                                f6e96b889dfa9d61789b9ef597d27ffefe7d1b23621a9eff06429eaeeb7efd28\
+                                ee5618c7565b0964bb3c7d3222f957dc76103533be35f9558264fd93e6a0a40d";
+
+fn bn254_one() -> bn254_snarks_fr {
+    Bn254Snarks::fr_from_be_bytes(&[1]).unwrap()
+}
+
+fn g2_is_eq(a: &bn254_snarks_g2_aff, b: &bn254_snarks_g2_aff) -> bool {
+    unsafe { ctt_bn254_snarks_g2_aff_is_eq(a, b) != 0 }
+}
+
+fn g1_is_neutral(a: &bn254_snarks_g1_aff) -> bool {
+    unsafe { ctt_bn254_snarks_g1_aff_is_neutral(a) != 0 }
+}
+
+fn ptau_section(kind: u32, data: &[u8]) -> Vec<u8> {
+    let mut section = Vec::new();
+    section.extend_from_slice(&kind.to_le_bytes());
+    section.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    section.extend_from_slice(data);
+    section
+}
+
+/// A power 1 ptau file with τ = 1, i.e. all powers are the generators
+fn bn254_ptau_tau_one() -> Vec<u8> {
+    let mut header = Vec::new();
+    header.extend_from_slice(&32u32.to_le_bytes());
+    header.extend_from_slice(&hex::decode(BN254_P_LE).unwrap());
+    header.extend_from_slice(&1u32.to_le_bytes()); // power
+    header.extend_from_slice(&1u32.to_le_bytes()); // ceremonyPower
+
+    let g1 = hex::decode(BN254_G1_MONT_LE).unwrap();
+    let g2 = hex::decode(BN254_G2_MONT_LE).unwrap();
+
+    let mut ptau = Vec::new();
+    ptau.extend_from_slice(b"ptau");
+    ptau.extend_from_slice(&1u32.to_le_bytes()); // version
+    ptau.extend_from_slice(&3u32.to_le_bytes()); // number of sections
+    ptau.extend(ptau_section(1, &header));
+    ptau.extend(ptau_section(2, &g1.repeat(3)));
+    ptau.extend(ptau_section(3, &g2.repeat(2)));
+    ptau
+}
+
+#[test]
+fn t_root_of_unity() {
+    // Ethereum EIP-4844 domain
+    assert_eq!(
+        hex::encode(Bls12_381::fr_to_be_bytes(
+            &Bls12_381::root_of_unity(4096).unwrap()
+        )),
+        "564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d36306"
+    );
+    // -1
+    assert_eq!(
+        hex::encode(Bls12_381::fr_to_be_bytes(
+            &Bls12_381::root_of_unity(2).unwrap()
+        )),
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000"
+    );
+    assert!(Bls12_381::root_of_unity(3).is_none());
+    #[cfg(target_pointer_width = "64")]
+    assert!(Bls12_381::root_of_unity(1 << 33).is_none());
+    assert!(Bn254Snarks::root_of_unity(1 << 28).is_some());
+    assert!(Bn254Snarks::root_of_unity(1 << 29).is_none());
+}
+
+#[test]
+fn t_ptau() {
+    let srs = Srs::<Bn254Snarks>::from_ptau(&bn254_ptau_tau_one()).unwrap();
+    let reference = Kzg::<Bn254Snarks>::setup_insecure_for_testing(3, &bn254_one());
+
+    assert_eq!(srs.g1_monomial.len(), 3);
+    assert_eq!(srs.g2_monomial.len(), 2);
+    assert!(srs.g1_lagrange.is_empty());
+    for (p, q) in srs.g1_monomial.iter().zip(reference.powers_of_tau_g1()) {
+        assert!(Bn254Snarks::g1_is_eq(p, q));
+    }
+    assert!(g2_is_eq(&srs.g2_monomial[1], reference.tau_g2()));
+
+    let tp = Threadpool::new(2);
+    assert_eq!(srs.validate(&tp), Ok(()));
+
+    // Lagrange basis of τ = 1 over {1, -1} is [G, 0]
+    let mut srs = srs;
+    srs.compute_lagrange(2).unwrap();
+    assert!(Bn254Snarks::g1_is_eq(
+        &srs.g1_lagrange[0],
+        &srs.g1_monomial[0]
+    ));
+    assert!(g1_is_neutral(&srs.g1_lagrange[1]));
+    assert_eq!(
+        srs.compute_lagrange(3),
+        Err(SrsError::InvalidDomainSize { len: 3 })
+    );
+    assert!(srs.compute_lagrange(4).is_err());
+}
+
+#[test]
+fn t_ptau_invalid() {
+    let ptau = bn254_ptau_tau_one();
+
+    // The header starts after magic, version, number of sections, section kind and size, n8
+    let mut wrong_curve = ptau.clone();
+    wrong_curve[4 + 4 + 4 + 4 + 8 + 4] ^= 1;
+    assert_eq!(
+        Srs::<Bn254Snarks>::from_ptau(&wrong_curve).err(),
+        Some(SrsError::CurveMismatch)
+    );
+    assert_eq!(
+        Srs::<Bls12_381>::from_ptau(&ptau).err(),
+        Some(SrsError::CurveMismatch)
+    );
+
+    // Corrupt the y coordinate of the second G1 point
+    let tau_g1_start = 4 + 4 + 4 + (4 + 8 + 44) + (4 + 8);
+    let mut not_on_curve = ptau.clone();
+    not_on_curve[tau_g1_start + 64 + 32] ^= 1;
+    assert_eq!(
+        Srs::<Bn254Snarks>::from_ptau(&not_on_curve).err(),
+        Some(SrsError::InvalidG1Point { index: 1 })
+    );
+
+    assert_eq!(
+        Srs::<Bn254Snarks>::from_ptau(&ptau[..ptau.len() - 1]).err(),
+        Some(SrsError::InvalidFormat("unexpected end of file"))
+    );
+    assert!(matches!(
+        Srs::<Bn254Snarks>::from_ptau_file("does/not/exist.ptau"),
+        Err(SrsError::MissingOrInaccessibleFile { .. })
+    ));
+}
+
+#[test]
+fn t_halo2_params() {
+    let g1 = hex::decode(BN254_G1_MONT_LE).unwrap();
+    let g2 = hex::decode(BN254_G2_MONT_LE).unwrap();
+
+    // k = 1 and τ = 1
+    let mut params = Vec::new();
+    params.extend_from_slice(&1u32.to_le_bytes());
+    params.extend_from_slice(&g1.repeat(2)); // g
+    params.extend_from_slice(&g1); // g_lagrange
+    params.extend_from_slice(&[0u8; 64]); // point at infinity
+    params.extend_from_slice(&g2.repeat(2)); // g2, s_g2
+
+    let srs = Srs::<Bn254Snarks>::from_halo2_params(&params).unwrap();
+    assert_eq!(srs.g1_monomial.len(), 2);
+    assert_eq!(srs.g1_lagrange.len(), 2);
+    assert!(g1_is_neutral(&srs.g1_lagrange[1]));
+
+    let tp = Threadpool::new(2);
+    assert_eq!(srs.validate(&tp), Ok(()));
+
+    let mut recomputed = srs.clone();
+    recomputed.compute_lagrange(2).unwrap();
+    for (p, q) in recomputed.g1_lagrange.iter().zip(srs.g1_lagrange.iter()) {
+        assert!(Bn254Snarks::g1_is_eq(p, q));
+    }
+
+    let kzg = srs.into_kzg().unwrap();
+    let poly = [bn254_one(), bn254_one()];
+    let z = Bn254Snarks::fr_from_be_bytes(&[42]).unwrap();
+    let commitment = kzg.commit(&poly).unwrap();
+    let (proof, y) = kzg.open(&poly, &z).unwrap();
+    assert!(kzg.verify(&commitment, &z, &y, &proof));
+
+    params.push(0);
+    assert_eq!(
+        Srs::<Bn254Snarks>::from_halo2_params(&params).err(),
+        Some(SrsError::InvalidFormat("trailing bytes"))
+    );
+}
+
+#[test]
+fn t_lagrange_sums_to_generator() {
+    // ∑ Lᵢ(τ) = 1
+    let tau =
+        Bn254Snarks::fr_from_be_bytes(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0]).unwrap();
+    let kzg = Kzg::<Bn254Snarks>::setup_insecure_for_testing(16, &tau);
+
+    let mut srs = Srs::<Bn254Snarks> {
+        g1_monomial: kzg.powers_of_tau_g1().to_vec(),
+        g1_lagrange: Vec::new(),
+        g2_monomial: Vec::new(),
+    };
+    srs.compute_lagrange(16).unwrap();
+
+    let lagrange = Kzg::<Bn254Snarks>::new(srs.g1_lagrange.clone(), *kzg.tau_g2());
+    let ones = vec![bn254_one(); 16];
+    assert!(Bn254Snarks::g1_is_eq(
+        &lagrange.commit(&ones).unwrap(),
+        &srs.g1_monomial[0]
+    ));
+}
+
+/// Convert the c-kzg-4844 text setup to the Ethereum trusted_setup.json format
+fn eth_setup_json() -> String {
+    let txt = fs::read_to_string(ETH_TRUSTED_SETUP).unwrap();
+    let mut lines = txt.lines();
+    let n_g1: usize = lines.next().unwrap().trim().parse().unwrap();
+    let n_g2: usize = lines.next().unwrap().trim().parse().unwrap();
+    let g1: Vec<String> = lines
+        .by_ref()
+        .take(n_g1)
+        .map(|l| format!("\"0x{}\"", l.trim()))
+        .collect();
+    let g2: Vec<String> = lines
+        .by_ref()
+        .take(n_g2)
+        .map(|l| format!("\"0x{}\"", l.trim()))
+        .collect();
+    format!(
+        "{{\"g1_lagrange\": [{}], \"g2_monomial\": [{}]}}",
+        g1.join(","),
+        g2.join(",")
+    )
+}
+
+#[test]
+fn t_ethereum_setup_json() {
+    let srs = Srs::<Bls12_381>::from_ethereum_setup_json(&eth_setup_json()).unwrap();
+    assert_eq!(srs.g1_lagrange.len(), 4096);
+    assert_eq!(srs.g2_monomial.len(), 65);
+    assert!(srs.g1_monomial.is_empty());
+
+    let tp = Threadpool::new(4);
+    assert_eq!(srs.validate(&tp), Ok(()));
+
+    // No monomial basis
+    assert_eq!(
+        srs.into_kzg().err(),
+        Some(SrsError::Missing("G1 monomial powers"))
+    );
+
+    assert!(matches!(
+        Srs::<Bls12_381>::from_ethereum_setup_json("{\"g1_lagrange\": []}"),
+        Err(SrsError::InvalidJson(_))
+    ));
+    assert_eq!(
+        Srs::<Bls12_381>::from_ethereum_setup_json(
+            "{\"g1_lagrange\": [\"0x00\"], \"g2_monomial\": []}"
+        )
+        .err(),
+        Some(SrsError::InvalidG1Point { index: 0 })
+    );
+}
+
+#[test]
+fn t_ethereum_setup_json_lagrange_order() {
+    // The Ethereum Lagrange basis is bit-reversal permuted:
+    // committing to the evaluations of p(X) = X at ω^brp(i) gives [τ]₁,
+    // which opens at z to z with the proof [1]₁.
+    let srs = Srs::<Bls12_381>::from_ethereum_setup_json(&eth_setup_json()).unwrap();
+    let n = srs.g1_lagrange.len();
+    let log_n = n.trailing_zeros();
+    let omega = Bls12_381::root_of_unity(n).unwrap();
+
+    let mut powers = Vec::with_capacity(n);
+    powers.push(Bls12_381::fr_from_be_bytes(&[1]).unwrap());
+    for i in 1..n {
+        let mut p = MaybeUninit::<bls12_381_fr>::uninit();
+        unsafe {
+            ctt_bls12_381_fr_prod(p.as_mut_ptr(), &powers[i - 1], &omega);
+            powers.push(p.assume_init());
+        }
+    }
+    let bit_reversed: Vec<_> = (0..n)
+        .map(|i| powers[i.reverse_bits() >> (usize::BITS - log_n)])
+        .collect();
+
+    let lagrange = Kzg::<Bls12_381>::new(srs.g1_lagrange.clone(), srs.g2_monomial[1]);
+    let generator = lagrange.commit(&vec![powers[0]; n]).unwrap();
+    let z = Bls12_381::fr_from_be_bytes(&[42]).unwrap();
+
+    let tau = lagrange.commit(&bit_reversed).unwrap();
+    assert!(lagrange.verify(&tau, &z, &z, &generator));
+
+    // Natural order does not match
+    let not_tau = lagrange.commit(&powers).unwrap();
+    assert!(!lagrange.verify(&not_tau, &z, &z, &generator));
+}
+
+#[test]
+fn t_ethereum_transcript_json() {
+    let setup = Srs::<Bls12_381>::from_ethereum_setup_json(&eth_setup_json()).unwrap();
+
+    let txt = fs::read_to_string(ETH_TRUSTED_SETUP).unwrap();
+    let points: Vec<String> = txt
+        .lines()
+        .skip(2)
+        .map(|l| format!("\"0x{}\"", l.trim()))
+        .collect();
+    let g1 = points[..4].join(",");
+    let g2 = points[4096..4096 + 3].join(",");
+    let transcript = format!(
+        "{{\"transcripts\": [{{\"numG1Powers\": 4, \"numG2Powers\": 3, \
+           \"powersOfTau\": {{\"G1Powers\": [{}], \"G2Powers\": [{}]}}, \
+           \"witness\": {{}}}}], \"participantIds\": []}}",
+        g1, g2
+    );
+
+    let srs = Srs::<Bls12_381>::from_ethereum_transcript_json(&transcript, 4).unwrap();
+    assert_eq!(srs.g1_monomial.len(), 4);
+    assert_eq!(srs.g2_monomial.len(), 3);
+    for (p, q) in srs.g1_monomial.iter().zip(setup.g1_lagrange.iter()) {
+        assert!(Bls12_381::g1_is_eq(p, q));
+    }
+
+    assert_eq!(
+        Srs::<Bls12_381>::from_ethereum_transcript_json(&transcript, 8).err(),
+        Some(SrsError::Missing(
+            "transcript with the requested number of G1 powers"
+        ))
+    );
+}
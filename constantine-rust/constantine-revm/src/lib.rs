@@ -0,0 +1,210 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Constantine EVM precompiles for revm
+//!
+//! ```ignore
+//! let ctx = KzgContext::load_trusted_setup(Path::new(SRS_PATH))?;
+//! let precompiles = precompiles(PrecompileSpecId::CANCUN, Some(Arc::new(ctx)));
+//! ```
+//!
+//! BLS12-381 precompiles follow the final EIP-2537 addresses and gas schedule,
+//! they are not interchangeable with the draft implementation of revm.
+
+use constantine_ethereum_evm_precompiles as ctt;
+use constantine_ethereum_evm_precompiles::{evm_sha256, EthKzgContext, Hardfork};
+use constantine_ethereum_kzg::KzgError;
+use constantine_sys::ctt_evm_status;
+
+use revm_precompile::{
+    primitives::Env, Address, PrecompileError, PrecompileErrors, PrecompileOutput,
+    PrecompileResult, PrecompileSpecId, PrecompileWithAddress, Precompiles, StatefulPrecompile,
+};
+
+use std::path::Path;
+use std::sync::Arc;
+
+// Reexport
+pub use constantine_ethereum_evm_precompiles::Precompile;
+
+/// Ethereum KZG context shareable between the threads running the EVM.
+///
+/// The context is immutable once the trusted setup is loaded
+/// and is never attached to a threadpool.
+/// It can only be created with `load_trusted_setup`.
+pub struct KzgContext(EthKzgContext<'static>);
+
+// SAFETY: the only constructor is `load_trusted_setup`, which creates a context
+// without threadpool, and the inner context is never exposed mutably.
+// The trusted setup is owned by the context, only freed on drop,
+// and only read by the serial verification procedures,
+// so it can be moved to and verified from any thread.
+// A context with a threadpool is not Send or Sync:
+// a Constantine threadpool must only be used from the thread that created it.
+unsafe impl Send for KzgContext {}
+// SAFETY: see `Send`, shared references only give access to read-only serial verification.
+unsafe impl Sync for KzgContext {}
+
+impl KzgContext {
+    /// Load the Ethereum mainnet trusted setup
+    pub fn load_trusted_setup(file_path: &Path) -> Result<Self, KzgError> {
+        Ok(Self(EthKzgContext::load_trusted_setup(file_path)?))
+    }
+}
+
+/// Hardfork pricing the precompiles of a revm spec
+pub fn hardfork(spec: PrecompileSpecId) -> Hardfork {
+    match spec {
+        PrecompileSpecId::HOMESTEAD => Hardfork::Homestead,
+        PrecompileSpecId::BYZANTIUM => Hardfork::Byzantium,
+        PrecompileSpecId::ISTANBUL => Hardfork::Istanbul,
+        PrecompileSpecId::BERLIN => Hardfork::Berlin,
+        PrecompileSpecId::CANCUN => Hardfork::Cancun,
+        PrecompileSpecId::PRAGUE | PrecompileSpecId::LATEST => Hardfork::Prague,
+    }
+}
+
+/// A Constantine precompile priced at a hardfork
+pub struct ConstantinePrecompile {
+    precompile: Precompile,
+    fork: Hardfork,
+    kzg_ctx: Option<Arc<KzgContext>>,
+}
+
+impl ConstantinePrecompile {
+    /// KZG point evaluation must be created with `new_kzg_point_evaluation`.
+    pub fn new(precompile: Precompile, fork: Hardfork) -> Self {
+        Self {
+            precompile,
+            fork,
+            kzg_ctx: None,
+        }
+    }
+
+    pub fn new_kzg_point_evaluation(ctx: Arc<KzgContext>, fork: Hardfork) -> Self {
+        Self {
+            precompile: Precompile::KzgPointEvaluation,
+            fork,
+            kzg_ctx: Some(ctx),
+        }
+    }
+
+    pub fn precompile(&self) -> Precompile {
+        self.precompile
+    }
+
+    /// Wrap with the precompile address for `Precompiles::extend`.
+    /// Returns `None` for precompiles without an address.
+    pub fn with_address(self) -> Option<PrecompileWithAddress> {
+        let address = Address::new(self.precompile.address()?);
+        Some(PrecompileWithAddress(
+            address,
+            revm_precompile::Precompile::new_stateful(self),
+        ))
+    }
+}
+
+impl StatefulPrecompile for ConstantinePrecompile {
+    fn call(&self, bytes: &revm_precompile::Bytes, gas_limit: u64, _env: &Env) -> PrecompileResult {
+        let mut registry = ctt::Precompiles::new(self.fork);
+        if let Some(ctx) = &self.kzg_ctx {
+            registry = registry.set_kzg_context(&ctx.0);
+        }
+        // Precompiles without an address cannot be registered in revm
+        let address = self
+            .precompile
+            .address()
+            .ok_or_else(|| PrecompileErrors::Fatal {
+                msg: format!("{:?} has no address", self.precompile),
+            })?;
+
+        match registry.call(&address, bytes, gas_limit) {
+            Ok(output) => Ok(PrecompileOutput::new(output.gas_used, output.bytes.into())),
+            Err(ctt::PrecompileError::OutOfGas { .. }) => Err(PrecompileError::OutOfGas.into()),
+            Err(ctt::PrecompileError::Failure(failure)) => {
+                Err(revm_error(self.precompile, failure.status, bytes).into())
+            }
+            // Configuration errors, the transaction is not at fault
+            Err(err @ ctt::PrecompileError::NotAPrecompile(_))
+            | Err(err @ ctt::PrecompileError::KzgContextNotSet) => Err(PrecompileErrors::Fatal {
+                msg: err.to_string(),
+            }),
+        }
+    }
+}
+
+/// Map a Constantine status to the error revm reports for the same input
+fn revm_error(precompile: Precompile, status: ctt_evm_status, input: &[u8]) -> PrecompileError {
+    use ctt_evm_status::*;
+    match (precompile, status) {
+        (Precompile::Blake2f, cttEVM_InvalidInputSize) => PrecompileError::Blake2WrongLength,
+        (Precompile::Blake2f, cttEVM_InvalidFinalFlag) => {
+            PrecompileError::Blake2WrongFinalIndicatorFlag
+        }
+
+        (Precompile::Bn254PairingCheck, cttEVM_InvalidInputSize) => {
+            PrecompileError::Bn128PairLength
+        }
+        (
+            Precompile::Bn254G1Add | Precompile::Bn254G1Mul | Precompile::Bn254PairingCheck,
+            cttEVM_IntLargerThanModulus,
+        ) => PrecompileError::Bn128FieldPointNotAMember,
+        (
+            Precompile::Bn254G1Add | Precompile::Bn254G1Mul | Precompile::Bn254PairingCheck,
+            cttEVM_PointNotOnCurve | cttEVM_PointNotInSubgroup,
+        ) => PrecompileError::Bn128AffineGFailedToCreate,
+
+        (Precompile::KzgPointEvaluation, cttEVM_InvalidInputSize) => {
+            PrecompileError::BlobInvalidInputLength
+        }
+        // Constantine does not distinguish a versioned hash mismatch from an invalid proof
+        (Precompile::KzgPointEvaluation, _) => {
+            if versioned_hash_matches(input) {
+                PrecompileError::BlobVerifyKzgProofFailed
+            } else {
+                PrecompileError::BlobMismatchedVersion
+            }
+        }
+
+        _ => PrecompileError::other(status.to_string()),
+    }
+}
+
+/// Check `versioned_hash == 0x01 || sha256(commitment)[1..]` for a 192-byte point evaluation input.
+/// Returns false for inputs too short to hold a versioned hash and a commitment.
+fn versioned_hash_matches(input: &[u8]) -> bool {
+    let (Some(versioned_hash), Some(commitment)) = (input.get(..32), input.get(96..144)) else {
+        return false;
+    };
+    let Ok(mut hash) = evm_sha256(commitment) else {
+        return false;
+    };
+    hash[0] = 0x01;
+    hash == versioned_hash
+}
+
+/// Precompiles active at `spec`, implemented by Constantine.
+///
+/// KZG point evaluation is only included if a context is given,
+/// like revm without the `c-kzg` feature.
+pub fn precompiles(spec: PrecompileSpecId, kzg_ctx: Option<Arc<KzgContext>>) -> Precompiles {
+    let fork = hardfork(spec);
+    let mut precompiles = Precompiles::default();
+    precompiles.extend(
+        ctt::Precompiles::new(fork)
+            .active()
+            .filter_map(|precompile| match precompile {
+                Precompile::KzgPointEvaluation => kzg_ctx
+                    .clone()
+                    .map(|ctx| ConstantinePrecompile::new_kzg_point_evaluation(ctx, fork)),
+                _ => Some(ConstantinePrecompile::new(precompile, fork)),
+            })
+            .filter_map(ConstantinePrecompile::with_address),
+    );
+    precompiles
+}
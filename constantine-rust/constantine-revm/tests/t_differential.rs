@@ -0,0 +1,192 @@
+//! Constantine
+//! Copyright (c) 2018-2019    Status Research & Development GmbH
+//! Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+//! Licensed and distributed under either of
+//!   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+//!   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+//! at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+//! Differential tests against the precompiles built in revm.
+//!
+//! revm implements draft EIP-2537 addresses and gas, BLS12-381 precompiles are not compared.
+
+use constantine_revm::*;
+
+use revm_precompile::{
+    primitives::Env, u64_to_address, Bytes, PrecompileError, PrecompileErrors, PrecompileResult,
+    PrecompileSpecId, Precompiles,
+};
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use hex;
+use serde::Deserialize;
+use serde_json;
+
+macro_rules! test_dir {
+    () => {
+        "../../tests/protocol_ethereum_evm_precompiles/"
+    };
+}
+
+const SRS_PATH: &str =
+    "../../constantine/commitments_setups/trusted_setup_ethereum_kzg4844_reference.dat";
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Debug)]
+struct PrecompileTest {
+    Input: String,
+    Name: String,
+}
+
+fn load_inputs(test_file: &str) -> Vec<(String, Bytes)> {
+    let unparsed = fs::read_to_string(test_file).unwrap();
+    let vectors: Vec<PrecompileTest> = serde_json::from_str(&unparsed).unwrap();
+    vectors
+        .into_iter()
+        .map(|v| {
+            let input = hex::decode(v.Input.trim_start_matches("0x")).unwrap();
+            (v.Name, input.into())
+        })
+        .collect()
+}
+
+fn assert_same_result(name: &str, ctt: PrecompileResult, revm: PrecompileResult) {
+    match (ctt, revm) {
+        (Ok(ctt), Ok(revm)) => assert_eq!(ctt, revm, "{}", name),
+        // BLS12-381 errors of revm are free-form strings
+        (Err(_), Err(PrecompileErrors::Error(PrecompileError::Other(_)))) => {}
+        (Err(ctt), Err(revm)) => assert_eq!(ctt, revm, "{}", name),
+        (ctt, revm) => panic!("{}: Constantine {:?}, revm {:?}", name, ctt, revm),
+    }
+}
+
+/// Run the inputs of `test_file` through Constantine and revm
+/// with an unlimited gas limit, a gas limit one short of the cost,
+/// and with the last byte of the input truncated.
+fn t_differential(
+    spec: PrecompileSpecId,
+    kzg_ctx: Option<Arc<KzgContext>>,
+    id: u64,
+    test_file: &str,
+) {
+    let address = u64_to_address(id);
+    let ctt_precompiles = precompiles(spec, kzg_ctx);
+    let ctt = ctt_precompiles.get(&address).unwrap();
+    let revm = Precompiles::new(spec).get(&address).unwrap();
+    let env = Env::default();
+
+    for (name, input) in load_inputs(test_file) {
+        println!("Running test case: {} at {:?}", name, spec);
+
+        let ctt_result = ctt.call_ref(&input, u64::MAX, &env);
+        let revm_result = revm.call_ref(&input, u64::MAX, &env);
+        let gas_used = ctt_result.as_ref().map(|output| output.gas_used).ok();
+        assert_same_result(&name, ctt_result, revm_result);
+
+        if let Some(gas) = gas_used.filter(|&gas| gas > 0) {
+            let ctt_result = ctt.call_ref(&input, gas - 1, &env);
+            assert_eq!(
+                ctt_result,
+                Err(PrecompileError::OutOfGas.into()),
+                "{}",
+                name
+            );
+            assert_same_result(&name, ctt_result, revm.call_ref(&input, gas - 1, &env));
+        }
+
+        if !input.is_empty() {
+            let truncated = input.slice(..input.len() - 1);
+            assert_same_result(
+                &name,
+                ctt.call_ref(&truncated, u64::MAX, &env),
+                revm.call_ref(&truncated, u64::MAX, &env),
+            );
+        }
+    }
+}
+
+#[test]
+fn t_ecrecover() {
+    let test_file = concat!(test_dir!(), "ecRecover.json");
+    t_differential(PrecompileSpecId::HOMESTEAD, None, 0x01, test_file);
+    t_differential(PrecompileSpecId::CANCUN, None, 0x01, test_file);
+}
+
+#[test]
+fn t_modexp() {
+    for test_file in [
+        concat!(test_dir!(), "modexp.json"),
+        concat!(test_dir!(), "modexp_eip2565.json"),
+    ] {
+        t_differential(PrecompileSpecId::BYZANTIUM, None, 0x05, test_file);
+        t_differential(PrecompileSpecId::BERLIN, None, 0x05, test_file);
+    }
+}
+
+#[test]
+fn t_bn254() {
+    for (id, test_file) in [
+        (0x06, concat!(test_dir!(), "bn256Add.json")),
+        (0x07, concat!(test_dir!(), "bn256ScalarMul.json")),
+        (0x08, concat!(test_dir!(), "bn256Pairing.json")),
+    ] {
+        t_differential(PrecompileSpecId::BYZANTIUM, None, id, test_file);
+        t_differential(PrecompileSpecId::ISTANBUL, None, id, test_file);
+    }
+}
+
+#[test]
+fn t_blake2f() {
+    for test_file in [
+        concat!(test_dir!(), "blake2F.json"),
+        concat!(test_dir!(), "fail-blake2f.json"),
+    ] {
+        t_differential(PrecompileSpecId::ISTANBUL, None, 0x09, test_file);
+    }
+}
+
+#[test]
+fn t_kzg_point_evaluation() {
+    let ctx = Arc::new(KzgContext::load_trusted_setup(Path::new(SRS_PATH)).unwrap());
+    let test_file = concat!(test_dir!(), "eip-4844/pointEvaluation.json");
+    t_differential(PrecompileSpecId::CANCUN, Some(ctx.clone()), 0x0a, test_file);
+
+    // Versioned hash mismatch
+    let address = u64_to_address(0x0a);
+    let ctt_precompiles = precompiles(PrecompileSpecId::CANCUN, Some(ctx));
+    let ctt = ctt_precompiles.get(&address).unwrap();
+    let env = Env::default();
+    for (name, input) in load_inputs(test_file) {
+        if input.len() != 192 {
+            continue;
+        }
+        let mut input = input.to_vec();
+        input[31] ^= 1;
+        let input = Bytes::from(input);
+        assert_eq!(
+            ctt.call_ref(&input, u64::MAX, &env),
+            Err(PrecompileError::BlobMismatchedVersion.into()),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn t_precompiles() {
+    let count = |spec| precompiles(spec, None).len();
+    assert_eq!(count(PrecompileSpecId::HOMESTEAD), 4);
+    assert_eq!(count(PrecompileSpecId::BYZANTIUM), 8);
+    assert_eq!(count(PrecompileSpecId::BERLIN), 9);
+    // KZG point evaluation requires a context
+    assert_eq!(count(PrecompileSpecId::CANCUN), 9);
+    assert_eq!(count(PrecompileSpecId::PRAGUE), 16);
+
+    // Same precompile addresses as revm up to Cancun
+    let ctx = Arc::new(KzgContext::load_trusted_setup(Path::new(SRS_PATH)).unwrap());
+    let ctt = precompiles(PrecompileSpecId::CANCUN, Some(ctx));
+    assert_eq!(ctt.addresses_set(), Precompiles::cancun().addresses_set());
+}
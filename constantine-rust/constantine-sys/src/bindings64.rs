@@ -37,6 +37,14 @@ unsafe extern "C" {
     #[doc = " Query the number of threads available at the OS-level\n  to run computations.\n\n  This takes into account cores disabled at the OS-level, for example in a VM.\n  However this doesn't detect restrictions based on time quotas often used for Docker\n  or taskset / cpuset restrictions from cgroups.\n\n  For Simultaneous-Multithreading (SMT often call HyperThreading),\n  this returns the number of available logical cores."]
     pub fn ctt_cpu_get_num_threads_os() -> ::core::ffi::c_int;
 }
+#[repr(u8)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum ctt_sha256_backend {
+    cttSha256_Generic = 0,
+    cttSha256_x86_SSSE3 = 1,
+    cttSha256_x86_SHA = 2,
+    cttSha256_ARM64_SHA2 = 3,
+}
 #[repr(C)]
 #[repr(align(64))]
 #[derive(Copy, Clone)]
@@ -85,6 +93,175 @@ unsafe extern "C" {
         clear_memory: bool,
     );
 }
+unsafe extern "C" {
+    #[doc = " Compute the SHA-256 hash of `len` 64-byte inputs\n  and store the results in digests.\n\n  This is the hash of Merkle tree inner nodes,\n  the constant padding block is not buffered."]
+    pub fn ctt_sha256_hash_64byte_pairs(
+        digests: *mut [byte; 32usize],
+        pairs: *const [byte; 64usize],
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    #[doc = " Compute the SHA-256 hash of `len` 64-byte inputs\n  and store the results in digests.\n\n  Parallelism: This only returns when computation is fully done"]
+    pub fn ctt_sha256_hash_64byte_pairs_parallel(
+        tp: *const ctt_threadpool,
+        digests: *mut [byte; 32usize],
+        pairs: *const [byte; 64usize],
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    #[doc = " Returns the SHA-256 implementation selected at runtime\n  depending on the CPU features."]
+    pub fn ctt_sha256_backend() -> ctt_sha256_backend;
+}
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Copy, Clone)]
+pub struct ctt_keccak256_context {
+    state: [u64; 25usize],
+    absorb_offset: i32,
+    squeeze_offset: i32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of ctt_keccak256_context"][::core::mem::size_of::<ctt_keccak256_context>() - 256usize];
+    ["Alignment of ctt_keccak256_context"]
+        [::core::mem::align_of::<ctt_keccak256_context>() - 64usize];
+    ["Offset of field: ctt_keccak256_context::state"]
+        [::core::mem::offset_of!(ctt_keccak256_context, state) - 0usize];
+    ["Offset of field: ctt_keccak256_context::absorb_offset"]
+        [::core::mem::offset_of!(ctt_keccak256_context, absorb_offset) - 200usize];
+    ["Offset of field: ctt_keccak256_context::squeeze_offset"]
+        [::core::mem::offset_of!(ctt_keccak256_context, squeeze_offset) - 204usize];
+};
+unsafe extern "C" {
+    #[doc = " Initialize or reinitialize a Keccak-256 context."]
+    pub fn ctt_keccak256_init(ctx: *mut ctt_keccak256_context);
+}
+unsafe extern "C" {
+    #[doc = " Append a message to a Keccak-256 context\n  for incremental Keccak-256 computation\n\n  Security note: the tail of your message might be stored\n  in an internal buffer.\n  if sensitive content is used, ensure that\n  `ctx.finish(...)` and `ctx.clear()` are called as soon as possible.\n  Additionally ensure that the message(s) passed were stored\n  in memory considered secure for your threat model."]
+    pub fn ctt_keccak256_update(
+        ctx: *mut ctt_keccak256_context,
+        message: *const byte,
+        message_len: usize,
+    );
+}
+unsafe extern "C" {
+    #[doc = " Finalize a Keccak-256 computation and output the\n  message digest to the `digest` buffer.\n\n  Security note: this does not clear the internal buffer.\n  if sensitive content is used, use \"ctx.clear()\"\n  and also make sure that the message(s) passed were stored\n  in memory considered secure for your threat model."]
+    pub fn ctt_keccak256_finish(ctx: *mut ctt_keccak256_context, digest: *mut byte);
+}
+unsafe extern "C" {
+    #[doc = " Clear the context internal buffers"]
+    pub fn ctt_keccak256_clear(ctx: *mut ctt_keccak256_context);
+}
+unsafe extern "C" {
+    #[doc = " Compute the Keccak-256 hash of message\n  and store the result in digest.\n  Optionally, clear the memory buffer used."]
+    pub fn ctt_keccak256_hash(
+        digest: *mut byte,
+        message: *const byte,
+        message_len: usize,
+        clear_memory: bool,
+    );
+}
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Copy, Clone)]
+pub struct ctt_sha3_256_context {
+    state: [u64; 25usize],
+    absorb_offset: i32,
+    squeeze_offset: i32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of ctt_sha3_256_context"][::core::mem::size_of::<ctt_sha3_256_context>() - 256usize];
+    ["Alignment of ctt_sha3_256_context"]
+        [::core::mem::align_of::<ctt_sha3_256_context>() - 64usize];
+    ["Offset of field: ctt_sha3_256_context::state"]
+        [::core::mem::offset_of!(ctt_sha3_256_context, state) - 0usize];
+    ["Offset of field: ctt_sha3_256_context::absorb_offset"]
+        [::core::mem::offset_of!(ctt_sha3_256_context, absorb_offset) - 200usize];
+    ["Offset of field: ctt_sha3_256_context::squeeze_offset"]
+        [::core::mem::offset_of!(ctt_sha3_256_context, squeeze_offset) - 204usize];
+};
+unsafe extern "C" {
+    #[doc = " Initialize or reinitialize a SHA3-256 context."]
+    pub fn ctt_sha3_256_init(ctx: *mut ctt_sha3_256_context);
+}
+unsafe extern "C" {
+    #[doc = " Append a message to a SHA3-256 context\n  for incremental SHA3-256 computation\n\n  Security note: the tail of your message might be stored\n  in an internal buffer.\n  if sensitive content is used, ensure that\n  `ctx.finish(...)` and `ctx.clear()` are called as soon as possible.\n  Additionally ensure that the message(s) passed were stored\n  in memory considered secure for your threat model."]
+    pub fn ctt_sha3_256_update(
+        ctx: *mut ctt_sha3_256_context,
+        message: *const byte,
+        message_len: usize,
+    );
+}
+unsafe extern "C" {
+    #[doc = " Finalize a SHA3-256 computation and output the\n  message digest to the `digest` buffer.\n\n  Security note: this does not clear the internal buffer.\n  if sensitive content is used, use \"ctx.clear()\"\n  and also make sure that the message(s) passed were stored\n  in memory considered secure for your threat model."]
+    pub fn ctt_sha3_256_finish(ctx: *mut ctt_sha3_256_context, digest: *mut byte);
+}
+unsafe extern "C" {
+    #[doc = " Clear the context internal buffers"]
+    pub fn ctt_sha3_256_clear(ctx: *mut ctt_sha3_256_context);
+}
+unsafe extern "C" {
+    #[doc = " Compute the SHA3-256 hash of message\n  and store the result in digest.\n  Optionally, clear the memory buffer used."]
+    pub fn ctt_sha3_256_hash(
+        digest: *mut byte,
+        message: *const byte,
+        message_len: usize,
+        clear_memory: bool,
+    );
+}
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Copy, Clone)]
+pub struct ctt_ripemd160_context {
+    state: [u32; 5usize],
+    __bindgen_padding_0: [u32; 11usize],
+    buf: [byte; 64usize],
+    msgLen: u64,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of ctt_ripemd160_context"][::core::mem::size_of::<ctt_ripemd160_context>() - 192usize];
+    ["Alignment of ctt_ripemd160_context"]
+        [::core::mem::align_of::<ctt_ripemd160_context>() - 64usize];
+    ["Offset of field: ctt_ripemd160_context::state"]
+        [::core::mem::offset_of!(ctt_ripemd160_context, state) - 0usize];
+    ["Offset of field: ctt_ripemd160_context::buf"]
+        [::core::mem::offset_of!(ctt_ripemd160_context, buf) - 64usize];
+    ["Offset of field: ctt_ripemd160_context::msgLen"]
+        [::core::mem::offset_of!(ctt_ripemd160_context, msgLen) - 128usize];
+};
+unsafe extern "C" {
+    #[doc = " Initialize or reinitialize a RIPEMD-160 context."]
+    pub fn ctt_ripemd160_init(ctx: *mut ctt_ripemd160_context);
+}
+unsafe extern "C" {
+    #[doc = " Append a message to a RIPEMD-160 context\n  for incremental RIPEMD-160 computation\n\n  Security note: the tail of your message might be stored\n  in an internal buffer.\n  if sensitive content is used, ensure that\n  `ctx.finish(...)` and `ctx.clear()` are called as soon as possible.\n  Additionally ensure that the message(s) passed were stored\n  in memory considered secure for your threat model."]
+    pub fn ctt_ripemd160_update(
+        ctx: *mut ctt_ripemd160_context,
+        message: *const byte,
+        message_len: usize,
+    );
+}
+unsafe extern "C" {
+    #[doc = " Finalize a RIPEMD-160 computation and output the\n  message digest to the `digest` buffer.\n\n  Security note: this does not clear the internal buffer.\n  if sensitive content is used, use \"ctx.clear()\"\n  and also make sure that the message(s) passed were stored\n  in memory considered secure for your threat model."]
+    pub fn ctt_ripemd160_finish(ctx: *mut ctt_ripemd160_context, digest: *mut byte);
+}
+unsafe extern "C" {
+    #[doc = " Clear the context internal buffers"]
+    pub fn ctt_ripemd160_clear(ctx: *mut ctt_ripemd160_context);
+}
+unsafe extern "C" {
+    #[doc = " Compute the RIPEMD-160 hash of message\n  and store the result in digest.\n  Optionally, clear the memory buffer used."]
+    pub fn ctt_ripemd160_hash(
+        digest: *mut byte,
+        message: *const byte,
+        message_len: usize,
+        clear_memory: bool,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_csprng_sysrand(buffer: *mut ::core::ffi::c_void, len: usize) -> bool;
 }
@@ -293,6 +470,34 @@ const _: () = {
     ["Offset of field: bls12_381_g2_prj::z"]
         [::core::mem::offset_of!(bls12_381_g2_prj, z) - 192usize];
 };
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bls12_381_g1_fixed_base_table {
+    points: *mut bls12_381_g1_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of bls12_381_g1_fixed_base_table"]
+        [::core::mem::size_of::<bls12_381_g1_fixed_base_table>() - 8usize];
+    ["Alignment of bls12_381_g1_fixed_base_table"]
+        [::core::mem::align_of::<bls12_381_g1_fixed_base_table>() - 8usize];
+    ["Offset of field: bls12_381_g1_fixed_base_table::points"]
+        [::core::mem::offset_of!(bls12_381_g1_fixed_base_table, points) - 0usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bls12_381_g2_fixed_base_table {
+    points: *mut bls12_381_g2_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of bls12_381_g2_fixed_base_table"]
+        [::core::mem::size_of::<bls12_381_g2_fixed_base_table>() - 8usize];
+    ["Alignment of bls12_381_g2_fixed_base_table"]
+        [::core::mem::align_of::<bls12_381_g2_fixed_base_table>() - 8usize];
+    ["Offset of field: bls12_381_g2_fixed_base_table::points"]
+        [::core::mem::offset_of!(bls12_381_g2_fixed_base_table, points) - 0usize];
+};
 unsafe extern "C" {
     pub fn ctt_big255_from_bls12_381_fr(dst: *mut big255, src: *const bls12_381_fr);
 }
@@ -839,6 +1044,22 @@ unsafe extern "C" {
         scalar: *const bls12_381_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_big_coefs(
+        r: *mut bls12_381_g1_jac,
+        coefs: *const big255,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs(
+        r: *mut bls12_381_g1_jac,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_big_coefs_vartime(
         r: *mut bls12_381_g1_jac,
@@ -855,6 +1076,31 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut bls12_381_g1_jac,
+        coefs: *const u64,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut bls12_381_g1_jac,
+        coefs: *const i64,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut bls12_381_g1_jac,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bls12_381_g1_prj_is_eq(
         P: *const bls12_381_g1_prj,
@@ -946,6 +1192,22 @@ unsafe extern "C" {
         scalar: *const bls12_381_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_big_coefs(
+        r: *mut bls12_381_g1_prj,
+        coefs: *const big255,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs(
+        r: *mut bls12_381_g1_prj,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_big_coefs_vartime(
         r: *mut bls12_381_g1_prj,
@@ -962,6 +1224,73 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut bls12_381_g1_prj,
+        coefs: *const u64,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut bls12_381_g1_prj,
+        coefs: *const i64,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut bls12_381_g1_prj,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_fixed_base_table_precompute(
+        table: *mut bls12_381_g1_fixed_base_table,
+        base: *const bls12_381_g1_aff,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_fixed_base_table_precompute_generator(
+        table: *mut bls12_381_g1_fixed_base_table,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_fixed_base_table_delete(table: *mut bls12_381_g1_fixed_base_table);
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_jac_fixed_base_scalar_mul_big_coef(
+        r: *mut bls12_381_g1_jac,
+        table: *const bls12_381_g1_fixed_base_table,
+        scalar: *const big255,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_jac_fixed_base_scalar_mul_fr_coef(
+        r: *mut bls12_381_g1_jac,
+        table: *const bls12_381_g1_fixed_base_table,
+        scalar: *const bls12_381_fr,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_fixed_base_scalar_mul_big_coef(
+        r: *mut bls12_381_g1_prj,
+        table: *const bls12_381_g1_fixed_base_table,
+        scalar: *const big255,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_fixed_base_scalar_mul_fr_coef(
+        r: *mut bls12_381_g1_prj,
+        table: *const bls12_381_g1_fixed_base_table,
+        scalar: *const bls12_381_fr,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bls12_381_g2_aff_is_eq(
         P: *const bls12_381_g2_aff,
@@ -1084,6 +1413,22 @@ unsafe extern "C" {
         scalar: *const bls12_381_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_big_coefs(
+        r: *mut bls12_381_g2_jac,
+        coefs: *const big255,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs(
+        r: *mut bls12_381_g2_jac,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_big_coefs_vartime(
         r: *mut bls12_381_g2_jac,
@@ -1100,6 +1445,31 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut bls12_381_g2_jac,
+        coefs: *const u64,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut bls12_381_g2_jac,
+        coefs: *const i64,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut bls12_381_g2_jac,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bls12_381_g2_prj_is_eq(
         P: *const bls12_381_g2_prj,
@@ -1192,7 +1562,7 @@ unsafe extern "C" {
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_big_coefs_vartime(
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_big_coefs(
         r: *mut bls12_381_g2_prj,
         coefs: *const big255,
         points: *const bls12_381_g2_aff,
@@ -1200,7 +1570,7 @@ unsafe extern "C" {
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_vartime(
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs(
         r: *mut bls12_381_g2_prj,
         coefs: *const bls12_381_fr,
         points: *const bls12_381_g2_aff,
@@ -1208,25 +1578,108 @@ unsafe extern "C" {
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bls12_381_g1_aff_sswu_sha256(
-        r: *mut bls12_381_g1_aff,
-        augmentation: *const byte,
-        augmentation_len: usize,
-        message: *const byte,
-        message_len: usize,
-        domainSepTag: *const byte,
-        domainSepTag_len: usize,
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_big_coefs_vartime(
+        r: *mut bls12_381_g2_prj,
+        coefs: *const big255,
+        points: *const bls12_381_g2_aff,
+        len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bls12_381_g1_jac_sswu_sha256(
-        r: *mut bls12_381_g1_jac,
-        augmentation: *const byte,
-        augmentation_len: usize,
-        message: *const byte,
-        message_len: usize,
-        domainSepTag: *const byte,
-        domainSepTag_len: usize,
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_vartime(
+        r: *mut bls12_381_g2_prj,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut bls12_381_g2_prj,
+        coefs: *const u64,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut bls12_381_g2_prj,
+        coefs: *const i64,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut bls12_381_g2_prj,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_fixed_base_table_precompute(
+        table: *mut bls12_381_g2_fixed_base_table,
+        base: *const bls12_381_g2_aff,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_fixed_base_table_precompute_generator(
+        table: *mut bls12_381_g2_fixed_base_table,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_fixed_base_table_delete(table: *mut bls12_381_g2_fixed_base_table);
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_fixed_base_scalar_mul_big_coef(
+        r: *mut bls12_381_g2_jac,
+        table: *const bls12_381_g2_fixed_base_table,
+        scalar: *const big255,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_fixed_base_scalar_mul_fr_coef(
+        r: *mut bls12_381_g2_jac,
+        table: *const bls12_381_g2_fixed_base_table,
+        scalar: *const bls12_381_fr,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_fixed_base_scalar_mul_big_coef(
+        r: *mut bls12_381_g2_prj,
+        table: *const bls12_381_g2_fixed_base_table,
+        scalar: *const big255,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_fixed_base_scalar_mul_fr_coef(
+        r: *mut bls12_381_g2_prj,
+        table: *const bls12_381_g2_fixed_base_table,
+        scalar: *const bls12_381_fr,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_aff_sswu_sha256(
+        r: *mut bls12_381_g1_aff,
+        augmentation: *const byte,
+        augmentation_len: usize,
+        message: *const byte,
+        message_len: usize,
+        domainSepTag: *const byte,
+        domainSepTag_len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_jac_sswu_sha256(
+        r: *mut bls12_381_g1_jac,
+        augmentation: *const byte,
+        augmentation_len: usize,
+        message: *const byte,
+        message_len: usize,
+        domainSepTag: *const byte,
+        domainSepTag_len: usize,
     );
 }
 unsafe extern "C" {
@@ -1273,6 +1726,79 @@ unsafe extern "C" {
         domainSepTag_len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bls12_381_kzg_commit_vartime(
+        commitment: *mut bls12_381_g1_aff,
+        poly: *const bls12_381_fr,
+        powers_of_tau_g1: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_kzg_prove_monomial_vartime(
+        proof: *mut bls12_381_g1_aff,
+        eval_at_challenge: *mut bls12_381_fr,
+        poly: *const bls12_381_fr,
+        powers_of_tau_g1: *const bls12_381_g1_aff,
+        len: usize,
+        opening_challenge: *const bls12_381_fr,
+    );
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bls12_381_kzg_verify_vartime(
+        commitment: *const bls12_381_g1_aff,
+        opening_challenge: *const bls12_381_fr,
+        eval_at_challenge: *const bls12_381_fr,
+        proof: *const bls12_381_g1_aff,
+        tau_g2: *const bls12_381_g2_aff,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bls12_381_kzg_verify_batch_vartime(
+        commitments: *const bls12_381_g1_aff,
+        challenges: *const bls12_381_fr,
+        evals_at_challenges: *const bls12_381_fr,
+        proofs: *const bls12_381_g1_aff,
+        linear_indep_random_numbers: *const bls12_381_fr,
+        len: usize,
+        tau_g2: *const bls12_381_g2_aff,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_kzg_setup_insecure_for_testing(
+        powers_of_tau_g1: *const bls12_381_g1_aff,
+        len: usize,
+        tau_g2: *mut bls12_381_g2_aff,
+        tau: *const bls12_381_fr,
+    );
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bls12_381_srs_g1_from_montgomery_le_unchecked(
+        dst: *const bls12_381_g1_aff,
+        src: *const byte,
+        len: usize,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bls12_381_srs_g2_from_montgomery_le_unchecked(
+        dst: *const bls12_381_g2_aff,
+        src: *const byte,
+        len: usize,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bls12_381_srs_monomial_to_lagrange_vartime(
+        lagrange_g1: *const bls12_381_g1_aff,
+        powers_of_tau_g1: *const bls12_381_g1_aff,
+        len: usize,
+        root_of_unity: *const bls12_381_fr,
+    ) -> bool;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct bn254_snarks_fr {
@@ -1410,6 +1936,34 @@ const _: () = {
     ["Offset of field: bn254_snarks_g2_prj::z"]
         [::core::mem::offset_of!(bn254_snarks_g2_prj, z) - 128usize];
 };
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bn254_snarks_g1_fixed_base_table {
+    points: *mut bn254_snarks_g1_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of bn254_snarks_g1_fixed_base_table"]
+        [::core::mem::size_of::<bn254_snarks_g1_fixed_base_table>() - 8usize];
+    ["Alignment of bn254_snarks_g1_fixed_base_table"]
+        [::core::mem::align_of::<bn254_snarks_g1_fixed_base_table>() - 8usize];
+    ["Offset of field: bn254_snarks_g1_fixed_base_table::points"]
+        [::core::mem::offset_of!(bn254_snarks_g1_fixed_base_table, points) - 0usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bn254_snarks_g2_fixed_base_table {
+    points: *mut bn254_snarks_g2_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of bn254_snarks_g2_fixed_base_table"]
+        [::core::mem::size_of::<bn254_snarks_g2_fixed_base_table>() - 8usize];
+    ["Alignment of bn254_snarks_g2_fixed_base_table"]
+        [::core::mem::align_of::<bn254_snarks_g2_fixed_base_table>() - 8usize];
+    ["Offset of field: bn254_snarks_g2_fixed_base_table::points"]
+        [::core::mem::offset_of!(bn254_snarks_g2_fixed_base_table, points) - 0usize];
+};
 unsafe extern "C" {
     pub fn ctt_big254_from_bn254_snarks_fr(dst: *mut big254, src: *const bn254_snarks_fr);
 }
@@ -1550,6 +2104,36 @@ unsafe extern "C" {
         ctl: secret_bool,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_fr_ntt_vartime(
+        values: *mut bn254_snarks_fr,
+        values_len: usize,
+        omega: *const bn254_snarks_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_fr_intt_vartime(
+        values: *mut bn254_snarks_fr,
+        values_len: usize,
+        omega: *const bn254_snarks_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_fr_coset_ntt_vartime(
+        values: *mut bn254_snarks_fr,
+        values_len: usize,
+        omega: *const bn254_snarks_fr,
+        shift: *const bn254_snarks_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_fr_coset_intt_vartime(
+        values: *mut bn254_snarks_fr,
+        values_len: usize,
+        omega: *const bn254_snarks_fr,
+        shift: *const bn254_snarks_fr,
+    ) -> bool;
+}
 unsafe extern "C" {
     pub fn ctt_big254_from_bn254_snarks_fp(dst: *mut big254, src: *const bn254_snarks_fp);
 }
@@ -1989,6 +2573,22 @@ unsafe extern "C" {
         scalar: *const bn254_snarks_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_big_coefs(
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const big254,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs(
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_big_coefs_vartime(
         r: *mut bn254_snarks_g1_jac,
@@ -2005,6 +2605,31 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const u64,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const i64,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bn254_snarks_g1_prj_is_eq(
         P: *const bn254_snarks_g1_prj,
@@ -2105,6 +2730,22 @@ unsafe extern "C" {
         scalar: *const bn254_snarks_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_big_coefs(
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const big254,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs(
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_big_coefs_vartime(
         r: *mut bn254_snarks_g1_prj,
@@ -2121,6 +2762,75 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const u64,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const i64,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_fixed_base_table_precompute(
+        table: *mut bn254_snarks_g1_fixed_base_table,
+        base: *const bn254_snarks_g1_aff,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_fixed_base_table_precompute_generator(
+        table: *mut bn254_snarks_g1_fixed_base_table,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_fixed_base_table_delete(
+        table: *mut bn254_snarks_g1_fixed_base_table,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_fixed_base_scalar_mul_big_coef(
+        r: *mut bn254_snarks_g1_jac,
+        table: *const bn254_snarks_g1_fixed_base_table,
+        scalar: *const big254,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_fixed_base_scalar_mul_fr_coef(
+        r: *mut bn254_snarks_g1_jac,
+        table: *const bn254_snarks_g1_fixed_base_table,
+        scalar: *const bn254_snarks_fr,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_fixed_base_scalar_mul_big_coef(
+        r: *mut bn254_snarks_g1_prj,
+        table: *const bn254_snarks_g1_fixed_base_table,
+        scalar: *const big254,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_fixed_base_scalar_mul_fr_coef(
+        r: *mut bn254_snarks_g1_prj,
+        table: *const bn254_snarks_g1_fixed_base_table,
+        scalar: *const bn254_snarks_fr,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bn254_snarks_g2_aff_is_eq(
         P: *const bn254_snarks_g2_aff,
@@ -2253,7 +2963,7 @@ unsafe extern "C" {
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_big_coefs_vartime(
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_big_coefs(
         r: *mut bn254_snarks_g2_jac,
         coefs: *const big254,
         points: *const bn254_snarks_g2_aff,
@@ -2261,7 +2971,7 @@ unsafe extern "C" {
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_vartime(
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs(
         r: *mut bn254_snarks_g2_jac,
         coefs: *const bn254_snarks_fr,
         points: *const bn254_snarks_g2_aff,
@@ -2269,26 +2979,67 @@ unsafe extern "C" {
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g2_prj_is_eq(
-        P: *const bn254_snarks_g2_prj,
-        Q: *const bn254_snarks_g2_prj,
-    ) -> secret_bool;
-}
-unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g2_prj_is_neutral(P: *const bn254_snarks_g2_prj) -> secret_bool;
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_big_coefs_vartime(
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const big254,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
 }
 unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g2_prj_set_neutral(P: *mut bn254_snarks_g2_prj);
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_vartime(
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
 }
 unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g2_prj_ccopy(
-        P: *mut bn254_snarks_g2_prj,
-        Q: *const bn254_snarks_g2_prj,
-        ctl: secret_bool,
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const u64,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g2_prj_neg(P: *mut bn254_snarks_g2_prj, Q: *const bn254_snarks_g2_prj);
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const i64,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_is_eq(
+        P: *const bn254_snarks_g2_prj,
+        Q: *const bn254_snarks_g2_prj,
+    ) -> secret_bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_is_neutral(P: *const bn254_snarks_g2_prj) -> secret_bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_set_neutral(P: *mut bn254_snarks_g2_prj);
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_ccopy(
+        P: *mut bn254_snarks_g2_prj,
+        Q: *const bn254_snarks_g2_prj,
+        ctl: secret_bool,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_neg(P: *mut bn254_snarks_g2_prj, Q: *const bn254_snarks_g2_prj);
 }
 unsafe extern "C" {
     pub fn ctt_bn254_snarks_g2_prj_neg_in_place(P: *mut bn254_snarks_g2_prj);
@@ -2368,6 +3119,22 @@ unsafe extern "C" {
         scalar: *const bn254_snarks_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_big_coefs(
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const big254,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs(
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_big_coefs_vartime(
         r: *mut bn254_snarks_g2_prj,
@@ -2384,6 +3151,75 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const u64,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const i64,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_fixed_base_table_precompute(
+        table: *mut bn254_snarks_g2_fixed_base_table,
+        base: *const bn254_snarks_g2_aff,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_fixed_base_table_precompute_generator(
+        table: *mut bn254_snarks_g2_fixed_base_table,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_fixed_base_table_delete(
+        table: *mut bn254_snarks_g2_fixed_base_table,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_fixed_base_scalar_mul_big_coef(
+        r: *mut bn254_snarks_g2_jac,
+        table: *const bn254_snarks_g2_fixed_base_table,
+        scalar: *const big254,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_fixed_base_scalar_mul_fr_coef(
+        r: *mut bn254_snarks_g2_jac,
+        table: *const bn254_snarks_g2_fixed_base_table,
+        scalar: *const bn254_snarks_fr,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_fixed_base_scalar_mul_big_coef(
+        r: *mut bn254_snarks_g2_prj,
+        table: *const bn254_snarks_g2_fixed_base_table,
+        scalar: *const big254,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_fixed_base_scalar_mul_fr_coef(
+        r: *mut bn254_snarks_g2_prj,
+        table: *const bn254_snarks_g2_fixed_base_table,
+        scalar: *const bn254_snarks_fr,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_bn254_snarks_g1_aff_svdw_sha256(
         r: *mut bn254_snarks_g1_aff,
@@ -2450,6 +3286,79 @@ unsafe extern "C" {
         domainSepTag_len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_kzg_commit_vartime(
+        commitment: *mut bn254_snarks_g1_aff,
+        poly: *const bn254_snarks_fr,
+        powers_of_tau_g1: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_kzg_prove_monomial_vartime(
+        proof: *mut bn254_snarks_g1_aff,
+        eval_at_challenge: *mut bn254_snarks_fr,
+        poly: *const bn254_snarks_fr,
+        powers_of_tau_g1: *const bn254_snarks_g1_aff,
+        len: usize,
+        opening_challenge: *const bn254_snarks_fr,
+    );
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bn254_snarks_kzg_verify_vartime(
+        commitment: *const bn254_snarks_g1_aff,
+        opening_challenge: *const bn254_snarks_fr,
+        eval_at_challenge: *const bn254_snarks_fr,
+        proof: *const bn254_snarks_g1_aff,
+        tau_g2: *const bn254_snarks_g2_aff,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bn254_snarks_kzg_verify_batch_vartime(
+        commitments: *const bn254_snarks_g1_aff,
+        challenges: *const bn254_snarks_fr,
+        evals_at_challenges: *const bn254_snarks_fr,
+        proofs: *const bn254_snarks_g1_aff,
+        linear_indep_random_numbers: *const bn254_snarks_fr,
+        len: usize,
+        tau_g2: *const bn254_snarks_g2_aff,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_kzg_setup_insecure_for_testing(
+        powers_of_tau_g1: *const bn254_snarks_g1_aff,
+        len: usize,
+        tau_g2: *mut bn254_snarks_g2_aff,
+        tau: *const bn254_snarks_fr,
+    );
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bn254_snarks_srs_g1_from_montgomery_le_unchecked(
+        dst: *const bn254_snarks_g1_aff,
+        src: *const byte,
+        len: usize,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bn254_snarks_srs_g2_from_montgomery_le_unchecked(
+        dst: *const bn254_snarks_g2_aff,
+        src: *const byte,
+        len: usize,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bn254_snarks_srs_monomial_to_lagrange_vartime(
+        lagrange_g1: *const bn254_snarks_g1_aff,
+        powers_of_tau_g1: *const bn254_snarks_g1_aff,
+        len: usize,
+        root_of_unity: *const bn254_snarks_fr,
+    ) -> bool;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct pallas_fr {
@@ -2515,6 +3424,20 @@ const _: () = {
     ["Offset of field: pallas_ec_prj::y"][::core::mem::offset_of!(pallas_ec_prj, y) - 32usize];
     ["Offset of field: pallas_ec_prj::z"][::core::mem::offset_of!(pallas_ec_prj, z) - 64usize];
 };
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct pallas_ec_fixed_base_table {
+    points: *mut pallas_ec_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pallas_ec_fixed_base_table"]
+        [::core::mem::size_of::<pallas_ec_fixed_base_table>() - 8usize];
+    ["Alignment of pallas_ec_fixed_base_table"]
+        [::core::mem::align_of::<pallas_ec_fixed_base_table>() - 8usize];
+    ["Offset of field: pallas_ec_fixed_base_table::points"]
+        [::core::mem::offset_of!(pallas_ec_fixed_base_table, points) - 0usize];
+};
 unsafe extern "C" {
     pub fn ctt_big255_from_pallas_fr(dst: *mut big255, src: *const pallas_fr);
 }
@@ -2617,6 +3540,36 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn ctt_pallas_fr_csub_in_place(a: *mut pallas_fr, b: *const pallas_fr, ctl: secret_bool);
 }
+unsafe extern "C" {
+    pub fn ctt_pallas_fr_ntt_vartime(
+        values: *mut pallas_fr,
+        values_len: usize,
+        omega: *const pallas_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_fr_intt_vartime(
+        values: *mut pallas_fr,
+        values_len: usize,
+        omega: *const pallas_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_fr_coset_ntt_vartime(
+        values: *mut pallas_fr,
+        values_len: usize,
+        omega: *const pallas_fr,
+        shift: *const pallas_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_fr_coset_intt_vartime(
+        values: *mut pallas_fr,
+        values_len: usize,
+        omega: *const pallas_fr,
+        shift: *const pallas_fr,
+    ) -> bool;
+}
 unsafe extern "C" {
     pub fn ctt_big255_from_pallas_fp(dst: *mut big255, src: *const pallas_fp);
 }
@@ -2861,6 +3814,22 @@ unsafe extern "C" {
         scalar: *const pallas_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_big_coefs(
+        r: *mut pallas_ec_jac,
+        coefs: *const big255,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs(
+        r: *mut pallas_ec_jac,
+        coefs: *const pallas_fr,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_pallas_ec_jac_multi_scalar_mul_big_coefs_vartime(
         r: *mut pallas_ec_jac,
@@ -2877,6 +3846,31 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut pallas_ec_jac,
+        coefs: *const u64,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut pallas_ec_jac,
+        coefs: *const i64,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut pallas_ec_jac,
+        coefs: *const pallas_fr,
+        points: *const pallas_ec_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_pallas_ec_prj_is_eq(P: *const pallas_ec_prj, Q: *const pallas_ec_prj)
         -> secret_bool;
@@ -2957,6 +3951,22 @@ unsafe extern "C" {
         scalar: *const pallas_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_big_coefs(
+        r: *mut pallas_ec_prj,
+        coefs: *const big255,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs(
+        r: *mut pallas_ec_prj,
+        coefs: *const pallas_fr,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_pallas_ec_prj_multi_scalar_mul_big_coefs_vartime(
         r: *mut pallas_ec_prj,
@@ -2973,6 +3983,73 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut pallas_ec_prj,
+        coefs: *const u64,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut pallas_ec_prj,
+        coefs: *const i64,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut pallas_ec_prj,
+        coefs: *const pallas_fr,
+        points: *const pallas_ec_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_fixed_base_table_precompute(
+        table: *mut pallas_ec_fixed_base_table,
+        base: *const pallas_ec_aff,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_fixed_base_table_precompute_generator(
+        table: *mut pallas_ec_fixed_base_table,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_fixed_base_table_delete(table: *mut pallas_ec_fixed_base_table);
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_fixed_base_scalar_mul_big_coef(
+        r: *mut pallas_ec_jac,
+        table: *const pallas_ec_fixed_base_table,
+        scalar: *const big255,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_fixed_base_scalar_mul_fr_coef(
+        r: *mut pallas_ec_jac,
+        table: *const pallas_ec_fixed_base_table,
+        scalar: *const pallas_fr,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_fixed_base_scalar_mul_big_coef(
+        r: *mut pallas_ec_prj,
+        table: *const pallas_ec_fixed_base_table,
+        scalar: *const big255,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_fixed_base_scalar_mul_fr_coef(
+        r: *mut pallas_ec_prj,
+        table: *const pallas_ec_fixed_base_table,
+        scalar: *const pallas_fr,
+    );
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct vesta_fr {
@@ -3038,6 +4115,20 @@ const _: () = {
     ["Offset of field: vesta_ec_prj::y"][::core::mem::offset_of!(vesta_ec_prj, y) - 32usize];
     ["Offset of field: vesta_ec_prj::z"][::core::mem::offset_of!(vesta_ec_prj, z) - 64usize];
 };
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct vesta_ec_fixed_base_table {
+    points: *mut vesta_ec_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of vesta_ec_fixed_base_table"]
+        [::core::mem::size_of::<vesta_ec_fixed_base_table>() - 8usize];
+    ["Alignment of vesta_ec_fixed_base_table"]
+        [::core::mem::align_of::<vesta_ec_fixed_base_table>() - 8usize];
+    ["Offset of field: vesta_ec_fixed_base_table::points"]
+        [::core::mem::offset_of!(vesta_ec_fixed_base_table, points) - 0usize];
+};
 unsafe extern "C" {
     pub fn ctt_big255_from_vesta_fr(dst: *mut big255, src: *const vesta_fr);
 }
@@ -3140,13 +4231,43 @@ unsafe extern "C" {
     pub fn ctt_vesta_fr_csub_in_place(a: *mut vesta_fr, b: *const vesta_fr, ctl: secret_bool);
 }
 unsafe extern "C" {
-    pub fn ctt_big255_from_vesta_fp(dst: *mut big255, src: *const vesta_fp);
+    pub fn ctt_vesta_fr_ntt_vartime(
+        values: *mut vesta_fr,
+        values_len: usize,
+        omega: *const vesta_fr,
+    ) -> bool;
 }
 unsafe extern "C" {
-    pub fn ctt_vesta_fp_from_big255(dst: *mut vesta_fp, src: *const big255);
+    pub fn ctt_vesta_fr_intt_vartime(
+        values: *mut vesta_fr,
+        values_len: usize,
+        omega: *const vesta_fr,
+    ) -> bool;
 }
 unsafe extern "C" {
-    #[must_use]
+    pub fn ctt_vesta_fr_coset_ntt_vartime(
+        values: *mut vesta_fr,
+        values_len: usize,
+        omega: *const vesta_fr,
+        shift: *const vesta_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_fr_coset_intt_vartime(
+        values: *mut vesta_fr,
+        values_len: usize,
+        omega: *const vesta_fr,
+        shift: *const vesta_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_big255_from_vesta_fp(dst: *mut big255, src: *const vesta_fp);
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_fp_from_big255(dst: *mut vesta_fp, src: *const big255);
+}
+unsafe extern "C" {
+    #[must_use]
     pub fn ctt_vesta_fp_unmarshalBE(dst: *mut vesta_fp, src: *const byte, src_len: usize) -> bool;
 }
 unsafe extern "C" {
@@ -3372,6 +4493,22 @@ unsafe extern "C" {
         scalar: *const vesta_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_big_coefs(
+        r: *mut vesta_ec_jac,
+        coefs: *const big255,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs(
+        r: *mut vesta_ec_jac,
+        coefs: *const vesta_fr,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_vesta_ec_jac_multi_scalar_mul_big_coefs_vartime(
         r: *mut vesta_ec_jac,
@@ -3388,6 +4525,31 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut vesta_ec_jac,
+        coefs: *const u64,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut vesta_ec_jac,
+        coefs: *const i64,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut vesta_ec_jac,
+        coefs: *const vesta_fr,
+        points: *const vesta_ec_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_vesta_ec_prj_is_eq(P: *const vesta_ec_prj, Q: *const vesta_ec_prj) -> secret_bool;
 }
@@ -3463,6 +4625,22 @@ unsafe extern "C" {
         scalar: *const vesta_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_big_coefs(
+        r: *mut vesta_ec_prj,
+        coefs: *const big255,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_fr_coefs(
+        r: *mut vesta_ec_prj,
+        coefs: *const vesta_fr,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_vesta_ec_prj_multi_scalar_mul_big_coefs_vartime(
         r: *mut vesta_ec_prj,
@@ -3479,6 +4657,73 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut vesta_ec_prj,
+        coefs: *const u64,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut vesta_ec_prj,
+        coefs: *const i64,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut vesta_ec_prj,
+        coefs: *const vesta_fr,
+        points: *const vesta_ec_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_fixed_base_table_precompute(
+        table: *mut vesta_ec_fixed_base_table,
+        base: *const vesta_ec_aff,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_fixed_base_table_precompute_generator(
+        table: *mut vesta_ec_fixed_base_table,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_fixed_base_table_delete(table: *mut vesta_ec_fixed_base_table);
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_fixed_base_scalar_mul_big_coef(
+        r: *mut vesta_ec_jac,
+        table: *const vesta_ec_fixed_base_table,
+        scalar: *const big255,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_fixed_base_scalar_mul_fr_coef(
+        r: *mut vesta_ec_jac,
+        table: *const vesta_ec_fixed_base_table,
+        scalar: *const vesta_fr,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_fixed_base_scalar_mul_big_coef(
+        r: *mut vesta_ec_prj,
+        table: *const vesta_ec_fixed_base_table,
+        scalar: *const big255,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_fixed_base_scalar_mul_fr_coef(
+        r: *mut vesta_ec_prj,
+        table: *const vesta_ec_fixed_base_table,
+        scalar: *const vesta_fr,
+    );
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = " Validate a scalar\n  Regarding timing attacks, this will leak information\n  if the scalar is 0 or larger than the curve order."]
@@ -3633,6 +4878,20 @@ const _: () = {
     ["Offset of field: banderwagon_ec_prj::z"]
         [::core::mem::offset_of!(banderwagon_ec_prj, z) - 64usize];
 };
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct banderwagon_ec_fixed_base_table {
+    points: *mut banderwagon_ec_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of banderwagon_ec_fixed_base_table"]
+        [::core::mem::size_of::<banderwagon_ec_fixed_base_table>() - 8usize];
+    ["Alignment of banderwagon_ec_fixed_base_table"]
+        [::core::mem::align_of::<banderwagon_ec_fixed_base_table>() - 8usize];
+    ["Offset of field: banderwagon_ec_fixed_base_table::points"]
+        [::core::mem::offset_of!(banderwagon_ec_fixed_base_table, points) - 0usize];
+};
 unsafe extern "C" {
     pub fn ctt_big253_from_banderwagon_fr(dst: *mut big253, src: *const banderwagon_fr);
 }
@@ -4092,6 +5351,22 @@ unsafe extern "C" {
         scalar: *const banderwagon_fr,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_big_coefs(
+        r: *mut banderwagon_ec_prj,
+        coefs: *const big253,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs(
+        r: *mut banderwagon_ec_prj,
+        coefs: *const banderwagon_fr,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+    );
+}
 unsafe extern "C" {
     pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_big_coefs_vartime(
         r: *mut banderwagon_ec_prj,
@@ -4108,6 +5383,102 @@ unsafe extern "C" {
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_u64_coefs_vartime(
+        r: *mut banderwagon_ec_prj,
+        coefs: *const u64,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_i64_coefs_vartime(
+        r: *mut banderwagon_ec_prj,
+        coefs: *const i64,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(
+        r: *mut banderwagon_ec_prj,
+        coefs: *const banderwagon_fr,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_fixed_base_table_precompute(
+        table: *mut banderwagon_ec_fixed_base_table,
+        base: *const banderwagon_ec_aff,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_fixed_base_table_precompute_generator(
+        table: *mut banderwagon_ec_fixed_base_table,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_fixed_base_table_delete(table: *mut banderwagon_ec_fixed_base_table);
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_fixed_base_scalar_mul_big_coef(
+        r: *mut banderwagon_ec_prj,
+        table: *const banderwagon_ec_fixed_base_table,
+        scalar: *const big253,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_fixed_base_scalar_mul_fr_coef(
+        r: *mut banderwagon_ec_prj,
+        table: *const banderwagon_ec_fixed_base_table,
+        scalar: *const banderwagon_fr,
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bls12_381_g1_msm_table {
+    len: usize,
+    points: *mut bls12_381_g1_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of bls12_381_g1_msm_table"][::core::mem::size_of::<bls12_381_g1_msm_table>() - 16usize];
+    ["Alignment of bls12_381_g1_msm_table"]
+        [::core::mem::align_of::<bls12_381_g1_msm_table>() - 8usize];
+    ["Offset of field: bls12_381_g1_msm_table::len"]
+        [::core::mem::offset_of!(bls12_381_g1_msm_table, len) - 0usize];
+    ["Offset of field: bls12_381_g1_msm_table::points"]
+        [::core::mem::offset_of!(bls12_381_g1_msm_table, points) - 8usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bls12_381_g2_msm_table {
+    len: usize,
+    points: *mut bls12_381_g2_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of bls12_381_g2_msm_table"][::core::mem::size_of::<bls12_381_g2_msm_table>() - 16usize];
+    ["Alignment of bls12_381_g2_msm_table"]
+        [::core::mem::align_of::<bls12_381_g2_msm_table>() - 8usize];
+    ["Offset of field: bls12_381_g2_msm_table::len"]
+        [::core::mem::offset_of!(bls12_381_g2_msm_table, len) - 0usize];
+    ["Offset of field: bls12_381_g2_msm_table::points"]
+        [::core::mem::offset_of!(bls12_381_g2_msm_table, points) - 8usize];
+};
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_msm_table_precompute_parallel(
+        tp: *const ctt_threadpool,
+        table: *mut bls12_381_g1_msm_table,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_msm_table_delete(table: *mut bls12_381_g1_msm_table);
+}
 unsafe extern "C" {
     pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_big_coefs_vartime_parallel(
         tp: *const ctt_threadpool,
@@ -4127,131 +5498,1394 @@ unsafe extern "C" {
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_big_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_u64_coefs_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut bls12_381_g1_prj,
-        coefs: *const big255,
+        r: *mut bls12_381_g1_jac,
+        coefs: *const u64,
         points: *const bls12_381_g1_aff,
         len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_i64_coefs_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut bls12_381_g1_prj,
-        coefs: *const bls12_381_fr,
+        r: *mut bls12_381_g1_jac,
+        coefs: *const i64,
         points: *const bls12_381_g1_aff,
         len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_big_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut bn254_snarks_g1_jac,
-        coefs: *const big254,
-        points: *const bn254_snarks_g1_aff,
+        r: *mut bls12_381_g1_jac,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g1_aff,
         len: usize,
+        coefs_max_bits: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut bn254_snarks_g1_jac,
-        coefs: *const bn254_snarks_fr,
-        points: *const bn254_snarks_g1_aff,
+        r: *mut bls12_381_g1_jac,
+        coefs: *const bls12_381_fr,
+        table: *const bls12_381_g1_msm_table,
         len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_big_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut bn254_snarks_g1_prj,
-        coefs: *const big254,
-        points: *const bn254_snarks_g1_aff,
+        r: *mut bls12_381_g1_jac,
+        r_len: usize,
+        coefs: *const *const bls12_381_fr,
+        points: *const bls12_381_g1_aff,
         len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut bn254_snarks_g1_prj,
-        coefs: *const bn254_snarks_fr,
-        points: *const bn254_snarks_g1_aff,
+        r: *mut bls12_381_g1_jac,
+        r_len: usize,
+        coefs: *const *const bls12_381_fr,
+        table: *const bls12_381_g1_msm_table,
         len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_pallas_ec_jac_multi_scalar_mul_big_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_jac_batch_affine_parallel(
         tp: *const ctt_threadpool,
-        r: *mut pallas_ec_jac,
-        coefs: *const big255,
-        points: *const pallas_ec_aff,
-        len: usize,
+        dst: *const bls12_381_g1_aff,
+        src: *const bls12_381_g1_jac,
+        n: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_jac_sum_reduce_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut pallas_ec_jac,
-        coefs: *const pallas_fr,
-        points: *const pallas_ec_aff,
+        r: *mut bls12_381_g1_jac,
+        points: *const bls12_381_g1_aff,
         len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_pallas_ec_prj_multi_scalar_mul_big_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_big_coefs_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut pallas_ec_prj,
+        r: *mut bls12_381_g1_prj,
         coefs: *const big255,
-        points: *const pallas_ec_aff,
+        points: *const bls12_381_g1_aff,
         len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut pallas_ec_prj,
-        coefs: *const pallas_fr,
-        points: *const pallas_ec_aff,
+        r: *mut bls12_381_g1_prj,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g1_aff,
         len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_vesta_ec_jac_multi_scalar_mul_big_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_u64_coefs_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut vesta_ec_jac,
-        coefs: *const big255,
-        points: *const vesta_ec_aff,
+        r: *mut bls12_381_g1_prj,
+        coefs: *const u64,
+        points: *const bls12_381_g1_aff,
         len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_vartime_parallel(
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_i64_coefs_vartime_parallel(
         tp: *const ctt_threadpool,
-        r: *mut vesta_ec_jac,
-        coefs: *const vesta_fr,
-        points: *const vesta_ec_aff,
-        len: usize,
+        r: *mut bls12_381_g1_prj,
+        coefs: *const i64,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g1_prj,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g1_prj,
+        coefs: *const bls12_381_fr,
+        table: *const bls12_381_g1_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g1_prj,
+        r_len: usize,
+        coefs: *const *const bls12_381_fr,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g1_prj,
+        r_len: usize,
+        coefs: *const *const bls12_381_fr,
+        table: *const bls12_381_g1_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const bls12_381_g1_aff,
+        src: *const bls12_381_g1_prj,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g1_prj_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g1_prj,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_msm_table_precompute_parallel(
+        tp: *const ctt_threadpool,
+        table: *mut bls12_381_g2_msm_table,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_msm_table_delete(table: *mut bls12_381_g2_msm_table);
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_jac,
+        coefs: *const big255,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_jac,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_u64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_jac,
+        coefs: *const u64,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_jac,
+        coefs: *const i64,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_jac,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_jac,
+        coefs: *const bls12_381_fr,
+        table: *const bls12_381_g2_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_jac,
+        r_len: usize,
+        coefs: *const *const bls12_381_fr,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_jac,
+        r_len: usize,
+        coefs: *const *const bls12_381_fr,
+        table: *const bls12_381_g2_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const bls12_381_g2_aff,
+        src: *const bls12_381_g2_jac,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_jac_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_jac,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_prj,
+        coefs: *const big255,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_prj,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_u64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_prj,
+        coefs: *const u64,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_prj,
+        coefs: *const i64,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_prj,
+        coefs: *const bls12_381_fr,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_prj,
+        coefs: *const bls12_381_fr,
+        table: *const bls12_381_g2_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_prj,
+        r_len: usize,
+        coefs: *const *const bls12_381_fr,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_prj,
+        r_len: usize,
+        coefs: *const *const bls12_381_fr,
+        table: *const bls12_381_g2_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const bls12_381_g2_aff,
+        src: *const bls12_381_g2_prj,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bls12_381_g2_prj_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bls12_381_g2_prj,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bls12_381_srs_g1_validate_parallel(
+        tp: *const ctt_threadpool,
+        points: *const bls12_381_g1_aff,
+        len: usize,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bls12_381_srs_g2_validate_parallel(
+        tp: *const ctt_threadpool,
+        points: *const bls12_381_g2_aff,
+        len: usize,
+    ) -> bool;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bn254_snarks_g1_msm_table {
+    len: usize,
+    points: *mut bn254_snarks_g1_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of bn254_snarks_g1_msm_table"]
+        [::core::mem::size_of::<bn254_snarks_g1_msm_table>() - 16usize];
+    ["Alignment of bn254_snarks_g1_msm_table"]
+        [::core::mem::align_of::<bn254_snarks_g1_msm_table>() - 8usize];
+    ["Offset of field: bn254_snarks_g1_msm_table::len"]
+        [::core::mem::offset_of!(bn254_snarks_g1_msm_table, len) - 0usize];
+    ["Offset of field: bn254_snarks_g1_msm_table::points"]
+        [::core::mem::offset_of!(bn254_snarks_g1_msm_table, points) - 8usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bn254_snarks_g2_msm_table {
+    len: usize,
+    points: *mut bn254_snarks_g2_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of bn254_snarks_g2_msm_table"]
+        [::core::mem::size_of::<bn254_snarks_g2_msm_table>() - 16usize];
+    ["Alignment of bn254_snarks_g2_msm_table"]
+        [::core::mem::align_of::<bn254_snarks_g2_msm_table>() - 8usize];
+    ["Offset of field: bn254_snarks_g2_msm_table::len"]
+        [::core::mem::offset_of!(bn254_snarks_g2_msm_table, len) - 0usize];
+    ["Offset of field: bn254_snarks_g2_msm_table::points"]
+        [::core::mem::offset_of!(bn254_snarks_g2_msm_table, points) - 8usize];
+};
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_fr_ntt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut bn254_snarks_fr,
+        values_len: usize,
+        omega: *const bn254_snarks_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_fr_intt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut bn254_snarks_fr,
+        values_len: usize,
+        omega: *const bn254_snarks_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_fr_coset_ntt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut bn254_snarks_fr,
+        values_len: usize,
+        omega: *const bn254_snarks_fr,
+        shift: *const bn254_snarks_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_fr_coset_intt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut bn254_snarks_fr,
+        values_len: usize,
+        omega: *const bn254_snarks_fr,
+        shift: *const bn254_snarks_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_msm_table_precompute_parallel(
+        tp: *const ctt_threadpool,
+        table: *mut bn254_snarks_g1_msm_table,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_msm_table_delete(table: *mut bn254_snarks_g1_msm_table);
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const big254,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_u64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const u64,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const i64,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_jac,
+        coefs: *const bn254_snarks_fr,
+        table: *const bn254_snarks_g1_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_jac,
+        r_len: usize,
+        coefs: *const *const bn254_snarks_fr,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_jac,
+        r_len: usize,
+        coefs: *const *const bn254_snarks_fr,
+        table: *const bn254_snarks_g1_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const bn254_snarks_g1_aff,
+        src: *const bn254_snarks_g1_jac,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_jac_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_jac,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const big254,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_u64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const u64,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const i64,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_prj,
+        coefs: *const bn254_snarks_fr,
+        table: *const bn254_snarks_g1_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_prj,
+        r_len: usize,
+        coefs: *const *const bn254_snarks_fr,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_prj,
+        r_len: usize,
+        coefs: *const *const bn254_snarks_fr,
+        table: *const bn254_snarks_g1_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const bn254_snarks_g1_aff,
+        src: *const bn254_snarks_g1_prj,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g1_prj_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g1_prj,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_msm_table_precompute_parallel(
+        tp: *const ctt_threadpool,
+        table: *mut bn254_snarks_g2_msm_table,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_msm_table_delete(table: *mut bn254_snarks_g2_msm_table);
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const big254,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_u64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const u64,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const i64,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_jac,
+        coefs: *const bn254_snarks_fr,
+        table: *const bn254_snarks_g2_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_jac,
+        r_len: usize,
+        coefs: *const *const bn254_snarks_fr,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_jac,
+        r_len: usize,
+        coefs: *const *const bn254_snarks_fr,
+        table: *const bn254_snarks_g2_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const bn254_snarks_g2_aff,
+        src: *const bn254_snarks_g2_jac,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_jac_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_jac,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const big254,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_u64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const u64,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const i64,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const bn254_snarks_fr,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_prj,
+        coefs: *const bn254_snarks_fr,
+        table: *const bn254_snarks_g2_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_prj,
+        r_len: usize,
+        coefs: *const *const bn254_snarks_fr,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_prj,
+        r_len: usize,
+        coefs: *const *const bn254_snarks_fr,
+        table: *const bn254_snarks_g2_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const bn254_snarks_g2_aff,
+        src: *const bn254_snarks_g2_prj,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_bn254_snarks_g2_prj_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut bn254_snarks_g2_prj,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bn254_snarks_srs_g1_validate_parallel(
+        tp: *const ctt_threadpool,
+        points: *const bn254_snarks_g1_aff,
+        len: usize,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[must_use]
+    pub fn ctt_bn254_snarks_srs_g2_validate_parallel(
+        tp: *const ctt_threadpool,
+        points: *const bn254_snarks_g2_aff,
+        len: usize,
+    ) -> bool;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct pallas_ec_msm_table {
+    len: usize,
+    points: *mut pallas_ec_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pallas_ec_msm_table"][::core::mem::size_of::<pallas_ec_msm_table>() - 16usize];
+    ["Alignment of pallas_ec_msm_table"][::core::mem::align_of::<pallas_ec_msm_table>() - 8usize];
+    ["Offset of field: pallas_ec_msm_table::len"]
+        [::core::mem::offset_of!(pallas_ec_msm_table, len) - 0usize];
+    ["Offset of field: pallas_ec_msm_table::points"]
+        [::core::mem::offset_of!(pallas_ec_msm_table, points) - 8usize];
+};
+unsafe extern "C" {
+    pub fn ctt_pallas_fr_ntt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut pallas_fr,
+        values_len: usize,
+        omega: *const pallas_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_fr_intt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut pallas_fr,
+        values_len: usize,
+        omega: *const pallas_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_fr_coset_ntt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut pallas_fr,
+        values_len: usize,
+        omega: *const pallas_fr,
+        shift: *const pallas_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_fr_coset_intt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut pallas_fr,
+        values_len: usize,
+        omega: *const pallas_fr,
+        shift: *const pallas_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_msm_table_precompute_parallel(
+        tp: *const ctt_threadpool,
+        table: *mut pallas_ec_msm_table,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_msm_table_delete(table: *mut pallas_ec_msm_table);
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_jac,
+        coefs: *const big255,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_jac,
+        coefs: *const pallas_fr,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_u64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_jac,
+        coefs: *const u64,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_jac,
+        coefs: *const i64,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_jac,
+        coefs: *const pallas_fr,
+        points: *const pallas_ec_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_jac,
+        coefs: *const pallas_fr,
+        table: *const pallas_ec_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_jac,
+        r_len: usize,
+        coefs: *const *const pallas_fr,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_jac,
+        r_len: usize,
+        coefs: *const *const pallas_fr,
+        table: *const pallas_ec_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const pallas_ec_aff,
+        src: *const pallas_ec_jac,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_jac_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_jac,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_prj,
+        coefs: *const big255,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_prj,
+        coefs: *const pallas_fr,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_u64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_prj,
+        coefs: *const u64,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_prj,
+        coefs: *const i64,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_prj,
+        coefs: *const pallas_fr,
+        points: *const pallas_ec_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_prj,
+        coefs: *const pallas_fr,
+        table: *const pallas_ec_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_prj,
+        r_len: usize,
+        coefs: *const *const pallas_fr,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_prj,
+        r_len: usize,
+        coefs: *const *const pallas_fr,
+        table: *const pallas_ec_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const pallas_ec_aff,
+        src: *const pallas_ec_prj,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_pallas_ec_prj_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut pallas_ec_prj,
+        points: *const pallas_ec_aff,
+        len: usize,
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct vesta_ec_msm_table {
+    len: usize,
+    points: *mut vesta_ec_aff,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of vesta_ec_msm_table"][::core::mem::size_of::<vesta_ec_msm_table>() - 16usize];
+    ["Alignment of vesta_ec_msm_table"][::core::mem::align_of::<vesta_ec_msm_table>() - 8usize];
+    ["Offset of field: vesta_ec_msm_table::len"]
+        [::core::mem::offset_of!(vesta_ec_msm_table, len) - 0usize];
+    ["Offset of field: vesta_ec_msm_table::points"]
+        [::core::mem::offset_of!(vesta_ec_msm_table, points) - 8usize];
+};
+unsafe extern "C" {
+    pub fn ctt_vesta_fr_ntt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut vesta_fr,
+        values_len: usize,
+        omega: *const vesta_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_fr_intt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut vesta_fr,
+        values_len: usize,
+        omega: *const vesta_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_fr_coset_ntt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut vesta_fr,
+        values_len: usize,
+        omega: *const vesta_fr,
+        shift: *const vesta_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_fr_coset_intt_vartime_parallel(
+        tp: *const ctt_threadpool,
+        values: *mut vesta_fr,
+        values_len: usize,
+        omega: *const vesta_fr,
+        shift: *const vesta_fr,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_msm_table_precompute_parallel(
+        tp: *const ctt_threadpool,
+        table: *mut vesta_ec_msm_table,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_msm_table_delete(table: *mut vesta_ec_msm_table);
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_jac,
+        coefs: *const big255,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_jac,
+        coefs: *const vesta_fr,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_u64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_jac,
+        coefs: *const u64,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_jac,
+        coefs: *const i64,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_jac,
+        coefs: *const vesta_fr,
+        points: *const vesta_ec_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_jac,
+        coefs: *const vesta_fr,
+        table: *const vesta_ec_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_jac,
+        r_len: usize,
+        coefs: *const *const vesta_fr,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_jac,
+        r_len: usize,
+        coefs: *const *const vesta_fr,
+        table: *const vesta_ec_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const vesta_ec_aff,
+        src: *const vesta_ec_jac,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_jac_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_jac,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_prj,
+        coefs: *const big255,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_prj,
+        coefs: *const vesta_fr,
+        points: *const vesta_ec_aff,
+        len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_vesta_ec_prj_multi_scalar_mul_big_coefs_vartime_parallel(
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_u64_coefs_vartime_parallel(
         tp: *const ctt_threadpool,
         r: *mut vesta_ec_prj,
-        coefs: *const big255,
+        coefs: *const u64,
         points: *const vesta_ec_aff,
         len: usize,
     );
 }
 unsafe extern "C" {
-    pub fn ctt_vesta_ec_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_prj,
+        coefs: *const i64,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_prj,
+        coefs: *const vesta_fr,
+        points: *const vesta_ec_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(
         tp: *const ctt_threadpool,
         r: *mut vesta_ec_prj,
         coefs: *const vesta_fr,
+        table: *const vesta_ec_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_prj,
+        r_len: usize,
+        coefs: *const *const vesta_fr,
+        points: *const vesta_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_prj,
+        r_len: usize,
+        coefs: *const *const vesta_fr,
+        table: *const vesta_ec_msm_table,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const vesta_ec_aff,
+        src: *const vesta_ec_prj,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_vesta_ec_prj_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut vesta_ec_prj,
         points: *const vesta_ec_aff,
         len: usize,
     );
 }
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_big_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut banderwagon_ec_prj,
+        coefs: *const big253,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut banderwagon_ec_prj,
+        coefs: *const banderwagon_fr,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_u64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut banderwagon_ec_prj,
+        coefs: *const u64,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_i64_coefs_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut banderwagon_ec_prj,
+        coefs: *const i64,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut banderwagon_ec_prj,
+        coefs: *const banderwagon_fr,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+        coefs_max_bits: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_batch_affine_parallel(
+        tp: *const ctt_threadpool,
+        dst: *const banderwagon_ec_aff,
+        src: *const banderwagon_ec_prj,
+        n: usize,
+    );
+}
+unsafe extern "C" {
+    pub fn ctt_banderwagon_ec_prj_sum_reduce_vartime_parallel(
+        tp: *const ctt_threadpool,
+        r: *mut banderwagon_ec_prj,
+        points: *const banderwagon_ec_aff,
+        len: usize,
+    );
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ctt_eth_bls_fp {
@@ -4714,6 +7348,19 @@ unsafe extern "C" {
         secure_random_bytes: *const byte,
     ) -> ctt_eth_kzg_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Verify `n` (blob, commitment, proof) sets efficiently\n  and locate the invalid sets on failure.\n\n  On return, `invalid[i]` is true if the i-th set is invalid,\n  including if its commitment, proof or blob cannot be deserialized.\n\n  Returns:\n  - cttEthKzg_Success if all sets are valid\n  - cttEthKzg_VerificationFailure if at least one set is invalid\n  - cttEthKzg_InputsLengthsMismatch if `n` overflows a signed integer, `invalid` is not written\n\n  The sets are first verified as a batch. On failure, each set is verified individually.\n\n  `secure_random_bytes` random bytes must come from a cryptographically secure RNG\n  or computed through the Fiat-Shamir heuristic."]
+    pub fn ctt_eth_kzg_verify_blob_kzg_proof_batch_find_invalid(
+        ctx: *const ctt_eth_kzg_context,
+        invalid: *mut bool,
+        blobs: *const ctt_eth_kzg_blob,
+        commitments: *const ctt_eth_kzg_commitment,
+        proofs: *const ctt_eth_kzg_proof,
+        n: usize,
+        secure_random_bytes: *const byte,
+    ) -> ctt_eth_kzg_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = " Load trusted setup from path\n  Currently the only format supported `cttEthTSFormat_ckzg4844`\n  is from the reference implementation c-kzg-4844 text file"]
@@ -4784,6 +7431,20 @@ unsafe extern "C" {
         secure_random_bytes: *const byte,
     ) -> ctt_eth_kzg_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Verify `n` (blob, commitment, proof) sets efficiently\n  and locate the invalid sets on failure.\n\n  On return, `invalid[i]` is true if the i-th set is invalid,\n  including if its commitment, proof or blob cannot be deserialized.\n\n  Returns:\n  - cttEthKzg_Success if all sets are valid\n  - cttEthKzg_VerificationFailure if at least one set is invalid\n  - cttEthKzg_InputsLengthsMismatch if `n` overflows a signed integer, `invalid` is not written\n\n  The sets are first verified as a batch. On failure, each set is verified individually,\n  in parallel.\n\n  `secure_random_bytes` random bytes must come from a cryptographically secure RNG\n  or computed through the Fiat-Shamir heuristic."]
+    pub fn ctt_eth_kzg_verify_blob_kzg_proof_batch_find_invalid_parallel(
+        tp: *const ctt_threadpool,
+        ctx: *const ctt_eth_kzg_context,
+        invalid: *mut bool,
+        blobs: *const ctt_eth_kzg_blob,
+        commitments: *const ctt_eth_kzg_commitment,
+        proofs: *const ctt_eth_kzg_proof,
+        n: usize,
+        secure_random_bytes: *const byte,
+    ) -> ctt_eth_kzg_status;
+}
 #[repr(u8)]
 #[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 pub enum ctt_evm_status {
@@ -4794,6 +7455,65 @@ pub enum ctt_evm_status {
     cttEVM_PointNotOnCurve = 4,
     cttEVM_PointNotInSubgroup = 5,
     cttEVM_VerificationFailure = 6,
+    cttEVM_MalformedSignature = 7,
+    cttEVM_InvalidFinalFlag = 8,
+}
+#[repr(u8)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum ctt_evm_failure_reason {
+    cttEVMFail_None = 0,
+    cttEVMFail_InvalidLength = 1,
+    cttEVMFail_NonZeroPadding = 2,
+    cttEVMFail_CoordinateGreaterOrEqualModulus = 3,
+    cttEVMFail_PointNotOnCurve = 4,
+    cttEVMFail_PointNotInSubgroup = 5,
+}
+#[doc = " Reason of an input rejection\n  and byte offset of the offending field element or point in the input of the precompile"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ctt_evm_failure {
+    reason: ctt_evm_failure_reason,
+    offset: usize,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of ctt_evm_failure"][::core::mem::size_of::<ctt_evm_failure>() - 16usize];
+    ["Alignment of ctt_evm_failure"][::core::mem::align_of::<ctt_evm_failure>() - 8usize];
+    ["Offset of field: ctt_evm_failure::reason"]
+        [::core::mem::offset_of!(ctt_evm_failure, reason) - 0usize];
+    ["Offset of field: ctt_evm_failure::offset"]
+        [::core::mem::offset_of!(ctt_evm_failure, offset) - 8usize];
+};
+unsafe extern "C" {
+    #[must_use]
+    #[doc = "  Recover the Ethereum address of the public key\n  which was used to sign a message digest.\n\n  Name: ECRECOVER\n\n  Inputs:\n  - r: array with 32 bytes of storage for the result\n  - r_len: length of `r`. Must be 32\n  - inputs: 128 bytes with the following breakdown:\n    - 32 bytes, keccak256 digest of the message that was signed\n    - 32 bytes, `v`, 27 or 28, the parity of the `y` coordinate of `R`\n    - 32 bytes, `r` of the signature\n    - 32 bytes, `s` of the signature\n    Shorter inputs are right-padded with zeros and extra bytes are ignored.\n  - inputs_len: length of the inputs array\n\n  Output:\n  - 32 bytes, the 20-byte Ethereum address left-padded with zeros\n  - status code:\n    cttEVM_Success\n    cttEVM_InvalidOutputSize\n    cttEVM_MalformedSignature\n\n  `cttEVM_MalformedSignature` is returned for invalid `v`, `r` or `s`\n  and for signatures without a recoverable public key.\n  The EVM returns an empty output in that case."]
+    pub fn ctt_eth_evm_ecrecover(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+    ) -> ctt_evm_status;
+}
+unsafe extern "C" {
+    #[must_use]
+    #[doc = "  Verify an ECDSA signature over the P-256 (secp256r1) curve\n  for a message digest.\n\n  Name: P256VERIFY\n\n  Inputs:\n  - r: array with 32 bytes of storage for the result\n  - r_len: length of `r`. Must be 32\n  - inputs: 160 bytes with the following breakdown:\n    - 32 bytes, the message digest that was signed\n    - 32 bytes, `r` of the signature\n    - 32 bytes, `s` of the signature\n    - 32 bytes, `x` coordinate of the public key\n    - 32 bytes, `y` coordinate of the public key\n  - inputs_len: length of the inputs array\n\n  Output:\n  - 32 bytes, 1 left-padded with zeros on valid signature\n  - status code:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_MalformedSignature\n    cttEVM_IntLargerThanModulus\n    cttEVM_PointNotOnCurve\n    cttEVM_VerificationFailure\n\n  Any status other than cttEVM_Success is an invalid signature\n  and the EVM returns an empty output in that case.\n\n  Spec https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md"]
+    pub fn ctt_eth_evm_p256verify(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+    ) -> ctt_evm_status;
+}
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_p256verify`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_p256verify_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
 }
 unsafe extern "C" {
     #[must_use]
@@ -4844,6 +7564,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bn254_g1add`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bn254_g1add_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Elliptic Curve multiplication on BN254_Snarks\n  (also called alt_bn128 in Ethereum specs\n   and bn256 in Ethereum tests)\n\n  Name: ECMUL\n\n  Inputs:\n  - A G1 point P with coordinates (Px, Py)\n  - A scalar s in 0 ..< 2²⁵⁶\n\n  Each coordinate is a 32-byte bigEndian integer\n  r is a 32-byte bigEndian integer\n  They are serialized concatenated in a byte array [Px, Py, r]\n  If the length is less than 96 bytes, input is virtually padded with zeros.\n  If the length is greater than 96 bytes, input is truncated to 96 bytes.\n\n  Output\n  - Output buffer MUST be of length 64 bytes\n  - A G1 point R = [s]P\n  - Status codes:\n    cttEVM_Success\n    cttEVM_IntLargerThanModulus\n    cttEVM_PointNotOnCurve\n\n  Spec https://eips.ethereum.org/EIPS/eip-196"]
@@ -4854,6 +7585,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bn254_g1mul`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bn254_g1mul_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Elliptic Curve pairing check on BN254_Snarks\n  (also called alt_bn128 in Ethereum specs\n   and bn256 in Ethereum tests)\n\n  Name: ECPAIRING / Pairing check\n\n  Inputs:\n  - An array of [(P0, Q0), (P1, Q1), ... (Pk, Qk)] points in (G1, G2)\n\n  Output\n  - Output buffer MUST be of length 32 bytes\n  - 0 or 1 in uint256 BigEndian representation\n  - Status codes:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_IntLargerThanModulus\n    cttEVM_PointNotOnCurve\n    cttEVM_PointNotInSubgroup\n\n  Specs https://eips.ethereum.org/EIPS/eip-197\n        https://eips.ethereum.org/EIPS/eip-1108"]
@@ -4864,6 +7606,27 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bn254_ecpairingcheck`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bn254_ecpairingcheck_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
+unsafe extern "C" {
+    #[must_use]
+    #[doc = "  BLAKE2b compression function F\n\n  Name: BLAKE2F\n\n  Inputs:\n  - 213 bytes with the following breakdown:\n    - 4 bytes, the number of rounds, big-endian\n    - 64 bytes, the state vector h, 8 little-endian 64-bit words\n    - 128 bytes, the message block m, 16 little-endian 64-bit words\n    - 16 bytes, the offset counters t, 2 little-endian 64-bit words\n    - 1 byte, the final block indicator flag f, 0 or 1\n\n  Output\n  - Output buffer MUST be of length 64 bytes\n  - The updated state vector h, 8 little-endian 64-bit words\n  - Status codes:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_InvalidFinalFlag\n\n  Spec https://eips.ethereum.org/EIPS/eip-152"]
+    pub fn ctt_eth_evm_blake2f(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Elliptic Curve addition on BLS12-381 G1\n\n  Name: BLS12_G1ADD\n\n  Inputs:\n  - A G1 point P with coordinates (Px, Py)\n  - A G1 point Q with coordinates (Qx, Qy)\n  - Input buffer MUST be 256 bytes\n\n  Each coordinate is a 64-byte bigEndian integer\n  They are serialized concatenated in a byte array [Px, Py, Qx, Qy]\n\n  Inputs are NOT subgroup-checked.\n\n  Output\n  - Output buffer MUST be of length 128 bytes\n  - A G1 point R=P+Q with coordinates (Rx, Ry)\n  - Status codes:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_IntLargerThanModulus\n    cttEVM_PointNotOnCurve\n\n  Spec https://eips.ethereum.org/EIPS/eip-2537"]
@@ -4874,6 +7637,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bls12381_g1add`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bls12381_g1add_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Elliptic Curve addition on BLS12-381 G2\n\n  Name: BLS12_G2ADD\n\n  Inputs:\n  - A G2 point P with coordinates (Px, Py)\n  - A G2 point Q with coordinates (Qx, Qy)\n  - Input buffer MUST be 512 bytes\n\n  Each coordinate is a 128-byte bigEndian integer pair (a+𝑖b) with 𝑖 = √-1\n  They are serialized concatenated in a byte array [Px, Py, Qx, Qy]\n\n  Inputs are NOT subgroup-checked.\n\n  Output\n  - Output buffer MUST be of length 256 bytes\n  - A G2 point R=P+Q with coordinates (Rx, Ry)\n  - Status codes:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_IntLargerThanModulus\n    cttEVM_PointNotOnCurve\n\n  Spec https://eips.ethereum.org/EIPS/eip-2537"]
@@ -4884,6 +7658,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bls12381_g2add`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bls12381_g2add_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Elliptic Curve scalar multiplication on BLS12-381 G1\n\n  Name: BLS12_G1MUL\n\n  Inputs:\n  - A G1 point P with coordinates (Px, Py)\n  - A scalar s in 0 ..< 2²⁵⁶\n  - Input buffer MUST be 160 bytes\n\n  Each coordinate is a 64-byte bigEndian integer\n  s is a 32-byte bigEndian integer\n  They are serialized concatenated in a byte array [Px, Py, s]\n\n  Output\n  - Output buffer MUST be of length 128 bytes\n  - A G1 point R=P+Q with coordinates (Rx, Ry)\n  - Status code:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_IntLargerThanModulus\n    cttEVM_PointNotOnCurve\n\n  Spec https://eips.ethereum.org/EIPS/eip-2537"]
@@ -4894,6 +7679,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bls12381_g1mul`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bls12381_g1mul_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Elliptic Curve scalar multiplication on BLS12-381 G2\n\n  Name: BLS12_G2MUL\n\n  Inputs:\n  - A G2 point P with coordinates (Px, Py)\n  - A scalar s in 0 ..< 2²⁵⁶\n  - Input buffer MUST be 288 bytes\n\n  Each coordinate is a 128-byte bigEndian integer pair (a+𝑖b) with 𝑖 = √-1\n  s is a 32-byte bigEndian integer\n  They are serialized concatenated in a byte array [Px, Py, s]\n\n  Output\n  - Output buffer MUST be of length 256 bytes\n  - A G2 point R=P+Q with coordinates (Rx, Ry)\n  - Status code:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_IntLargerThanModulus\n    cttEVM_PointNotOnCurve\n\n  Spec https://eips.ethereum.org/EIPS/eip-2537"]
@@ -4904,6 +7700,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bls12381_g2mul`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bls12381_g2mul_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Elliptic Curve addition on BLS12-381 G1\n\n  Name: BLS12_G1MSM\n\n  Inputs:\n  - A sequence of pairs of points\n    - G1 points Pᵢ with coordinates (Pᵢx, Pᵢy)\n    - scalar sᵢ in 0 ..< 2²⁵⁶\n  - Each pair MUST be 160 bytes\n  - The total length MUST be a multiple of 160 bytes\n\n  Each coordinate is a 64-byte bigEndian integer\n  s is a 32-byte bigEndian integer\n  They are serialized concatenated in a byte array [(P₀x, P₀y, r₀), (P₁x, P₁y, r₁) ..., (Pₙx, Pₙy, rₙ)]\n\n  Output\n  - Output buffer MUST be of length 128 bytes\n  - A G1 point R=P+Q with coordinates (Rx, Ry)\n  - Status code:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_IntLargerThanModulus\n    cttEVM_PointNotOnCurve\n\n  Spec https://eips.ethereum.org/EIPS/eip-2537"]
@@ -4914,6 +7721,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bls12381_g1msm`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bls12381_g1msm_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Elliptic Curve addition on BLS12-381 G2\n\n  Name: BLS12_G2MSM\n\n  Inputs:\n  - A sequence of pairs of points\n    - G2 points Pᵢ with coordinates (Pᵢx, Pᵢy)\n    - scalar sᵢ in 0 ..< 2²⁵⁶\n  - Each pair MUST be 288 bytes\n  - The total length MUST be a multiple of 288 bytes\n\n  Each coordinate is a 128-byte bigEndian integer pair (a+𝑖b) with 𝑖 = √-1\n  s is a 32-byte bigEndian integer\n  They are serialized concatenated in a byte array [(P₀x, P₀y, r₀), (P₁x, P₁y, r₁) ..., (Pₙx, Pₙy, rₙ)]\n\n  Output\n  - Output buffer MUST be of length 512 bytes\n  - A G2 point R=P+Q with coordinates (Rx, Ry)\n  - Status code:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_IntLargerThanModulus\n    cttEVM_PointNotOnCurve\n\n  Spec https://eips.ethereum.org/EIPS/eip-2537"]
@@ -4924,6 +7742,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bls12381_g2msm`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bls12381_g2msm_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Elliptic curve pairing check on BLS12-381\n\n  Name: BLS12_PAIRINGCHECK\n\n  Inputs:\n  - An array of [(P0, Q0), (P1, Q1), ... (Pk, Qk)] points in (G1, G2)\n\n  Output\n  - Output buffer MUST be of length 32 bytes\n  - 0 or 1 in uint256 BigEndian representation\n  - Status codes:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_IntLargerThanModulus\n    cttEVM_PointNotOnCurve\n    cttEVM_PointNotInSubgroup\n\n  specs https://eips.ethereum.org/EIPS/eip-2537"]
@@ -4934,6 +7763,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bls12381_pairingcheck`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bls12381_pairingcheck_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Map a field element to G1\n\n  Name: BLS12_MAP_FP_TO_G1\n\n  Input:\n  - A field element in 0 ..< p, p the prime field of BLS12-381\n  - The length MUST be a 48-byte (381-bit) number serialized in 64-byte big-endian number\n\n  Output\n  - Output buffer MUST be of length 64 bytes\n  - A G1 point R with coordinates (Rx, Ry)\n  - Status code:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_IntLargerThanModulus\n\n  Spec https://eips.ethereum.org/EIPS/eip-2537"]
@@ -4944,6 +7784,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bls12381_map_fp_to_g1`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bls12381_map_fp_to_g1_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  Map an Fp2 extension field element to G2\n\n  Name: BLS12_MAP_FP2_TO_G2\n\n  Input:\n  - An extension field element in (0, 0) ..< (p, p), p the prime field of BLS12-381\n  - The length MUST be a tuple of 48-byte (381-bit) number serialized in tuple of 64-byte big-endian numbers\n\n  Output\n  - Output buffer MUST be of length 128 bytes\n  - A G2 point R with coordinates (Rx, Ry)\n  - Status code:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_InvalidOutputSize\n    cttEVM_IntLargerThanModulus\n\n  Spec https://eips.ethereum.org/EIPS/eip-2537"]
@@ -4954,6 +7805,17 @@ unsafe extern "C" {
         inputs_len: usize,
     ) -> ctt_evm_status;
 }
+unsafe extern "C" {
+    #[must_use]
+    #[doc = " Same as `ctt_eth_evm_bls12381_map_fp2_to_g2`\n  On rejection of the input, `failure` holds the reason\n  and the byte offset of the offending field element or point in `inputs`.\n  Otherwise its reason is cttEVMFail_None."]
+    pub fn ctt_eth_evm_bls12381_map_fp2_to_g2_detailed(
+        r: *mut byte,
+        r_len: usize,
+        inputs: *const byte,
+        inputs_len: usize,
+        failure: *mut ctt_evm_failure,
+    ) -> ctt_evm_status;
+}
 unsafe extern "C" {
     #[must_use]
     #[doc = "  EIP-4844 Blobs KZG point evaluation\n\n  Name: POINT_EVALUATION\n\n  Verify `p(z) = y` given commitment that corresponds to the polynomial `p(x)` and a KZG proof.\n\n  Input:\n  - versioned_hash | z | y | commitment | proof |\n  - The length MUST be 192 bytes with the following breakdown:\n    - 32 bytes, SHA256 versioned hash of the commitment VERSIONED_HASH_VERSION_KZG + sha256(commitment)[1:]\n      currently VERSIONED_HASH_VERSION_KZG is hardcoded at 0x01.\n    - 32 bytes, z a polynomial opening challenge\n    - 32 bytes, y the evaluation of the polynomial `p` at the challenge\n    - 48 bytes, C a commitment to the polynomial `p`\n    - 48 bytes, a succinct proof that allows verifying p(z) = y withut the full polynomial\n\n  Output\n  - Output buffer MUST be of length 64 bytes\n  - On success, returns:\n      - 32 bytes, the number of field elements per EIP-4844 blobs, encoded in big-endian\n      - 32 bytes, the 255-bit BLS12-381 scalar field modulus (i.e. curve order r), encoded in big endian\n  - Status code:\n    cttEVM_Success\n    cttEVM_InvalidInputSize\n    cttEVM_VerificationFailure\n\n  Spec https://eips.ethereum.org/EIPS/eip-4844"]
@@ -55,6 +55,36 @@ impl std::fmt::Display for ctt_evm_status {
             ctt_evm_status::cttEVM_PointNotOnCurve => "cttEVM_PointNotOnCurve",
             ctt_evm_status::cttEVM_PointNotInSubgroup => "cttEVM_PointNotInSubgroup",
             ctt_evm_status::cttEVM_VerificationFailure => "cttEVM_VerificationFailure",
+            ctt_evm_status::cttEVM_MalformedSignature => "cttEVM_MalformedSignature",
+            ctt_evm_status::cttEVM_InvalidFinalFlag => "cttEVM_InvalidFinalFlag",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl std::fmt::Display for ctt_eth_kzg_status {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            ctt_eth_kzg_status::cttEthKzg_Success => "cttEthKzg_Success",
+            ctt_eth_kzg_status::cttEthKzg_VerificationFailure => "cttEthKzg_VerificationFailure",
+            ctt_eth_kzg_status::cttEthKzg_InputsLengthsMismatch => "cttEthKzg_InputsLengthsMismatch",
+            ctt_eth_kzg_status::cttEthKzg_ScalarZero => "cttEthKzg_ScalarZero",
+            ctt_eth_kzg_status::cttEthKzg_ScalarLargerThanCurveOrder => "cttEthKzg_ScalarLargerThanCurveOrder",
+            ctt_eth_kzg_status::cttEthKzg_EccInvalidEncoding => "cttEthKzg_EccInvalidEncoding",
+            ctt_eth_kzg_status::cttEthKzg_EccCoordinateGreaterThanOrEqualModulus => "cttEthKzg_EccCoordinateGreaterThanOrEqualModulus",
+            ctt_eth_kzg_status::cttEthKzg_EccPointNotOnCurve => "cttEthKzg_EccPointNotOnCurve",
+            ctt_eth_kzg_status::cttEthKzg_EccPointNotInSubgroup => "cttEthKzg_EccPointNotInSubgroup",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl std::fmt::Display for ctt_eth_trusted_setup_status {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            ctt_eth_trusted_setup_status::cttEthTS_Success => "cttEthTS_Success",
+            ctt_eth_trusted_setup_status::cttEthTS_MissingOrInaccessibleFile => "cttEthTS_MissingOrInaccessibleFile",
+            ctt_eth_trusted_setup_status::cttEthTS_InvalidFile => "cttEthTS_InvalidFile",
         };
         write!(f, "{}", s)
     }
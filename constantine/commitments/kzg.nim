@@ -203,6 +203,100 @@ func kzg_prove*[N: static int, Name: static Algebra](
 
   freeHeapAligned(quotientPoly)
 
+# KZG - Prover - Monomial basis
+# ------------------------------------------------------------
+#
+# Runtime-sized variants, for use with user-supplied setups
+# that are not tied to a specific domain size.
+# The polynomial may have less coefficients than the powers of τ.
+
+func kzg_commit*[Name: static Algebra](
+       powers_of_tau: ptr UncheckedArray[EC_ShortW_Aff[Fp[Name], G1]],
+       commitment: var EC_ShortW_Aff[Fp[Name], G1],
+       poly: ptr UncheckedArray[Fr[Name]],
+       n: int) {.tags:[Alloca, HeapAlloc, Vartime].} =
+  ## Commit to a polynomial of `n` coefficients
+  ##   [commitment]₁ = [p(τ)]₁ = ∑ pᵢ.[τⁱ]₁
+  ##
+  ## The powers of τ and the polynomial must use the same basis,
+  ## either both monomial or both Lagrange.
+  var commitmentJac {.noInit.}: EC_ShortW_Jac[Fp[Name], G1]
+  commitmentJac.multiScalarMul_vartime(poly, powers_of_tau, n)
+  commitment.affine(commitmentJac)
+
+func kzg_prove_monomial*[Name: static Algebra](
+       powers_of_tau: ptr UncheckedArray[EC_ShortW_Aff[Fp[Name], G1]],
+       eval_at_challenge: var Fr[Name],
+       proof: var EC_ShortW_Aff[Fp[Name], G1],
+       poly: ptr UncheckedArray[Fr[Name]],
+       n: int,
+       opening_challenge: Fr[Name]) {.tags:[Alloca, HeapAlloc, Vartime].} =
+  ## Open a polynomial p of `n` coefficients in monomial basis
+  ## at an arbitrary point z = `opening_challenge`
+  ##
+  ##   eval_at_challenge = p(z) = y
+  ##   [proof]₁ = [(p(τ) - p(z)) / (τ-z)]₁
+  ##
+  ## The quotient q(x) = (p(x) - p(z)) / (x-z) is computed by synthetic division,
+  ## which yields p(z) as the remainder.
+  if n <= 0:
+    eval_at_challenge.setZero()
+    proof.setNeutral()
+    return
+  if n == 1:
+    # Constant polynomial, the quotient is zero.
+    eval_at_challenge = poly[0]
+    proof.setNeutral()
+    return
+
+  # Horner's method:
+  #   bₙ₋₁ = pₙ₋₁
+  #   bᵢ   = pᵢ + z.bᵢ₊₁
+  # with p(z) = b₀ and qᵢ = bᵢ₊₁
+  let quotient = allocHeapArrayAligned(Fr[Name], n-1, alignment = 64)
+  var acc = poly[n-1]
+  for i in countdown(n-2, 0):
+    quotient[i] = acc
+    acc *= opening_challenge
+    acc += poly[i]
+  eval_at_challenge = acc
+
+  var proofJac {.noInit.}: EC_ShortW_Jac[Fp[Name], G1]
+  proofJac.multiScalarMul_vartime(quotient, powers_of_tau, n-1)
+  proof.affine(proofJac)
+
+  freeHeapAligned(quotient)
+
+# KZG - Insecure setup
+# ------------------------------------------------------------
+
+func kzg_setup_insecure_for_testing*[F2; Name: static Algebra](
+       powers_of_tau: ptr UncheckedArray[EC_ShortW_Aff[Fp[Name], G1]],
+       n: int,
+       tauG2: var EC_ShortW_Aff[F2, G2],
+       tau: Fr[Name]) {.tags:[Alloca, HeapAlloc].} =
+  ## Generate a monomial-basis setup [τⁱ]₁ for i in [0, n) and [τ]₂
+  ## from a known τ.
+  ##
+  ## ⚠️ Anyone who knows τ can forge proofs.
+  ##    This is only suitable for tests and benchmarks.
+  let powersJac = allocHeapArrayAligned(EC_ShortW_Jac[Fp[Name], G1], n, alignment = 64)
+
+  var tauPow {.noInit.}: Fr[Name]
+  tauPow.setOne()
+  for i in 0 ..< n:
+    powersJac[i].setGenerator()
+    powersJac[i].scalarMul(tauPow)
+    tauPow *= tau
+
+  powers_of_tau.batchAffine(powersJac, n)
+  freeHeapAligned(powersJac)
+
+  var tauG2Jac {.noInit.}: EC_ShortW_Jac[F2, G2]
+  tauG2Jac.setGenerator()
+  tauG2Jac.scalarMul(tau)
+  tauG2.affine(tauG2Jac)
+
 # KZG - Verifier
 # ------------------------------------------------------------
 
@@ -0,0 +1,150 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  constantine/named/algebras,
+  constantine/math/[ec_shortweierstrass, arithmetic, extension_fields],
+  constantine/math/io/io_bigints,
+  constantine/math/elliptic/ec_shortweierstrass_batch_ops,
+  constantine/math/polynomials/fft,
+  constantine/platforms/[abstractions, allocs]
+
+## ############################################################
+##
+##           Powers-of-τ Structured Reference Strings
+##
+## ############################################################
+##
+## Helpers to import the powers of τ produced by trusted setup ceremonies
+## that are not using the Zcash compressed serialization:
+##
+## - snarkjs / Perpetual Powers of Tau `.ptau` files
+## - halo2 `ParamsKZG` serialized in `SerdeFormat::RawBytes`
+##
+## Both serialize points uncompressed as (x, y)
+## with coordinates in Montgomery representation, little-endian,
+## and 𝔽p2 coordinates as c0 then c1.
+##
+## The Montgomery constant R is 2^(8*bytes) with bytes the size of a field element,
+## for BN254 and BLS12-381 this matches Constantine internal representation
+## on both 32-bit and 64-bit platforms.
+
+{.push raises: [], checks: off.}
+
+func fromMontgomeryLE_unchecked[Name: static Algebra](dst: var Fp[Name], src: openArray[byte]): bool =
+  ## Load a field element from its Montgomery representation in little-endian
+  ## Returns false if the input is not reduced modulo p
+  static: doAssert sizeof(Fp[Name]) == Fp[Name].bits().ceilDiv_vartime(8), block:
+    "The Montgomery constant R must match 2^(8*bytes)"
+  if src.len != sizeof(Fp[Name]):
+    return false
+  dst.mres.unmarshal(src, littleEndian)
+  return bool(dst.mres < Fp[Name].getModulus())
+
+func fromMontgomeryLE_unchecked[Name: static Algebra](dst: var Fp2[Name], src: openArray[byte]): bool =
+  ## Load a quadratic extension field element c0 + c1 𝑖
+  ## from the Montgomery representation of c0 then c1 in little-endian
+  ## Returns false if a coordinate is not reduced modulo p
+  const L = sizeof(Fp[Name])
+  if src.len != 2*L:
+    return false
+  if not dst.c0.fromMontgomeryLE_unchecked(src.toOpenArray(0, L-1)):
+    return false
+  return dst.c1.fromMontgomeryLE_unchecked(src.toOpenArray(L, 2*L-1))
+
+func fromMontgomeryLE_unchecked*[F; G: static Subgroup](dst: var EC_ShortW_Aff[F, G], src: openArray[byte]): bool =
+  ## Deserialize an uncompressed point (x, y)
+  ## with coordinates in Montgomery representation, little-endian.
+  ##
+  ## (0, 0) is the point at infinity.
+  ##
+  ## Returns false if a coordinate is not reduced modulo p
+  ## or if the point is not on the curve.
+  ##
+  ## Warning ⚠:
+  ##   This procedure skips the very expensive subgroup checks.
+  ##   Not checking subgroup exposes a protocol to small subgroup attacks.
+  const L = sizeof(F)
+  if src.len != 2*L:
+    return false
+  if not dst.x.fromMontgomeryLE_unchecked(src.toOpenArray(0, L-1)):
+    return false
+  if not dst.y.fromMontgomeryLE_unchecked(src.toOpenArray(L, 2*L-1)):
+    return false
+  if dst.isNeutral().bool():
+    return true
+  return bool isOnCurve(dst.x, dst.y, G)
+
+func batchFromMontgomeryLE_unchecked*[F; G: static Subgroup](
+       dst: ptr UncheckedArray[EC_ShortW_Aff[F, G]],
+       src: ptr UncheckedArray[byte],
+       len: int): bool =
+  ## Deserialize `len` consecutive uncompressed points
+  ## with coordinates in Montgomery representation, little-endian.
+  ##
+  ## Returns false if any point is invalid.
+  ##
+  ## Warning ⚠:
+  ##   This procedure skips the very expensive subgroup checks.
+  const L = 2*sizeof(F)
+  for i in 0 ..< len:
+    if not dst[i].fromMontgomeryLE_unchecked(src.toOpenArray(i*L, (i+1)*L-1)):
+      return false
+  return true
+
+# Basis conversion
+# ------------------------------------------------------------
+
+func monomialToLagrange_vartime*[Name: static Algebra](
+       lagrange: ptr UncheckedArray[EC_ShortW_Aff[Fp[Name], G1]],
+       powers_of_tau: ptr UncheckedArray[EC_ShortW_Aff[Fp[Name], G1]],
+       n: int,
+       rootOfUnity: Fr[Name]): bool =
+  ## Convert the powers of τ [τⁱ]₁ for i in [0, n)
+  ## to the Lagrange basis [Lᵢ(τ)]₁ over the domain {ωⁱ}
+  ## with ω a primitive n-th root of unity, using an inverse FFT over 𝔾1:
+  ##
+  ##   [Lᵢ(τ)]₁ = 1/n ∑ⱼ ω⁻ⁱʲ [τʲ]₁
+  ##
+  ## The output is in natural order.
+  ## Ethereum EIP-4844 uses the bit-reversal permutation of this order.
+  ##
+  ## Returns false if n is not a power of 2
+  ## or ω is not a primitive n-th root of unity.
+  if n <= 0 or not n.uint64.isPowerOf2_vartime():
+    return false
+  if n == 1:
+    lagrange[0] = powers_of_tau[0]
+    return rootOfUnity.isOne().bool()
+
+  # ω is a primitive 2ᵏ-th root of unity iff ω^(2ᵏ⁻¹) = -1
+  var t = rootOfUnity
+  for _ in 0 ..< log2_vartime(uint64 n) - 1:
+    t.square()
+  if not t.isMinusOne().bool():
+    return false
+
+  type EC = EC_ShortW_Prj[Fp[Name], G1]
+
+  let fftDesc = ECFFT_Descriptor[EC].new(order = n, rootOfUnity)
+  let vals = allocHeapArrayAligned(EC, n, alignment = 64)
+  let coefs = allocHeapArrayAligned(EC, n, alignment = 64)
+
+  for i in 0 ..< n:
+    vals[i].fromAffine(powers_of_tau[i])
+
+  let status = fftDesc.ifft_vartime(coefs.toOpenArray(0, n-1), vals.toOpenArray(0, n-1))
+  if status == FFTS_Success:
+    lagrange.batchAffine(coefs, n)
+
+  freeHeapAligned(coefs)
+  freeHeapAligned(vals)
+  fftDesc.delete()
+  return status == FFTS_Success
+
+{.pop.}
@@ -0,0 +1,46 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  constantine/named/[algebras, zoo_subgroups],
+  constantine/math/[ec_shortweierstrass, extension_fields],
+  constantine/threadpool
+
+## ############################################################
+##
+##       Powers-of-τ Structured Reference Strings
+##                  Parallel validation
+##
+## ############################################################
+
+proc validate_parallel*[F; G: static Subgroup](
+       tp: Threadpool,
+       points: ptr UncheckedArray[EC_ShortW_Aff[F, G]],
+       len: int): bool =
+  ## Check that all points are on the curve and in the prime-order subgroup.
+  ## The point at infinity is accepted.
+  ##
+  ## Subgroup checks are expensive and trusted setups may have millions of points,
+  ## they are distributed on the threadpool.
+  mixin globalValid
+
+  tp.parallelFor i in 0 ..< len:
+    captures: {points}
+    reduceInto(globalValid: Flowvar[bool]):
+      prologue:
+        var workerValid = true
+      forLoop:
+        if workerValid and not points[i].isNeutral().bool():
+          workerValid = bool(isOnCurve(points[i].x, points[i].y, G) and points[i].isInSubgroup())
+      merge(remoteValid: Flowvar[bool]):
+        let remote = sync(remoteValid)
+        workerValid = workerValid and remote
+      epilogue:
+        return workerValid
+
+  return sync(globalValid)
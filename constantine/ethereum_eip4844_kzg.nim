@@ -546,3 +546,45 @@ func verify_blob_kzg_proof_batch*(
   freeHeapAligned(commitments)
 
   return result
+
+func verify_blob_kzg_proof_batch_find_invalid*(
+       ctx: ptr EthereumKZGContext,
+       invalid: ptr UncheckedArray[bool],
+       blobs: ptr UncheckedArray[Blob],
+       commitments_bytes: ptr UncheckedArray[array[48, byte]],
+       proof_bytes: ptr UncheckedArray[array[48, byte]],
+       n: int,
+       secureRandomBytes: array[32, byte]): cttEthKzgStatus {.libPrefix: prefix_eth_kzg4844, tags:[Alloca, HeapAlloc, Vartime].} =
+  ## Verify `n` (blob, commitment, proof) sets efficiently
+  ## and locate the invalid sets on failure.
+  ##
+  ## On return, `invalid[i]` is true if the i-th set is invalid,
+  ## including if its commitment, proof or blob cannot be deserialized.
+  ##
+  ## Returns:
+  ## - cttEthKzg_Success if all sets are valid
+  ## - cttEthKzg_VerificationFailure if at least one set is invalid
+  ## - cttEthKzg_InputsLengthsMismatch if `n` is negative, `invalid` is not written
+  ##
+  ## The sets are first verified as a batch. On failure, each set is verified individually.
+  ## Bisecting is not worth it: halving a batch saves pairings
+  ## but the blobs must still be converted and evaluated at their challenge,
+  ## which dominates the cost of a single verification.
+  ##
+  ## `secureRandomBytes` random byte must come from a cryptographically secure RNG
+  ## or computed through the Fiat-Shamir heuristic.
+  if n < 0:
+    return cttEthKzg_InputsLengthsMismatch
+
+  result = ctx.verify_blob_kzg_proof_batch(blobs, commitments_bytes, proof_bytes, n, secureRandomBytes)
+  if result == cttEthKzg_Success:
+    for i in 0 ..< n:
+      invalid[i] = false
+    return
+
+  result = cttEthKzg_Success
+  for i in 0 ..< n:
+    let status = ctx.verify_blob_kzg_proof(blobs[i], commitments_bytes[i], proof_bytes[i])
+    invalid[i] = status != cttEthKzg_Success
+    if invalid[i]:
+      result = cttEthKzg_VerificationFailure
@@ -421,3 +421,58 @@ proc verify_blob_kzg_proof_batch_parallel*(
   freeHeapAligned(commitments)
 
   return result
+
+proc verify_blob_kzg_proof_batch_find_invalid_parallel*(
+       tp: Threadpool,
+       ctx: ptr EthereumKZGContext,
+       invalid: ptr UncheckedArray[bool],
+       blobs: ptr UncheckedArray[Blob],
+       commitments_bytes: ptr UncheckedArray[array[48, byte]],
+       proof_bytes: ptr UncheckedArray[array[48, byte]],
+       n: int,
+       secureRandomBytes: array[32, byte]): cttEthKzgStatus {.libPrefix: prefix_eth_kzg4844.} =
+  ## Verify `n` (blob, commitment, proof) sets efficiently
+  ## and locate the invalid sets on failure.
+  ##
+  ## On return, `invalid[i]` is true if the i-th set is invalid,
+  ## including if its commitment, proof or blob cannot be deserialized.
+  ##
+  ## Returns:
+  ## - cttEthKzg_Success if all sets are valid
+  ## - cttEthKzg_VerificationFailure if at least one set is invalid
+  ## - cttEthKzg_InputsLengthsMismatch if `n` is negative, `invalid` is not written
+  ##
+  ## The sets are first verified as a batch. On failure, each set is verified individually,
+  ## in parallel.
+  ##
+  ## `secureRandomBytes` random byte must come from a cryptographically secure RNG
+  ## or computed through the Fiat-Shamir heuristic.
+  mixin globalValid
+
+  if n < 0:
+    return cttEthKzg_InputsLengthsMismatch
+
+  result = tp.verify_blob_kzg_proof_batch_parallel(ctx, blobs, commitments_bytes, proof_bytes, n, secureRandomBytes)
+  if result == cttEthKzg_Success:
+    for i in 0 ..< n:
+      invalid[i] = false
+    return
+
+  tp.parallelFor i in 0 ..< n:
+    captures: {tp, ctx, invalid, blobs, commitments_bytes, proof_bytes}
+    reduceInto(globalValid: Flowvar[bool]):
+      prologue:
+        var workerValid = true
+      forLoop:
+        let status = tp.verify_blob_kzg_proof_parallel(ctx, blobs[i], commitments_bytes[i], proof_bytes[i])
+        invalid[i] = status != cttEthKzg_Success
+        workerValid = workerValid and not invalid[i]
+      merge(remoteValid: Flowvar[bool]):
+        let remote = sync(remoteValid)
+        workerValid = workerValid and remote
+      epilogue:
+        return workerValid
+
+  if sync(globalValid):
+    return cttEthKzg_Success
+  return cttEthKzg_VerificationFailure
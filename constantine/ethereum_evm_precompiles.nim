@@ -8,8 +8,9 @@
 
 import
   ./hashes,
+  ./hashes/blake2b/blake2b_compress,
   ./platforms/abstractions,
-  ./serialization/io_limbs,
+  ./serialization/[io_limbs, endians],
   constantine/named/algebras,
   ./math/[arithmetic, extension_fields],
   ./math/arithmetic/limbs_montgomery,
@@ -24,7 +25,9 @@ import
   ./ethereum_eip4844_kzg,
   ./serialization/codecs_status_codes,
   # ECDSA for ECRecover
-  ./ethereum_ecdsa_signatures
+  ./ethereum_ecdsa_signatures,
+  # ECDSA for P256Verify
+  ./signatures/ecdsa
 
 # For KZG point precompile
 export EthereumKZGContext, TrustedSetupFormat, TrustedSetupStatus, trusted_setup_load, trusted_setup_delete
@@ -55,6 +58,25 @@ type
     cttEVM_PointNotInSubgroup
     cttEVM_VerificationFailure
     cttEVM_MalformedSignature
+    cttEVM_InvalidFinalFlag
+
+  CttEVMFailureReason* = enum
+    cttEVMFail_None
+    cttEVMFail_InvalidLength
+    cttEVMFail_NonZeroPadding
+    cttEVMFail_CoordinateGreaterOrEqualModulus
+    cttEVMFail_PointNotOnCurve
+    cttEVMFail_PointNotInSubgroup
+
+  CttEVMFailure* = object
+    ## Reason of an input rejection
+    ## and byte offset of the offending field element or point in the input of the precompile
+    reason*: CttEVMFailureReason
+    offset*: csize_t
+
+func setFailure(failure: var CttEVMFailure, reason: CttEVMFailureReason, offset: int) {.inline.} =
+  failure.reason = reason
+  failure.offset = csize_t(offset)
 
 func eth_evm_sha256*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## SHA256
@@ -255,7 +277,8 @@ func eth_evm_modexp*(r: var openArray[byte], inputs: openArray[byte]): CttEVMSta
 # Elliptic Curves
 # ----------------------------------------------------------------
 
-proc parseEip2537(dst: var Fp[BLS12_381], src: openArray[byte]): CttEvmStatus {.inline.} =
+proc parseEip2537(dst: var Fp[BLS12_381], src: openArray[byte],
+                  offset: int, failure: var CttEVMFailure): CttEvmStatus {.inline.} =
   ## Parses a curve point following the encoding rules defined in EIP-2537,
   ## i.e. requiring the input to be exactly 64 bytes, with the 'upper' 16 bytes
   ## required to be empty.
@@ -266,48 +289,58 @@ proc parseEip2537(dst: var Fp[BLS12_381], src: openArray[byte]): CttEvmStatus {.
   ## byte concatenated and the caller takes care of splitting the input for a
   ## before calling this.
   ##
+  ## `offset` is the position of `src` in the input of the precompile,
+  ## it is reported in `failure` if `src` is rejected.
+  ##
   ## Ref: https://eips.ethereum.org/EIPS/eip-2537#fine-points-and-encoding-of-base-elements
 
   var big {.noInit.}: Fp[BLS12_381].getBigInt()
   if src.len != 64:
+    failure.setFailure(cttEVMFail_InvalidLength, offset)
     return cttEVM_InvalidInputSize
 
-  # Parse the subslice of 48 bytes using regular `unmarshal`
-  var success = big.unmarshal(src.toOpenArray(16, 63), bigEndian)
-
-  # Now check that all lower bytes are empty
-  var allZero = success # if `success` already false we still continue
-  for i in 0 ..< 15: # order irrelevant
+  # Check that the 16 upper bytes are empty
+  var allZero = true
+  for i in 0 ..< 16: # order irrelevant
     allZero = allZero and (src[i] == 0)
 
-  if not allZero or not bool(big < Fp[BLS12_381].getModulus()):
+  if not allZero:
+    failure.setFailure(cttEVMFail_NonZeroPadding, offset)
+    return cttEVM_IntLargerThanModulus
+
+  # Parse the subslice of 48 bytes using regular `unmarshal`
+  let success = big.unmarshal(src.toOpenArray(16, 63), bigEndian)
+
+  if not success or not bool(big < Fp[BLS12_381].getModulus()):
+    failure.setFailure(cttEVMFail_CoordinateGreaterOrEqualModulus, offset)
     return cttEVM_IntLargerThanModulus
 
   # assign result
   dst.fromBig(big)
   result = cttEVM_Success
 
-func parseRawUint(dst: var Fp[BLS12_381], src: openarray[byte]): CttEVMStatus =
+func parseRawUint(dst: var Fp[BLS12_381], src: openarray[byte],
+                  offset: int, failure: var CttEVMFailure): CttEVMStatus =
   ## Parse an unsigned integer from its canonical
   ## big-endian or little-endian unsigned representation
   ## And store it into a field element for
   ##
   ## Return false if the integer is larger than the field modulus.
   ## Returns true on success.
-  result = dst.parseEip2537(src)
+  result = dst.parseEip2537(src, offset, failure)
 
-func parseRawUint(dst: var Fp[BN254_Snarks], src: openarray[byte]): CttEVMStatus =
+func parseRawUint(dst: var (Fp[BN254_Snarks] or Fp[P256]), src: openarray[byte],
+                  offset: int, failure: var CttEVMFailure): CttEVMStatus =
   ## Parse an unsigned integer from its canonical
   ## big-endian or little-endian unsigned representation
   ## And store it into a field element.
   ##
   ## Return false if the integer is larger than the field modulus.
   ## Returns true on success.
-  var big {.noInit.}: Fp[BN254_Snarks].getBigInt()
-  if not big.unmarshal(src, bigEndian):
-    return cttEVM_IntLargerThanModulus # `dst` too small for `src`!
-
-  if not bool(big < Fp[BN254_Snarks].getModulus()):
+  var big {.noInit.}: typeof(dst).getBigInt()
+  if not big.unmarshal(src, bigEndian) or # `dst` too small for `src`!
+      not bool(big < typeof(dst).getModulus()):
+    failure.setFailure(cttEVMFail_CoordinateGreaterOrEqualModulus, offset)
     return cttEVM_IntLargerThanModulus
 
   dst.fromBig(big)
@@ -316,17 +349,21 @@ func parseRawUint(dst: var Fp[BN254_Snarks], src: openarray[byte]): CttEVMStatus
 func fromRawCoords[Name: static Algebra, G: static Subgroup](
        dst: var EC_ShortW_Aff[Fp[Name], G],
        x, y: openarray[byte],
-       checkSubgroup: bool): CttEVMStatus =
+       offsets: array[2, int],
+       checkSubgroup: bool,
+       failure: var CttEVMFailure): CttEVMStatus =
+  ## `offsets` are the positions of `x` and `y` in the input of the precompile,
+  ## they are reported in `failure` if the point is rejected.
 
   # Deserialization
   # ----------------------
   # Encoding spec BN254: https://eips.ethereum.org/EIPS/eip-196
   #           BLS12-381: https://eips.ethereum.org/EIPS/eip-2537
 
-  let status_x = dst.x.parseRawUint(x)
+  let status_x = dst.x.parseRawUint(x, offsets[0], failure)
   if status_x != cttEVM_Success:
     return status_x
-  let status_y = dst.y.parseRawUint(y)
+  let status_y = dst.y.parseRawUint(y, offsets[1], failure)
   if status_y != cttEVM_Success:
     return status_y
 
@@ -339,10 +376,12 @@ func fromRawCoords[Name: static Algebra, G: static Subgroup](
 
   # Point on curve
   if not bool(isOnCurve(dst.x, dst.y, G)):
+    failure.setFailure(cttEVMFail_PointNotOnCurve, offsets[0])
     return cttEVM_PointNotOnCurve
 
   if checkSubgroup:
     if not dst.isInSubgroup().bool:
+      failure.setFailure(cttEVMFail_PointNotInSubgroup, offsets[0])
       return cttEVM_PointNotInSubgroup
 
   return cttEVM_Success
@@ -350,24 +389,29 @@ func fromRawCoords[Name: static Algebra, G: static Subgroup](
 func fromRawCoords[Name: static Algebra](
        dst: var EC_ShortW_Aff[Fp2[Name], G2],
        x0, x1, y0, y1: openarray[byte],
-       checkSubgroup: bool): CttEVMStatus =
+       offsets: array[4, int],
+       checkSubgroup: bool,
+       failure: var CttEVMFailure): CttEVMStatus =
+  ## `offsets` are the positions of `x0`, `x1`, `y0` and `y1` in the input of the precompile,
+  ## they are reported in `failure` if the point is rejected.
+  ## The point starts at `x0` or at `x1` for EIP-197 encoding.
 
   # Deserialization
   # ----------------------
   # Encoding spec BN254: https://eips.ethereum.org/EIPS/eip-196
   #           BLS12-381: https://eips.ethereum.org/EIPS/eip-2537
 
-  let status_x0 = dst.x.c0.parseRawUint(x0)
+  let status_x0 = dst.x.c0.parseRawUint(x0, offsets[0], failure)
   if status_x0 != cttEVM_Success:
     return status_x0
-  let status_x1 = dst.x.c1.parseRawUint(x1)
+  let status_x1 = dst.x.c1.parseRawUint(x1, offsets[1], failure)
   if status_x1 != cttEVM_Success:
     return status_x1
 
-  let status_y0 = dst.y.c0.parseRawUint(y0)
+  let status_y0 = dst.y.c0.parseRawUint(y0, offsets[2], failure)
   if status_y0 != cttEVM_Success:
     return status_y0
-  let status_y1 = dst.y.c1.parseRawUint(y1)
+  let status_y1 = dst.y.c1.parseRawUint(y1, offsets[3], failure)
   if status_y1 != cttEVM_Success:
     return status_y1
 
@@ -380,10 +424,12 @@ func fromRawCoords[Name: static Algebra](
 
   # Point on curve
   if not bool(isOnCurve(dst.x, dst.y, G2)):
+    failure.setFailure(cttEVMFail_PointNotOnCurve, min(offsets[0], offsets[1]))
     return cttEVM_PointNotOnCurve
 
   if checkSubgroup:
     if not dst.isInSubgroup().bool:
+      failure.setFailure(cttEVMFail_PointNotInSubgroup, min(offsets[0], offsets[1]))
       return cttEVM_PointNotInSubgroup
 
   return cttEVM_Success
@@ -391,10 +437,12 @@ func fromRawCoords[Name: static Algebra](
 func fromRawCoords[Name: static Algebra, G: static Subgroup](
        dst: var EC_ShortW_Jac[Fp[Name], G],
        x, y: openarray[byte],
-       checkSubgroup: bool): CttEVMStatus =
+       offsets: array[2, int],
+       checkSubgroup: bool,
+       failure: var CttEVMFailure): CttEVMStatus =
 
   var aff{.noInit.}: EC_ShortW_Aff[Fp[Name], G]
-  let status = aff.fromRawCoords(x, y, checkSubgroup)
+  let status = aff.fromRawCoords(x, y, offsets, checkSubgroup, failure)
   if status != cttEVM_Success:
     return status
   dst.fromAffine(aff)
@@ -402,15 +450,18 @@ func fromRawCoords[Name: static Algebra, G: static Subgroup](
 func fromRawCoords[Name: static Algebra, G: static Subgroup](
        dst: var EC_ShortW_Jac[Fp2[Name], G],
        x0, x1, y0, y1: openarray[byte],
-       checkSubgroup: bool): CttEVMStatus =
+       offsets: array[4, int],
+       checkSubgroup: bool,
+       failure: var CttEVMFailure): CttEVMStatus =
 
   var aff{.noInit.}: EC_ShortW_Aff[Fp2[Name], G]
-  let status = aff.fromRawCoords(x0, x1, y0, y1, checkSubgroup)
+  let status = aff.fromRawCoords(x0, x1, y0, y1, offsets, checkSubgroup, failure)
   if status != cttEVM_Success:
     return status
   dst.fromAffine(aff)
 
-func eth_evm_bn254_g1add*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bn254_g1add_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                   failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Elliptic Curve addition on BN254_Snarks
   ## (also called alt_bn128 in Ethereum specs
   ##  and bn256 in Ethereum tests)
@@ -436,6 +487,11 @@ func eth_evm_bn254_g1add*(r: var openArray[byte], inputs: openarray[byte]): CttE
   ##   cttEVM_PointNotOnCurve
   ##
   ## Spec https://eips.ethereum.org/EIPS/eip-196
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
 
   if r.len != 64:
     return cttEVM_InvalidOutputSize
@@ -449,14 +505,18 @@ func eth_evm_bn254_g1add*(r: var openArray[byte], inputs: openarray[byte]): CttE
   let statusP = P.fromRawCoords(
     x = padded.toOpenArray(0, 31),
     y = padded.toOpenArray(32, 63),
-    checkSubgroup = false) # Note: BN254 G1 cofactor is 1, there is no subgroup
+    offsets = [0, 32],
+    checkSubgroup = false,
+    failure = failure) # Note: BN254 G1 cofactor is 1, there is no subgroup
   if statusP != cttEVM_Success:
     return statusP
 
   let statusQ = Q.fromRawCoords(
     x = padded.toOpenArray(64, 95),
     y = padded.toOpenArray(96, 127),
-    checkSubgroup = false) # Note: BN254 G1 cofactor is 1, there is no subgroup
+    offsets = [64, 96],
+    checkSubgroup = false,
+    failure = failure) # Note: BN254 G1 cofactor is 1, there is no subgroup
   if statusQ != cttEVM_Success:
     return statusQ
 
@@ -468,7 +528,13 @@ func eth_evm_bn254_g1add*(r: var openArray[byte], inputs: openarray[byte]): CttE
   r.toOpenArray(32, 63).marshal(aff.y, bigEndian)
   return cttEVM_Success
 
-func eth_evm_bn254_g1mul*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bn254_g1add*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bn254_g1add_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bn254_g1add_detailed(r, inputs, failure)
+
+func eth_evm_bn254_g1mul_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                   failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Elliptic Curve multiplication on BN254_Snarks
   ## (also called alt_bn128 in Ethereum specs
   ##  and bn256 in Ethereum tests)
@@ -494,6 +560,11 @@ func eth_evm_bn254_g1mul*(r: var openArray[byte], inputs: openarray[byte]): CttE
   ##   cttEVM_PointNotOnCurve
   ##
   ## Spec https://eips.ethereum.org/EIPS/eip-196
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
 
   if r.len != 64:
     return cttEVM_InvalidOutputSize
@@ -507,7 +578,9 @@ func eth_evm_bn254_g1mul*(r: var openArray[byte], inputs: openarray[byte]): CttE
   let statusP = P.fromRawCoords(
     x = padded.toOpenArray(0, 31),
     y = padded.toOpenArray(32, 63),
-    checkSubgroup = false) # Note: BN254 G1 cofactor is 1, there is no subgroup
+    offsets = [0, 32],
+    checkSubgroup = false,
+    failure = failure) # Note: BN254 G1 cofactor is 1, there is no subgroup
   if statusP != cttEVM_Success:
     return statusP
 
@@ -540,8 +613,13 @@ func eth_evm_bn254_g1mul*(r: var openArray[byte], inputs: openarray[byte]): CttE
   r.toOpenArray(32, 63).marshal(aff.y, bigEndian)
   return cttEVM_Success
 
-func eth_evm_bn254_ecpairingcheck*(
-      r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bn254_g1mul*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bn254_g1mul_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bn254_g1mul_detailed(r, inputs, failure)
+
+func eth_evm_bn254_ecpairingcheck_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                            failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Elliptic Curve pairing check on BN254_Snarks
   ## (also called alt_bn128 in Ethereum specs
   ##  and bn256 in Ethereum tests)
@@ -564,6 +642,11 @@ func eth_evm_bn254_ecpairingcheck*(
   ##
   ## Specs https://eips.ethereum.org/EIPS/eip-197
   ##       https://eips.ethereum.org/EIPS/eip-1108
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
   if r.len != 32:
     return cttEVM_InvalidOutputSize
 
@@ -590,7 +673,9 @@ func eth_evm_bn254_ecpairingcheck*(
     let statusP = P.fromRawCoords(
       x = inputs.toOpenArray(pos, pos+31),
       y = inputs.toOpenArray(pos+32, pos+63),
-      checkSubgroup = false) # Note: BN254 G1 cofactor is 1, there is no subgroup
+      offsets = [pos, pos+32],
+      checkSubgroup = false,
+      failure = failure) # Note: BN254 G1 cofactor is 1, there is no subgroup
 
     if statusP != cttEVM_Success:
       return statusP
@@ -602,7 +687,9 @@ func eth_evm_bn254_ecpairingcheck*(
       x0 = inputs.toOpenArray(pos+96, pos+127),
       y1 = inputs.toOpenArray(pos+128, pos+159),
       y0 = inputs.toOpenArray(pos+160, pos+191),
-      checkSubgroup = true)
+      offsets = [pos+96, pos+64, pos+160, pos+128],
+      checkSubgroup = true,
+      failure = failure)
 
     if statusQ != cttEVM_Success:
       return statusQ
@@ -625,7 +712,61 @@ func eth_evm_bn254_ecpairingcheck*(
     r[r.len-1] = byte 1
   return cttEVM_Success
 
-func eth_evm_bls12381_g1add*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bn254_ecpairingcheck*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bn254_ecpairingcheck_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bn254_ecpairingcheck_detailed(r, inputs, failure)
+
+func eth_evm_blake2f*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+  ## BLAKE2b compression function F
+  ##
+  ## Name: BLAKE2F
+  ##
+  ## Inputs:
+  ## - 213 bytes with the following breakdown:
+  ##   - 4 bytes, the number of rounds, big-endian
+  ##   - 64 bytes, the state vector h, 8 little-endian 64-bit words
+  ##   - 128 bytes, the message block m, 16 little-endian 64-bit words
+  ##   - 16 bytes, the offset counters t, 2 little-endian 64-bit words
+  ##   - 1 byte, the final block indicator flag f, 0 or 1
+  ##
+  ## Output:
+  ## - 64 bytes, the updated state vector h, 8 little-endian 64-bit words
+  ## - Status code:
+  ##   cttEVM_Success
+  ##   cttEVM_InvalidInputSize
+  ##   cttEVM_InvalidOutputSize
+  ##   cttEVM_InvalidFinalFlag
+  ##
+  ## Spec https://eips.ethereum.org/EIPS/eip-152
+  if inputs.len != 213:
+    return cttEVM_InvalidInputSize
+
+  if r.len != 64:
+    return cttEVM_InvalidOutputSize
+
+  if inputs[212] > 1:
+    return cttEVM_InvalidFinalFlag
+
+  let rounds = uint32.fromBytes(inputs, 0, bigEndian)
+  var h {.noInit.}: array[8, uint64]
+  var m {.noInit.}: array[16, uint64]
+  var t {.noInit.}: array[2, uint64]
+  for i in 0 ..< 8:
+    h[i] = uint64.fromBytes(inputs, 4 + 8*i, littleEndian)
+  for i in 0 ..< 16:
+    m[i] = uint64.fromBytes(inputs, 68 + 8*i, littleEndian)
+  for i in 0 ..< 2:
+    t[i] = uint64.fromBytes(inputs, 196 + 8*i, littleEndian)
+
+  h.blake2b_compress(m, t, f = inputs[212] == 1, rounds)
+
+  for i in 0 ..< 8:
+    r.blobFrom(h[i], 8*i, littleEndian)
+  return cttEVM_Success
+
+func eth_evm_bls12381_g1add_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                      failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Elliptic Curve addition on BLS12-381 G1
   ##
   ## Name: BLS12_G1ADD
@@ -651,6 +792,11 @@ func eth_evm_bls12381_g1add*(r: var openArray[byte], inputs: openarray[byte]): C
   ##   cttEVM_PointNotOnCurve
   ##
   ## Spec https://eips.ethereum.org/EIPS/eip-2537
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
   if inputs.len != 256:
     return cttEVM_InvalidInputSize
 
@@ -666,14 +812,18 @@ func eth_evm_bls12381_g1add*(r: var openArray[byte], inputs: openarray[byte]): C
   let statusP = P.fromRawCoords(
     x = inputs.toOpenArray( 0,  64-1),
     y = inputs.toOpenArray(64, 128-1),
-    checkSubgroup = false)
+    offsets = [0, 64],
+    checkSubgroup = false,
+    failure = failure)
   if statusP != cttEVM_Success:
     return statusP
 
   let statusQ = Q.fromRawCoords(
     x = inputs.toOpenArray(128, 192-1),
     y = inputs.toOpenArray(192, 256-1),
-    checkSubgroup = false)
+    offsets = [128, 192],
+    checkSubgroup = false,
+    failure = failure)
   if statusQ != cttEVM_Success:
     return statusQ
 
@@ -685,7 +835,13 @@ func eth_evm_bls12381_g1add*(r: var openArray[byte], inputs: openarray[byte]): C
   r.toOpenArray(64, 128-1).marshal(aff.y, bigEndian)
   return cttEVM_Success
 
-func eth_evm_bls12381_g2add*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bls12381_g1add*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bls12381_g1add_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bls12381_g1add_detailed(r, inputs, failure)
+
+func eth_evm_bls12381_g2add_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                      failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Elliptic Curve addition on BLS12-381 G2
   ##
   ## Name: BLS12_G2ADD
@@ -711,6 +867,11 @@ func eth_evm_bls12381_g2add*(r: var openArray[byte], inputs: openarray[byte]): C
   ##   cttEVM_PointNotOnCurve
   ##
   ## Spec https://eips.ethereum.org/EIPS/eip-2537
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
   if inputs.len != 512:
     return cttEVM_InvalidInputSize
 
@@ -728,7 +889,9 @@ func eth_evm_bls12381_g2add*(r: var openArray[byte], inputs: openarray[byte]): C
     x1 = inputs.toOpenArray( 64, 128-1),
     y0 = inputs.toOpenArray(128, 192-1),
     y1 = inputs.toOpenArray(192, 256-1),
-    checkSubgroup = false)
+    offsets = [0, 64, 128, 192],
+    checkSubgroup = false,
+    failure = failure)
   if statusP != cttEVM_Success:
     return statusP
 
@@ -737,7 +900,9 @@ func eth_evm_bls12381_g2add*(r: var openArray[byte], inputs: openarray[byte]): C
     x1 = inputs.toOpenArray(320, 384-1),
     y0 = inputs.toOpenArray(384, 448-1),
     y1 = inputs.toOpenArray(448, 512-1),
-    checkSubgroup = false)
+    offsets = [256, 320, 384, 448],
+    checkSubgroup = false,
+    failure = failure)
   if statusQ != cttEVM_Success:
     return statusQ
 
@@ -751,7 +916,13 @@ func eth_evm_bls12381_g2add*(r: var openArray[byte], inputs: openarray[byte]): C
   r.toOpenArray(192, 256-1).marshal(aff.y.c1, bigEndian)
   return cttEVM_Success
 
-func eth_evm_bls12381_g1mul*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bls12381_g2add*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bls12381_g2add_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bls12381_g2add_detailed(r, inputs, failure)
+
+func eth_evm_bls12381_g1mul_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                      failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Elliptic Curve scalar multiplication on BLS12-381 G1
   ##
   ## Name: BLS12_G1MUL
@@ -776,6 +947,11 @@ func eth_evm_bls12381_g1mul*(r: var openArray[byte], inputs: openarray[byte]): C
   ##   cttEVM_PointNotOnCurve
   ##
   ## Spec https://eips.ethereum.org/EIPS/eip-2537
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
   if inputs.len != 160:
     return cttEVM_InvalidInputSize
 
@@ -787,7 +963,9 @@ func eth_evm_bls12381_g1mul*(r: var openArray[byte], inputs: openarray[byte]): C
   let statusP = P.fromRawCoords(
     x = inputs.toOpenArray( 0,  64-1),
     y = inputs.toOpenArray(64, 128-1),
-    checkSubgroup = true)
+    offsets = [0, 64],
+    checkSubgroup = true,
+    failure = failure)
   if statusP != cttEVM_Success:
     return statusP
 
@@ -819,7 +997,13 @@ func eth_evm_bls12381_g1mul*(r: var openArray[byte], inputs: openarray[byte]): C
   r.toOpenArray(64, 128-1).marshal(aff.y, bigEndian)
   return cttEVM_Success
 
-func eth_evm_bls12381_g2mul*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bls12381_g1mul*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bls12381_g1mul_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bls12381_g1mul_detailed(r, inputs, failure)
+
+func eth_evm_bls12381_g2mul_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                      failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Elliptic Curve scalar multiplication on BLS12-381 G2
   ##
   ## Name: BLS12_G2MUL
@@ -844,6 +1028,11 @@ func eth_evm_bls12381_g2mul*(r: var openArray[byte], inputs: openarray[byte]): C
   ##   cttEVM_PointNotOnCurve
   ##
   ## Spec https://eips.ethereum.org/EIPS/eip-2537
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
   if inputs.len != 288:
     return cttEVM_InvalidInputSize
 
@@ -857,7 +1046,9 @@ func eth_evm_bls12381_g2mul*(r: var openArray[byte], inputs: openarray[byte]): C
     x1 = inputs.toOpenArray( 64, 128-1),
     y0 = inputs.toOpenArray(128, 192-1),
     y1 = inputs.toOpenArray(192, 256-1),
-    checkSubgroup = true)
+    offsets = [0, 64, 128, 192],
+    checkSubgroup = true,
+    failure = failure)
   if statusP != cttEVM_Success:
     return statusP
 
@@ -891,7 +1082,13 @@ func eth_evm_bls12381_g2mul*(r: var openArray[byte], inputs: openarray[byte]): C
   r.toOpenArray(192, 256-1).marshal(aff.y.c1, bigEndian)
   return cttEVM_Success
 
-func eth_evm_bls12381_g1msm*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bls12381_g2mul*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bls12381_g2mul_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bls12381_g2mul_detailed(r, inputs, failure)
+
+func eth_evm_bls12381_g1msm_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                      failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Elliptic Curve addition on BLS12-381 G1
   ##
   ## Name: BLS12_G1MSM
@@ -918,6 +1115,11 @@ func eth_evm_bls12381_g1msm*(r: var openArray[byte], inputs: openarray[byte]): C
   ##   cttEVM_PointNotOnCurve
   ##
   ## Spec https://eips.ethereum.org/EIPS/eip-2537
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
   if inputs.len == 0 or inputs.len mod 160 != 0:
     return cttEVM_InvalidInputSize
 
@@ -937,7 +1139,9 @@ func eth_evm_bls12381_g1msm*(r: var openArray[byte], inputs: openarray[byte]): C
       let statusP = points[i].fromRawCoords(
         x = inputs.toOpenArray( i*160     , i*160 +  64-1),
         y = inputs.toOpenArray( i*160 + 64, i*160 + 128-1),
-        checkSubgroup = true)
+        offsets = [i*160, i*160 + 64],
+        checkSubgroup = true,
+        failure = failure)
 
       if statusP != cttEVM_Success:
         result = statusP
@@ -974,7 +1178,13 @@ func eth_evm_bls12381_g1msm*(r: var openArray[byte], inputs: openarray[byte]): C
   freeHeapAligned(points)
   freeHeapAligned(coefs_big)
 
-func eth_evm_bls12381_g2msm*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bls12381_g1msm*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bls12381_g1msm_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bls12381_g1msm_detailed(r, inputs, failure)
+
+func eth_evm_bls12381_g2msm_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                      failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Elliptic Curve addition on BLS12-381 G2
   ##
   ## Name: BLS12_G2MSM
@@ -1001,6 +1211,11 @@ func eth_evm_bls12381_g2msm*(r: var openArray[byte], inputs: openarray[byte]): C
   ##   cttEVM_PointNotOnCurve
   ##
   ## Spec https://eips.ethereum.org/EIPS/eip-2537
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
   if inputs.len == 0 or inputs.len mod 288 != 0:
     return cttEVM_InvalidInputSize
 
@@ -1022,7 +1237,9 @@ func eth_evm_bls12381_g2msm*(r: var openArray[byte], inputs: openarray[byte]): C
         x1 = inputs.toOpenArray( i*288 +  64, i*288 + 128-1),
         y0 = inputs.toOpenArray( i*288 + 128, i*288 + 192-1),
         y1 = inputs.toOpenArray( i*288 + 192, i*288 + 256-1),
-        checkSubgroup = true)
+        offsets = [i*288, i*288 +  64, i*288 + 128, i*288 + 192],
+        checkSubgroup = true,
+        failure = failure)
 
       if statusP != cttEVM_Success:
         result = statusP
@@ -1061,8 +1278,14 @@ func eth_evm_bls12381_g2msm*(r: var openArray[byte], inputs: openarray[byte]): C
   freeHeapAligned(points)
   freeHeapAligned(coefs_big)
 
+func eth_evm_bls12381_g2msm*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bls12381_g2msm_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bls12381_g2msm_detailed(r, inputs, failure)
 
-func eth_evm_bls12381_pairingcheck*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+
+func eth_evm_bls12381_pairingcheck_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                             failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Elliptic curve pairing check on BLS12-381
   ##
   ## Name: BLS12_PAIRINGCHECK
@@ -1082,6 +1305,11 @@ func eth_evm_bls12381_pairingcheck*(r: var openArray[byte], inputs: openarray[by
   ##   cttEVM_PointNotInSubgroup
   ##
   ## specs https://eips.ethereum.org/EIPS/eip-2537
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
   if r.len != 32:
     return cttEVM_InvalidOutputSize
 
@@ -1106,7 +1334,9 @@ func eth_evm_bls12381_pairingcheck*(r: var openArray[byte], inputs: openarray[by
     let statusP = P.fromRawCoords(
       x = inputs.toOpenArray(pos     , pos +  64-1),
       y = inputs.toOpenArray(pos + 64, pos + 128-1),
-      checkSubgroup = true)
+      offsets = [pos, pos + 64],
+      checkSubgroup = true,
+      failure = failure)
 
     if statusP != cttEVM_Success:
       return statusP
@@ -1118,7 +1348,9 @@ func eth_evm_bls12381_pairingcheck*(r: var openArray[byte], inputs: openarray[by
       x1 = inputs.toOpenArray(pos+192, pos+256-1),
       y0 = inputs.toOpenArray(pos+256, pos+320-1),
       y1 = inputs.toOpenArray(pos+320, pos+384-1),
-      checkSubgroup = true)
+      offsets = [pos+128, pos+192, pos+256, pos+320],
+      checkSubgroup = true,
+      failure = failure)
 
     if statusQ != cttEVM_Success:
       return statusQ
@@ -1141,7 +1373,13 @@ func eth_evm_bls12381_pairingcheck*(r: var openArray[byte], inputs: openarray[by
     r[r.len-1] = byte 1
   return cttEVM_Success
 
-func eth_evm_bls12381_map_fp_to_g1*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bls12381_pairingcheck*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bls12381_pairingcheck_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bls12381_pairingcheck_detailed(r, inputs, failure)
+
+func eth_evm_bls12381_map_fp_to_g1_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                             failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Map a field element to G1
   ##
   ## Name: BLS12_MAP_FP_TO_G1
@@ -1160,6 +1398,11 @@ func eth_evm_bls12381_map_fp_to_g1*(r: var openArray[byte], inputs: openarray[by
   ##   cttEVM_IntLargerThanModulus
   ##
   ## Spec https://eips.ethereum.org/EIPS/eip-2537
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
 
   if inputs.len != 64:
     return cttEVM_InvalidInputSize
@@ -1167,19 +1410,12 @@ func eth_evm_bls12381_map_fp_to_g1*(r: var openArray[byte], inputs: openarray[by
   if r.len != 128:
     return cttEVM_InvalidOutputSize
 
-  var u_big {.noInit.}: BigInt[381]
-  # Input is 64 bytes, with lower 48 used.
-  for i in 0 ..< 16:
-    if inputs[i] != 0:
-      return cttEVM_IntLargerThanModulus
-  discard u_big.unmarshal(inputs.toOpenArray(16, 64-1), bigEndian)
-  if bool(u_big >= Fp[BLS12_381].getModulus()):
-    return cttEVM_IntLargerThanModulus
-
   var u {.noInit.}: Fp[BLS12_381]
-  var R {.noInit.}: EC_ShortW_Jac[Fp[BLS12_381], G1]
+  let status = u.parseEip2537(inputs, offset = 0, failure)
+  if status != cttEVM_Success:
+    return status
 
-  u.fromBig(u_big)
+  var R {.noInit.}: EC_ShortW_Jac[Fp[BLS12_381], G1]
   R.mapToCurve_sswu(u)
   R.clearCofactor()
 
@@ -1190,7 +1426,13 @@ func eth_evm_bls12381_map_fp_to_g1*(r: var openArray[byte], inputs: openarray[by
   r.toOpenArray(64, 128-1).marshal(aff.y, bigEndian)
   return cttEVM_Success
 
-func eth_evm_bls12381_map_fp2_to_g2*(r: var openArray[byte], inputs: openarray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+func eth_evm_bls12381_map_fp_to_g1*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bls12381_map_fp_to_g1_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bls12381_map_fp_to_g1_detailed(r, inputs, failure)
+
+func eth_evm_bls12381_map_fp2_to_g2_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                              failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
   ## Map an Fp2 extension field element to G2
   ##
   ## Name: BLS12_MAP_FP2_TO_G2
@@ -1209,6 +1451,11 @@ func eth_evm_bls12381_map_fp2_to_g2*(r: var openArray[byte], inputs: openarray[b
   ##   cttEVM_IntLargerThanModulus
   ##
   ## Spec https://eips.ethereum.org/EIPS/eip-2537
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
 
   if inputs.len != 128:
     return cttEVM_InvalidInputSize
@@ -1217,24 +1464,12 @@ func eth_evm_bls12381_map_fp2_to_g2*(r: var openArray[byte], inputs: openarray[b
     return cttEVM_InvalidOutputSize
 
   var u {.noInit.}: Fp2[BLS12_381]
-  var t {.noInit.}: BigInt[381]
-
-  # Input is 64 bytes, with lower 48 used.
-  for i in 0 ..< 16:
-    if inputs[i] != 0:
-      return cttEVM_IntLargerThanModulus
-  discard t.unmarshal(inputs.toOpenArray(16, 64-1), bigEndian)
-  if bool(t >= Fp[BLS12_381].getModulus()):
-    return cttEVM_IntLargerThanModulus
-  u.c0.fromBig(t)
-
-  for i in 64 ..< 64+16:
-    if inputs[i] != 0:
-      return cttEVM_IntLargerThanModulus
-  discard t.unmarshal(inputs.toOpenArray(64+16, 64+64-1), bigEndian)
-  if bool(t >= Fp[BLS12_381].getModulus()):
-    return cttEVM_IntLargerThanModulus
-  u.c1.fromBig(t)
+  let status_c0 = u.c0.parseEip2537(inputs.toOpenArray(0, 64-1), offset = 0, failure)
+  if status_c0 != cttEVM_Success:
+    return status_c0
+  let status_c1 = u.c1.parseEip2537(inputs.toOpenArray(64, 128-1), offset = 64, failure)
+  if status_c1 != cttEVM_Success:
+    return status_c1
 
   var R {.noInit.}: EC_ShortW_Jac[Fp2[BLS12_381], G2]
 
@@ -1250,6 +1485,11 @@ func eth_evm_bls12381_map_fp2_to_g2*(r: var openArray[byte], inputs: openarray[b
   r.toOpenArray(192, 256-1).marshal(aff.y.c1, bigEndian)
   return cttEVM_Success
 
+func eth_evm_bls12381_map_fp2_to_g2*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_bls12381_map_fp2_to_g2_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_bls12381_map_fp2_to_g2_detailed(r, inputs, failure)
+
 proc kzg_to_versioned_hash(r: var array[32, byte], commitment_bytes: array[48, byte]) =
   ## Spec: https://eips.ethereum.org/EIPS/eip-4844#helpers
   ## `return VERSIONED_HASH_VERSION_KZG + sha256(commitment)[1:]`
@@ -1306,22 +1546,33 @@ func eth_evm_kzg_point_evaluation*(ctx: ptr EthereumKZGContext,
 
 import std / importutils # Alternatively make `r`, `s` visible or define setter or constructor
 func eth_evm_ecrecover*(r: var openArray[byte],
-                        input: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
-  ## Attempts to recover the public key, which was used to sign the given `data`
-  ## to obtain the given signature `sig`.
+                        inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+  ## Recover the Ethereum address of the public key
+  ## which was used to sign a message digest.
   ##
-  ## If the signature is invalid, the result array `r` will contain the neutral
-  ## element of the curve.
+  ## Name: ECRECOVER
   ##
   ## Inputs:
-  ##   - `r`: Array of the recovered public key. An elliptic curve point in affine
-  ##     coordinates (`EC_ShortW_Aff[Fp[Secp256k1], G1]`).
-  ##   - `input`: The input data as an array of 128 bytes. The data is as follows:
+  ##   - `r`: Array with 32 bytes of storage for the result
+  ##   - `inputs`: The input data as an array of 128 bytes. The data is as follows:
   ##     - 32 byte: `keccak256` digest of the message that was signed
-  ##     - 32 byte: `v`, decides if the even or odd coordinate in `R` was used
+  ##     - 32 byte: `v`, 27 or 28, decides if the even or odd coordinate in `R` was used
   ##     - 32 byte: `r` of the signature, scalar `Fr[Secp256k1]`
   ##     - 32 byte: `s` of the signature, scalar `Fr[Secp256k1]`
   ##
+  ##     Shorter inputs are right-padded with zeros and extra bytes are ignored.
+  ##
+  ## Output:
+  ##   - 32 bytes, the 20-byte Ethereum address left-padded with zeros
+  ##   - Status code:
+  ##     cttEVM_Success
+  ##     cttEVM_InvalidOutputSize
+  ##     cttEVM_MalformedSignature
+  ##
+  ## `cttEVM_MalformedSignature` is returned for invalid `v`, `r` or `s`
+  ## and for signatures without a recoverable public key.
+  ## The EVM returns an empty output in that case.
+  ##
   ## Implementation follows Geth here:
   ## https://github.com/ethereum/go-ethereum/blob/341647f1865dab437a690dc1424ba71495de2dd8/core/vm/contracts.go#L243-L272
   ##
@@ -1330,12 +1581,17 @@ func eth_evm_ecrecover*(r: var openArray[byte],
   ##
   ## Internal Geth implementation in:
   ## https://github.com/ethereum/go-ethereum/blob/master/signer/core/signed_data.go#L292-L319
-  if len(input) != 128:
-    return cttEVM_InvalidInputSize
-
   if len(r) != 32:
     return cttEVM_InvalidOutputSize
 
+  # 0. Right-pad the inputs
+  var input {.noInit.}: array[128, byte]
+  let inLen = min(inputs.len, 128)
+  if inLen > 0:
+    input.rawCopy(0, inputs, 0, inLen)
+  for i in inLen ..< 128:
+    input[i] = byte 0
+
   # 1. construct message hash as scalar in field `Fr[Secp256k1]`
   var msgBI {.noinit.}: BigInt[256]
   msgBI.unmarshal(input.toOpenArray(0, 32-1), bigEndian)
@@ -1348,17 +1604,21 @@ func eth_evm_ecrecover*(r: var openArray[byte],
     if input[i] != byte 0:
       return cttEVM_MalformedSignature
   let v = input[63]
-  if v notin [byte 0, 1, 27, 28]:
+  if v notin [byte 27, 28]: # Geth rejects the raw recovery ids 0 and 1
     return cttEVM_MalformedSignature
   # 2a. determine if even or odd `y` coordinate
-  let evenY = v in [byte 0, 27] # 0 / 27 indicates `y` to be even, 1 / 28 odd
+  let evenY = v == byte 27 # 27 indicates `y` to be even, 28 odd
 
-  # 3. unmarshal signature data
+  # 3. unmarshal signature data, `r` and `s` must be in [1, n)
   var signature {.noinit.}: Signature
   privateAccess(Signature)
   var rSig {.noinit}, sSig {.noinit.}: BigInt[256]
   rSig.unmarshal(input.toOpenArray(64,  96-1), bigEndian)
   sSig.unmarshal(input.toOpenArray(96, 128-1), bigEndian)
+  if rSig.isZero().bool() or not bool(rSig < Fr[Secp256k1].getModulus()):
+    return cttEVM_MalformedSignature
+  if sSig.isZero().bool() or not bool(sSig < Fr[Secp256k1].getModulus()):
+    return cttEVM_MalformedSignature
   signature.r = Fr[Secp256k1].fromBig(rSig)
   signature.s = Fr[Secp256k1].fromBig(sSig)
 
@@ -1366,15 +1626,118 @@ func eth_evm_ecrecover*(r: var openArray[byte],
   var pubKey {.noinit.}: PublicKey
   pubKey.recoverPubkeyFromDigest(msgHash, signature, evenY)
 
-  # 4. now calculate the Ethereum address of the public key (keccak256)
   privateAccess(PublicKey)
+  if pubKey.raw.isNeutral().bool():
+    return cttEVM_MalformedSignature
+
+  # 5. now calculate the Ethereum address of the public key (keccak256)
   var rawPubkey {.noinit.}: array[64, byte] # `[x, y]` coordinates of public key
   rawPubkey.toOpenArray( 0, 32-1).marshal(pubKey.raw.x, bigEndian)
   rawPubkey.toOpenArray(32, 64-1).marshal(pubKey.raw.y, bigEndian)
   var dgst {.noinit.}: array[32, byte] # keccak256 digest
   keccak256.hash(dgst, rawPubkey)
 
-  # 5. and effectively truncate to last 20 bytes of digest
+  # 6. and effectively truncate to last 20 bytes of digest
+  for i in 0 ..< 12:
+    r[i] = byte 0
   r.rawCopy(12, dgst, 12, 20)
 
   result = cttEVM_Success
+
+type P256Signature = object
+  ## A P-256 signature with `r` and `s` in [1, n)
+  r*: Fr[P256]
+  s*: Fr[P256]
+
+func eth_evm_p256verify_detailed*(r: var openArray[byte], inputs: openArray[byte],
+                                  failure: var CttEVMFailure): CttEVMStatus {.libPrefix: prefix_ffi, meter.} =
+  ## Verify an ECDSA signature over the P-256 (secp256r1) curve
+  ## for a message digest.
+  ##
+  ## Name: P256VERIFY
+  ##
+  ## Inputs:
+  ##   - `r`: Array with 32 bytes of storage for the result
+  ##   - `inputs`: The input data as an array of 160 bytes. The data is as follows:
+  ##     - 32 byte: the message digest that was signed
+  ##     - 32 byte: `r` of the signature, scalar `Fr[P256]`
+  ##     - 32 byte: `s` of the signature, scalar `Fr[P256]`
+  ##     - 32 byte: `x` coordinate of the public key, `Fp[P256]`
+  ##     - 32 byte: `y` coordinate of the public key, `Fp[P256]`
+  ##
+  ## Output:
+  ##   - 32 bytes, 1 left-padded with zeros on valid signature
+  ##   - Status code:
+  ##     cttEVM_Success
+  ##     cttEVM_InvalidInputSize
+  ##     cttEVM_InvalidOutputSize
+  ##     cttEVM_MalformedSignature
+  ##     cttEVM_IntLargerThanModulus
+  ##     cttEVM_PointNotOnCurve
+  ##     cttEVM_VerificationFailure
+  ##
+  ## Any status other than `cttEVM_Success` is an invalid signature
+  ## and the EVM returns an empty output in that case.
+  ## Malleable signatures with a high `s` are accepted.
+  ##
+  ## Spec
+  ##   https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md
+  ##   https://eips.ethereum.org/EIPS/eip-7951
+  ##
+  ## On rejection of the input, `failure` holds the reason
+  ## and the byte offset of the offending field element or point in `inputs`.
+  ## Otherwise its reason is `cttEVMFail_None`.
+  failure = CttEVMFailure(reason: cttEVMFail_None, offset: 0)
+  if len(inputs) != 160:
+    return cttEVM_InvalidInputSize
+
+  if len(r) != 32:
+    return cttEVM_InvalidOutputSize
+
+  # 1. Message digest, reduced modulo the curve order
+  var msgBI {.noinit.}: BigInt[256]
+  msgBI.unmarshal(inputs.toOpenArray(0, 32-1), bigEndian)
+  var msgHash {.noinit.}: Fr[P256]
+  msgHash.fromBig(msgBI)
+
+  # 2. `r` and `s` must be in [1, n)
+  var signature {.noinit.}: P256Signature
+  var rSig {.noinit.}, sSig {.noinit.}: BigInt[256]
+  rSig.unmarshal(inputs.toOpenArray(32, 64-1), bigEndian)
+  sSig.unmarshal(inputs.toOpenArray(64, 96-1), bigEndian)
+  if rSig.isZero().bool() or not bool(rSig < Fr[P256].getModulus()):
+    return cttEVM_MalformedSignature
+  if sSig.isZero().bool() or not bool(sSig < Fr[P256].getModulus()):
+    return cttEVM_MalformedSignature
+  signature.r = Fr[P256].fromBig(rSig)
+  signature.s = Fr[P256].fromBig(sSig)
+
+  # 3. The public key must be on the curve and not the point at infinity
+  #    P-256 has a cofactor of 1, all points on the curve are in the group.
+  var pubKey {.noInit.}: EC_ShortW_Aff[Fp[P256], G1]
+  let status = pubKey.fromRawCoords(
+    x = inputs.toOpenArray(96, 128-1),
+    y = inputs.toOpenArray(128, 160-1),
+    offsets = [96, 128],
+    checkSubgroup = false,
+    failure = failure)
+  if status != cttEVM_Success:
+    return status
+  if pubKey.isNeutral().bool():
+    failure.setFailure(cttEVMFail_PointNotOnCurve, 96)
+    return cttEVM_PointNotOnCurve
+
+  # 4. Verify
+  if not pubKey.verifyImpl(signature, msgHash):
+    return cttEVM_VerificationFailure
+
+  for i in 0 ..< 31:
+    r[i] = byte 0
+  r[31] = byte 1
+
+  result = cttEVM_Success
+
+func eth_evm_p256verify*(r: var openArray[byte], inputs: openArray[byte]): CttEVMStatus {.libPrefix: prefix_ffi.} =
+  ## Same as `eth_evm_p256verify_detailed` without the failure details
+  var failure {.noInit.}: CttEVMFailure
+  return eth_evm_p256verify_detailed(r, inputs, failure)
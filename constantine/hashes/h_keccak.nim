@@ -127,6 +127,8 @@ template internalBlockSize*(H: type KeccakContext): int =
 func init*(ctx: var KeccakContext) {.inline.} =
   ## Initialize or reinitialize a Keccak context
   ctx.reset()
+  # Signal that finishing without any update must pad the empty message
+  ctx.squeeze_offset = int32 ctx.rate()
 
 template genAbsorb(isaFeatures: untyped) =
   func `absorb _ isaFeatures`*(ctx: var KeccakContext, message: openArray[byte]) =
@@ -275,8 +277,6 @@ func finish*(ctx: var KeccakContext, digest: var array[32, byte]) =
   ## Finalize a Keccak computation and output the
   ## message digest to the `digest` buffer.
   ##
-  ## An `update` MUST be called before finish even with empty message.
-  ##
   ## Security note: this does not clear the internal buffer.
   ## if sensitive content is used, use "ctx.clear()"
   ## and also make sure that the message(s) passed were stored
@@ -56,11 +56,11 @@ template internalBlockSize*(H: type ripemd160): int =
   ## Returns the byte size of the hash function ingested blocks
   BlockSize
 
-func init*(ctx: var Ripemd160Context) =
+func init*(ctx: var Ripemd160Context) {.libPrefix: prefix_ripemd160.} =
   ## Initialize or reinitialize a Ripemd160 context
   ctx.reset()
 
-func update*(ctx: var Ripemd160Context, message: openarray[byte]) =
+func update*(ctx: var Ripemd160Context, message: openarray[byte]) {.libPrefix: prefix_ripemd160.} =
   ## Append a message to a Ripemd160 context for incremental Ripemd160 computation.
   ##
   ## Security note: the tail of your message might be stored
@@ -71,7 +71,7 @@ func update*(ctx: var Ripemd160Context, message: openarray[byte]) =
   ## in memory considered secure for your threat model.
   ctx.write(message, message.len.uint64)
 
-func finish*(ctx: var Ripemd160Context, digest: var array[DigestSize, byte])  =
+func finish*(ctx: var Ripemd160Context, digest: var array[DigestSize, byte]) {.libPrefix: prefix_ripemd160.} =
   ## Finalize a Ripemd160 computation and output the
   ## message digest to the `digest` buffer.
   ##
@@ -81,7 +81,7 @@ func finish*(ctx: var Ripemd160Context, digest: var array[DigestSize, byte])  =
   ## in memory considered secure for your threat model.
   ctx.finalize(digest)
 
-func clear*(ctx: var Ripemd160Context) =
+func clear*(ctx: var Ripemd160Context) {.libPrefix: prefix_ripemd160.} =
   ## Clear the context internal buffers
   # TODO: ensure compiler cannot optimize the code away
   ctx.reset()
@@ -43,6 +43,13 @@ type
 
   sha256* = Sha256Context
 
+  Sha256Backend* = enum
+    ## SHA256 implementation selected at runtime
+    cttSha256_Generic
+    cttSha256_x86_SSSE3
+    cttSha256_x86_SHA
+    cttSha256_ARM64_SHA2
+
 # Internals
 # ----------------------------------------------------------------
 
@@ -67,6 +74,16 @@ func hashMessageBlocks(
   else:
     hashMessageBlocks_generic(s, message, numBlocks)
 
+func initState(s: var Sha256_state) {.inline.} =
+  s.H[0] = 0x6a09e667'u32
+  s.H[1] = 0xbb67ae85'u32
+  s.H[2] = 0x3c6ef372'u32
+  s.H[3] = 0xa54ff53a'u32
+  s.H[4] = 0x510e527f'u32
+  s.H[5] = 0x9b05688c'u32
+  s.H[6] = 0x1f83d9ab'u32
+  s.H[7] = 0x5be0cd19'u32
+
 func dumpHash(
        digest: var array[DigestSize, byte],
        s: Sha256_state) {.inline.} =
@@ -96,15 +113,7 @@ func init*(ctx: var Sha256Context) {.libPrefix: prefix_sha256.} =
 
   ctx.msgLen = 0
   ctx.buf.setZero()
-
-  ctx.s.H[0] = 0x6a09e667'u32
-  ctx.s.H[1] = 0xbb67ae85'u32
-  ctx.s.H[2] = 0x3c6ef372'u32
-  ctx.s.H[3] = 0xa54ff53a'u32
-  ctx.s.H[4] = 0x510e527f'u32
-  ctx.s.H[5] = 0x9b05688c'u32
-  ctx.s.H[6] = 0x1f83d9ab'u32
-  ctx.s.H[7] = 0x5be0cd19'u32
+  ctx.s.initState()
 
 func initZeroPadded*(ctx: var Sha256Context) =
   ## Initialize a Sha256 context
@@ -199,6 +208,47 @@ func finish*(ctx: var Sha256Context, digest: var array[32, byte]) {.libPrefix: p
   ctx.s.hashMessageBlocks(ctx.buf.asUnchecked(), numBlocks = 1)
   digest.dumpHash(ctx.s)
 
+func backend*(H: type sha256): Sha256Backend =
+  ## Returns the SHA256 implementation selected at runtime
+  ## depending on the CPU features.
+  when UseASM_X86_32:
+    if ({.noSideEffect.}: hasSha()):
+      cttSha256_x86_SHA
+    elif ({.noSideEffect.}: hasSSSE3()):
+      cttSha256_x86_SSSE3
+    else:
+      cttSha256_Generic
+  elif UseASM_ARM_64 and defined(macosx):
+    cttSha256_ARM64_SHA2
+  else:
+    cttSha256_Generic
+
+func hash_64byte_pairs*(
+       H: type sha256,
+       digests: var openArray[array[DigestSize, byte]],
+       pairs: openArray[array[BlockSize, byte]]) =
+  ## Compute the SHA256 hash of each 64-byte input of `pairs`
+  ## and store them in `digests`.
+  ## `digests` and `pairs` MUST have the same length.
+  ##
+  ## This is the hash of Merkle tree inner nodes,
+  ## a 64-byte input is a single block followed by a constant padding block
+  ## so the buffering of the streaming API is skipped.
+  debug: doAssert digests.len == pairs.len
+
+  # 0x80 end-of-message marker, then the message length (512 bits) in big-endian
+  var padding {.noInit.}: array[BlockSize, byte]
+  padding.setZero()
+  padding[0] = 0x80
+  padding[BlockSize-2] = 0x02
+
+  var s {.noInit.}: Sha256_state
+  for i in 0 ..< pairs.len:
+    s.initState()
+    s.hashMessageBlocks(pairs[i].asUnchecked(), numBlocks = 1)
+    s.hashMessageBlocks(padding.asUnchecked(), numBlocks = 1)
+    digests[i].dumpHash(s)
+
 func clear*(ctx: var Sha256Context) {.libPrefix: prefix_sha256.} =
   ## Clear the context internal buffers
   ## Security note:
@@ -0,0 +1,48 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  constantine/threadpool/threadpool,
+  ./h_sha256
+
+export h_sha256
+
+# ############################################################
+#
+#                         SHA256
+#                    Parallel Edition
+#
+# ############################################################
+
+# No exceptions allowed in core cryptographic operations
+{.push raises: [].}
+{.push checks: off.}
+
+proc hash_64byte_pairs_parallel*(
+       tp: Threadpool,
+       H: type sha256,
+       digests: ptr UncheckedArray[array[32, byte]],
+       pairs: ptr UncheckedArray[array[64, byte]],
+       len: int) =
+  ## Compute the SHA256 hash of each 64-byte input of `pairs`
+  ## and store them in `digests`.
+  ##
+  ## Parallelism: This only returns when computation is fully done
+
+  # A pair is hashed in ~0.1µs with SHA extensions,
+  # schedule enough work per task to amortize the scheduling overhead.
+  const chunkSize = 256
+
+  syncScope:
+    tp.parallelFor i in 0 ..< len:
+      stride: chunkSize
+      captures: {digests, pairs, len}
+      let stop = min(i+chunkSize, len)
+      sha256.hash_64byte_pairs(
+        digests.toOpenArray(i, stop-1),
+        pairs.toOpenArray(i, stop-1))
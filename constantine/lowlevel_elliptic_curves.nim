@@ -14,7 +14,8 @@ import
     ./math/ec_twistededwards,
     ./math/elliptic/[
       ec_scalar_mul_vartime,
-      ec_multi_scalar_mul],
+      ec_multi_scalar_mul,
+      ec_scalar_mul_fixed_base],
     ./hash_to_curve/hash_to_curve
 
 # ############################################################
@@ -94,6 +95,12 @@ export ec_shortweierstrass.mixedDiff
 export ec_shortweierstrass.scalarMul
 export ec_scalar_mul_vartime.scalarMul_vartime
 export ec_multi_scalar_mul.multiScalarMul_vartime
+export ec_multi_scalar_mul.multiScalarMul
+
+export ec_scalar_mul_fixed_base.FixedBaseTable
+export ec_scalar_mul_fixed_base.precompute
+export ec_scalar_mul_fixed_base.delete
+export ec_scalar_mul_fixed_base.scalarMul
 
 # Twisted edwards curve
 export ec_twistededwards.`==`
@@ -11,6 +11,9 @@ import
   ./platforms/abstractions,
   ./threadpool,
   ./math/elliptic/ec_multi_scalar_mul_parallel,
+  ./math/elliptic/ec_multi_scalar_mul_precomp_parallel,
+  ./math/elliptic/ec_shortweierstrass_batch_ops_parallel,
+  ./math/elliptic/ec_twistededwards_batch_ops_parallel,
   ./math/ec_shortweierstrass
 
 # ############################################################
@@ -53,3 +56,17 @@ export
 
 export
   ec_multi_scalar_mul_parallel.multiScalarMul_vartime_parallel
+
+export
+  ec_multi_scalar_mul_precomp_parallel.MsmBasesTable,
+  ec_multi_scalar_mul_precomp_parallel.precompute_parallel,
+  ec_multi_scalar_mul_precomp_parallel.delete,
+  ec_multi_scalar_mul_precomp_parallel.len,
+  ec_multi_scalar_mul_precomp_parallel.multiScalarMul_vartime_parallel,
+  ec_multi_scalar_mul_precomp_parallel.multiScalarMul_batch_vartime_parallel
+
+export
+  ec_shortweierstrass_batch_ops_parallel.batchAffine_parallel,
+  ec_shortweierstrass_batch_ops_parallel.sum_reduce_vartime_parallel,
+  ec_twistededwards_batch_ops_parallel.batchAffine_parallel,
+  ec_twistededwards_batch_ops_parallel.sum_reduce_vartime_parallel
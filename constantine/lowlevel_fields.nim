@@ -10,7 +10,8 @@ import
     ./platforms/abstractions,
     ./named/algebras,
     ./math/arithmetic,
-    ./math/io/[io_bigints, io_fields]
+    ./math/io/[io_bigints, io_fields],
+    ./math/polynomials/fft
 
 # ############################################################
 #
@@ -135,3 +136,12 @@ export arithmetic.`*`
 export arithmetic.`^`
 export arithmetic.`~^`
 export arithmetic.toBig
+
+# Number Theoretic Transform
+# ------------------------------------------------------------
+
+export fft.FFTStatus
+export fft.ntt_vartime
+export fft.intt_vartime
+export fft.coset_ntt_vartime
+export fft.coset_intt_vartime
@@ -22,13 +22,14 @@ import
     ec_shortweierstrass_batch_ops,
     ec_scalar_mul, ec_scalar_mul_vartime,
     ec_multi_scalar_mul,
+    ec_scalar_mul_fixed_base,
   ],
   ../named/zoo_generators
 
 export ec_shortweierstrass_affine, ec_shortweierstrass_jacobian, ec_shortweierstrass_projective,
        ec_shortweierstrass_jacobian_extended,
        ec_shortweierstrass_batch_ops, ec_scalar_mul, ec_scalar_mul_vartime,
-       ec_multi_scalar_mul
+       ec_multi_scalar_mul, ec_scalar_mul_fixed_base
 
 type EC_ShortW*[F; G: static Subgroup] = EC_ShortW_Aff[F, G] | EC_ShortW_Jac[F, G] | EC_ShortW_Prj[F, G]
 
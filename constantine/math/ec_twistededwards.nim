@@ -19,12 +19,13 @@ import
     ec_twistededwards_batch_ops,
     ec_scalar_mul, ec_scalar_mul_vartime,
     ec_multi_scalar_mul,
+    ec_scalar_mul_fixed_base,
   ],
   ../named/zoo_generators
 
 export ec_twistededwards_affine, ec_twistededwards_projective,
        ec_twistededwards_batch_ops, ec_scalar_mul, ec_scalar_mul_vartime,
-       ec_multi_scalar_mul
+       ec_multi_scalar_mul, ec_scalar_mul_fixed_base
 
 type EC_TwEdw*[F] = EC_TwEdw_Aff[F] | EC_TwEdw_Prj[F]
 
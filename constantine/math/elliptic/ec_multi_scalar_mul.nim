@@ -566,3 +566,220 @@ func multiScalarMul_vartime*[EC, ECaff](
   debug: doAssert coefs.len == points.len
   let N = points.len
   multiScalarMul_vartime(r, coefs.asUnchecked(), points.asUnchecked(), N)
+
+# Small and signed coefficients
+# -----------------------------------------------------------------------------------------------------------------------
+#
+# The number of windows, hence of bucket accumulations and reductions,
+# is proportional to the coefficients bit-width.
+# Provers often use small coefficients (booleans, witness bits, u64 lookups) or signed ones,
+# for which paying for the full scalar field bit-width is wasteful.
+
+func fromSigned*[ECaff](dst: var BigInt[64], P: var ECaff, coef: int64, point: ECaff) {.inline.} =
+  ## Convert a signed coefficient to its absolute value
+  ## and negate the corresponding point if it is negative.
+  if coef < 0:
+    dst.setUint(not(cast[uint64](coef)) + 1) # |int64.low| does not fit in int64
+    P.neg(point)
+  else:
+    dst.setUint(uint64 coef)
+    P = point
+
+func fromFieldTruncated*[bits: static int, F](dst: var BigInt[bits], src: F) {.inline.} =
+  ## Convert a field element to a bigint of `bits` bits
+  ## The field element MUST be less than 2^bits
+  var big {.noInit.}: F.getBigInt()
+  big.fromField(src)
+  dst.copyTruncatedFrom(big)
+
+template withCoefsMaxBits*(coefsMaxBits: int, fullBits: static int, msmProc: untyped) =
+  ## Dispatch to an MSM with coefficients of the smallest supported bit-width
+  ## that fits `coefsMaxBits`. `msmProc` is a template taking the static bit-width.
+  if coefsMaxBits <= 8 and 8 < fullBits:
+    msmProc(8)
+  elif coefsMaxBits <= 16 and 16 < fullBits:
+    msmProc(16)
+  elif coefsMaxBits <= 32 and 32 < fullBits:
+    msmProc(32)
+  elif coefsMaxBits <= 64 and 64 < fullBits:
+    msmProc(64)
+  elif coefsMaxBits <= 128 and 128 < fullBits:
+    msmProc(128)
+  else:
+    msmProc(fullBits)
+
+func multiScalarMul_vartime*[EC, ECaff](
+       r: var EC,
+       coefs: ptr UncheckedArray[uint64],
+       points: ptr UncheckedArray[ECaff],
+       len: int) {.tags:[VarTime, Alloca, HeapAlloc], meter.} =
+  ## Multiscalar multiplication with 64-bit unsigned coefficients:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  let coefs_big = allocHeapArrayAligned(BigInt[64], len, alignment = 64)
+  for i in 0 ..< len:
+    coefs_big[i].setUint(coefs[i])
+  r.multiScalarMul_vartime(coefs_big, points, len)
+
+  coefs_big.freeHeapAligned()
+
+func multiScalarMul_vartime*[EC, ECaff](
+       r: var EC,
+       coefs: ptr UncheckedArray[int64],
+       points: ptr UncheckedArray[ECaff],
+       len: int) {.tags:[VarTime, Alloca, HeapAlloc], meter.} =
+  ## Multiscalar multiplication with 64-bit signed coefficients:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  ##
+  ## [-a]P is computed as [a](-P)
+  let coefs_big = allocHeapArrayAligned(BigInt[64], len, alignment = 64)
+  let signedPoints = allocHeapArrayAligned(ECaff, len, alignment = 64)
+  for i in 0 ..< len:
+    fromSigned(coefs_big[i], signedPoints[i], coefs[i], points[i])
+  r.multiScalarMul_vartime(coefs_big, signedPoints, len)
+
+  signedPoints.freeHeapAligned()
+  coefs_big.freeHeapAligned()
+
+func multiScalarMul_vartime*[F, EC, ECaff](
+       r: var EC,
+       coefs: ptr UncheckedArray[F],
+       points: ptr UncheckedArray[ECaff],
+       len: int,
+       coefsMaxBits: int) {.tags:[VarTime, Alloca, HeapAlloc], meter.} =
+  ## Multiscalar multiplication with coefficients less than 2^coefsMaxBits:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  ##
+  ## Coefficients greater or equal to 2^coefsMaxBits lead to an incorrect result.
+  template msmTruncated(bits: static int) =
+    let coefs_big = allocHeapArrayAligned(BigInt[bits], len, alignment = 64)
+    for i in 0 ..< len:
+      coefs_big[i].fromFieldTruncated(coefs[i])
+    r.multiScalarMul_vartime(coefs_big, points, len)
+    coefs_big.freeHeapAligned()
+
+  withCoefsMaxBits(coefsMaxBits, F.bits(), msmTruncated)
+
+# ########################################################### #
+#                                                             #
+#                 Multi Scalar Multiplication                 #
+#                        Constant-time                        #
+#                                                             #
+# ########################################################### #
+#
+# The bucket method accesses buckets depending on the coefficients bits
+# and skips zero windows, so it leaks the coefficients through timing and cache accesses.
+# For secret coefficients, for example blinding factors or threshold signing shares,
+# we use an interleaved fixed-window method (Straus):
+# - each point has a table of its multiples [0]P, [1]P, ..., [2ᶜ-1]P,
+# - all windows are processed, zero or not,
+# - table lookups scan the whole table of a point,
+# - additions use complete formulas.
+#
+# The tables are built for chunks of points to bound memory usage.
+
+const MsmCtWindow = 4
+const MsmCtChunkSize = 64
+
+func msmImpl_ct[bits: static int, EC, ECaff](
+       r: var EC,
+       coefs: ptr UncheckedArray[BigInt[bits]], points: ptr UncheckedArray[ECaff],
+       N: int) {.tags:[HeapAlloc], meter.} =
+  ## Multiscalar multiplication:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  ##
+  ## The coefficients are not leaked, the number of points is.
+
+  const c = MsmCtWindow
+  const tableLen = 1 shl c
+  const numWindows = bits.ceilDiv_vartime(c)
+
+  r.setNeutral()
+  if N == 0:
+    return
+
+  let tables = allocHeapArrayAligned(EC, min(N, MsmCtChunkSize) * tableLen, alignment = 64)
+
+  var chunkStart = 0
+  while chunkStart < N:
+    let n = min(N - chunkStart, MsmCtChunkSize)
+
+    # 1. Tables of multiples [k]P for k in [0, 2ᶜ)
+    for i in 0 ..< n:
+      let tab = tables +% i*tableLen
+      tab[0].setNeutral()
+      tab[1].fromAffine(points[chunkStart+i])
+      for k in 2 ..< tableLen:
+        tab[k].sum(tab[k-1], tab[1])
+
+    # 2. Interleaved fixed-window accumulation
+    var acc {.noInit.}, P {.noInit.}: EC
+    acc.setNeutral()
+    for w in countdown(numWindows-1, 0):
+      for _ in 0 ..< c:
+        acc.double()
+      for i in 0 ..< n:
+        let tab = tables +% i*tableLen
+        P.secretLookup(tab.toOpenArray(0, tableLen-1), coefs[chunkStart+i].getWindowAt(w*c, c))
+        acc += P
+
+    r += acc
+    chunkStart += n
+
+  tables.freeHeapAligned()
+
+func multiScalarMul*[bits: static int, EC, ECaff](
+       r: var EC,
+       coefs: ptr UncheckedArray[BigInt[bits]],
+       points: ptr UncheckedArray[ECaff],
+       len: int) {.tags:[HeapAlloc], meter.} =
+  ## Constant-time multiscalar multiplication:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  ##
+  ## This is suitable for secret coefficients.
+  ## For public coefficients, `multiScalarMul_vartime` is significantly faster.
+  msmImpl_ct(r, coefs, points, len)
+
+func multiScalarMul*[bits: static int, EC, ECaff](
+       r: var EC,
+       coefs: openArray[BigInt[bits]],
+       points: openArray[ECaff]) {.tags:[HeapAlloc], meter.} =
+  ## Constant-time multiscalar multiplication:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  ##
+  ## This is suitable for secret coefficients.
+  ## For public coefficients, `multiScalarMul_vartime` is significantly faster.
+  debug: doAssert coefs.len == points.len
+  let N = points.len
+  msmImpl_ct(r, coefs.asUnchecked(), points.asUnchecked(), N)
+
+func multiScalarMul*[F, EC, ECaff](
+       r: var EC,
+       coefs: ptr UncheckedArray[F],
+       points: ptr UncheckedArray[ECaff],
+       len: int) {.tags:[HeapAlloc], meter.} =
+  ## Constant-time multiscalar multiplication:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  ##
+  ## This is suitable for secret coefficients.
+  ## For public coefficients, `multiScalarMul_vartime` is significantly faster.
+  let coefs_big = allocHeapArrayAligned(F.getBigInt(), len, alignment = 64)
+  coefs_big.batchFromField(coefs, len)
+  r.multiScalarMul(coefs_big, points, len)
+
+  # Coefficients are secret, clear them before releasing the memory
+  # TODO: ensure compiler cannot optimize the code away
+  zeroMem(coefs_big, len * sizeof(F.getBigInt()))
+  coefs_big.freeHeapAligned()
+
+func multiScalarMul*[EC, ECaff](
+       r: var EC,
+       coefs: openArray[Fr],
+       points: openArray[ECaff]) {.tags:[HeapAlloc], inline.} =
+  ## Constant-time multiscalar multiplication:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  ##
+  ## This is suitable for secret coefficients.
+  ## For public coefficients, `multiScalarMul_vartime` is significantly faster.
+  debug: doAssert coefs.len == points.len
+  let N = points.len
+  multiScalarMul(r, coefs.asUnchecked(), points.asUnchecked(), N)
@@ -638,3 +638,68 @@ proc multiScalarMul_vartime_parallel*[EC, ECaff](
   debug: doAssert coefs.len == points.len
   let N = points.len
   tp.multiScalarMul_vartime_parallel(r.addr, coefs.asUnchecked(), points.asUnchecked(), N)
+
+# Small and signed coefficients
+# -----------------------------------------------------------------------------------------------------------------------
+
+proc multiScalarMul_vartime_parallel*[EC, ECaff](
+       tp: Threadpool,
+       r: ptr EC,
+       coefs: ptr UncheckedArray[uint64],
+       points: ptr UncheckedArray[ECaff],
+       len: int) {.meter.} =
+  ## Multiscalar multiplication with 64-bit unsigned coefficients:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  let coefs_big = allocHeapArrayAligned(BigInt[64], len, alignment = 64)
+
+  syncScope:
+    tp.parallelFor i in 0 ..< len:
+      captures: {coefs, coefs_big}
+      coefs_big[i].setUint(coefs[i])
+  tp.multiScalarMul_vartime_parallel(r, coefs_big, points, len)
+
+  freeHeapAligned(coefs_big)
+
+proc multiScalarMul_vartime_parallel*[EC, ECaff](
+       tp: Threadpool,
+       r: ptr EC,
+       coefs: ptr UncheckedArray[int64],
+       points: ptr UncheckedArray[ECaff],
+       len: int) {.meter.} =
+  ## Multiscalar multiplication with 64-bit signed coefficients:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  ##
+  ## [-a]P is computed as [a](-P)
+  let coefs_big = allocHeapArrayAligned(BigInt[64], len, alignment = 64)
+  let signedPoints = allocHeapArrayAligned(ECaff, len, alignment = 64)
+
+  syncScope:
+    tp.parallelFor i in 0 ..< len:
+      captures: {coefs, coefs_big, points, signedPoints}
+      fromSigned(coefs_big[i], signedPoints[i], coefs[i], points[i])
+  tp.multiScalarMul_vartime_parallel(r, coefs_big, signedPoints, len)
+
+  freeHeapAligned(signedPoints)
+  freeHeapAligned(coefs_big)
+
+proc multiScalarMul_vartime_parallel*[F, EC, ECaff](
+       tp: Threadpool,
+       r: ptr EC,
+       coefs: ptr UncheckedArray[F],
+       points: ptr UncheckedArray[ECaff],
+       len: int,
+       coefsMaxBits: int) {.meter.} =
+  ## Multiscalar multiplication with coefficients less than 2^coefsMaxBits:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  ##
+  ## Coefficients greater or equal to 2^coefsMaxBits lead to an incorrect result.
+  template msmTruncated(bits: static int) =
+    let coefs_big = allocHeapArrayAligned(BigInt[bits], len, alignment = 64)
+    syncScope:
+      tp.parallelFor i in 0 ..< len:
+        captures: {coefs, coefs_big}
+        coefs_big[i].fromFieldTruncated(coefs[i])
+    tp.multiScalarMul_vartime_parallel(r, coefs_big, points, len)
+    freeHeapAligned(coefs_big)
+
+  withCoefsMaxBits(coefsMaxBits, F.bits(), msmTruncated)
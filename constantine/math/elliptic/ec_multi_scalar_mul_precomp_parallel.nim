@@ -0,0 +1,218 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import constantine/named/algebras,
+       constantine/math/arithmetic,
+       constantine/math/ec_shortweierstrass,
+       constantine/platforms/abstractions,
+       constantine/threadpool/threadpool,
+       ./ec_multi_scalar_mul_parallel
+
+# No exceptions allowed in core cryptographic operations
+{.push raises: [].}
+{.push checks: off.}
+
+# ########################################################### #
+#                                                             #
+#   Parallel Multi Scalar Multiplication with fixed bases     #
+#                                                             #
+# ########################################################### #
+
+# When the bases of many MSMs are fixed, for example the commitment key
+# of a proving session, we can trade memory for speed.
+#
+# Each coefficient aᵢ is split into t chunks of 64 bits
+#   aᵢ = aᵢ₀ + 2⁶⁴ aᵢ₁ + ... + 2⁶⁴⁽ᵗ⁻¹⁾ aᵢ₍ₜ₋₁₎
+# hence
+#   [aᵢ]Pᵢ = [aᵢ₀]Pᵢ + [aᵢ₁]([2⁶⁴]Pᵢ) + ... + [aᵢ₍ₜ₋₁₎]([2⁶⁴⁽ᵗ⁻¹⁾]Pᵢ)
+#
+# After precomputing the multiples [2⁶⁴ʲ]Pᵢ of each base once,
+# an MSM of size n with b-bit coefficients becomes an MSM of size t·n with 64-bit coefficients:
+# - there are t times fewer windows, hence t times fewer bucket reductions and no doublings between windows.
+# - the larger input size allows larger buckets, reducing the total number of additions.
+# - the endomorphism decomposition of coefficients is not needed anymore.
+#
+# The table uses t times the memory of the bases, t = 4 for 254-bit and 255-bit scalar fields.
+
+const MsmTableChunkBits = 64
+
+type
+  MsmBasesTable*[ECaff] = object
+    ## Precomputed multiples [2⁶⁴ʲ]Pᵢ of fixed MSM bases Pᵢ
+    ## stored at index i*t + j
+    len: int
+    points: ptr UncheckedArray[ECaff]
+
+template chunksPerBase(ECaff: typedesc): int =
+  (ECaff.getScalarField().bits() + MsmTableChunkBits - 1) div MsmTableChunkBits
+
+func precomputeMultiples[F; G: static Subgroup](
+       table: ptr UncheckedArray[EC_ShortW_Aff[F, G]],
+       points: ptr UncheckedArray[EC_ShortW_Aff[F, G]],
+       len: int) {.tags:[HeapAlloc, Alloca].} =
+  ## Compute table[i*t + j] = [2⁶⁴ʲ]points[i]
+  const t = chunksPerBase(EC_ShortW_Aff[F, G])
+  let multiples = allocHeapArrayAligned(EC_ShortW_Jac[F, G], len*t, alignment = 64)
+
+  for i in 0 ..< len:
+    multiples[i*t].fromAffine(points[i])
+    for j in 1 ..< t:
+      multiples[i*t+j] = multiples[i*t+j-1]
+      for _ in 0 ..< MsmTableChunkBits:
+        multiples[i*t+j].double()
+
+  table.batchAffine(multiples, len*t)
+  multiples.freeHeapAligned()
+
+proc precompute_parallel*[F; G: static Subgroup](
+       tp: Threadpool,
+       table: var MsmBasesTable[EC_ShortW_Aff[F, G]],
+       points: ptr UncheckedArray[EC_ShortW_Aff[F, G]],
+       len: int) =
+  ## Precompute the table of fixed MSM bases `points`.
+  ## The table MUST be freed with `delete`.
+  const t = chunksPerBase(EC_ShortW_Aff[F, G])
+  const chunkSize = 256
+
+  table.len = len
+  table.points = allocHeapArrayAligned(EC_ShortW_Aff[F, G], len*t, alignment = 64)
+  let dst = table.points
+
+  syncScope:
+    tp.parallelFor i in 0 ..< len:
+      stride: chunkSize
+      captures: {dst, points, len}
+      let stop = min(i+chunkSize, len)
+      precomputeMultiples(dst +% i*t, points +% i, stop-i)
+
+func delete*(table: var MsmBasesTable) =
+  ## Free the memory of a table of MSM bases
+  if not table.points.isNil:
+    table.points.freeHeapAligned()
+  table.points = nil
+  table.len = 0
+
+func len*(table: MsmBasesTable): int {.inline.} =
+  ## Number of bases in the table
+  table.len
+
+func splitChunks[bits: static int](
+       dst: ptr UncheckedArray[BigInt[MsmTableChunkBits]],
+       src: BigInt[bits], t: static int) {.inline.} =
+  ## Split `src` into `t` chunks of 64 bits, least significant first
+  const L = BigInt[MsmTableChunkBits].limbs.len
+  for j in 0 ..< t:
+    for k in 0 ..< L:
+      let idx = j*L + k
+      dst[j].limbs[k] = if idx < src.limbs.len: src.limbs[idx]
+                        else: Zero
+
+proc multiScalarMul_vartime_parallel*[EC, F; G: static Subgroup](
+       tp: Threadpool,
+       r: ptr EC,
+       coefs: ptr UncheckedArray[Fr],
+       table: ptr MsmBasesTable[EC_ShortW_Aff[F, G]],
+       len: int) {.meter.} =
+  ## Multiscalar multiplication with precomputed fixed bases:
+  ##   r <- [a₀]P₀ + [a₁]P₁ + ... + [aₙ₋₁]Pₙ₋₁
+  ##
+  ## The MSM uses the first `len` bases of the table, `len` MUST be less or equal to the table length.
+  debug: doAssert len <= table.len
+  const t = chunksPerBase(EC_ShortW_Aff[F, G])
+  let chunks = allocHeapArrayAligned(BigInt[MsmTableChunkBits], len*t, alignment = 64)
+
+  syncScope:
+    tp.parallelFor i in 0 ..< len:
+      captures: {coefs, chunks}
+      var big {.noInit.}: typeof(coefs[i]).getBigInt()
+      big.fromField(coefs[i])
+      splitChunks(chunks +% i*t, big, t)
+
+  tp.multiScalarMul_vartime_parallel(r, chunks, table.points, len*t)
+  chunks.freeHeapAligned()
+
+# ########################################################### #
+#                                                             #
+#         Batch of Multi Scalar Multiplications               #
+#                 with shared bases                           #
+#                                                             #
+# ########################################################### #
+
+# Provers commit to many polynomials of the same size against the same bases.
+# Running the MSMs one after the other leaves threads idle
+# during the reduction tail of each MSM.
+# Instead, all MSMs are spawned together and idle threads steal work from the others.
+
+const MsmBatchTableThreshold = 8
+  ## Number of MSMs over the same bases from which precomputing a table of the bases
+  ## is amortized. The table costs 192 doublings per base for 254-bit and 255-bit scalar fields.
+
+proc msmTable_task[EC, F; G: static Subgroup](
+       tp: Threadpool,
+       r: ptr EC,
+       coefs: ptr UncheckedArray[Fr],
+       table: ptr MsmBasesTable[EC_ShortW_Aff[F, G]],
+       len: int) =
+  tp.multiScalarMul_vartime_parallel(r, coefs, table, len)
+
+proc msm_task[EC, F; G: static Subgroup](
+       tp: Threadpool,
+       r: ptr EC,
+       coefs: ptr UncheckedArray[Fr],
+       points: ptr UncheckedArray[EC_ShortW_Aff[F, G]],
+       len: int) =
+  tp.multiScalarMul_vartime_parallel(r, coefs, points, len)
+
+proc multiScalarMul_batch_vartime_parallel*[EC, F; G: static Subgroup](
+       tp: Threadpool,
+       r: ptr UncheckedArray[EC],
+       coefs: ptr UncheckedArray[ptr UncheckedArray[Fr]],
+       numMSMs: int,
+       table: ptr MsmBasesTable[EC_ShortW_Aff[F, G]],
+       len: int) {.meter.} =
+  ## Batch of independent multiscalar multiplications with precomputed fixed bases:
+  ##   r[k] <- [aₖ₀]P₀ + [aₖ₁]P₁ + ... + [aₖ₍ₙ₋₁₎]Pₙ₋₁ for k in [0, numMSMs)
+  ##
+  ## The MSMs use the first `len` bases of the table, `len` MUST be less or equal to the table length.
+  debug: doAssert len <= table.len
+  let ready = allocHeapArrayAligned(Flowvar[bool], numMSMs, alignment = 64)
+
+  for k in 0 ..< numMSMs:
+    ready[k] = tp.spawnAwaitable msmTable_task(tp, r[k].addr, coefs[k], table, len)
+  for k in 0 ..< numMSMs:
+    discard sync ready[k]
+
+  ready.freeHeapAligned()
+
+proc multiScalarMul_batch_vartime_parallel*[EC, F; G: static Subgroup](
+       tp: Threadpool,
+       r: ptr UncheckedArray[EC],
+       coefs: ptr UncheckedArray[ptr UncheckedArray[Fr]],
+       numMSMs: int,
+       points: ptr UncheckedArray[EC_ShortW_Aff[F, G]],
+       len: int) {.meter.} =
+  ## Batch of independent multiscalar multiplications with the same bases:
+  ##   r[k] <- [aₖ₀]P₀ + [aₖ₁]P₁ + ... + [aₖ₍ₙ₋₁₎]Pₙ₋₁ for k in [0, numMSMs)
+  ##
+  ## If the batch is large enough, a table of the bases is precomputed
+  ## and shared by all MSMs.
+  if numMSMs >= MsmBatchTableThreshold:
+    var table: MsmBasesTable[EC_ShortW_Aff[F, G]]
+    tp.precompute_parallel(table, points, len)
+    tp.multiScalarMul_batch_vartime_parallel(r, coefs, numMSMs, table.addr, len)
+    table.delete()
+    return
+
+  let ready = allocHeapArrayAligned(Flowvar[bool], numMSMs, alignment = 64)
+
+  for k in 0 ..< numMSMs:
+    ready[k] = tp.spawnAwaitable msm_task(tp, r[k].addr, coefs[k], points, len)
+  for k in 0 ..< numMSMs:
+    discard sync ready[k]
+
+  ready.freeHeapAligned()
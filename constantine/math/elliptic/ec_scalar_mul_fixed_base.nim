@@ -0,0 +1,123 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  constantine/platforms/abstractions,
+  constantine/named/algebras,
+  constantine/math/arithmetic,
+  ./ec_shortweierstrass_affine,
+  ./ec_shortweierstrass_projective,
+  ./ec_shortweierstrass_jacobian,
+  ./ec_twistededwards_affine,
+  ./ec_twistededwards_projective,
+  ./ec_shortweierstrass_batch_ops,
+  ./ec_twistededwards_batch_ops
+
+{.push raises: [].} # No exceptions allowed in core cryptographic operations
+{.push checks: off.} # No defects due to array bound checking or signed integer overflow allowed
+
+# ############################################################
+#                                                            #
+#             Fixed-base Scalar Multiplication               #
+#                                                            #
+# ############################################################
+#
+# Key generation, signing and commitments multiply the same base,
+# usually the generator G, by many scalars.
+#
+# We precompute for each window j of c bits of the scalar
+# the multiples [k·2ᶜʲ]G for k in [1, 2ᶜ).
+# Then [a]G = ∑ⱼ [aⱼ·2ᶜʲ]G with aⱼ the j-th window of a,
+# and needs no doubling, only one addition per window.
+#
+# For secret scalars:
+# - all windows are processed, zero or not,
+# - table lookups scan all the multiples of a window,
+# - additions use complete formulas.
+
+const FixedBaseWindow = 4
+
+type
+  FixedBaseTable*[ECaff] = object
+    ## Precomputed multiples [k·2ᶜʲ]G of a fixed base G
+    ## stored at index j*(2ᶜ-1) + k-1
+    points: ptr UncheckedArray[ECaff]
+
+template numWindows(bits: static int): int =
+  bits.ceilDiv_vartime(FixedBaseWindow)
+
+template numMultiples(): int =
+  (1 shl FixedBaseWindow) - 1
+
+func precompute*[ECaff](table: var FixedBaseTable[ECaff], base: ECaff) {.tags:[HeapAlloc, Alloca].} =
+  ## Precompute the table of the fixed base `base`.
+  ## The table MUST be freed with `delete`.
+  const t = numWindows(ECaff.getScalarField().bits())
+  const m = numMultiples()
+  type EC = projective(ECaff)
+
+  table.points = allocHeapArrayAligned(ECaff, t*m, alignment = 64)
+  let multiples = allocHeapArrayAligned(EC, t*m, alignment = 64)
+
+  var B {.noInit.}: EC # [2ᶜʲ]G
+  B.fromAffine(base)
+  for j in 0 ..< t:
+    let row = multiples +% j*m
+    row[0] = B
+    for k in 1 ..< m:
+      row[k].sum(row[k-1], B)
+    B += row[m-1]
+
+  table.points.batchAffine(multiples, t*m)
+  multiples.freeHeapAligned()
+
+func delete*(table: var FixedBaseTable) =
+  ## Free the memory of a fixed-base table
+  if not table.points.isNil:
+    table.points.freeHeapAligned()
+  table.points = nil
+
+func scalarMul*[EC, ECaff; bits: static int](
+       r: var EC,
+       table: FixedBaseTable[ECaff],
+       scalar: BigInt[bits]) {.meter.} =
+  ## Fixed-base scalar multiplication
+  ##
+  ##   r <- [k] G
+  ##
+  ## with G the base of the precomputed table.
+  ## This is constant-time and suitable for secret scalars.
+  static: doAssert bits <= ECaff.getScalarField().bits(), block:
+      "The scalar is larger than the precomputed table:\n" &
+      "  scalar: " & $bits & "-bit\n" &
+      "  table:  " & $ECaff.getScalarField().bits() & "-bit\n"
+  const c = FixedBaseWindow
+  const m = numMultiples()
+
+  var P: ECaff
+  var tmp {.noInit.}: EC
+  r.setNeutral()
+  for j in 0 ..< numWindows(bits):
+    let k = scalar.getWindowAt(j*c, c)
+    # Index k-1, no multiple is selected for a zero window and P is then discarded.
+    P.secretLookup(table.points.toOpenArray(j*m, j*m+m-1), k - One)
+    tmp = r
+    tmp += P
+    r.ccopy(tmp, k.isNonZero())
+
+func scalarMul*[EC, ECaff](
+       r: var EC,
+       table: FixedBaseTable[ECaff],
+       scalar: Fr) {.inline.} =
+  ## Fixed-base scalar multiplication
+  ##
+  ##   r <- [k] G
+  ##
+  ## with G the base of the precomputed table.
+  ## This is constant-time and suitable for secret scalars.
+  r.scalarMul(table, scalar.toBig())
@@ -78,12 +78,16 @@ func curve_eq_rhs*[F](y2: var F, x: F, G: static Subgroup) =
 
   var t{.noInit.}: F
   t.square(x)
-  when F.Name.getCoefA() != 0:
-    t += F.Name.getCoefA()
   t *= x
+  when F.Name.getCoefA() != 0:
+    var ax{.noInit.}: F
+    ax.prod(x, F.Name.getCoefA())
+    t += ax
 
   when G == G1:
-    when F.Name.getCoefB() >= 0:
+    when F.Name.getCoefB() isnot int:
+      y2.sum(F.Name.getCoefB(), t)
+    elif F.Name.getCoefB() >= 0:
       y2.fromUint uint F.Name.getCoefB()
       y2 += t
     else:
@@ -228,7 +228,10 @@ func lambdaDouble*[F; G: static Subgroup](lambda_num, lambda_den: var F, P: EC_S
   lambda_num.square(P.x)
   lambda_num *= 3
   when F.Name.getCoefA() != 0:
-    t += F.Name.getCoefA()
+    var a{.noInit.}: F
+    a.setOne()
+    a.prod(a, F.Name.getCoefA())
+    lambda_num += a
 
   lambda_den.double(P.y)
 
@@ -17,6 +17,47 @@ import
 # No exceptions allowed
 {.push raises:[], checks: off.}
 
+# ############################################################
+#
+#             Elliptic Curve in Short Weierstrass form
+#                   Parallel Batch conversion
+#
+# ############################################################
+
+proc batchAffine_parallel*[F, G](
+       tp: Threadpool,
+       affs: ptr UncheckedArray[EC_ShortW_Aff[F, G]],
+       points: ptr UncheckedArray[EC_ShortW_Jac[F, G]] or ptr UncheckedArray[EC_ShortW_Prj[F, G]],
+       N: int) {.noInline.} =
+  ## Parallel batch conversion of `points` to affine coordinates
+  ## Each chunk of points uses a single inversion.
+  if N <= 0:
+    return
+
+  # batchAffine allocates a SecretBool per point on the stack,
+  # chunks are capped to keep the stack of worker threads small.
+  const minChunkSize = 1024
+  const maxChunkSize = 8192
+
+  let chunkDesc = balancedChunksPrioSize(
+    start = 0, stopEx = N,
+    minChunkSize, maxChunkSize,
+    numChunksHint = tp.numThreads.int)
+
+  if chunkDesc.numChunks == 1:
+    affs.batchAffine(points, N)
+    return
+
+  syncScope:
+    for iter in items(chunkDesc):
+      proc batchAffine_chunk_wrapper(dst: ptr, src: ptr, len: int) {.nimcall.} =
+        dst.batchAffine(src, len)
+
+      tp.spawn batchAffine_chunk_wrapper(
+                affs +% iter.start,
+                points +% iter.start,
+                iter.size)
+
 # ############################################################
 #
 #             Elliptic Curve in Short Weierstrass form
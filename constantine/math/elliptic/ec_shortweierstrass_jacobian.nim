@@ -281,7 +281,7 @@ template sumImpl[F; G: static Subgroup](
     elif CoefA_eq_minus3:
       var a{.noInit.}, b{.noInit.}: F
       a.sum(P.x, Z1Z1)
-      b.diff(P.z, Z1Z1)
+      b.diff(P.x, Z1Z1)
       a.ccopy(H_or_Y, not isDbl)    # H   or X₁+ZZ
       b.ccopy(HH_or_YY, not isDbl)  # HH  or X₁-ZZ
       HHH_or_Mpre.prod(a, b)        # HHH or X₁²-ZZ²
@@ -498,7 +498,7 @@ func mixedSum*[F; G: static Subgroup](
     elif CoefA_eq_minus3:
       var a{.noInit.}, b{.noInit.}: F
       a.sum(P.x, Z1Z1)
-      b.diff(P.z, Z1Z1)
+      b.diff(P.x, Z1Z1)
       a.ccopy(H_or_Y, not isDbl)    # H   or X₁+ZZ
       b.ccopy(HH_or_YY, not isDbl)  # HH  or X₁-ZZ
       HHH_or_Mpre.prod(a, b)        # HHH or X₁²-ZZ²
@@ -591,6 +591,39 @@ func dbl_1998_cmo_rescaled_a0_impl[F; G: static Subgroup](r: var EC_ShortW_Jac[F
   r.y *= M           # Y₃ = M*(S-X₃)
   r.y -= Y           # Y₃ = M*(S-X₃)-YY²
 
+func dbl_1998_cmo_rescaled_aminus3_impl[F; G: static Subgroup](r: var EC_ShortW_Jac[F, G], P: EC_ShortW_Jac[F, G]) {.inline.} =
+  static: doAssert F.Name.getCoefA() == -3
+  # "dbl-1998-cmo" doubling formula with a = -3
+  # rescaled by 1/2, 1/4, 1/8 like the a = 0 formula
+  #
+  #     Cost: 4M + 4S + 5add + 1*3 + 1half
+  #
+  #        ZZ = Z₁²
+  #        YY = Y₁²
+  #         M = 3(X₁-ZZ)(X₁+ZZ)/2
+  #         S = X₁*YY
+  #        X₃ = M²-2*S
+  #        Y₃ = M*(S-X₃)-YY²
+  #        Z₃ = Y₁*Z₁
+  var Y {.noInit.}, M {.noInit.}, S {.noInit.}: F
+  S.square(P.z)
+  M.sum(P.x, S)
+  S.diff(P.x, S)
+  M *= S
+  M *= 3
+  M.div2()
+  Y.square(P.y)
+  S.prod(P.x, Y)
+  Y.square()
+
+  r.z.prod(P.z, P.y) # Z₃ = Y₁*Z₁, no aliasing
+  r.x.square(M)      # X₃ = M²
+  r.x -= S           # X₃ = M²-S
+  r.x -= S           # X₃ = M²-2*S
+  r.y.diff(S, r.x)   # Y₃ = S-X₃
+  r.y *= M           # Y₃ = M*(S-X₃)
+  r.y -= Y           # Y₃ = M*(S-X₃)-YY²
+
 func double*[F; G: static Subgroup](r: var EC_ShortW_Jac[F, G], P: EC_ShortW_Jac[F, G]) {.meter.} =
   ## Elliptic curve point doubling for Short Weierstrass curves in projective coordinate
   ##
@@ -606,6 +639,8 @@ func double*[F; G: static Subgroup](r: var EC_ShortW_Jac[F, G], P: EC_ShortW_Jac
   ## Implementation is constant-time.
   when F.Name.getCoefA() == 0:
     dbl_1998_cmo_rescaled_a0_impl(r, P)
+  elif F.Name.getCoefA() == -3:
+    dbl_1998_cmo_rescaled_aminus3_impl(r, P)
   else:
     {.error: "Not implemented.".}
 
@@ -157,7 +157,10 @@ func double*[F; G: static Subgroup](r: var EC_ShortW_JacExt[F, G], P: EC_ShortW_
   M.square(P.x)
   M *= 3
   when F.Name.getCoefA() != 0:
-    {.error: "Not implemented.".}
+    var t{.noInit.}: F
+    t.square(P.zz)
+    t.prod(t, F.Name.getCoefA())
+    M += t                     # M = 3X₁²+aZZ₁²
 
   # aliasing, we don't use P.x and U anymore
   r.x.square(M)
@@ -242,7 +245,10 @@ func mdouble*[F; G: static Subgroup](r: var EC_ShortW_JacExt[F, G], P: EC_ShortW
   M.square(P.x)
   M *= 3
   when F.Name.getCoefA() != 0:
-    {.error: "Not implemented.".}
+    var a{.noInit.}: F
+    a.setOne()
+    a.prod(a, F.Name.getCoefA())
+    M += a                     # M = 3X₁²+a
 
   # aliasing, we don't use P.x and U anymore
   r.x.square(M)
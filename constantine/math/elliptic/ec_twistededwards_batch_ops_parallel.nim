@@ -0,0 +1,105 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  constantine/platforms/abstractions,
+  ../../threadpool/[threadpool, partitioners],
+  ./ec_twistededwards_affine,
+  ./ec_twistededwards_projective,
+  ./ec_twistededwards_batch_ops
+
+# No exceptions allowed
+{.push raises:[], checks: off.}
+
+# ############################################################
+#
+#             Elliptic Curve in Twisted Edwards form
+#                   Parallel Batch conversion
+#
+# ############################################################
+
+proc batchAffine_parallel*[F](
+       tp: Threadpool,
+       affs: ptr UncheckedArray[EC_TwEdw_Aff[F]],
+       points: ptr UncheckedArray[EC_TwEdw_Prj[F]],
+       N: int) {.noInline.} =
+  ## Parallel batch conversion of `points` to affine coordinates
+  ## Each chunk of points uses a single inversion.
+  if N <= 0:
+    return
+
+  # batchAffine allocates a SecretBool per point on the stack,
+  # chunks are capped to keep the stack of worker threads small.
+  const minChunkSize = 1024
+  const maxChunkSize = 8192
+
+  let chunkDesc = balancedChunksPrioSize(
+    start = 0, stopEx = N,
+    minChunkSize, maxChunkSize,
+    numChunksHint = tp.numThreads.int)
+
+  if chunkDesc.numChunks == 1:
+    affs.batchAffine(points, N)
+    return
+
+  syncScope:
+    for iter in items(chunkDesc):
+      proc batchAffine_chunk_wrapper(dst: ptr, src: ptr, len: int) {.nimcall.} =
+        dst.batchAffine(src, len)
+
+      tp.spawn batchAffine_chunk_wrapper(
+                affs +% iter.start,
+                points +% iter.start,
+                iter.size)
+
+# ############################################################
+#
+#             Elliptic Curve in Twisted Edwards form
+#                    Parallel Batch addition
+#
+# ############################################################
+
+proc sum_reduce_vartime_parallel*[F](
+       tp: Threadpool,
+       r: var EC_TwEdw_Prj[F],
+       points: openArray[EC_TwEdw_Aff[F]]) {.noInline.} =
+  ## Parallel Batch addition of `points` into `r`
+  ## `r` is overwritten
+  const minChunkSize = 256
+  const maxChunkSize = 8192
+
+  let chunkDesc = balancedChunksPrioSize(
+    start = 0, stopEx = points.len,
+    minChunkSize, maxChunkSize,
+    numChunksHint = tp.numThreads.int)
+
+  r.setNeutral()
+  if chunkDesc.numChunks == 1:
+    for i in 0 ..< points.len:
+      r.mixedSum_vartime(r, points[i])
+    return
+
+  let partialResults = allocHeapArrayAligned(EC_TwEdw_Prj[F], chunkDesc.numChunks, alignment = 64)
+
+  syncScope:
+    for iter in items(chunkDesc):
+      proc sum_reduce_chunk_vartime_wrapper(res: ptr, p: ptr, pLen: int) {.nimcall.} =
+        # The borrow checker prevents capturing `var` and `openArray`
+        # so we capture pointers instead.
+        res[].setNeutral()
+        for i in 0 ..< pLen:
+          res[].mixedSum_vartime(res[], p[i])
+
+      tp.spawn partialResults[iter.chunkID].addr.sum_reduce_chunk_vartime_wrapper(
+                points.asUnchecked() +% iter.start,
+                iter.size)
+
+  for i in 0 ..< chunkDesc.numChunks:
+    r.sum_vartime(r, partialResults[i])
+
+  partialResults.freeHeapAligned()
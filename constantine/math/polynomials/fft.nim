@@ -9,7 +9,7 @@
 import
   constantine/named/algebras,
   constantine/math/arithmetic,
-  constantine/math/io/io_bigints,
+  constantine/math/io/[io_bigints, io_fields],
   constantine/math/ec_shortweierstrass,
   constantine/math/elliptic/ec_scalar_mul_vartime,
   constantine/platforms/[abstractions, allocs, views]
@@ -137,9 +137,10 @@ func ifft_vartime*[EC](
   var voutput = output.toStridedView()
   fft_internal(voutput, vals.toStridedView(), rootz)
 
-  var invLen {.noInit.}: EC.F.getBigInt()
+  # 1/n scales points, it is inverted modulo the curve order, not the base field modulus
+  var invLen {.noInit.}: EC.getScalarField().getBigInt()
   invLen.fromUint(vals.len.uint64)
-  invLen.invmod_vartime(invLen, EC.F.getModulus())
+  invLen.invmod_vartime(invLen, EC.getScalarField().getModulus())
 
   for i in 0 ..< output.len:
     output[i].scalarMul_vartime(invLen)
@@ -294,6 +295,122 @@ func bit_reversal_permutation*[T](buf: var openArray[T]) =
 
   freeHeap(t)
 
+# ############################################################
+#
+#            Finite field Number Theoretic Transform
+#
+# ############################################################
+
+# The NTT is the FFT over a finite field.
+# Unlike the elliptic curve FFT, it takes the primitive root of unity ω
+# of order n = values.len as input, like halo2's `best_fft`, and works in-place:
+# values are bit-reversal permuted, then an iterative radix-2 Cooley-Tukey
+# butterfly network is applied, so that input and output are in natural order.
+#
+# For a coset FFT, evaluations are done over the coset g·⟨ω⟩ with g the shift.
+
+func ntt_twiddles[F](twiddles: ptr UncheckedArray[F], omega: F, half: int) =
+  ## Compute twiddles[i] = ωⁱ for i in [0, n/2)
+  twiddles[0].setOne()
+  for i in 1 ..< half:
+    twiddles[i].prod(twiddles[i-1], omega)
+
+func ntt_butterfly[F](
+       values: ptr UncheckedArray[F],
+       twiddles: ptr UncheckedArray[F],
+       k, m, twiddleStride: int) {.inline.} =
+  ## Butterfly between values[k] and values[k+m]
+  ## with twiddle ω^((k mod m)·twiddleStride)
+  var t {.noInit.}: F
+  t.prod(values[k+m], twiddles[(k mod m)*twiddleStride])
+  values[k+m].diff(values[k], t)
+  values[k] += t
+
+func ntt_butterflies[F](values: ptr UncheckedArray[F], twiddles: ptr UncheckedArray[F], len: int) =
+  ## Iterative radix-2 decimation-in-time on bit-reversed input
+  var m = 1
+  while m < len:
+    let twiddleStride = len div (2*m)
+    var k = 0
+    while k < len:
+      for j in k ..< k+m:
+        ntt_butterfly(values, twiddles, j, m, twiddleStride)
+      k += 2*m
+    m *= 2
+
+func scaleByPowers[F](values: var openArray[F], g: F) =
+  ## values[i] <- values[i]·gⁱ
+  if values.len <= 1:
+    return
+  var cur = g
+  for i in 1 ..< values.len:
+    values[i] *= cur
+    cur *= g
+
+func ntt_vartime*[F](values: var openArray[F], omega: F): FFTStatus =
+  ## In-place Number Theoretic Transform
+  ##   values[i] <- ∑ⱼ values[j]·ωⁱʲ
+  ##
+  ## ω MUST be a primitive root of unity of order values.len
+  ## Input and output are in natural order.
+  if not values.len.uint64.isPowerOf2_vartime():
+    return FFTS_SizeNotPowerOfTwo
+  if values.len <= 1:
+    return FFTS_Success
+
+  let half = values.len div 2
+  let twiddles = allocHeapArrayAligned(F, half, alignment = 64)
+  twiddles.ntt_twiddles(omega, half)
+
+  values.bit_reversal_permutation()
+  ntt_butterflies(values.asUnchecked(), twiddles, values.len)
+
+  twiddles.freeHeapAligned()
+  return FFTS_Success
+
+func intt_vartime*[F](values: var openArray[F], omega: F): FFTStatus =
+  ## In-place inverse Number Theoretic Transform
+  ##   values[i] <- 1/n ∑ⱼ values[j]·ω⁻ⁱʲ
+  ##
+  ## ω MUST be a primitive root of unity of order n = values.len,
+  ## the same as the forward transform.
+  var omegaInv {.noInit.}: F
+  omegaInv.inv_vartime(omega)
+  let status = values.ntt_vartime(omegaInv)
+  if status != FFTS_Success:
+    return status
+
+  var invLen {.noInit.}: F
+  invLen.fromUint(values.len.uint64)
+  invLen.inv_vartime()
+  for i in 0 ..< values.len:
+    values[i] *= invLen
+
+  return FFTS_Success
+
+func coset_ntt_vartime*[F](values: var openArray[F], omega, shift: F): FFTStatus =
+  ## In-place coset Number Theoretic Transform
+  ## Evaluate the polynomial with coefficients `values`
+  ## over the coset shift·⟨ω⟩
+  ##   values[i] <- ∑ⱼ values[j]·(shift·ωⁱ)ʲ
+  if not values.len.uint64.isPowerOf2_vartime():
+    return FFTS_SizeNotPowerOfTwo
+  values.scaleByPowers(shift)
+  return values.ntt_vartime(omega)
+
+func coset_intt_vartime*[F](values: var openArray[F], omega, shift: F): FFTStatus =
+  ## In-place inverse coset Number Theoretic Transform
+  ## Interpolate the coefficients of a polynomial
+  ## from its evaluations over the coset shift·⟨ω⟩
+  let status = values.intt_vartime(omega)
+  if status != FFTS_Success:
+    return status
+
+  var shiftInv {.noInit.}: F
+  shiftInv.inv_vartime(shift)
+  values.scaleByPowers(shiftInv)
+  return FFTS_Success
+
 # ############################################################
 #
 #                    Sanity checks
@@ -0,0 +1,176 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import ./fft {.all.}
+export fft
+
+import
+  constantine/math/arithmetic,
+  constantine/math/io/[io_bigints, io_fields],
+  constantine/platforms/[abstractions, allocs],
+  constantine/threadpool/threadpool
+
+## ############################################################
+##
+##         Finite field Number Theoretic Transform
+##                   Parallel Edition
+##
+## ############################################################
+
+proc bit_reversal_permutation_parallel[F](
+       tp: Threadpool,
+       values: ptr UncheckedArray[F],
+       len: int) =
+  ## In-place bit reversal permutation
+  ## Each index is swapped with its bit-reversed index at most once
+  ## hence iterations are independent.
+  let logN = log2_vartime(uint64 len)
+
+  syncScope:
+    tp.parallelFor i in 0 ..< len:
+      captures: {values, logN}
+      let j = int reverseBits(uint64 i, logN)
+      if i < j:
+        swap(values[i], values[j])
+
+func scaleByPowers[F](
+       values: ptr UncheckedArray[F],
+       g: F, start, stop: int) =
+  ## values[i] <- values[i]·gⁱ for i in [start, stop)
+  var cur {.noInit.}: F
+  var e {.noInit.}: BigInt[64]
+  e.fromUint(uint64 start)
+  cur.pow_vartime(g, e)
+  for i in start ..< stop:
+    values[i] *= cur
+    cur *= g
+
+proc scaleByPowers_parallel[F](
+       tp: Threadpool,
+       values: ptr UncheckedArray[F],
+       len: int, g: F) =
+  ## values[i] <- values[i]·gⁱ
+  const chunkSize = 1024
+
+  syncScope:
+    tp.parallelFor i in 0 ..< len:
+      stride: chunkSize
+      captures: {values, len, g}
+      scaleByPowers(values, g, i, min(i+chunkSize, len))
+
+proc ntt_butterflies_parallel[F](
+       tp: Threadpool,
+       values: ptr UncheckedArray[F],
+       twiddles: ptr UncheckedArray[F],
+       len: int) =
+  ## Iterative radix-2 decimation-in-time on bit-reversed input
+  ## The n/2 butterflies of a stage are independent,
+  ## stages are sequential.
+  var m = 1
+  while m < len:
+    let twiddleStride = len div (2*m)
+
+    syncScope:
+      tp.parallelFor j in 0 ..< len div 2:
+        captures: {values, twiddles, m, twiddleStride}
+        # j-th butterfly of the stage, in block j div m at offset j mod m
+        let k = (j div m) * (2*m) + (j mod m)
+        ntt_butterfly(values, twiddles, k, m, twiddleStride)
+
+    m *= 2
+
+proc ntt_vartime_parallel*[F](
+       tp: Threadpool,
+       values: ptr UncheckedArray[F],
+       len: int,
+       omega: F): FFTStatus =
+  ## In-place Number Theoretic Transform
+  ##   values[i] <- ∑ⱼ values[j]·ωⁱʲ
+  ##
+  ## ω MUST be a primitive root of unity of order `len`
+  ## Input and output are in natural order.
+  ##
+  ## Parallelism: This only returns when computation is fully done
+  if not len.uint64.isPowerOf2_vartime():
+    return FFTS_SizeNotPowerOfTwo
+  if len <= 1:
+    return FFTS_Success
+
+  let half = len div 2
+  let twiddles = allocHeapArrayAligned(F, half, alignment = 64)
+  twiddles.ntt_twiddles(omega, half)
+
+  tp.bit_reversal_permutation_parallel(values, len)
+  tp.ntt_butterflies_parallel(values, twiddles, len)
+
+  twiddles.freeHeapAligned()
+  return FFTS_Success
+
+proc intt_vartime_parallel*[F](
+       tp: Threadpool,
+       values: ptr UncheckedArray[F],
+       len: int,
+       omega: F): FFTStatus =
+  ## In-place inverse Number Theoretic Transform
+  ##   values[i] <- 1/n ∑ⱼ values[j]·ω⁻ⁱʲ
+  ##
+  ## ω MUST be a primitive root of unity of order n = `len`,
+  ## the same as the forward transform.
+  ##
+  ## Parallelism: This only returns when computation is fully done
+  var omegaInv {.noInit.}: F
+  omegaInv.inv_vartime(omega)
+  let status = tp.ntt_vartime_parallel(values, len, omegaInv)
+  if status != FFTS_Success:
+    return status
+
+  var invLen {.noInit.}: F
+  invLen.fromUint(len.uint64)
+  invLen.inv_vartime()
+
+  syncScope:
+    tp.parallelFor i in 0 ..< len:
+      captures: {values, invLen}
+      values[i] *= invLen
+
+  return FFTS_Success
+
+proc coset_ntt_vartime_parallel*[F](
+       tp: Threadpool,
+       values: ptr UncheckedArray[F],
+       len: int,
+       omega, shift: F): FFTStatus =
+  ## In-place coset Number Theoretic Transform
+  ## Evaluate the polynomial with coefficients `values`
+  ## over the coset shift·⟨ω⟩
+  ##   values[i] <- ∑ⱼ values[j]·(shift·ωⁱ)ʲ
+  ##
+  ## Parallelism: This only returns when computation is fully done
+  if not len.uint64.isPowerOf2_vartime():
+    return FFTS_SizeNotPowerOfTwo
+  tp.scaleByPowers_parallel(values, len, shift)
+  return tp.ntt_vartime_parallel(values, len, omega)
+
+proc coset_intt_vartime_parallel*[F](
+       tp: Threadpool,
+       values: ptr UncheckedArray[F],
+       len: int,
+       omega, shift: F): FFTStatus =
+  ## In-place inverse coset Number Theoretic Transform
+  ## Interpolate the coefficients of a polynomial
+  ## from its evaluations over the coset shift·⟨ω⟩
+  ##
+  ## Parallelism: This only returns when computation is fully done
+  let status = tp.intt_vartime_parallel(values, len, omega)
+  if status != FFTS_Success:
+    return status
+
+  var shiftInv {.noInit.}: F
+  shiftInv.inv_vartime(shift)
+  tp.scaleByPowers_parallel(values, len, shiftInv)
+  return FFTS_Success
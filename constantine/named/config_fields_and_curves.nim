@@ -219,6 +219,9 @@ declareCurves:
     modulus: "0xffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
     order: "0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
     orderBitwidth: 256
+    eq_form: ShortWeierstrass
+    coef_a: -3
+    coef_b: "0x5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b"
   curve Secp256k1: # Bitcoin curve
     bitwidth: 256
     modulus: "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"
@@ -0,0 +1,26 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+import
+  constantine/named/algebras,
+  constantine/math/elliptic/ec_shortweierstrass_affine,
+  constantine/math/io/[io_fields, io_extfields]
+
+{.used.}
+
+# Generators
+# -----------------------------------------------------------------
+# https://www.secg.org/sec2-v2.pdf page 13 (17 of PDF), sec. 2.4.2
+# https://doi.org/10.6028/NIST.SP.800-186 sec. 3.2.1.3
+
+# The group G_1 (== G) is defined on the curve Y^2 = X^3 - 3X + b over the field F_p
+# with p = 2^256 - 2^224 + 2^192 + 2^96 - 1
+# with generator:
+const P256_generator_G1* = EC_ShortW_Aff[Fp[P256], G1](
+  x: Fp[P256].fromHex"0x6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+  y: Fp[P256].fromHex"0x4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5"
+)
@@ -0,0 +1,59 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+import
+  constantine/named/algebras,
+  constantine/math/arithmetic/finite_fields
+
+# ############################################################
+#
+#           Specialized inversion for P-256
+#
+# ############################################################
+
+func invsqrt_addchain*(r: var Fp[P256], a: Fp[P256]) {.addchain.} =
+  ## Returns a^((p-3)/4) = 2²⁵⁴-2²²²+2¹⁹⁰+2⁹⁴-1 for inverse square root computation
+  var
+    x2  {.noInit.}: Fp[P256]
+    x4  {.noInit.}: Fp[P256]
+    x8  {.noInit.}: Fp[P256]
+    x16 {.noInit.}: Fp[P256]
+    x32 {.noInit.}: Fp[P256]
+    x64 {.noInit.}: Fp[P256]
+    t   {.noInit.}: Fp[P256]
+
+  # xN = a^(2ᴺ-1)
+  x2.square(a)
+  x2 *= a
+  x4.square_repeated(x2, 2)
+  x4 *= x2
+  x8.square_repeated(x4, 4)
+  x8 *= x4
+  x16.square_repeated(x8, 8)
+  x16 *= x8
+  x32.square_repeated(x16, 16)
+  x32 *= x16
+
+  # 2⁶⁴-2³²+1
+  r.square_repeated(x32, 32)
+  r *= a
+
+  # 2⁹⁴-1
+  x64.square_repeated(x32, 32)
+  x64 *= x32
+  t.square_repeated(x64, 16)
+  t *= x16                  # 2⁸⁰-1
+  t.square_repeated(8)
+  t *= x8                   # 2⁸⁸-1
+  t.square_repeated(4)
+  t *= x4                   # 2⁹²-1
+  t.square_repeated(2)
+  t *= x2                   # 2⁹⁴-1
+
+  # 2²⁵⁴-2²²²+2¹⁹⁰+2⁹⁴-1
+  r.square_repeated(190)
+  r *= t
@@ -0,0 +1,25 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  constantine/named/algebras,
+  constantine/math/elliptic/ec_shortweierstrass_affine,
+  constantine/math/io/[io_fields, io_extfields]
+
+{.used.}
+
+# Generators
+# -----------------------------------------------------------------
+# https://github.com/zcash/pasta
+
+# The group G_1 (== G) is defined on the curve Y^2 = X^3 + 5 over the field F_p
+# with generator (-1, 2), as used by halo2curves:
+const Pallas_generator_G1* = EC_ShortW_Aff[Fp[Pallas], G1](
+  x: Fp[Pallas].fromHex"0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000",
+  y: Fp[Pallas].fromHex"0x2"
+)
@@ -13,7 +13,10 @@ import
   ./constants/bn254_snarks_generators,
   ./constants/bandersnatch_generators,
   ./constants/banderwagon_generators,
-  ./constants/secp256k1_generators
+  ./constants/p256_generators,
+  ./constants/secp256k1_generators,
+  ./constants/pallas_generators,
+  ./constants/vesta_generators
 
 {.experimental: "dynamicbindsym".}
 
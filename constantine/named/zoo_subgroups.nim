@@ -15,6 +15,7 @@ import
   ./constants/bn254_nogami_subgroups,
   ./constants/bn254_snarks_subgroups,
   ./constants/bw6_761_subgroups,
+  ./constants/p256_subgroups,
   ./constants/pallas_subgroups,
   ./constants/vesta_subgroups,
   ./constants/secp256k1_subgroups
@@ -26,6 +27,7 @@ export
   bn254_nogami_subgroups,
   bn254_snarks_subgroups,
   bw6_761_subgroups,
+  p256_subgroups,
   pallas_subgroups,
   vesta_subgroups,
   secp256k1_subgroups
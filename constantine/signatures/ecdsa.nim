@@ -256,12 +256,15 @@ proc coreSign*[Sig, SecKey](
   # 2. sign
   signature.signImpl(secretKey, msgHash, H, nonceSampler)
 
-proc verifyImpl[Name: static Algebra; Sig](
+proc verifyImpl*[Name: static Algebra; Sig](
     publicKey: EC_ShortW_Aff[Fp[Name], G1],
     signature: Sig,
     msgHash: Fr[Name]
 ): bool =
-  ## Verify a given `signature` for a `message` using the given `publicKey`.
+  ## Verify a given `signature` for a message digest `msgHash`
+  ## using the given `publicKey`.
+  ##
+  ## `signature.r` and `signature.s` MUST be non-zero.
   # 1. Compute w = s⁻¹
   var w = signature.s
   w.inv() # w = s⁻¹
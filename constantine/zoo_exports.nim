@@ -25,6 +25,7 @@
 # By assigning to the prefix, in a static block **before** importing the module.
 
 var prefix_sha256* {.compileTime.} = "ctt_sha256_"
+var prefix_ripemd160* {.compileTime.} = "ctt_ripemd160_"
 var prefix_eth_kzg4844* {.compileTime.} = "ctt_eth_kzg4844_"
 
 # Conditional exports
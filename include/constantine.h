@@ -16,6 +16,10 @@
 
 // Hash functions
 #include "constantine/hashes/sha256.h"
+#include "constantine/hashes/keccak256.h"
+#include "constantine/hashes/sha3_256.h"
+#include "constantine/hashes/ripemd160.h"
+#include "constantine/hashes/sha256_parallel.h"
 
 // Cryptographically Secure Random Number Generators
 #include "constantine/csprngs/sysrand.h"
@@ -33,6 +37,7 @@
 #include "constantine/curves/bn254_snarks_parallel.h"
 #include "constantine/curves/pallas_parallel.h"
 #include "constantine/curves/vesta_parallel.h"
+#include "constantine/curves/banderwagon_parallel.h"
 
 // Protocols
 #include "constantine/protocols/ethereum_bls_signatures.h"
@@ -21,6 +21,7 @@ typedef struct { secret_word limbs[CTT_WORDS_REQUIRED(255)]; } banderwagon_fp;
 typedef struct { banderwagon_fp x, y; } banderwagon_ec_aff;
 typedef struct { banderwagon_fp x, y, z; } banderwagon_ec_jac;
 typedef struct { banderwagon_fp x, y, z; } banderwagon_ec_prj;
+typedef struct { banderwagon_ec_aff* points; } banderwagon_ec_fixed_base_table;
 
 void        ctt_big253_from_banderwagon_fr(big253* dst, const banderwagon_fr* src);
 void        ctt_banderwagon_fr_from_big253(banderwagon_fr* dst, const big253* src);
@@ -124,8 +125,18 @@ void        ctt_banderwagon_ec_prj_scalar_mul_big_coef(banderwagon_ec_prj* P, co
 void        ctt_banderwagon_ec_prj_scalar_mul_fr_coef(banderwagon_ec_prj* P, const banderwagon_fr* scalar);
 void        ctt_banderwagon_ec_prj_scalar_mul_big_coef_vartime(banderwagon_ec_prj* P, const big253* scalar);
 void        ctt_banderwagon_ec_prj_scalar_mul_fr_coef_vartime(banderwagon_ec_prj* P, const banderwagon_fr* scalar);
+void        ctt_banderwagon_ec_prj_multi_scalar_mul_big_coefs(banderwagon_ec_prj* r, const big253 coefs[], const banderwagon_ec_aff points[], size_t len);
+void        ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs(banderwagon_ec_prj* r, const banderwagon_fr coefs[], const banderwagon_ec_aff points[], size_t len);
 void        ctt_banderwagon_ec_prj_multi_scalar_mul_big_coefs_vartime(banderwagon_ec_prj* r, const big253 coefs[], const banderwagon_ec_aff points[], size_t len);
 void        ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs_vartime(banderwagon_ec_prj* r, const banderwagon_fr coefs[], const banderwagon_ec_aff points[], size_t len);
+void        ctt_banderwagon_ec_prj_multi_scalar_mul_u64_coefs_vartime(banderwagon_ec_prj* r, const uint64_t coefs[], const banderwagon_ec_aff points[], size_t len);
+void        ctt_banderwagon_ec_prj_multi_scalar_mul_i64_coefs_vartime(banderwagon_ec_prj* r, const int64_t coefs[], const banderwagon_ec_aff points[], size_t len);
+void        ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(banderwagon_ec_prj* r, const banderwagon_fr coefs[], const banderwagon_ec_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_banderwagon_ec_fixed_base_table_precompute(banderwagon_ec_fixed_base_table* table, const banderwagon_ec_aff* base);
+void        ctt_banderwagon_ec_fixed_base_table_precompute_generator(banderwagon_ec_fixed_base_table* table);
+void        ctt_banderwagon_ec_fixed_base_table_delete(banderwagon_ec_fixed_base_table* table);
+void        ctt_banderwagon_ec_prj_fixed_base_scalar_mul_big_coef(banderwagon_ec_prj* r, const banderwagon_ec_fixed_base_table* table, const big253* scalar);
+void        ctt_banderwagon_ec_prj_fixed_base_scalar_mul_fr_coef(banderwagon_ec_prj* r, const banderwagon_ec_fixed_base_table* table, const banderwagon_fr* scalar);
 
 #ifdef __cplusplus
 }
@@ -0,0 +1,33 @@
+/** Constantine
+ *  Copyright (c) 2018-2019    Status Research & Development GmbH
+ *  Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+ *  Licensed and distributed under either of
+ *    * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+ *    * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+ *  at your option. This file may not be copied, modified, or distributed except according to those terms.
+ */
+#ifndef __CTT_H_BANDERWAGON_PARALLEL__
+#define __CTT_H_BANDERWAGON_PARALLEL__
+
+#include "constantine/core/datatypes.h"
+#include "constantine/core/threadpool.h"
+#include "constantine/curves/bigints.h"
+#include "constantine/curves/banderwagon.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+void        ctt_banderwagon_ec_prj_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, banderwagon_ec_prj* r, const big253 coefs[], const banderwagon_ec_aff points[], size_t len);
+void        ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, banderwagon_ec_prj* r, const banderwagon_fr coefs[], const banderwagon_ec_aff points[], size_t len);
+void        ctt_banderwagon_ec_prj_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, banderwagon_ec_prj* r, const uint64_t coefs[], const banderwagon_ec_aff points[], size_t len);
+void        ctt_banderwagon_ec_prj_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, banderwagon_ec_prj* r, const int64_t coefs[], const banderwagon_ec_aff points[], size_t len);
+void        ctt_banderwagon_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, banderwagon_ec_prj* r, const banderwagon_fr coefs[], const banderwagon_ec_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_banderwagon_ec_prj_batch_affine_parallel(const ctt_threadpool* tp, const banderwagon_ec_aff dst[], const banderwagon_ec_prj src[], size_t n);
+void        ctt_banderwagon_ec_prj_sum_reduce_vartime_parallel(const ctt_threadpool* tp, banderwagon_ec_prj* r, const banderwagon_ec_aff points[], size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // __CTT_H_BANDERWAGON_PARALLEL__
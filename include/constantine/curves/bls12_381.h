@@ -25,6 +25,8 @@ typedef struct { bls12_381_fp x, y, z; } bls12_381_g1_prj;
 typedef struct { bls12_381_fp2 x, y; } bls12_381_g2_aff;
 typedef struct { bls12_381_fp2 x, y, z; } bls12_381_g2_jac;
 typedef struct { bls12_381_fp2 x, y, z; } bls12_381_g2_prj;
+typedef struct { bls12_381_g1_aff* points; } bls12_381_g1_fixed_base_table;
+typedef struct { bls12_381_g2_aff* points; } bls12_381_g2_fixed_base_table;
 
 void        ctt_big255_from_bls12_381_fr(big255* dst, const bls12_381_fr* src);
 void        ctt_bls12_381_fr_from_big255(bls12_381_fr* dst, const big255* src);
@@ -160,8 +162,13 @@ void        ctt_bls12_381_g1_jac_scalar_mul_big_coef(bls12_381_g1_jac* P, const
 void        ctt_bls12_381_g1_jac_scalar_mul_fr_coef(bls12_381_g1_jac* P, const bls12_381_fr* scalar);
 void        ctt_bls12_381_g1_jac_scalar_mul_big_coef_vartime(bls12_381_g1_jac* P, const big255* scalar);
 void        ctt_bls12_381_g1_jac_scalar_mul_fr_coef_vartime(bls12_381_g1_jac* P, const bls12_381_fr* scalar);
+void        ctt_bls12_381_g1_jac_multi_scalar_mul_big_coefs(bls12_381_g1_jac* r, const big255 coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs(bls12_381_g1_jac* r, const bls12_381_fr coefs[], const bls12_381_g1_aff points[], size_t len);
 void        ctt_bls12_381_g1_jac_multi_scalar_mul_big_coefs_vartime(bls12_381_g1_jac* r, const big255 coefs[], const bls12_381_g1_aff points[], size_t len);
 void        ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs_vartime(bls12_381_g1_jac* r, const bls12_381_fr coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_jac_multi_scalar_mul_u64_coefs_vartime(bls12_381_g1_jac* r, const uint64_t coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_jac_multi_scalar_mul_i64_coefs_vartime(bls12_381_g1_jac* r, const int64_t coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(bls12_381_g1_jac* r, const bls12_381_fr coefs[], const bls12_381_g1_aff points[], size_t len, size_t coefs_max_bits);
 secret_bool ctt_bls12_381_g1_prj_is_eq(const bls12_381_g1_prj* P, const bls12_381_g1_prj* Q);
 secret_bool ctt_bls12_381_g1_prj_is_neutral(const bls12_381_g1_prj* P);
 void        ctt_bls12_381_g1_prj_set_neutral(bls12_381_g1_prj* P);
@@ -181,8 +188,20 @@ void        ctt_bls12_381_g1_prj_scalar_mul_big_coef(bls12_381_g1_prj* P, const
 void        ctt_bls12_381_g1_prj_scalar_mul_fr_coef(bls12_381_g1_prj* P, const bls12_381_fr* scalar);
 void        ctt_bls12_381_g1_prj_scalar_mul_big_coef_vartime(bls12_381_g1_prj* P, const big255* scalar);
 void        ctt_bls12_381_g1_prj_scalar_mul_fr_coef_vartime(bls12_381_g1_prj* P, const bls12_381_fr* scalar);
+void        ctt_bls12_381_g1_prj_multi_scalar_mul_big_coefs(bls12_381_g1_prj* r, const big255 coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs(bls12_381_g1_prj* r, const bls12_381_fr coefs[], const bls12_381_g1_aff points[], size_t len);
 void        ctt_bls12_381_g1_prj_multi_scalar_mul_big_coefs_vartime(bls12_381_g1_prj* r, const big255 coefs[], const bls12_381_g1_aff points[], size_t len);
 void        ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_vartime(bls12_381_g1_prj* r, const bls12_381_fr coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_prj_multi_scalar_mul_u64_coefs_vartime(bls12_381_g1_prj* r, const uint64_t coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_prj_multi_scalar_mul_i64_coefs_vartime(bls12_381_g1_prj* r, const int64_t coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(bls12_381_g1_prj* r, const bls12_381_fr coefs[], const bls12_381_g1_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bls12_381_g1_fixed_base_table_precompute(bls12_381_g1_fixed_base_table* table, const bls12_381_g1_aff* base);
+void        ctt_bls12_381_g1_fixed_base_table_precompute_generator(bls12_381_g1_fixed_base_table* table);
+void        ctt_bls12_381_g1_fixed_base_table_delete(bls12_381_g1_fixed_base_table* table);
+void        ctt_bls12_381_g1_jac_fixed_base_scalar_mul_big_coef(bls12_381_g1_jac* r, const bls12_381_g1_fixed_base_table* table, const big255* scalar);
+void        ctt_bls12_381_g1_jac_fixed_base_scalar_mul_fr_coef(bls12_381_g1_jac* r, const bls12_381_g1_fixed_base_table* table, const bls12_381_fr* scalar);
+void        ctt_bls12_381_g1_prj_fixed_base_scalar_mul_big_coef(bls12_381_g1_prj* r, const bls12_381_g1_fixed_base_table* table, const big255* scalar);
+void        ctt_bls12_381_g1_prj_fixed_base_scalar_mul_fr_coef(bls12_381_g1_prj* r, const bls12_381_g1_fixed_base_table* table, const bls12_381_fr* scalar);
 secret_bool ctt_bls12_381_g2_aff_is_eq(const bls12_381_g2_aff* P, const bls12_381_g2_aff* Q);
 secret_bool ctt_bls12_381_g2_aff_is_neutral(const bls12_381_g2_aff* P);
 void        ctt_bls12_381_g2_aff_set_neutral(bls12_381_g2_aff* P);
@@ -209,8 +228,13 @@ void        ctt_bls12_381_g2_jac_scalar_mul_big_coef(bls12_381_g2_jac* P, const
 void        ctt_bls12_381_g2_jac_scalar_mul_fr_coef(bls12_381_g2_jac* P, const bls12_381_fr* scalar);
 void        ctt_bls12_381_g2_jac_scalar_mul_big_coef_vartime(bls12_381_g2_jac* P, const big255* scalar);
 void        ctt_bls12_381_g2_jac_scalar_mul_fr_coef_vartime(bls12_381_g2_jac* P, const bls12_381_fr* scalar);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_big_coefs(bls12_381_g2_jac* r, const big255 coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs(bls12_381_g2_jac* r, const bls12_381_fr coefs[], const bls12_381_g2_aff points[], size_t len);
 void        ctt_bls12_381_g2_jac_multi_scalar_mul_big_coefs_vartime(bls12_381_g2_jac* r, const big255 coefs[], const bls12_381_g2_aff points[], size_t len);
 void        ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs_vartime(bls12_381_g2_jac* r, const bls12_381_fr coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_u64_coefs_vartime(bls12_381_g2_jac* r, const uint64_t coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_i64_coefs_vartime(bls12_381_g2_jac* r, const int64_t coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(bls12_381_g2_jac* r, const bls12_381_fr coefs[], const bls12_381_g2_aff points[], size_t len, size_t coefs_max_bits);
 secret_bool ctt_bls12_381_g2_prj_is_eq(const bls12_381_g2_prj* P, const bls12_381_g2_prj* Q);
 secret_bool ctt_bls12_381_g2_prj_is_neutral(const bls12_381_g2_prj* P);
 void        ctt_bls12_381_g2_prj_set_neutral(bls12_381_g2_prj* P);
@@ -230,14 +254,34 @@ void        ctt_bls12_381_g2_prj_scalar_mul_big_coef(bls12_381_g2_prj* P, const
 void        ctt_bls12_381_g2_prj_scalar_mul_fr_coef(bls12_381_g2_prj* P, const bls12_381_fr* scalar);
 void        ctt_bls12_381_g2_prj_scalar_mul_big_coef_vartime(bls12_381_g2_prj* P, const big255* scalar);
 void        ctt_bls12_381_g2_prj_scalar_mul_fr_coef_vartime(bls12_381_g2_prj* P, const bls12_381_fr* scalar);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_big_coefs(bls12_381_g2_prj* r, const big255 coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs(bls12_381_g2_prj* r, const bls12_381_fr coefs[], const bls12_381_g2_aff points[], size_t len);
 void        ctt_bls12_381_g2_prj_multi_scalar_mul_big_coefs_vartime(bls12_381_g2_prj* r, const big255 coefs[], const bls12_381_g2_aff points[], size_t len);
 void        ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_vartime(bls12_381_g2_prj* r, const bls12_381_fr coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_u64_coefs_vartime(bls12_381_g2_prj* r, const uint64_t coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_i64_coefs_vartime(bls12_381_g2_prj* r, const int64_t coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(bls12_381_g2_prj* r, const bls12_381_fr coefs[], const bls12_381_g2_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bls12_381_g2_fixed_base_table_precompute(bls12_381_g2_fixed_base_table* table, const bls12_381_g2_aff* base);
+void        ctt_bls12_381_g2_fixed_base_table_precompute_generator(bls12_381_g2_fixed_base_table* table);
+void        ctt_bls12_381_g2_fixed_base_table_delete(bls12_381_g2_fixed_base_table* table);
+void        ctt_bls12_381_g2_jac_fixed_base_scalar_mul_big_coef(bls12_381_g2_jac* r, const bls12_381_g2_fixed_base_table* table, const big255* scalar);
+void        ctt_bls12_381_g2_jac_fixed_base_scalar_mul_fr_coef(bls12_381_g2_jac* r, const bls12_381_g2_fixed_base_table* table, const bls12_381_fr* scalar);
+void        ctt_bls12_381_g2_prj_fixed_base_scalar_mul_big_coef(bls12_381_g2_prj* r, const bls12_381_g2_fixed_base_table* table, const big255* scalar);
+void        ctt_bls12_381_g2_prj_fixed_base_scalar_mul_fr_coef(bls12_381_g2_prj* r, const bls12_381_g2_fixed_base_table* table, const bls12_381_fr* scalar);
 void        ctt_bls12_381_g1_aff_sswu_sha256(bls12_381_g1_aff* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
 void        ctt_bls12_381_g1_jac_sswu_sha256(bls12_381_g1_jac* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
 void        ctt_bls12_381_g1_prj_sswu_sha256(bls12_381_g1_prj* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
 void        ctt_bls12_381_g2_aff_sswu_sha256(bls12_381_g2_aff* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
 void        ctt_bls12_381_g2_jac_sswu_sha256(bls12_381_g2_jac* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
 void        ctt_bls12_381_g2_prj_sswu_sha256(bls12_381_g2_prj* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
+void        ctt_bls12_381_kzg_commit_vartime(bls12_381_g1_aff* commitment, const bls12_381_fr poly[], const bls12_381_g1_aff powers_of_tau_g1[], size_t len);
+void        ctt_bls12_381_kzg_prove_monomial_vartime(bls12_381_g1_aff* proof, bls12_381_fr* eval_at_challenge, const bls12_381_fr poly[], const bls12_381_g1_aff powers_of_tau_g1[], size_t len, const bls12_381_fr* opening_challenge);
+ctt_bool    ctt_bls12_381_kzg_verify_vartime(const bls12_381_g1_aff* commitment, const bls12_381_fr* opening_challenge, const bls12_381_fr* eval_at_challenge, const bls12_381_g1_aff* proof, const bls12_381_g2_aff* tau_g2) __attribute__((warn_unused_result));
+ctt_bool    ctt_bls12_381_kzg_verify_batch_vartime(const bls12_381_g1_aff commitments[], const bls12_381_fr challenges[], const bls12_381_fr evals_at_challenges[], const bls12_381_g1_aff proofs[], const bls12_381_fr linear_indep_random_numbers[], size_t len, const bls12_381_g2_aff* tau_g2) __attribute__((warn_unused_result));
+void        ctt_bls12_381_kzg_setup_insecure_for_testing(const bls12_381_g1_aff powers_of_tau_g1[], size_t len, bls12_381_g2_aff* tau_g2, const bls12_381_fr* tau);
+ctt_bool    ctt_bls12_381_srs_g1_from_montgomery_le_unchecked(const bls12_381_g1_aff dst[], const byte src[], size_t len) __attribute__((warn_unused_result));
+ctt_bool    ctt_bls12_381_srs_g2_from_montgomery_le_unchecked(const bls12_381_g2_aff dst[], const byte src[], size_t len) __attribute__((warn_unused_result));
+ctt_bool    ctt_bls12_381_srs_monomial_to_lagrange_vartime(const bls12_381_g1_aff lagrange_g1[], const bls12_381_g1_aff powers_of_tau_g1[], size_t len, const bls12_381_fr* root_of_unity) __attribute__((warn_unused_result));
 
 #ifdef __cplusplus
 }
@@ -18,10 +18,55 @@
 extern "C" {
 #endif
 
+typedef struct { size_t len; bls12_381_g1_aff* points; } bls12_381_g1_msm_table;
+typedef struct { size_t len; bls12_381_g2_aff* points; } bls12_381_g2_msm_table;
+
+void        ctt_bls12_381_g1_msm_table_precompute_parallel(const ctt_threadpool* tp, bls12_381_g1_msm_table* table, const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_msm_table_delete(bls12_381_g1_msm_table* table);
 void        ctt_bls12_381_g1_jac_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_jac* r, const big255 coefs[], const bls12_381_g1_aff points[], size_t len);
 void        ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_jac* r, const bls12_381_fr coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_jac_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_jac* r, const uint64_t coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_jac_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_jac* r, const int64_t coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_jac* r, const bls12_381_fr coefs[], const bls12_381_g1_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bls12_381_g1_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_jac* r, const bls12_381_fr coefs[], const bls12_381_g1_msm_table* table, size_t len);
+void        ctt_bls12_381_g1_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_jac r[], size_t r_len, const bls12_381_fr* const coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_jac r[], size_t r_len, const bls12_381_fr* const coefs[], const bls12_381_g1_msm_table* table, size_t len);
+void        ctt_bls12_381_g1_jac_batch_affine_parallel(const ctt_threadpool* tp, const bls12_381_g1_aff dst[], const bls12_381_g1_jac src[], size_t n);
+void        ctt_bls12_381_g1_jac_sum_reduce_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_jac* r, const bls12_381_g1_aff points[], size_t len);
 void        ctt_bls12_381_g1_prj_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_prj* r, const big255 coefs[], const bls12_381_g1_aff points[], size_t len);
 void        ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_prj* r, const bls12_381_fr coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_prj_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_prj* r, const uint64_t coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_prj_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_prj* r, const int64_t coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_prj* r, const bls12_381_fr coefs[], const bls12_381_g1_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bls12_381_g1_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_prj* r, const bls12_381_fr coefs[], const bls12_381_g1_msm_table* table, size_t len);
+void        ctt_bls12_381_g1_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_prj r[], size_t r_len, const bls12_381_fr* const coefs[], const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g1_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_prj r[], size_t r_len, const bls12_381_fr* const coefs[], const bls12_381_g1_msm_table* table, size_t len);
+void        ctt_bls12_381_g1_prj_batch_affine_parallel(const ctt_threadpool* tp, const bls12_381_g1_aff dst[], const bls12_381_g1_prj src[], size_t n);
+void        ctt_bls12_381_g1_prj_sum_reduce_vartime_parallel(const ctt_threadpool* tp, bls12_381_g1_prj* r, const bls12_381_g1_aff points[], size_t len);
+void        ctt_bls12_381_g2_msm_table_precompute_parallel(const ctt_threadpool* tp, bls12_381_g2_msm_table* table, const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_msm_table_delete(bls12_381_g2_msm_table* table);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_jac* r, const big255 coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_jac* r, const bls12_381_fr coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_jac* r, const uint64_t coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_jac* r, const int64_t coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_jac* r, const bls12_381_fr coefs[], const bls12_381_g2_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bls12_381_g2_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_jac* r, const bls12_381_fr coefs[], const bls12_381_g2_msm_table* table, size_t len);
+void        ctt_bls12_381_g2_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_jac r[], size_t r_len, const bls12_381_fr* const coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_jac r[], size_t r_len, const bls12_381_fr* const coefs[], const bls12_381_g2_msm_table* table, size_t len);
+void        ctt_bls12_381_g2_jac_batch_affine_parallel(const ctt_threadpool* tp, const bls12_381_g2_aff dst[], const bls12_381_g2_jac src[], size_t n);
+void        ctt_bls12_381_g2_jac_sum_reduce_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_jac* r, const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_prj* r, const big255 coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_prj* r, const bls12_381_fr coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_prj* r, const uint64_t coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_prj* r, const int64_t coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_prj* r, const bls12_381_fr coefs[], const bls12_381_g2_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bls12_381_g2_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_prj* r, const bls12_381_fr coefs[], const bls12_381_g2_msm_table* table, size_t len);
+void        ctt_bls12_381_g2_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_prj r[], size_t r_len, const bls12_381_fr* const coefs[], const bls12_381_g2_aff points[], size_t len);
+void        ctt_bls12_381_g2_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_prj r[], size_t r_len, const bls12_381_fr* const coefs[], const bls12_381_g2_msm_table* table, size_t len);
+void        ctt_bls12_381_g2_prj_batch_affine_parallel(const ctt_threadpool* tp, const bls12_381_g2_aff dst[], const bls12_381_g2_prj src[], size_t n);
+void        ctt_bls12_381_g2_prj_sum_reduce_vartime_parallel(const ctt_threadpool* tp, bls12_381_g2_prj* r, const bls12_381_g2_aff points[], size_t len);
+ctt_bool    ctt_bls12_381_srs_g1_validate_parallel(const ctt_threadpool* tp, const bls12_381_g1_aff points[], size_t len) __attribute__((warn_unused_result));
+ctt_bool    ctt_bls12_381_srs_g2_validate_parallel(const ctt_threadpool* tp, const bls12_381_g2_aff points[], size_t len) __attribute__((warn_unused_result));
 
 #ifdef __cplusplus
 }
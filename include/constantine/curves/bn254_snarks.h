@@ -25,6 +25,8 @@ typedef struct { bn254_snarks_fp x, y, z; } bn254_snarks_g1_prj;
 typedef struct { bn254_snarks_fp2 x, y; } bn254_snarks_g2_aff;
 typedef struct { bn254_snarks_fp2 x, y, z; } bn254_snarks_g2_jac;
 typedef struct { bn254_snarks_fp2 x, y, z; } bn254_snarks_g2_prj;
+typedef struct { bn254_snarks_g1_aff* points; } bn254_snarks_g1_fixed_base_table;
+typedef struct { bn254_snarks_g2_aff* points; } bn254_snarks_g2_fixed_base_table;
 
 void        ctt_big254_from_bn254_snarks_fr(big254* dst, const bn254_snarks_fr* src);
 void        ctt_bn254_snarks_fr_from_big254(bn254_snarks_fr* dst, const big254* src);
@@ -59,6 +61,10 @@ void        ctt_bn254_snarks_fr_cset_one(bn254_snarks_fr* a, secret_bool ctl);
 void        ctt_bn254_snarks_fr_cneg_in_place(bn254_snarks_fr* a, secret_bool ctl);
 void        ctt_bn254_snarks_fr_cadd_in_place(bn254_snarks_fr* a, const bn254_snarks_fr* b, secret_bool ctl);
 void        ctt_bn254_snarks_fr_csub_in_place(bn254_snarks_fr* a, const bn254_snarks_fr* b, secret_bool ctl);
+ctt_bool    ctt_bn254_snarks_fr_ntt_vartime(bn254_snarks_fr values[], size_t values_len, const bn254_snarks_fr* omega) __attribute__((warn_unused_result));
+ctt_bool    ctt_bn254_snarks_fr_intt_vartime(bn254_snarks_fr values[], size_t values_len, const bn254_snarks_fr* omega) __attribute__((warn_unused_result));
+ctt_bool    ctt_bn254_snarks_fr_coset_ntt_vartime(bn254_snarks_fr values[], size_t values_len, const bn254_snarks_fr* omega, const bn254_snarks_fr* shift) __attribute__((warn_unused_result));
+ctt_bool    ctt_bn254_snarks_fr_coset_intt_vartime(bn254_snarks_fr values[], size_t values_len, const bn254_snarks_fr* omega, const bn254_snarks_fr* shift) __attribute__((warn_unused_result));
 void        ctt_big254_from_bn254_snarks_fp(big254* dst, const bn254_snarks_fp* src);
 void        ctt_bn254_snarks_fp_from_big254(bn254_snarks_fp* dst, const big254* src);
 ctt_bool    ctt_bn254_snarks_fp_unmarshalBE(bn254_snarks_fp* dst, const byte src[], size_t src_len) __attribute__((warn_unused_result));
@@ -160,8 +166,13 @@ void        ctt_bn254_snarks_g1_jac_scalar_mul_big_coef(bn254_snarks_g1_jac* P,
 void        ctt_bn254_snarks_g1_jac_scalar_mul_fr_coef(bn254_snarks_g1_jac* P, const bn254_snarks_fr* scalar);
 void        ctt_bn254_snarks_g1_jac_scalar_mul_big_coef_vartime(bn254_snarks_g1_jac* P, const big254* scalar);
 void        ctt_bn254_snarks_g1_jac_scalar_mul_fr_coef_vartime(bn254_snarks_g1_jac* P, const bn254_snarks_fr* scalar);
+void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_big_coefs(bn254_snarks_g1_jac* r, const big254 coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs(bn254_snarks_g1_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_aff points[], size_t len);
 void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_big_coefs_vartime(bn254_snarks_g1_jac* r, const big254 coefs[], const bn254_snarks_g1_aff points[], size_t len);
 void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_vartime(bn254_snarks_g1_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_u64_coefs_vartime(bn254_snarks_g1_jac* r, const uint64_t coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_i64_coefs_vartime(bn254_snarks_g1_jac* r, const int64_t coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(bn254_snarks_g1_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_aff points[], size_t len, size_t coefs_max_bits);
 secret_bool ctt_bn254_snarks_g1_prj_is_eq(const bn254_snarks_g1_prj* P, const bn254_snarks_g1_prj* Q);
 secret_bool ctt_bn254_snarks_g1_prj_is_neutral(const bn254_snarks_g1_prj* P);
 void        ctt_bn254_snarks_g1_prj_set_neutral(bn254_snarks_g1_prj* P);
@@ -181,8 +192,20 @@ void        ctt_bn254_snarks_g1_prj_scalar_mul_big_coef(bn254_snarks_g1_prj* P,
 void        ctt_bn254_snarks_g1_prj_scalar_mul_fr_coef(bn254_snarks_g1_prj* P, const bn254_snarks_fr* scalar);
 void        ctt_bn254_snarks_g1_prj_scalar_mul_big_coef_vartime(bn254_snarks_g1_prj* P, const big254* scalar);
 void        ctt_bn254_snarks_g1_prj_scalar_mul_fr_coef_vartime(bn254_snarks_g1_prj* P, const bn254_snarks_fr* scalar);
+void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_big_coefs(bn254_snarks_g1_prj* r, const big254 coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs(bn254_snarks_g1_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_aff points[], size_t len);
 void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_big_coefs_vartime(bn254_snarks_g1_prj* r, const big254 coefs[], const bn254_snarks_g1_aff points[], size_t len);
 void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_vartime(bn254_snarks_g1_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_u64_coefs_vartime(bn254_snarks_g1_prj* r, const uint64_t coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_i64_coefs_vartime(bn254_snarks_g1_prj* r, const int64_t coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(bn254_snarks_g1_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bn254_snarks_g1_fixed_base_table_precompute(bn254_snarks_g1_fixed_base_table* table, const bn254_snarks_g1_aff* base);
+void        ctt_bn254_snarks_g1_fixed_base_table_precompute_generator(bn254_snarks_g1_fixed_base_table* table);
+void        ctt_bn254_snarks_g1_fixed_base_table_delete(bn254_snarks_g1_fixed_base_table* table);
+void        ctt_bn254_snarks_g1_jac_fixed_base_scalar_mul_big_coef(bn254_snarks_g1_jac* r, const bn254_snarks_g1_fixed_base_table* table, const big254* scalar);
+void        ctt_bn254_snarks_g1_jac_fixed_base_scalar_mul_fr_coef(bn254_snarks_g1_jac* r, const bn254_snarks_g1_fixed_base_table* table, const bn254_snarks_fr* scalar);
+void        ctt_bn254_snarks_g1_prj_fixed_base_scalar_mul_big_coef(bn254_snarks_g1_prj* r, const bn254_snarks_g1_fixed_base_table* table, const big254* scalar);
+void        ctt_bn254_snarks_g1_prj_fixed_base_scalar_mul_fr_coef(bn254_snarks_g1_prj* r, const bn254_snarks_g1_fixed_base_table* table, const bn254_snarks_fr* scalar);
 secret_bool ctt_bn254_snarks_g2_aff_is_eq(const bn254_snarks_g2_aff* P, const bn254_snarks_g2_aff* Q);
 secret_bool ctt_bn254_snarks_g2_aff_is_neutral(const bn254_snarks_g2_aff* P);
 void        ctt_bn254_snarks_g2_aff_set_neutral(bn254_snarks_g2_aff* P);
@@ -209,8 +232,13 @@ void        ctt_bn254_snarks_g2_jac_scalar_mul_big_coef(bn254_snarks_g2_jac* P,
 void        ctt_bn254_snarks_g2_jac_scalar_mul_fr_coef(bn254_snarks_g2_jac* P, const bn254_snarks_fr* scalar);
 void        ctt_bn254_snarks_g2_jac_scalar_mul_big_coef_vartime(bn254_snarks_g2_jac* P, const big254* scalar);
 void        ctt_bn254_snarks_g2_jac_scalar_mul_fr_coef_vartime(bn254_snarks_g2_jac* P, const bn254_snarks_fr* scalar);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_big_coefs(bn254_snarks_g2_jac* r, const big254 coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs(bn254_snarks_g2_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_aff points[], size_t len);
 void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_big_coefs_vartime(bn254_snarks_g2_jac* r, const big254 coefs[], const bn254_snarks_g2_aff points[], size_t len);
 void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_vartime(bn254_snarks_g2_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_u64_coefs_vartime(bn254_snarks_g2_jac* r, const uint64_t coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_i64_coefs_vartime(bn254_snarks_g2_jac* r, const int64_t coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(bn254_snarks_g2_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_aff points[], size_t len, size_t coefs_max_bits);
 secret_bool ctt_bn254_snarks_g2_prj_is_eq(const bn254_snarks_g2_prj* P, const bn254_snarks_g2_prj* Q);
 secret_bool ctt_bn254_snarks_g2_prj_is_neutral(const bn254_snarks_g2_prj* P);
 void        ctt_bn254_snarks_g2_prj_set_neutral(bn254_snarks_g2_prj* P);
@@ -230,14 +258,34 @@ void        ctt_bn254_snarks_g2_prj_scalar_mul_big_coef(bn254_snarks_g2_prj* P,
 void        ctt_bn254_snarks_g2_prj_scalar_mul_fr_coef(bn254_snarks_g2_prj* P, const bn254_snarks_fr* scalar);
 void        ctt_bn254_snarks_g2_prj_scalar_mul_big_coef_vartime(bn254_snarks_g2_prj* P, const big254* scalar);
 void        ctt_bn254_snarks_g2_prj_scalar_mul_fr_coef_vartime(bn254_snarks_g2_prj* P, const bn254_snarks_fr* scalar);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_big_coefs(bn254_snarks_g2_prj* r, const big254 coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs(bn254_snarks_g2_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_aff points[], size_t len);
 void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_big_coefs_vartime(bn254_snarks_g2_prj* r, const big254 coefs[], const bn254_snarks_g2_aff points[], size_t len);
 void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_vartime(bn254_snarks_g2_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_u64_coefs_vartime(bn254_snarks_g2_prj* r, const uint64_t coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_i64_coefs_vartime(bn254_snarks_g2_prj* r, const int64_t coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(bn254_snarks_g2_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bn254_snarks_g2_fixed_base_table_precompute(bn254_snarks_g2_fixed_base_table* table, const bn254_snarks_g2_aff* base);
+void        ctt_bn254_snarks_g2_fixed_base_table_precompute_generator(bn254_snarks_g2_fixed_base_table* table);
+void        ctt_bn254_snarks_g2_fixed_base_table_delete(bn254_snarks_g2_fixed_base_table* table);
+void        ctt_bn254_snarks_g2_jac_fixed_base_scalar_mul_big_coef(bn254_snarks_g2_jac* r, const bn254_snarks_g2_fixed_base_table* table, const big254* scalar);
+void        ctt_bn254_snarks_g2_jac_fixed_base_scalar_mul_fr_coef(bn254_snarks_g2_jac* r, const bn254_snarks_g2_fixed_base_table* table, const bn254_snarks_fr* scalar);
+void        ctt_bn254_snarks_g2_prj_fixed_base_scalar_mul_big_coef(bn254_snarks_g2_prj* r, const bn254_snarks_g2_fixed_base_table* table, const big254* scalar);
+void        ctt_bn254_snarks_g2_prj_fixed_base_scalar_mul_fr_coef(bn254_snarks_g2_prj* r, const bn254_snarks_g2_fixed_base_table* table, const bn254_snarks_fr* scalar);
 void        ctt_bn254_snarks_g1_aff_svdw_sha256(bn254_snarks_g1_aff* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
 void        ctt_bn254_snarks_g1_jac_svdw_sha256(bn254_snarks_g1_jac* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
 void        ctt_bn254_snarks_g1_prj_svdw_sha256(bn254_snarks_g1_prj* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
 void        ctt_bn254_snarks_g2_aff_svdw_sha256(bn254_snarks_g2_aff* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
 void        ctt_bn254_snarks_g2_jac_svdw_sha256(bn254_snarks_g2_jac* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
 void        ctt_bn254_snarks_g2_prj_svdw_sha256(bn254_snarks_g2_prj* r, const byte augmentation[], size_t augmentation_len, const byte message[], size_t message_len, const byte domainSepTag[], size_t domainSepTag_len);
+void        ctt_bn254_snarks_kzg_commit_vartime(bn254_snarks_g1_aff* commitment, const bn254_snarks_fr poly[], const bn254_snarks_g1_aff powers_of_tau_g1[], size_t len);
+void        ctt_bn254_snarks_kzg_prove_monomial_vartime(bn254_snarks_g1_aff* proof, bn254_snarks_fr* eval_at_challenge, const bn254_snarks_fr poly[], const bn254_snarks_g1_aff powers_of_tau_g1[], size_t len, const bn254_snarks_fr* opening_challenge);
+ctt_bool    ctt_bn254_snarks_kzg_verify_vartime(const bn254_snarks_g1_aff* commitment, const bn254_snarks_fr* opening_challenge, const bn254_snarks_fr* eval_at_challenge, const bn254_snarks_g1_aff* proof, const bn254_snarks_g2_aff* tau_g2) __attribute__((warn_unused_result));
+ctt_bool    ctt_bn254_snarks_kzg_verify_batch_vartime(const bn254_snarks_g1_aff commitments[], const bn254_snarks_fr challenges[], const bn254_snarks_fr evals_at_challenges[], const bn254_snarks_g1_aff proofs[], const bn254_snarks_fr linear_indep_random_numbers[], size_t len, const bn254_snarks_g2_aff* tau_g2) __attribute__((warn_unused_result));
+void        ctt_bn254_snarks_kzg_setup_insecure_for_testing(const bn254_snarks_g1_aff powers_of_tau_g1[], size_t len, bn254_snarks_g2_aff* tau_g2, const bn254_snarks_fr* tau);
+ctt_bool    ctt_bn254_snarks_srs_g1_from_montgomery_le_unchecked(const bn254_snarks_g1_aff dst[], const byte src[], size_t len) __attribute__((warn_unused_result));
+ctt_bool    ctt_bn254_snarks_srs_g2_from_montgomery_le_unchecked(const bn254_snarks_g2_aff dst[], const byte src[], size_t len) __attribute__((warn_unused_result));
+ctt_bool    ctt_bn254_snarks_srs_monomial_to_lagrange_vartime(const bn254_snarks_g1_aff lagrange_g1[], const bn254_snarks_g1_aff powers_of_tau_g1[], size_t len, const bn254_snarks_fr* root_of_unity) __attribute__((warn_unused_result));
 
 #ifdef __cplusplus
 }
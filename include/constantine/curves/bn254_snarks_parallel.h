@@ -18,10 +18,59 @@
 extern "C" {
 #endif
 
+typedef struct { size_t len; bn254_snarks_g1_aff* points; } bn254_snarks_g1_msm_table;
+typedef struct { size_t len; bn254_snarks_g2_aff* points; } bn254_snarks_g2_msm_table;
+
+ctt_bool    ctt_bn254_snarks_fr_ntt_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_fr values[], size_t values_len, const bn254_snarks_fr* omega) __attribute__((warn_unused_result));
+ctt_bool    ctt_bn254_snarks_fr_intt_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_fr values[], size_t values_len, const bn254_snarks_fr* omega) __attribute__((warn_unused_result));
+ctt_bool    ctt_bn254_snarks_fr_coset_ntt_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_fr values[], size_t values_len, const bn254_snarks_fr* omega, const bn254_snarks_fr* shift) __attribute__((warn_unused_result));
+ctt_bool    ctt_bn254_snarks_fr_coset_intt_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_fr values[], size_t values_len, const bn254_snarks_fr* omega, const bn254_snarks_fr* shift) __attribute__((warn_unused_result));
+void        ctt_bn254_snarks_g1_msm_table_precompute_parallel(const ctt_threadpool* tp, bn254_snarks_g1_msm_table* table, const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_msm_table_delete(bn254_snarks_g1_msm_table* table);
 void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_jac* r, const big254 coefs[], const bn254_snarks_g1_aff points[], size_t len);
 void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_jac* r, const uint64_t coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_jac* r, const int64_t coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bn254_snarks_g1_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_msm_table* table, size_t len);
+void        ctt_bn254_snarks_g1_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_jac r[], size_t r_len, const bn254_snarks_fr* const coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_jac r[], size_t r_len, const bn254_snarks_fr* const coefs[], const bn254_snarks_g1_msm_table* table, size_t len);
+void        ctt_bn254_snarks_g1_jac_batch_affine_parallel(const ctt_threadpool* tp, const bn254_snarks_g1_aff dst[], const bn254_snarks_g1_jac src[], size_t n);
+void        ctt_bn254_snarks_g1_jac_sum_reduce_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_jac* r, const bn254_snarks_g1_aff points[], size_t len);
 void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_prj* r, const big254 coefs[], const bn254_snarks_g1_aff points[], size_t len);
 void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_prj* r, const uint64_t coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_prj* r, const int64_t coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bn254_snarks_g1_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g1_msm_table* table, size_t len);
+void        ctt_bn254_snarks_g1_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_prj r[], size_t r_len, const bn254_snarks_fr* const coefs[], const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g1_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_prj r[], size_t r_len, const bn254_snarks_fr* const coefs[], const bn254_snarks_g1_msm_table* table, size_t len);
+void        ctt_bn254_snarks_g1_prj_batch_affine_parallel(const ctt_threadpool* tp, const bn254_snarks_g1_aff dst[], const bn254_snarks_g1_prj src[], size_t n);
+void        ctt_bn254_snarks_g1_prj_sum_reduce_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g1_prj* r, const bn254_snarks_g1_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_msm_table_precompute_parallel(const ctt_threadpool* tp, bn254_snarks_g2_msm_table* table, const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_msm_table_delete(bn254_snarks_g2_msm_table* table);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_jac* r, const big254 coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_jac* r, const uint64_t coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_jac* r, const int64_t coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bn254_snarks_g2_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_jac* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_msm_table* table, size_t len);
+void        ctt_bn254_snarks_g2_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_jac r[], size_t r_len, const bn254_snarks_fr* const coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_jac r[], size_t r_len, const bn254_snarks_fr* const coefs[], const bn254_snarks_g2_msm_table* table, size_t len);
+void        ctt_bn254_snarks_g2_jac_batch_affine_parallel(const ctt_threadpool* tp, const bn254_snarks_g2_aff dst[], const bn254_snarks_g2_jac src[], size_t n);
+void        ctt_bn254_snarks_g2_jac_sum_reduce_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_jac* r, const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_prj* r, const big254 coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_prj* r, const uint64_t coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_prj* r, const int64_t coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_bn254_snarks_g2_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_prj* r, const bn254_snarks_fr coefs[], const bn254_snarks_g2_msm_table* table, size_t len);
+void        ctt_bn254_snarks_g2_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_prj r[], size_t r_len, const bn254_snarks_fr* const coefs[], const bn254_snarks_g2_aff points[], size_t len);
+void        ctt_bn254_snarks_g2_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_prj r[], size_t r_len, const bn254_snarks_fr* const coefs[], const bn254_snarks_g2_msm_table* table, size_t len);
+void        ctt_bn254_snarks_g2_prj_batch_affine_parallel(const ctt_threadpool* tp, const bn254_snarks_g2_aff dst[], const bn254_snarks_g2_prj src[], size_t n);
+void        ctt_bn254_snarks_g2_prj_sum_reduce_vartime_parallel(const ctt_threadpool* tp, bn254_snarks_g2_prj* r, const bn254_snarks_g2_aff points[], size_t len);
+ctt_bool    ctt_bn254_snarks_srs_g1_validate_parallel(const ctt_threadpool* tp, const bn254_snarks_g1_aff points[], size_t len) __attribute__((warn_unused_result));
+ctt_bool    ctt_bn254_snarks_srs_g2_validate_parallel(const ctt_threadpool* tp, const bn254_snarks_g2_aff points[], size_t len) __attribute__((warn_unused_result));
 
 #ifdef __cplusplus
 }
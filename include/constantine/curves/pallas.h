@@ -21,6 +21,7 @@ typedef struct { secret_word limbs[CTT_WORDS_REQUIRED(255)]; } pallas_fp;
 typedef struct { pallas_fp x, y; } pallas_ec_aff;
 typedef struct { pallas_fp x, y, z; } pallas_ec_jac;
 typedef struct { pallas_fp x, y, z; } pallas_ec_prj;
+typedef struct { pallas_ec_aff* points; } pallas_ec_fixed_base_table;
 
 void        ctt_big255_from_pallas_fr(big255* dst, const pallas_fr* src);
 void        ctt_pallas_fr_from_big255(pallas_fr* dst, const big255* src);
@@ -55,6 +56,10 @@ void        ctt_pallas_fr_cset_one(pallas_fr* a, secret_bool ctl);
 void        ctt_pallas_fr_cneg_in_place(pallas_fr* a, secret_bool ctl);
 void        ctt_pallas_fr_cadd_in_place(pallas_fr* a, const pallas_fr* b, secret_bool ctl);
 void        ctt_pallas_fr_csub_in_place(pallas_fr* a, const pallas_fr* b, secret_bool ctl);
+ctt_bool    ctt_pallas_fr_ntt_vartime(pallas_fr values[], size_t values_len, const pallas_fr* omega) __attribute__((warn_unused_result));
+ctt_bool    ctt_pallas_fr_intt_vartime(pallas_fr values[], size_t values_len, const pallas_fr* omega) __attribute__((warn_unused_result));
+ctt_bool    ctt_pallas_fr_coset_ntt_vartime(pallas_fr values[], size_t values_len, const pallas_fr* omega, const pallas_fr* shift) __attribute__((warn_unused_result));
+ctt_bool    ctt_pallas_fr_coset_intt_vartime(pallas_fr values[], size_t values_len, const pallas_fr* omega, const pallas_fr* shift) __attribute__((warn_unused_result));
 void        ctt_big255_from_pallas_fp(big255* dst, const pallas_fp* src);
 void        ctt_pallas_fp_from_big255(pallas_fp* dst, const big255* src);
 ctt_bool    ctt_pallas_fp_unmarshalBE(pallas_fp* dst, const byte src[], size_t src_len) __attribute__((warn_unused_result));
@@ -122,8 +127,13 @@ void        ctt_pallas_ec_jac_scalar_mul_big_coef(pallas_ec_jac* P, const big255
 void        ctt_pallas_ec_jac_scalar_mul_fr_coef(pallas_ec_jac* P, const pallas_fr* scalar);
 void        ctt_pallas_ec_jac_scalar_mul_big_coef_vartime(pallas_ec_jac* P, const big255* scalar);
 void        ctt_pallas_ec_jac_scalar_mul_fr_coef_vartime(pallas_ec_jac* P, const pallas_fr* scalar);
+void        ctt_pallas_ec_jac_multi_scalar_mul_big_coefs(pallas_ec_jac* r, const big255 coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs(pallas_ec_jac* r, const pallas_fr coefs[], const pallas_ec_aff points[], size_t len);
 void        ctt_pallas_ec_jac_multi_scalar_mul_big_coefs_vartime(pallas_ec_jac* r, const big255 coefs[], const pallas_ec_aff points[], size_t len);
 void        ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_vartime(pallas_ec_jac* r, const pallas_fr coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_jac_multi_scalar_mul_u64_coefs_vartime(pallas_ec_jac* r, const uint64_t coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_jac_multi_scalar_mul_i64_coefs_vartime(pallas_ec_jac* r, const int64_t coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(pallas_ec_jac* r, const pallas_fr coefs[], const pallas_ec_aff points[], size_t len, size_t coefs_max_bits);
 secret_bool ctt_pallas_ec_prj_is_eq(const pallas_ec_prj* P, const pallas_ec_prj* Q);
 secret_bool ctt_pallas_ec_prj_is_neutral(const pallas_ec_prj* P);
 void        ctt_pallas_ec_prj_set_neutral(pallas_ec_prj* P);
@@ -143,8 +153,20 @@ void        ctt_pallas_ec_prj_scalar_mul_big_coef(pallas_ec_prj* P, const big255
 void        ctt_pallas_ec_prj_scalar_mul_fr_coef(pallas_ec_prj* P, const pallas_fr* scalar);
 void        ctt_pallas_ec_prj_scalar_mul_big_coef_vartime(pallas_ec_prj* P, const big255* scalar);
 void        ctt_pallas_ec_prj_scalar_mul_fr_coef_vartime(pallas_ec_prj* P, const pallas_fr* scalar);
+void        ctt_pallas_ec_prj_multi_scalar_mul_big_coefs(pallas_ec_prj* r, const big255 coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs(pallas_ec_prj* r, const pallas_fr coefs[], const pallas_ec_aff points[], size_t len);
 void        ctt_pallas_ec_prj_multi_scalar_mul_big_coefs_vartime(pallas_ec_prj* r, const big255 coefs[], const pallas_ec_aff points[], size_t len);
 void        ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_vartime(pallas_ec_prj* r, const pallas_fr coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_prj_multi_scalar_mul_u64_coefs_vartime(pallas_ec_prj* r, const uint64_t coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_prj_multi_scalar_mul_i64_coefs_vartime(pallas_ec_prj* r, const int64_t coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(pallas_ec_prj* r, const pallas_fr coefs[], const pallas_ec_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_pallas_ec_fixed_base_table_precompute(pallas_ec_fixed_base_table* table, const pallas_ec_aff* base);
+void        ctt_pallas_ec_fixed_base_table_precompute_generator(pallas_ec_fixed_base_table* table);
+void        ctt_pallas_ec_fixed_base_table_delete(pallas_ec_fixed_base_table* table);
+void        ctt_pallas_ec_jac_fixed_base_scalar_mul_big_coef(pallas_ec_jac* r, const pallas_ec_fixed_base_table* table, const big255* scalar);
+void        ctt_pallas_ec_jac_fixed_base_scalar_mul_fr_coef(pallas_ec_jac* r, const pallas_ec_fixed_base_table* table, const pallas_fr* scalar);
+void        ctt_pallas_ec_prj_fixed_base_scalar_mul_big_coef(pallas_ec_prj* r, const pallas_ec_fixed_base_table* table, const big255* scalar);
+void        ctt_pallas_ec_prj_fixed_base_scalar_mul_fr_coef(pallas_ec_prj* r, const pallas_ec_fixed_base_table* table, const pallas_fr* scalar);
 
 #ifdef __cplusplus
 }
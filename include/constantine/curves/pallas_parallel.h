@@ -18,10 +18,34 @@
 extern "C" {
 #endif
 
+typedef struct { size_t len; pallas_ec_aff* points; } pallas_ec_msm_table;
+
+ctt_bool    ctt_pallas_fr_ntt_vartime_parallel(const ctt_threadpool* tp, pallas_fr values[], size_t values_len, const pallas_fr* omega) __attribute__((warn_unused_result));
+ctt_bool    ctt_pallas_fr_intt_vartime_parallel(const ctt_threadpool* tp, pallas_fr values[], size_t values_len, const pallas_fr* omega) __attribute__((warn_unused_result));
+ctt_bool    ctt_pallas_fr_coset_ntt_vartime_parallel(const ctt_threadpool* tp, pallas_fr values[], size_t values_len, const pallas_fr* omega, const pallas_fr* shift) __attribute__((warn_unused_result));
+ctt_bool    ctt_pallas_fr_coset_intt_vartime_parallel(const ctt_threadpool* tp, pallas_fr values[], size_t values_len, const pallas_fr* omega, const pallas_fr* shift) __attribute__((warn_unused_result));
+void        ctt_pallas_ec_msm_table_precompute_parallel(const ctt_threadpool* tp, pallas_ec_msm_table* table, const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_msm_table_delete(pallas_ec_msm_table* table);
 void        ctt_pallas_ec_jac_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, pallas_ec_jac* r, const big255 coefs[], const pallas_ec_aff points[], size_t len);
 void        ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, pallas_ec_jac* r, const pallas_fr coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_jac_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, pallas_ec_jac* r, const uint64_t coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_jac_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, pallas_ec_jac* r, const int64_t coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, pallas_ec_jac* r, const pallas_fr coefs[], const pallas_ec_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_pallas_ec_jac_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, pallas_ec_jac* r, const pallas_fr coefs[], const pallas_ec_msm_table* table, size_t len);
+void        ctt_pallas_ec_jac_batch_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, pallas_ec_jac r[], size_t r_len, const pallas_fr* const coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_jac_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, pallas_ec_jac r[], size_t r_len, const pallas_fr* const coefs[], const pallas_ec_msm_table* table, size_t len);
+void        ctt_pallas_ec_jac_batch_affine_parallel(const ctt_threadpool* tp, const pallas_ec_aff dst[], const pallas_ec_jac src[], size_t n);
+void        ctt_pallas_ec_jac_sum_reduce_vartime_parallel(const ctt_threadpool* tp, pallas_ec_jac* r, const pallas_ec_aff points[], size_t len);
 void        ctt_pallas_ec_prj_multi_scalar_mul_big_coefs_vartime_parallel(const ctt_threadpool* tp, pallas_ec_prj* r, const big255 coefs[], const pallas_ec_aff points[], size_t len);
 void        ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, pallas_ec_prj* r, const pallas_fr coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_prj_multi_scalar_mul_u64_coefs_vartime_parallel(const ctt_threadpool* tp, pallas_ec_prj* r, const uint64_t coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_prj_multi_scalar_mul_i64_coefs_vartime_parallel(const ctt_threadpool* tp, pallas_ec_prj* r, const int64_t coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime_parallel(const ctt_threadpool* tp, pallas_ec_prj* r, const pallas_fr coefs[], const pallas_ec_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_pallas_ec_prj_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, pallas_ec_prj* r, const pallas_fr coefs[], const pallas_ec_msm_table* table, size_t len);
+void        ctt_pallas_ec_prj_batch_multi_scalar_mul_fr_coefs_vartime_parallel(const ctt_threadpool* tp, pallas_ec_prj r[], size_t r_len, const pallas_fr* const coefs[], const pallas_ec_aff points[], size_t len);
+void        ctt_pallas_ec_prj_batch_multi_scalar_mul_fr_coefs_table_vartime_parallel(const ctt_threadpool* tp, pallas_ec_prj r[], size_t r_len, const pallas_fr* const coefs[], const pallas_ec_msm_table* table, size_t len);
+void        ctt_pallas_ec_prj_batch_affine_parallel(const ctt_threadpool* tp, const pallas_ec_aff dst[], const pallas_ec_prj src[], size_t n);
+void        ctt_pallas_ec_prj_sum_reduce_vartime_parallel(const ctt_threadpool* tp, pallas_ec_prj* r, const pallas_ec_aff points[], size_t len);
 
 #ifdef __cplusplus
 }
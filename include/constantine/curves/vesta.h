@@ -21,6 +21,7 @@ typedef struct { secret_word limbs[CTT_WORDS_REQUIRED(255)]; } vesta_fp;
 typedef struct { vesta_fp x, y; } vesta_ec_aff;
 typedef struct { vesta_fp x, y, z; } vesta_ec_jac;
 typedef struct { vesta_fp x, y, z; } vesta_ec_prj;
+typedef struct { vesta_ec_aff* points; } vesta_ec_fixed_base_table;
 
 void        ctt_big255_from_vesta_fr(big255* dst, const vesta_fr* src);
 void        ctt_vesta_fr_from_big255(vesta_fr* dst, const big255* src);
@@ -55,6 +56,10 @@ void        ctt_vesta_fr_cset_one(vesta_fr* a, secret_bool ctl);
 void        ctt_vesta_fr_cneg_in_place(vesta_fr* a, secret_bool ctl);
 void        ctt_vesta_fr_cadd_in_place(vesta_fr* a, const vesta_fr* b, secret_bool ctl);
 void        ctt_vesta_fr_csub_in_place(vesta_fr* a, const vesta_fr* b, secret_bool ctl);
+ctt_bool    ctt_vesta_fr_ntt_vartime(vesta_fr values[], size_t values_len, const vesta_fr* omega) __attribute__((warn_unused_result));
+ctt_bool    ctt_vesta_fr_intt_vartime(vesta_fr values[], size_t values_len, const vesta_fr* omega) __attribute__((warn_unused_result));
+ctt_bool    ctt_vesta_fr_coset_ntt_vartime(vesta_fr values[], size_t values_len, const vesta_fr* omega, const vesta_fr* shift) __attribute__((warn_unused_result));
+ctt_bool    ctt_vesta_fr_coset_intt_vartime(vesta_fr values[], size_t values_len, const vesta_fr* omega, const vesta_fr* shift) __attribute__((warn_unused_result));
 void        ctt_big255_from_vesta_fp(big255* dst, const vesta_fp* src);
 void        ctt_vesta_fp_from_big255(vesta_fp* dst, const big255* src);
 ctt_bool    ctt_vesta_fp_unmarshalBE(vesta_fp* dst, const byte src[], size_t src_len) __attribute__((warn_unused_result));
@@ -122,8 +127,13 @@ void        ctt_vesta_ec_jac_scalar_mul_big_coef(vesta_ec_jac* P, const big255*
 void        ctt_vesta_ec_jac_scalar_mul_fr_coef(vesta_ec_jac* P, const vesta_fr* scalar);
 void        ctt_vesta_ec_jac_scalar_mul_big_coef_vartime(vesta_ec_jac* P, const big255* scalar);
 void        ctt_vesta_ec_jac_scalar_mul_fr_coef_vartime(vesta_ec_jac* P, const vesta_fr* scalar);
+void        ctt_vesta_ec_jac_multi_scalar_mul_big_coefs(vesta_ec_jac* r, const big255 coefs[], const vesta_ec_aff points[], size_t len);
+void        ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs(vesta_ec_jac* r, const vesta_fr coefs[], const vesta_ec_aff points[], size_t len);
 void        ctt_vesta_ec_jac_multi_scalar_mul_big_coefs_vartime(vesta_ec_jac* r, const big255 coefs[], const vesta_ec_aff points[], size_t len);
 void        ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_vartime(vesta_ec_jac* r, const vesta_fr coefs[], const vesta_ec_aff points[], size_t len);
+void        ctt_vesta_ec_jac_multi_scalar_mul_u64_coefs_vartime(vesta_ec_jac* r, const uint64_t coefs[], const vesta_ec_aff points[], size_t len);
+void        ctt_vesta_ec_jac_multi_scalar_mul_i64_coefs_vartime(vesta_ec_jac* r, const int64_t coefs[], const vesta_ec_aff points[], size_t len);
+void        ctt_vesta_ec_jac_multi_scalar_mul_fr_coefs_max_bits_vartime(vesta_ec_jac* r, const vesta_fr coefs[], const vesta_ec_aff points[], size_t len, size_t coefs_max_bits);
 secret_bool ctt_vesta_ec_prj_is_eq(const vesta_ec_prj* P, const vesta_ec_prj* Q);
 secret_bool ctt_vesta_ec_prj_is_neutral(const vesta_ec_prj* P);
 void        ctt_vesta_ec_prj_set_neutral(vesta_ec_prj* P);
@@ -143,8 +153,20 @@ void        ctt_vesta_ec_prj_scalar_mul_big_coef(vesta_ec_prj* P, const big255*
 void        ctt_vesta_ec_prj_scalar_mul_fr_coef(vesta_ec_prj* P, const vesta_fr* scalar);
 void        ctt_vesta_ec_prj_scalar_mul_big_coef_vartime(vesta_ec_prj* P, const big255* scalar);
 void        ctt_vesta_ec_prj_scalar_mul_fr_coef_vartime(vesta_ec_prj* P, const vesta_fr* scalar);
+void        ctt_vesta_ec_prj_multi_scalar_mul_big_coefs(vesta_ec_prj* r, const big255 coefs[], const vesta_ec_aff points[], size_t len);
+void        ctt_vesta_ec_prj_multi_scalar_mul_fr_coefs(vesta_ec_prj* r, const vesta_fr coefs[], const vesta_ec_aff points[], size_t len);
 void        ctt_vesta_ec_prj_multi_scalar_mul_big_coefs_vartime(vesta_ec_prj* r, const big255 coefs[], const vesta_ec_aff points[], size_t len);
 void        ctt_vesta_ec_prj_multi_scalar_mul_fr_coefs_vartime(vesta_ec_prj* r, const vesta_fr coefs[], const vesta_ec_aff points[], size_t len);
+void        ctt_vesta_ec_prj_multi_scalar_mul_u64_coefs_vartime(vesta_ec_prj* r, const uint64_t coefs[], const vesta_ec_aff points[], size_t len);
+void        ctt_vesta_ec_prj_multi_scalar_mul_i64_coefs_vartime(vesta_ec_prj* r, const int64_t coefs[], const vesta_ec_aff points[], size_t len);
+void        ctt_vesta_ec_prj_multi_scalar_mul_fr_coefs_max_bits_vartime(vesta_ec_prj* r, const vesta_fr coefs[], const vesta_ec_aff points[], size_t len, size_t coefs_max_bits);
+void        ctt_vesta_ec_fixed_base_table_precompute(vesta_ec_fixed_base_table* table, const vesta_ec_aff* base);
+void        ctt_vesta_ec_fixed_base_table_precompute_generator(vesta_ec_fixed_base_table* table);
+void        ctt_vesta_ec_fixed_base_table_delete(vesta_ec_fixed_base_table* table);
+void        ctt_vesta_ec_jac_fixed_base_scalar_mul_big_coef(vesta_ec_jac* r, const vesta_ec_fixed_base_table* table, const big255* scalar);
+void        ctt_vesta_ec_jac_fixed_base_scalar_mul_fr_coef(vesta_ec_jac* r, const vesta_ec_fixed_base_table* table, const vesta_fr* scalar);
+void        ctt_vesta_ec_prj_fixed_base_scalar_mul_big_coef(vesta_ec_prj* r, const vesta_ec_fixed_base_table* table, const big255* scalar);
+void        ctt_vesta_ec_prj_fixed_base_scalar_mul_fr_coef(vesta_ec_prj* r, const vesta_ec_fixed_base_table* table, const vesta_fr* scalar);
 
 #ifdef __cplusplus
 }
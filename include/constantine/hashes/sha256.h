@@ -15,6 +15,13 @@
 extern "C" {
 #endif
 
+typedef enum __attribute__((__packed__)) {
+    cttSha256_Generic,
+    cttSha256_x86_SSSE3,
+    cttSha256_x86_SHA,
+    cttSha256_ARM64_SHA2,
+} ctt_sha256_backend;
+
 typedef struct {
   ctt_align(64) uint32_t message_schedule[16];
   ctt_align(64) byte     buf[64];
@@ -66,6 +73,19 @@ void ctt_sha256_clear(ctt_sha256_context* ctx);
  */
 void ctt_sha256_hash(byte digest[32], const byte* message, size_t message_len, ctt_bool clear_memory);
 
+/** Compute the SHA-256 hash of `len` 64-byte inputs
+ *  and store the results in digests.
+ *
+ *  This is the hash of Merkle tree inner nodes,
+ *  the constant padding block is not buffered.
+ */
+void ctt_sha256_hash_64byte_pairs(byte digests[][32], const byte pairs[][64], size_t len);
+
+/** Returns the SHA-256 implementation selected at runtime
+ *  depending on the CPU features.
+ */
+ctt_sha256_backend ctt_sha256_backend(void);
+
 #ifdef __cplusplus
 }
 #endif
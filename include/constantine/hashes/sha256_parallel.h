@@ -0,0 +1,31 @@
+/** Constantine
+ *  Copyright (c) 2018-2019    Status Research & Development GmbH
+ *  Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+ *  Licensed and distributed under either of
+ *    * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+ *    * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+ *  at your option. This file may not be copied, modified, or distributed except according to those terms.
+ */
+#ifndef __CTT_H_SHA256_PARALLEL__
+#define __CTT_H_SHA256_PARALLEL__
+
+#include "constantine/core/datatypes.h"
+#include "constantine/core/threadpool.h"
+#include "constantine/hashes/sha256.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/** Compute the SHA-256 hash of `len` 64-byte inputs
+ *  and store the results in digests.
+ *
+ *  Parallelism: This only returns when computation is fully done
+ */
+void ctt_sha256_hash_64byte_pairs_parallel(const ctt_threadpool* tp, byte digests[][32], const byte pairs[][64], size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // __CTT_H_SHA256_PARALLEL__
@@ -186,6 +186,32 @@ ctt_eth_kzg_status ctt_eth_kzg_verify_blob_kzg_proof_batch(
         const byte secure_random_bytes[32]
 ) __attribute__((__warn_unused_result__));
 
+/** Verify `n` (blob, commitment, proof) sets efficiently
+ *  and locate the invalid sets on failure.
+ *
+ *  On return, `invalid[i]` is true if the i-th set is invalid,
+ *  including if its commitment, proof or blob cannot be deserialized.
+ *
+ *  Returns:
+ *  - cttEthKzg_Success if all sets are valid
+ *  - cttEthKzg_VerificationFailure if at least one set is invalid
+ *  - cttEthKzg_InputsLengthsMismatch if `n` overflows a signed integer, `invalid` is not written
+ *
+ *  The sets are first verified as a batch. On failure, each set is verified individually.
+ *
+ *  `secure_random_bytes` random bytes must come from a cryptographically secure RNG
+ *  or computed through the Fiat-Shamir heuristic.
+ */
+ctt_eth_kzg_status ctt_eth_kzg_verify_blob_kzg_proof_batch_find_invalid(
+        const ctt_eth_kzg_context* ctx,
+        ctt_bool invalid[],
+        const ctt_eth_kzg_blob blobs[],
+        const ctt_eth_kzg_commitment commitments[],
+        const ctt_eth_kzg_proof proofs[],
+        size_t n,
+        const byte secure_random_bytes[32]
+) __attribute__((__warn_unused_result__));
+
 
 // EIP4844 KZG Trusted setup
 // ------------------------------------------------------------------------------------------------
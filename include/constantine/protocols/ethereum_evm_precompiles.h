@@ -24,6 +24,8 @@ typedef enum __attribute__((__packed__)) {
     cttEVM_PointNotOnCurve,
     cttEVM_PointNotInSubgroup,
     cttEVM_VerificationFailure,
+    cttEVM_MalformedSignature,
+    cttEVM_InvalidFinalFlag,
 } ctt_evm_status;
 
 static const char* ctt_evm_status_to_string(ctt_evm_status status) {
@@ -35,6 +37,8 @@ static const char* ctt_evm_status_to_string(ctt_evm_status status) {
       "cttEVM_PointNotOnCurve",
       "cttEVM_PointNotInSubgroup",
       "cttEVM_VerificationFailure",
+      "cttEVM_MalformedSignature",
+      "cttEVM_InvalidFinalFlag",
   };
   size_t length = sizeof statuses / sizeof *statuses;
   if (0 <= status && status < length) {
@@ -43,6 +47,105 @@ static const char* ctt_evm_status_to_string(ctt_evm_status status) {
   return "cttEVM_InvalidStatusCode";
 }
 
+typedef enum __attribute__((__packed__)) {
+    cttEVMFail_None,
+    cttEVMFail_InvalidLength,
+    cttEVMFail_NonZeroPadding,
+    cttEVMFail_CoordinateGreaterOrEqualModulus,
+    cttEVMFail_PointNotOnCurve,
+    cttEVMFail_PointNotInSubgroup,
+} ctt_evm_failure_reason;
+
+/** Reason of an input rejection
+ *  and byte offset of the offending field element or point in the input of the precompile
+ */
+typedef struct {
+    ctt_evm_failure_reason reason;
+    size_t offset;
+} ctt_evm_failure;
+
+/**
+ *  Recover the Ethereum address of the public key
+ *  which was used to sign a message digest.
+ *
+ *  Name: ECRECOVER
+ *
+ *  Inputs:
+ *  - r: array with 32 bytes of storage for the result
+ *  - r_len: length of `r`. Must be 32
+ *  - inputs: 128 bytes with the following breakdown:
+ *    - 32 bytes, keccak256 digest of the message that was signed
+ *    - 32 bytes, `v`, 27 or 28, the parity of the `y` coordinate of `R`
+ *    - 32 bytes, `r` of the signature
+ *    - 32 bytes, `s` of the signature
+ *    Shorter inputs are right-padded with zeros and extra bytes are ignored.
+ *  - inputs_len: length of the inputs array
+ *
+ *  Output:
+ *  - 32 bytes, the 20-byte Ethereum address left-padded with zeros
+ *  - status code:
+ *    cttEVM_Success
+ *    cttEVM_InvalidOutputSize
+ *    cttEVM_MalformedSignature
+ *
+ *  `cttEVM_MalformedSignature` is returned for invalid `v`, `r` or `s`
+ *  and for signatures without a recoverable public key.
+ *  The EVM returns an empty output in that case.
+ */
+ctt_evm_status ctt_eth_evm_ecrecover(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len
+    ) __attribute__((warn_unused_result));
+
+/**
+ *  Verify an ECDSA signature over the P-256 (secp256r1) curve
+ *  for a message digest.
+ *
+ *  Name: P256VERIFY
+ *
+ *  Inputs:
+ *  - r: array with 32 bytes of storage for the result
+ *  - r_len: length of `r`. Must be 32
+ *  - inputs: 160 bytes with the following breakdown:
+ *    - 32 bytes, the message digest that was signed
+ *    - 32 bytes, `r` of the signature
+ *    - 32 bytes, `s` of the signature
+ *    - 32 bytes, `x` coordinate of the public key
+ *    - 32 bytes, `y` coordinate of the public key
+ *  - inputs_len: length of the inputs array
+ *
+ *  Output:
+ *  - 32 bytes, 1 left-padded with zeros on valid signature
+ *  - status code:
+ *    cttEVM_Success
+ *    cttEVM_InvalidInputSize
+ *    cttEVM_InvalidOutputSize
+ *    cttEVM_MalformedSignature
+ *    cttEVM_IntLargerThanModulus
+ *    cttEVM_PointNotOnCurve
+ *    cttEVM_VerificationFailure
+ *
+ *  Any status other than cttEVM_Success is an invalid signature
+ *  and the EVM returns an empty output in that case.
+ *
+ *  Spec https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md
+ */
+ctt_evm_status ctt_eth_evm_p256verify(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len
+    ) __attribute__((warn_unused_result));
+
+/** Same as `ctt_eth_evm_p256verify`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_p256verify_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+    ) __attribute__((warn_unused_result));
+
 /**
  *  SHA256
  *
@@ -168,6 +271,17 @@ ctt_evm_status ctt_eth_evm_bn254_g1add(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bn254_g1add`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bn254_g1add_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
   *  Elliptic Curve multiplication on BN254_Snarks
   *  (also called alt_bn128 in Ethereum specs
@@ -200,6 +314,17 @@ ctt_evm_status ctt_eth_evm_bn254_g1mul(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bn254_g1mul`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bn254_g1mul_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
   *  Elliptic Curve pairing check on BN254_Snarks
   *  (also called alt_bn128 in Ethereum specs
@@ -229,6 +354,46 @@ ctt_evm_status ctt_eth_evm_bn254_ecpairingcheck(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bn254_ecpairingcheck`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bn254_ecpairingcheck_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
+/**
+  *  BLAKE2b compression function F
+  *
+  *  Name: BLAKE2F
+  *
+  *  Inputs:
+  *  - 213 bytes with the following breakdown:
+  *    - 4 bytes, the number of rounds, big-endian
+  *    - 64 bytes, the state vector h, 8 little-endian 64-bit words
+  *    - 128 bytes, the message block m, 16 little-endian 64-bit words
+  *    - 16 bytes, the offset counters t, 2 little-endian 64-bit words
+  *    - 1 byte, the final block indicator flag f, 0 or 1
+  *
+  *  Output
+  *  - Output buffer MUST be of length 64 bytes
+  *  - The updated state vector h, 8 little-endian 64-bit words
+  *  - Status codes:
+  *    cttEVM_Success
+  *    cttEVM_InvalidInputSize
+  *    cttEVM_InvalidOutputSize
+  *    cttEVM_InvalidFinalFlag
+  *
+  *  Spec https://eips.ethereum.org/EIPS/eip-152
+  */
+ctt_evm_status ctt_eth_evm_blake2f(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len
+) __attribute__((warn_unused_result));
+
 /**
   *  Elliptic Curve addition on BLS12-381 G1
   *
@@ -261,6 +426,17 @@ ctt_evm_status ctt_eth_evm_bls12381_g1add(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bls12381_g1add`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bls12381_g1add_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
   *  Elliptic Curve addition on BLS12-381 G2
   *
@@ -293,6 +469,17 @@ ctt_evm_status ctt_eth_evm_bls12381_g2add(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bls12381_g2add`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bls12381_g2add_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
   *  Elliptic Curve scalar multiplication on BLS12-381 G1
   *
@@ -324,6 +511,17 @@ ctt_evm_status ctt_eth_evm_bls12381_g1mul(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bls12381_g1mul`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bls12381_g1mul_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
      *  Elliptic Curve scalar multiplication on BLS12-381 G2
   *
@@ -355,6 +553,17 @@ ctt_evm_status ctt_eth_evm_bls12381_g2mul(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bls12381_g2mul`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bls12381_g2mul_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
   *  Elliptic Curve addition on BLS12-381 G1
   *
@@ -388,6 +597,17 @@ ctt_evm_status ctt_eth_evm_bls12381_g1msm(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bls12381_g1msm`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bls12381_g1msm_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
   *  Elliptic Curve addition on BLS12-381 G2
   *
@@ -421,6 +641,17 @@ ctt_evm_status ctt_eth_evm_bls12381_g2msm(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bls12381_g2msm`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bls12381_g2msm_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
   *  Elliptic curve pairing check on BLS12-381
   *
@@ -447,6 +678,17 @@ ctt_evm_status ctt_eth_evm_bls12381_pairingcheck(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bls12381_pairingcheck`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bls12381_pairingcheck_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
   *  Map a field element to G1
   *
@@ -472,6 +714,17 @@ ctt_evm_status ctt_eth_evm_bls12381_map_fp_to_g1(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bls12381_map_fp_to_g1`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bls12381_map_fp_to_g1_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
   *  Map an Fp2 extension field element to G2
   *
@@ -497,6 +750,17 @@ ctt_evm_status ctt_eth_evm_bls12381_map_fp2_to_g2(
     const byte* inputs, size_t inputs_len
 ) __attribute__((warn_unused_result));
 
+/** Same as `ctt_eth_evm_bls12381_map_fp2_to_g2`
+ *  On rejection of the input, `failure` holds the reason
+ *  and the byte offset of the offending field element or point in `inputs`.
+ *  Otherwise its reason is cttEVMFail_None.
+ */
+ctt_evm_status ctt_eth_evm_bls12381_map_fp2_to_g2_detailed(
+    byte* r, size_t r_len,
+    const byte* inputs, size_t inputs_len,
+    ctt_evm_failure* failure
+) __attribute__((warn_unused_result));
+
 /**
   *  EIP-4844 Blobs KZG point evaluation
   *
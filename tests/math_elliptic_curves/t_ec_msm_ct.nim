@@ -0,0 +1,76 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+
+import
+  # Standard library
+  std/[times, unittest],
+  # Internals
+  constantine/platforms/abstractions,
+  constantine/named/[algebras, zoo_subgroups],
+  constantine/math/[arithmetic, extension_fields],
+  constantine/math/elliptic/[
+    ec_shortweierstrass_affine,
+    ec_shortweierstrass_jacobian,
+    ec_shortweierstrass_projective,
+    ec_twistededwards_affine,
+    ec_twistededwards_projective,
+    ec_multi_scalar_mul],
+  # Test utilities
+  helpers/prng_unsafe
+
+# The constant-time MSM processes points by chunks of 64
+const numPoints = [1, 2, 8, 63, 64, 65, 200]
+
+var rng: RngState
+let timeseed = uint32(toUnix(getTime()) and (1'i64 shl 32 - 1)) # unixTime mod 2^32
+seed(rng, timeseed)
+echo "\n------------------------------------------------------\n"
+echo "test_ec_msm_ct seed: ", timeseed
+
+proc t_msm_ct(EC: typedesc) =
+  type F = EC.getScalarField()
+
+  for n in numPoints:
+    var points = newSeq[affine(EC)](n)
+    var coefs = newSeq[F](n)
+    for i in 0 ..< n:
+      var tmp = rng.random_unsafe(EC)
+      tmp.clearCofactor()
+      points[i].affine(tmp)
+      coefs[i] = rng.random_unsafe(F)
+    if n >= 2:
+      coefs[1].setZero()
+    if n >= 3:
+      points[2].setNeutral()
+
+    var expected, msm: EC
+    expected.multiScalarMul_vartime(coefs, points)
+    msm.multiScalarMul(coefs, points)
+    doAssert bool(expected == msm)
+
+  block: # Empty MSM
+    var msm: EC
+    msm.multiScalarMul(newSeq[F](), newSeq[affine(EC)]())
+    doAssert bool(msm.isNeutral())
+
+suite "Constant-time multi-scalar-multiplication - [" & $WordBitWidth & "-bit mode]":
+  test "BN254_Snarks 𝔾1 - projective":
+    t_msm_ct(EC_ShortW_Prj[Fp[BN254_Snarks], G1])
+  test "BN254_Snarks 𝔾2 - jacobian":
+    t_msm_ct(EC_ShortW_Jac[Fp2[BN254_Snarks], G2])
+  test "BLS12_381 𝔾1 - jacobian":
+    t_msm_ct(EC_ShortW_Jac[Fp[BLS12_381], G1])
+  test "BLS12_381 𝔾2 - projective":
+    t_msm_ct(EC_ShortW_Prj[Fp2[BLS12_381], G2])
+  test "Pallas - jacobian":
+    t_msm_ct(EC_ShortW_Jac[Fp[Pallas], G1])
+  test "Vesta - projective":
+    t_msm_ct(EC_ShortW_Prj[Fp[Vesta], G1])
+  test "Bandersnatch - projective":
+    t_msm_ct(EC_TwEdw_Prj[Fp[Bandersnatch]])
@@ -0,0 +1,111 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  # Standard library
+  std/[times, unittest],
+  # Internals
+  constantine/platforms/abstractions,
+  constantine/named/[algebras, zoo_subgroups],
+  constantine/math/[arithmetic, extension_fields],
+  constantine/math/io/io_fields,
+  constantine/math/elliptic/[
+    ec_shortweierstrass_affine,
+    ec_shortweierstrass_jacobian,
+    ec_shortweierstrass_projective,
+    ec_twistededwards_affine,
+    ec_twistededwards_projective,
+    ec_multi_scalar_mul],
+  # Test utilities
+  helpers/prng_unsafe
+
+const numPoints = [1, 2, 8, 128, 1024]
+
+var rng: RngState
+let timeseed = uint32(toUnix(getTime()) and (1'i64 shl 32 - 1)) # unixTime mod 2^32
+seed(rng, timeseed)
+echo "\n------------------------------------------------------\n"
+echo "test_ec_msm_small_coefs seed: ", timeseed
+
+func fromSignedInt[F](dst: var F, src: int64) =
+  ## int64.low cannot be negated in int64
+  if src < 0:
+    dst.fromUint(not(cast[uint64](src)) + 1)
+    dst.neg()
+  else:
+    dst.fromUint(uint64 src)
+
+proc randomPoints(EC: typedesc, n: int): seq[affine(EC)] =
+  result.setLen(n)
+  for i in 0 ..< n:
+    var tmp = rng.random_unsafe(EC)
+    tmp.clearCofactor()
+    result[i].affine(tmp)
+
+proc t_msm_small_coefs(EC: typedesc) =
+  type F = EC.getScalarField()
+
+  for n in numPoints:
+    let points = EC.randomPoints(n)
+    var expected, msm: EC
+
+    # Unsigned 64-bit
+    var coefsU64 = newSeq[uint64](n)
+    var coefsFr = newSeq[F](n)
+    for i in 0 ..< n:
+      coefsU64[i] = rng.next()
+      coefsFr[i].fromUint(coefsU64[i])
+    expected.multiScalarMul_vartime(coefsFr, points)
+    msm.multiScalarMul_vartime(coefsU64.asUnchecked(), points.asUnchecked(), n)
+    doAssert bool(expected == msm)
+
+    # Signed 64-bit
+    var coefsI64 = newSeq[int64](n)
+    for i in 0 ..< n:
+      coefsI64[i] = cast[int64](rng.next())
+    coefsI64[0] = int64.low()
+    for i in 0 ..< n:
+      coefsFr[i].fromSignedInt(coefsI64[i])
+    expected.multiScalarMul_vartime(coefsFr, points)
+    msm.multiScalarMul_vartime(coefsI64.asUnchecked(), points.asUnchecked(), n)
+    doAssert bool(expected == msm)
+
+    # Bounded bit-width
+    template checkMaxBits(maxBits: static int) =
+      for i in 0 ..< n:
+        var big: F.getBigInt()
+        big.copyTruncatedFrom(rng.random_unsafe(BigInt[maxBits]))
+        coefsFr[i].fromBig(big)
+      expected.multiScalarMul_vartime(coefsFr, points)
+      msm.multiScalarMul_vartime(coefsFr.asUnchecked(), points.asUnchecked(), n, maxBits)
+      doAssert bool(expected == msm)
+
+    checkMaxBits(1)
+    checkMaxBits(8)
+    checkMaxBits(20)
+    checkMaxBits(64)
+    checkMaxBits(100)
+    checkMaxBits(128)
+    checkMaxBits(200)
+    checkMaxBits(F.bits())
+
+suite "Multi-scalar-multiplication with small and signed coefficients - [" & $WordBitWidth & "-bit mode]":
+  test "BN254_Snarks 𝔾1 - projective":
+    t_msm_small_coefs(EC_ShortW_Prj[Fp[BN254_Snarks], G1])
+  test "BN254_Snarks 𝔾2 - projective":
+    t_msm_small_coefs(EC_ShortW_Prj[Fp2[BN254_Snarks], G2])
+  test "BLS12_381 𝔾1 - jacobian":
+    t_msm_small_coefs(EC_ShortW_Jac[Fp[BLS12_381], G1])
+  test "BLS12_381 𝔾2 - jacobian":
+    t_msm_small_coefs(EC_ShortW_Jac[Fp2[BLS12_381], G2])
+  test "Pallas - jacobian":
+    t_msm_small_coefs(EC_ShortW_Jac[Fp[Pallas], G1])
+  test "Vesta - projective":
+    t_msm_small_coefs(EC_ShortW_Prj[Fp[Vesta], G1])
+  test "Bandersnatch - projective":
+    t_msm_small_coefs(EC_TwEdw_Prj[Fp[Bandersnatch]])
@@ -0,0 +1,92 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+
+import
+  # Standard library
+  std/[times, unittest],
+  # Internals
+  constantine/platforms/abstractions,
+  constantine/named/[algebras, zoo_subgroups],
+  constantine/math/[arithmetic, extension_fields],
+  constantine/math/[ec_shortweierstrass, ec_twistededwards],
+  # Test utilities
+  helpers/prng_unsafe
+
+const Iters = 16
+
+var rng: RngState
+let timeseed = uint32(toUnix(getTime()) and (1'i64 shl 32 - 1)) # unixTime mod 2^32
+seed(rng, timeseed)
+echo "\n------------------------------------------------------\n"
+echo "test_ec_scalar_mul_fixed_base seed: ", timeseed
+
+proc t_fixed_base(EC: typedesc) =
+  type F = EC.getScalarField()
+
+  var base: affine(EC)
+  block:
+    var tmp = rng.random_unsafe(EC)
+    tmp.clearCofactor()
+    base.affine(tmp)
+  var gen: affine(EC)
+  gen.setGenerator()
+
+  var table, genTable: FixedBaseTable[affine(EC)]
+  table.precompute(base)
+  genTable.precompute(gen)
+
+  proc check(P: affine(EC), table: FixedBaseTable[affine(EC)], k: F) =
+    var expected {.noInit.}, r {.noInit.}: EC
+    expected.fromAffine(P)
+    expected.scalarMul(k.toBig())
+    r.scalarMul(table, k)
+    doAssert bool(expected == r)
+
+  for _ in 0 ..< Iters:
+    let k = rng.random_unsafe(F)
+    check(base, table, k)
+    check(gen, genTable, k)
+
+  block: # Zero, one and -1
+    var k: F
+    k.setZero()
+    check(base, table, k)
+    k.setOne()
+    check(base, table, k)
+    k.neg()
+    check(base, table, k)
+
+  block: # Scalars smaller than the table
+    let k = rng.random_unsafe(BigInt[64])
+    var expected {.noInit.}, r {.noInit.}: EC
+    expected.fromAffine(base)
+    expected.scalarMul(k)
+    r.scalarMul(table, k)
+    doAssert bool(expected == r)
+
+  table.delete()
+  genTable.delete()
+
+suite "Fixed-base scalar multiplication - [" & $WordBitWidth & "-bit mode]":
+  test "BN254_Snarks 𝔾1 - projective":
+    t_fixed_base(EC_ShortW_Prj[Fp[BN254_Snarks], G1])
+  test "BN254_Snarks 𝔾2 - jacobian":
+    t_fixed_base(EC_ShortW_Jac[Fp2[BN254_Snarks], G2])
+  test "BLS12_381 𝔾1 - jacobian":
+    t_fixed_base(EC_ShortW_Jac[Fp[BLS12_381], G1])
+  test "BLS12_381 𝔾2 - projective":
+    t_fixed_base(EC_ShortW_Prj[Fp2[BLS12_381], G2])
+  test "Pallas - jacobian":
+    t_fixed_base(EC_ShortW_Jac[Fp[Pallas], G1])
+  test "Vesta - projective":
+    t_fixed_base(EC_ShortW_Prj[Fp[Vesta], G1])
+  test "Secp256k1 - jacobian":
+    t_fixed_base(EC_ShortW_Jac[Fp[Secp256k1], G1])
+  test "Banderwagon - projective":
+    t_fixed_base(EC_TwEdw_Prj[Fp[Banderwagon]])
@@ -28,6 +28,12 @@ run_EC_addition_tests(
     moduleName = "test_ec_shortweierstrass_jacobian_g1_add_double_" & $Secp256k1
   )
 
+run_EC_addition_tests(
+    ec = EC_ShortW_Jac[Fp[P256], G1],
+    Iters = Iters,
+    moduleName = "test_ec_shortweierstrass_jacobian_g1_add_double_" & $P256
+  )
+
 run_EC_addition_tests(
     ec = EC_ShortW_Jac[Fp[BLS12_381], G1],
     Iters = Iters,
@@ -70,6 +76,12 @@ run_EC_addition_vartime_tests(
     moduleName = "test_ec_shortweierstrass_jacobian_g1_add_double_vartime_" & $Secp256k1
   )
 
+run_EC_addition_vartime_tests(
+    ec = EC_ShortW_Jac[Fp[P256], G1],
+    Iters = Iters,
+    moduleName = "test_ec_shortweierstrass_jacobian_g1_add_double_vartime_" & $P256
+  )
+
 run_EC_addition_vartime_tests(
     ec = EC_ShortW_Jac[Fp[BLS12_381], G1],
     Iters = Iters,
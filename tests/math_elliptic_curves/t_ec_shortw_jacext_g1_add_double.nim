@@ -28,6 +28,12 @@ run_EC_addition_tests(
     moduleName = "test_ec_shortweierstrass_jacobian_extended_g1_add_double_" & $Secp256k1
   )
 
+run_EC_addition_tests(
+    ec = EC_ShortW_JacExt[Fp[P256], G1],
+    Iters = Iters,
+    moduleName = "test_ec_shortweierstrass_jacobian_extended_g1_add_double_" & $P256
+  )
+
 run_EC_addition_tests(
     ec = EC_ShortW_JacExt[Fp[BLS12_381], G1],
     Iters = Iters,
@@ -0,0 +1,74 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  # Standard library
+  std/[times, unittest],
+  # Internals
+  constantine/platforms/abstractions,
+  constantine/math/arithmetic,
+  constantine/named/algebras,
+  constantine/math/ec_shortweierstrass,
+  constantine/math/polynomials/fft,
+  # Test utilities
+  helpers/prng_unsafe
+
+const MaxLogN = 5
+
+var rng: RngState
+let timeseed = uint32(toUnix(getTime()) and (1'i64 shl 32 - 1)) # unixTime mod 2^32
+seed(rng, timeseed)
+echo "\n------------------------------------------------------\n"
+echo "test_ec_fft seed: ", timeseed
+
+func primitiveRootOfUnity(F: typedesc, logN: int): F =
+  ## Returns a primitive root of unity of order 2^logN
+  ## c^((r-1)/2^logN) is primitive if and only if c^((r-1)/2) = -1
+  if logN == 0:
+    result.setOne()
+    return
+  var e = F.getModulus()
+  e.shiftRight(logN) # r-1 is divisible by 2^logN, the low bit of r is discarded
+  var c = 2'u64
+  while true:
+    result.fromUint(c)
+    result.pow_vartime(e)
+    var check = result
+    check.square_repeated(logN-1)
+    if check.isMinusOne().bool:
+      return
+    c += 1
+
+proc t_ec_fft_roundtrip(EC: typedesc) =
+  let fftDesc = ECFFT_Descriptor[EC].new(
+    order = 1 shl MaxLogN,
+    Fr[EC.getName()].primitiveRootOfUnity(MaxLogN))
+  defer: fftDesc.delete()
+
+  for logN in 0 .. MaxLogN:
+    let n = 1 shl logN
+
+    var data = newSeq[EC](n)
+    for i in 0 ..< n:
+      data[i] = rng.random_unsafe(EC)
+
+    var coefs = newSeq[EC](n)
+    doAssert fft_vartime(fftDesc, coefs, data) == FFTS_Success
+
+    # The inverse FFT scales by 1/n modulo the curve order
+    var res = newSeq[EC](n)
+    doAssert ifft_vartime(fftDesc, res, coefs) == FFTS_Success
+
+    for i in 0 ..< n:
+      doAssert bool(res[i] == data[i]), "EC FFT round-trip failure at size " & $n & " index " & $i
+
+suite "Elliptic curve FFT":
+  test "FFT and inverse FFT round-trip - BLS12_381 G1":
+    t_ec_fft_roundtrip(EC_ShortW_Prj[Fp[BLS12_381], G1])
+  test "FFT and inverse FFT round-trip - BN254_Snarks G1":
+    t_ec_fft_roundtrip(EC_ShortW_Prj[Fp[BN254_Snarks], G1])
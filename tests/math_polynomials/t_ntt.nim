@@ -0,0 +1,124 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  # Standard library
+  std/[times, unittest],
+  # Internals
+  constantine/platforms/abstractions,
+  constantine/math/arithmetic,
+  constantine/named/algebras,
+  constantine/math/polynomials/fft,
+  constantine/math/io/io_fields,
+  # Test utilities
+  helpers/prng_unsafe
+
+const MaxLogN = 7
+
+var rng: RngState
+let timeseed = uint32(toUnix(getTime()) and (1'i64 shl 32 - 1)) # unixTime mod 2^32
+seed(rng, timeseed)
+echo "\n------------------------------------------------------\n"
+echo "test_ntt seed: ", timeseed
+
+func primitiveRootOfUnity(F: typedesc, logN: int): F =
+  ## Returns a primitive root of unity of order 2^logN
+  ## c^((r-1)/2^logN) is primitive if and only if c^((r-1)/2) = -1
+  if logN == 0:
+    result.setOne()
+    return
+  var e = F.getModulus()
+  e.shiftRight(logN) # r-1 is divisible by 2^logN, the low bit of r is discarded
+  var c = 2'u64
+  while true:
+    result.fromUint(c)
+    result.pow_vartime(e)
+    var check = result
+    check.square_repeated(logN-1)
+    if check.isMinusOne().bool:
+      return
+    c += 1
+
+func naiveDFT[F](output: var openArray[F], values: openArray[F], omega, shift: F) =
+  ## output[i] = ∑ⱼ values[j]·(shift·ωⁱ)ʲ
+  var x = shift
+  for i in 0 ..< output.len:
+    var xj {.noInit.}: F
+    xj.setOne()
+    output[i].setZero()
+    for j in 0 ..< values.len:
+      var t {.noInit.}: F
+      t.prod(values[j], xj)
+      output[i] += t
+      xj *= x
+    x *= omega
+
+func isEqual[F](a, b: seq[F]): bool =
+  if a.len != b.len:
+    return false
+  for i in 0 ..< a.len:
+    if not bool(a[i] == b[i]):
+      return false
+  return true
+
+proc t_ntt(F: typedesc) =
+  for logN in 0 .. MaxLogN:
+    let n = 1 shl logN
+    let omega = F.primitiveRootOfUnity(logN)
+    let shift = rng.random_unsafe(F)
+
+    var values = newSeq[F](n)
+    rng.random_unsafe(values)
+
+    var one {.noInit.}: F
+    one.setOne()
+
+    # NTT
+    var expected = newSeq[F](n)
+    expected.naiveDFT(values, omega, one)
+    var ntt = values
+    doAssert ntt.ntt_vartime(omega) == FFTS_Success
+    doAssert ntt.isEqual(expected)
+
+    # Inverse NTT
+    doAssert ntt.intt_vartime(omega) == FFTS_Success
+    doAssert ntt.isEqual(values)
+
+    # Coset NTT
+    expected.naiveDFT(values, omega, shift)
+    var coset = values
+    doAssert coset.coset_ntt_vartime(omega, shift) == FFTS_Success
+    doAssert coset.isEqual(expected)
+
+    # Inverse coset NTT
+    doAssert coset.coset_intt_vartime(omega, shift) == FFTS_Success
+    doAssert coset.isEqual(values)
+
+suite "Number Theoretic Transform":
+  test "NTT, inverse NTT and coset NTT vs naive DFT - BN254_Snarks Fr":
+    t_ntt(Fr[BN254_Snarks])
+  test "NTT, inverse NTT and coset NTT vs naive DFT - Pallas Fr":
+    t_ntt(Fr[Pallas])
+  test "NTT, inverse NTT and coset NTT vs naive DFT - Vesta Fr":
+    t_ntt(Fr[Vesta])
+
+  test "NTT rejects sizes that are not a power of 2":
+    var values = newSeq[Fr[BN254_Snarks]](3)
+    let omega = Fr[BN254_Snarks].primitiveRootOfUnity(2)
+    doAssert values.ntt_vartime(omega) == FFTS_SizeNotPowerOfTwo
+    doAssert values.intt_vartime(omega) == FFTS_SizeNotPowerOfTwo
+    doAssert values.coset_ntt_vartime(omega, omega) == FFTS_SizeNotPowerOfTwo
+    doAssert values.coset_intt_vartime(omega, omega) == FFTS_SizeNotPowerOfTwo
+
+  test "NTT rejects empty inputs":
+    var values: seq[Fr[BN254_Snarks]]
+    let omega = Fr[BN254_Snarks].primitiveRootOfUnity(0)
+    doAssert values.ntt_vartime(omega) == FFTS_SizeNotPowerOfTwo
+    doAssert values.intt_vartime(omega) == FFTS_SizeNotPowerOfTwo
+    doAssert values.coset_ntt_vartime(omega, omega) == FFTS_SizeNotPowerOfTwo
+    doAssert values.coset_intt_vartime(omega, omega) == FFTS_SizeNotPowerOfTwo
@@ -0,0 +1,51 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  # Internals
+  constantine/named/algebras,
+  constantine/math/elliptic/[
+    ec_shortweierstrass_jacobian,
+    ec_shortweierstrass_projective,
+    ec_twistededwards_projective],
+  constantine/math/[arithmetic, extension_fields],
+  # Test utilities
+  ./t_ec_template_parallel
+
+# Chunks hold between 1024 and 8192 points
+const numPoints = [1, 2, 16, 1023, 1024, 4096, 16384, 40000]
+
+run_EC_batch_affine_parallel_impl(
+    ec = EC_ShortW_Jac[Fp[BN254_Snarks], G1],
+    numPoints = numPoints,
+    moduleName = "test_ec_batch_affine_parallel_" & $BN254_Snarks
+  )
+
+run_EC_batch_affine_parallel_impl(
+    ec = EC_ShortW_Prj[Fp[BLS12_381], G1],
+    numPoints = numPoints,
+    moduleName = "test_ec_batch_affine_parallel_" & $BLS12_381
+  )
+
+run_EC_batch_affine_parallel_impl(
+    ec = EC_ShortW_Prj[Fp2[BLS12_381], G2],
+    numPoints = numPoints,
+    moduleName = "test_ec_batch_affine_parallel_" & $BLS12_381
+  )
+
+run_EC_batch_affine_parallel_impl(
+    ec = EC_ShortW_Jac[Fp[Pallas], G1],
+    numPoints = numPoints,
+    moduleName = "test_ec_batch_affine_parallel_" & $Pallas
+  )
+
+run_EC_batch_affine_parallel_impl(
+    ec = EC_TwEdw_Prj[Fp[Banderwagon]],
+    numPoints = numPoints,
+    moduleName = "test_ec_batch_affine_parallel_" & $Banderwagon
+  )
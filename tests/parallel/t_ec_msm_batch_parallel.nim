@@ -0,0 +1,80 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  # Standard library
+  std/[times, unittest],
+  # Internals
+  constantine/platforms/abstractions,
+  constantine/named/[algebras, zoo_subgroups],
+  constantine/math/arithmetic,
+  constantine/math/elliptic/[
+    ec_shortweierstrass_affine,
+    ec_shortweierstrass_jacobian,
+    ec_shortweierstrass_projective,
+    ec_multi_scalar_mul,
+    ec_multi_scalar_mul_precomp_parallel],
+  constantine/threadpool/threadpool,
+  # Test utilities
+  helpers/prng_unsafe
+
+const numPoints = [1, 2, 8, 128, 1024]
+const numMSMs = [1, 3, 10] # Below and above the threshold for sharing a table of the bases
+
+var rng: RngState
+let timeseed = uint32(toUnix(getTime()) and (1'i64 shl 32 - 1)) # unixTime mod 2^32
+seed(rng, timeseed)
+echo "\n------------------------------------------------------\n"
+echo "test_ec_msm_batch_parallel seed: ", timeseed
+
+proc t_msm_batch_parallel(EC: typedesc) =
+  type F = EC.getScalarField()
+  let tp = Threadpool.new()
+  defer: tp.shutdown()
+
+  for n in numPoints:
+    var points = newSeq[affine(EC)](n)
+    for i in 0 ..< n:
+      var tmp = rng.random_unsafe(EC)
+      tmp.clearCofactor()
+      points[i].affine(tmp)
+
+    var table: MsmBasesTable[affine(EC)]
+    tp.precompute_parallel(table, points.asUnchecked(), n)
+
+    for m in numMSMs:
+      var coefs = newSeq[seq[F]](m)
+      var coefsRows = newSeq[ptr UncheckedArray[F]](m)
+      var expected = newSeq[EC](m)
+      for k in 0 ..< m:
+        coefs[k] = newSeq[F](n)
+        rng.random_unsafe(coefs[k])
+        coefsRows[k] = coefs[k].asUnchecked()
+        expected[k].multiScalarMul_vartime(coefs[k], points)
+
+      var batch = newSeq[EC](m)
+      tp.multiScalarMul_batch_vartime_parallel(batch.asUnchecked(), coefsRows.asUnchecked(), m, points.asUnchecked(), n)
+      for k in 0 ..< m:
+        doAssert bool(expected[k] == batch[k])
+
+      var batchTable = newSeq[EC](m)
+      tp.multiScalarMul_batch_vartime_parallel(batchTable.asUnchecked(), coefsRows.asUnchecked(), m, table.addr, n)
+      for k in 0 ..< m:
+        doAssert bool(expected[k] == batchTable[k])
+
+    table.delete()
+
+suite "Batch of parallel multi-scalar-multiplications - [" & $WordBitWidth & "-bit mode]":
+  test "BN254_Snarks 𝔾1 - projective":
+    t_msm_batch_parallel(EC_ShortW_Prj[Fp[BN254_Snarks], G1])
+  test "BLS12_381 𝔾1 - jacobian":
+    t_msm_batch_parallel(EC_ShortW_Jac[Fp[BLS12_381], G1])
+  test "Pallas - jacobian":
+    t_msm_batch_parallel(EC_ShortW_Jac[Fp[Pallas], G1])
+  test "Vesta - projective":
+    t_msm_batch_parallel(EC_ShortW_Prj[Fp[Vesta], G1])
@@ -0,0 +1,42 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+
+import
+  # Internals
+  constantine/named/algebras,
+  constantine/math/ec_shortweierstrass,
+  constantine/math/[arithmetic, extension_fields],
+  # Test utilities
+  ./t_ec_template_parallel
+
+const numPoints = [1, 2, 3, 4, 5, 6, 7, 8, 16, 128, 1024]
+
+run_EC_multi_scalar_mul_parallel_impl(
+    ec = EC_ShortW_Prj[Fp2[BN254_Snarks], G2],
+    numPoints = numPoints,
+    moduleName = "test_ec_shortweierstrass_projective_g2_msm_parallel_" & $BN254_Snarks
+  )
+
+run_EC_multi_scalar_mul_parallel_impl(
+    ec = EC_ShortW_Prj[Fp2[BLS12_381], G2],
+    numPoints = numPoints,
+    moduleName = "test_ec_shortweierstrass_projective_g2_msm_parallel_" & $BLS12_381
+  )
+
+run_EC_multi_scalar_mul_table_parallel_impl(
+    ec = EC_ShortW_Prj[Fp2[BN254_Snarks], G2],
+    numPoints = numPoints,
+    moduleName = "test_ec_shortweierstrass_projective_g2_msm_table_parallel_" & $BN254_Snarks
+  )
+
+run_EC_multi_scalar_mul_table_parallel_impl(
+    ec = EC_ShortW_Prj[Fp2[BLS12_381], G2],
+    numPoints = numPoints,
+    moduleName = "test_ec_shortweierstrass_projective_g2_msm_table_parallel_" & $BLS12_381
+  )
@@ -25,10 +25,14 @@ import
     ec_shortweierstrass_projective,
     ec_twistededwards_affine,
     ec_twistededwards_projective,
+    ec_shortweierstrass_batch_ops,
+    ec_twistededwards_batch_ops,
     ec_shortweierstrass_batch_ops_parallel,
+    ec_twistededwards_batch_ops_parallel,
     ec_scalar_mul,
     ec_multi_scalar_mul,
-    ec_multi_scalar_mul_parallel],
+    ec_multi_scalar_mul_parallel,
+    ec_multi_scalar_mul_precomp_parallel],
   constantine/threadpool/threadpool,
   # Test utilities
   helpers/prng_unsafe
@@ -146,6 +150,69 @@ proc run_EC_batch_add_parallel_impl*[N: static int](
         test(ec, gen = Long01Sequence)
 
 
+proc run_EC_batch_affine_parallel_impl*[N: static int](
+       ec: typedesc,
+       numPoints: array[N, int],
+       moduleName: string) =
+
+  # Random seed for reproducibility
+  var rng: RngState
+  let seed = uint32(getTime().toUnix() and (1'i64 shl 32 - 1)) # unixTime mod 2^32
+  rng.seed(seed)
+  echo "\n------------------------------------------------------\n"
+  echo moduleName, " xoshiro512** seed: ", seed
+
+  const testSuiteDesc = "Elliptic curve parallel batch affine conversion"
+
+  suite testSuiteDesc & " - " & $ec & " - [" & $WordBitWidth & "-bit mode]":
+
+    for n in numPoints:
+      test $ec & " parallel batch affine (N=" & $n & ")":
+        proc test(EC: typedesc, gen: RandomGen) =
+          let tp = Threadpool.new()
+          defer: tp.shutdown()
+
+          var points = newSeq[EC](n)
+          for i in 0 ..< n:
+            points[i] = rng.random_point(EC, randZ = true, gen)
+          if n >= 2:
+            points[1].setNeutral()
+
+          var r_batch = newSeq[affine(EC)](n)
+          var r_ref = newSeq[affine(EC)](n)
+          for i in 0 ..< n:
+            r_ref[i].affine(points[i])
+
+          tp.batchAffine_parallel(r_batch.asUnchecked(), points.asUnchecked(), n)
+
+          for i in 0 ..< n:
+            check: bool(r_batch[i] == r_ref[i])
+
+        test(ec, gen = Uniform)
+        test(ec, gen = HighHammingWeight)
+        test(ec, gen = Long01Sequence)
+
+      test $ec & " parallel sum reduction (N=" & $n & ")":
+        proc test(EC: typedesc, gen: RandomGen) =
+          let tp = Threadpool.new()
+          defer: tp.shutdown()
+
+          var points = newSeq[affine(EC)](n)
+          for i in 0 ..< n:
+            points[i] = rng.random_point(affine(EC), randZ = false, gen)
+
+          var r_batch{.noinit.}, r_ref{.noInit.}: EC
+
+          r_ref.setNeutral()
+          for i in 0 ..< n:
+            r_ref += points[i]
+
+          tp.sum_reduce_vartime_parallel(r_batch, points)
+
+          check: bool(r_batch == r_ref)
+
+        test(ec, gen = Uniform)
+
 proc run_EC_multi_scalar_mul_parallel_impl*[N: static int](
        ec: typedesc,
        numPoints: array[N, int],
@@ -190,3 +257,52 @@ proc run_EC_multi_scalar_mul_parallel_impl*[N: static int](
         test(ec, gen = Uniform)
         test(ec, gen = HighHammingWeight)
         test(ec, gen = Long01Sequence)
+
+proc run_EC_multi_scalar_mul_table_parallel_impl*[N: static int](
+       ec: typedesc,
+       numPoints: array[N, int],
+       moduleName: string) =
+  # Random seed for reproducibility
+  var rng: RngState
+  let seed = uint32(getTime().toUnix() and (1'i64 shl 32 - 1)) # unixTime mod 2^32
+  rng.seed(seed)
+  echo "\n------------------------------------------------------\n"
+  echo moduleName, " xoshiro512** seed: ", seed
+
+  const testSuiteDesc = "Elliptic curve parallel multi-scalar-multiplication with precomputed bases"
+
+  suite testSuiteDesc & " - " & $ec & " - [" & $WordBitWidth & "-bit mode]":
+    for n in numPoints:
+      test $ec & " Parallel Multi-scalar-mul with precomputed bases (N=" & $n & ")":
+        proc test(EC: typedesc, gen: RandomGen) =
+          let tp = Threadpool.new()
+          defer: tp.shutdown()
+          var points = newSeq[affine(EC)](n)
+          var coefs = newSeq[EC.getScalarField()](n)
+
+          for i in 0 ..< n:
+            var tmp = rng.random_unsafe(EC)
+            tmp.clearCofactor()
+            points[i].affine(tmp)
+            coefs[i] = rng.random_unsafe(EC.getScalarField())
+
+          var table: MsmBasesTable[affine(EC)]
+          tp.precompute_parallel(table, points.asUnchecked(), n)
+          defer: table.delete()
+          doAssert table.len == n
+
+          var msm, msm_table: EC
+          tp.multiScalarMul_vartime_parallel(msm, coefs, points)
+          tp.multiScalarMul_vartime_parallel(msm_table.addr, coefs.asUnchecked(), table.addr, n)
+          doAssert bool(msm == msm_table)
+
+          # A prefix of the bases
+          let half = n div 2
+          if half > 0:
+            tp.multiScalarMul_vartime_parallel(msm, coefs.toOpenArray(0, half-1), points.toOpenArray(0, half-1))
+            tp.multiScalarMul_vartime_parallel(msm_table.addr, coefs.asUnchecked(), table.addr, half)
+            doAssert bool(msm == msm_table)
+
+        test(ec, gen = Uniform)
+        test(ec, gen = HighHammingWeight)
+        test(ec, gen = Long01Sequence)
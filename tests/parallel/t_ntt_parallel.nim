@@ -0,0 +1,103 @@
+# Constantine
+# Copyright (c) 2018-2019    Status Research & Development GmbH
+# Copyright (c) 2020-Present Mamy André-Ratsimbazafy
+# Licensed and distributed under either of
+#   * MIT license (license terms in the root directory or at http://opensource.org/licenses/MIT).
+#   * Apache v2 license (license terms in the root directory or at http://www.apache.org/licenses/LICENSE-2.0).
+# at your option. This file may not be copied, modified, or distributed except according to those terms.
+
+import
+  # Standard library
+  std/[times, unittest],
+  # Internals
+  constantine/platforms/abstractions,
+  constantine/math/arithmetic,
+  constantine/named/algebras,
+  constantine/math/polynomials/fft_parallel,
+  constantine/math/io/io_fields,
+  constantine/threadpool,
+  # Test utilities
+  helpers/prng_unsafe
+
+const MaxLogN = 12
+
+var rng: RngState
+let timeseed = uint32(toUnix(getTime()) and (1'i64 shl 32 - 1)) # unixTime mod 2^32
+seed(rng, timeseed)
+echo "\n------------------------------------------------------\n"
+echo "test_ntt_parallel seed: ", timeseed
+
+func primitiveRootOfUnity(F: typedesc, logN: int): F =
+  ## Returns a primitive root of unity of order 2^logN
+  ## c^((r-1)/2^logN) is primitive if and only if c^((r-1)/2) = -1
+  if logN == 0:
+    result.setOne()
+    return
+  var e = F.getModulus()
+  e.shiftRight(logN) # r-1 is divisible by 2^logN, the low bit of r is discarded
+  var c = 2'u64
+  while true:
+    result.fromUint(c)
+    result.pow_vartime(e)
+    var check = result
+    check.square_repeated(logN-1)
+    if check.isMinusOne().bool:
+      return
+    c += 1
+
+func isEqual[F](a, b: seq[F]): bool =
+  if a.len != b.len:
+    return false
+  for i in 0 ..< a.len:
+    if not bool(a[i] == b[i]):
+      return false
+  return true
+
+proc t_ntt_parallel(F: typedesc) =
+  let tp = Threadpool.new()
+  defer: tp.shutdown()
+
+  for logN in 0 .. MaxLogN:
+    let n = 1 shl logN
+    let omega = F.primitiveRootOfUnity(logN)
+    let shift = rng.random_unsafe(F)
+
+    var values = newSeq[F](n)
+    rng.random_unsafe(values)
+
+    var serial = values
+    var par = values
+    doAssert serial.ntt_vartime(omega) == FFTS_Success
+    doAssert tp.ntt_vartime_parallel(par.asUnchecked(), n, omega) == FFTS_Success
+    doAssert par.isEqual(serial)
+
+    doAssert tp.intt_vartime_parallel(par.asUnchecked(), n, omega) == FFTS_Success
+    doAssert par.isEqual(values)
+
+    serial = values
+    doAssert serial.coset_ntt_vartime(omega, shift) == FFTS_Success
+    doAssert tp.coset_ntt_vartime_parallel(par.asUnchecked(), n, omega, shift) == FFTS_Success
+    doAssert par.isEqual(serial)
+
+    doAssert tp.coset_intt_vartime_parallel(par.asUnchecked(), n, omega, shift) == FFTS_Success
+    doAssert par.isEqual(values)
+
+suite "Number Theoretic Transform - Parallel":
+  test "Parallel NTT, inverse NTT and coset NTT vs serial - BN254_Snarks Fr":
+    t_ntt_parallel(Fr[BN254_Snarks])
+  test "Parallel NTT, inverse NTT and coset NTT vs serial - Pallas Fr":
+    t_ntt_parallel(Fr[Pallas])
+  test "Parallel NTT, inverse NTT and coset NTT vs serial - Vesta Fr":
+    t_ntt_parallel(Fr[Vesta])
+
+  test "Parallel NTT rejects empty inputs like the serial NTT":
+    let tp = Threadpool.new()
+    defer: tp.shutdown()
+
+    var values: seq[Fr[BN254_Snarks]]
+    let omega = Fr[BN254_Snarks].primitiveRootOfUnity(0)
+    doAssert values.ntt_vartime(omega) == FFTS_SizeNotPowerOfTwo
+    doAssert tp.ntt_vartime_parallel(nil, 0, omega) == FFTS_SizeNotPowerOfTwo
+    doAssert tp.intt_vartime_parallel(nil, 0, omega) == FFTS_SizeNotPowerOfTwo
+    doAssert tp.coset_ntt_vartime_parallel(nil, 0, omega, omega) == FFTS_SizeNotPowerOfTwo
+    doAssert tp.coset_intt_vartime_parallel(nil, 0, omega, omega) == FFTS_SizeNotPowerOfTwo
@@ -18,6 +18,7 @@ import
 # Test vector source:
 # - https://github.com/ethereum/go-ethereum/tree/release/1.14/core/vm/testdata/precompiles
 # - https://github.com/ethereum/EIPs/tree/3b5fcad/assets/eip-2537
+# - https://github.com/C2SP/wycheproof (P256VERIFY, see protocol_ethereum_evm_precompiles/README.md)
 
 type
   HexString = string
@@ -158,11 +159,33 @@ proc testRipemd160() =
 
   stdout.write "Success\n"
 
+proc testPaddingFailure() =
+  echo "Running EIP-2537 padding failure tests"
+  stdout.write "    Testing non-zero last padding byte ... "
+
+  # Only the 16th byte of the padding of the second coordinate is non-zero
+  var inputbytes = newSeq[byte](128)
+  inputbytes[64 + 15] = byte 1
+
+  var r = newSeq[byte](256)
+  var failure: CttEVMFailure
+  let status = eth_evm_bls12381_map_fp2_to_g2_detailed(r, inputbytes, failure)
+
+  doAssert status == cttEVM_IntLargerThanModulus, "[Test Failure]\n" &
+    "  eth_evm_bls12381_map_fp2_to_g2_detailed status: " & $status & '\n'
+  doAssert failure.reason == cttEVMFail_NonZeroPadding, "[Test Failure]\n" &
+    "  failure reason: " & $failure.reason & '\n'
+  doAssert failure.offset == 64, "[Test Failure]\n" &
+    "  failure offset: " & $failure.offset & '\n'
+
+  stdout.write "Success\n"
+
 
 # ----------------------------------------------------------------------
 
 testSha256()
 testRipemd160()
+testPaddingFailure()
 
 runPrecompileTests("modexp.json", eth_evm_modexp, 0)
 runPrecompileTests("modexp_eip2565.json", eth_evm_modexp, 0)
@@ -171,6 +194,9 @@ runPrecompileTests("bn256Add.json", eth_evm_bn254_g1add, 64)
 runPrecompileTests("bn256ScalarMul.json", eth_evm_bn254_g1mul, 64)
 runPrecompileTests("bn256Pairing.json", eth_evm_bn254_ecpairingcheck, 32)
 
+runPrecompileTests("blake2F.json", eth_evm_blake2f, 64)
+runPrecompileTests("fail-blake2f.json", eth_evm_blake2f, 64)
+
 runPrecompileTests("eip-2537/add_G1_bls.json", eth_evm_bls12381_g1add, 128)
 runPrecompileTests("eip-2537/fail-add_G1_bls.json", eth_evm_bls12381_g1add, 128)
 runPrecompileTests("eip-2537/add_G2_bls.json", eth_evm_bls12381_g2add, 256)
@@ -197,3 +223,5 @@ runPrecompileTests("eip-2537/fail-map_fp2_to_G2_bls.json", eth_evm_bls12381_map_
 runPrecompileTests("eip-4844/pointEvaluation.json", eth_evm_kzg_point_evaluation, 64, true)
 
 runPrecompileTests("ecRecover.json", eth_evm_ecrecover, 32)
+runPrecompileTests("p256Verify.json", eth_evm_p256verify, 32)
+runPrecompileTests("p256Verify_edge_cases.json", eth_evm_p256verify, 32)
@@ -52,6 +52,14 @@ proc t_sha3_256_empty =
   sha3_256.hash(bufCt, msg)
   doAssert bufCt == hashed
 
+proc t_sha3_256_empty_streaming =
+  var bufCt: array[32, byte]
+  let hashed = array[32, byte].fromHex("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a")
+  var ctx {.noInit.}: sha3_256
+  ctx.init()
+  ctx.finish(bufCt)
+  doAssert bufCt == hashed
+
 proc t_sha3_256_abc =
   var bufCt: array[32, byte]
   let msg = "abc"
@@ -80,6 +88,14 @@ proc t_keccak256_empty =
   keccak256.hash(bufCt, msg)
   doAssert bufCt == hashed
 
+proc t_keccak256_empty_streaming =
+  var bufCt: array[32, byte]
+  let hashed = array[32, byte].fromHex("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+  var ctx {.noInit.}: keccak256
+  ctx.init()
+  ctx.finish(bufCt)
+  doAssert bufCt == hashed
+
 proc t_keccak256_abc =
   var bufCt: array[32, byte]
   let msg = "abc"
@@ -118,15 +134,6 @@ when not defined(windows) and not defined(macosx):
     SHA3_256_OpenSSL(bufOssl, msg)
     doAssert bufCt == bufOssl, "Test failed with message of length " & $size
 
-template doWhile(a: bool, b: untyped): untyped =
-  ## For Keccak / SHA-3, an update MUST be called
-  ## before finish, hence we need do while loop
-  ## for empty inputs
-  while true:
-    b
-    if not a:
-      break
-
 proc chunkTest(rng: var RngState, sizeRange: Slice[int]) =
   let size = rng.random_unsafe(sizeRange)
   let msg = rng.random_byte_seq(size)
@@ -140,7 +147,7 @@ proc chunkTest(rng: var RngState, sizeRange: Slice[int]) =
   var ctx {.noInit.}: sha3_256
   ctx.init()
   var cur = 0
-  doWhile size - cur > 0:
+  while size - cur > 0:
     let chunkSize = rng.random_unsafe(0 ..< maxChunk)
     let len = min(chunkSize, size-cur)
     let consumed = len
@@ -159,10 +166,12 @@ proc main() =
   echo "\n------------------------------------------------------\n"
   echo "SHA3-256 & Keccak256 - sanity checks"
   t_sha3_256_empty()
+  t_sha3_256_empty_streaming()
   t_sha3_256_abc()
   t_sha3_256_abcdef0123456789()
   t_sha3_256_abc_long()
   t_keccak256_empty()
+  t_keccak256_empty_streaming()
   t_keccak256_abc()
   t_keccak256_abcdef0123456789()
   t_keccak256_abclong()
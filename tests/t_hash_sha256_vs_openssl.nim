@@ -84,6 +84,19 @@ proc chunkTest(rng: var RngState, sizeRange: Slice[int]) =
 
   doAssert bufOnePass == bufChunked
 
+proc pairsTest(rng: var RngState, count: int) =
+  var pairs = newSeq[array[64, byte]](count)
+  for i in 0 ..< count:
+    rng.random_unsafe(pairs[i])
+
+  var digests = newSeq[array[32, byte]](count)
+  sha256.hash_64byte_pairs(digests, pairs)
+
+  for i in 0 ..< count:
+    var bufOnePass: array[32, byte]
+    sha256.hash(bufOnePass, pairs[i])
+    doAssert digests[i] == bufOnePass, "Test failed for pair " & $i & "/" & $count
+
 # --------------------------------------------------------------------
 
 proc main() =
@@ -108,6 +121,10 @@ proc main() =
   for i in 0 ..< 64:
     rng.chunkTest(i .. i)
 
+  echo "SHA256 - 64-byte pairs (backend: " & $sha256.backend() & ")"
+  for count in [0, 1, 2, 3, 100]:
+    rng.pairsTest(count)
+
   echo "SHA256 - 64 <= size < 1024B - chunked"
   for _ in 0 ..< SmallSizeIters:
     rng.chunkTest(0 ..< 1024)